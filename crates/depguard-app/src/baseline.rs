@@ -91,10 +91,13 @@ pub fn generate_baseline(report: &ReportVariant) -> DepguardBaselineV1 {
 }
 
 /// Apply baseline suppression and recompute verdict/counts.
+///
+/// `verdict_ignores` lists severities that do not count toward the recomputed verdict.
 pub fn apply_baseline(
     report: &mut ReportVariant,
     baseline: &DepguardBaselineV1,
     fail_on: FailOn,
+    verdict_ignores: &[Severity],
 ) -> BaselineApplyResult {
     let suppressions = baseline_fingerprint_set(baseline);
 
@@ -118,7 +121,7 @@ pub fn apply_baseline(
             });
 
             let suppressed = (before - r.findings.len()) as u32;
-            r.verdict = verdict_from_v1_findings(&r.findings, fail_on);
+            r.verdict = verdict_from_v1_findings(&r.findings, fail_on, verdict_ignores);
             r.data.findings_emitted = r.findings.len() as u32;
 
             BaselineApplyResult {
@@ -135,12 +138,15 @@ pub fn apply_baseline(
 
             let suppressed = (before - r.findings.len()) as u32;
             let (info, warn, error) = counts_from_v2_findings(&r.findings);
-            r.verdict.status = verdict_from_v2_findings(&r.findings, fail_on);
+            r.verdict.status = verdict_from_v2_findings(&r.findings, fail_on, verdict_ignores);
             r.verdict.counts.info = info;
             r.verdict.counts.warn = warn;
             r.verdict.counts.error = error;
             r.verdict.counts.suppressed = r.verdict.counts.suppressed.saturating_add(suppressed);
-            r.verdict.reasons.clear();
+            // Truncation happened before the baseline and still applies to the run.
+            r.verdict
+                .reasons
+                .retain(|reason| reason == ids::REASON_FINDINGS_TRUNCATED);
             r.data.findings_emitted = r.findings.len() as u32;
 
             BaselineApplyResult {
@@ -270,7 +276,7 @@ fn finding_key_v2(finding: &FindingV2) -> String {
     })
 }
 
-fn legacy_finding_key(
+pub(crate) fn legacy_finding_key(
     check_id: &str,
     code: &str,
     location: Option<(&str, Option<u32>, Option<u32>)>,
@@ -283,13 +289,19 @@ fn legacy_finding_key(
     )
 }
 
-fn verdict_from_v1_findings(findings: &[Finding], fail_on: FailOn) -> Verdict {
-    let has_error = findings.iter().any(|f| f.severity == Severity::Error);
-    if has_error {
+fn verdict_from_v1_findings(
+    findings: &[Finding],
+    fail_on: FailOn,
+    ignores: &[Severity],
+) -> Verdict {
+    let counts = |severity: Severity| {
+        !ignores.contains(&severity) && findings.iter().any(|f| f.severity == severity)
+    };
+    if counts(Severity::Error) {
         return Verdict::Fail;
     }
 
-    let has_warn = findings.iter().any(|f| f.severity == Severity::Warning);
+    let has_warn = counts(Severity::Warning);
     if has_warn {
         return match fail_on {
             FailOn::Error => Verdict::Warn,
@@ -300,13 +312,19 @@ fn verdict_from_v1_findings(findings: &[Finding], fail_on: FailOn) -> Verdict {
     Verdict::Pass
 }
 
-fn verdict_from_v2_findings(findings: &[FindingV2], fail_on: FailOn) -> VerdictStatus {
-    let has_error = findings.iter().any(|f| f.severity == SeverityV2::Error);
-    if has_error {
+fn verdict_from_v2_findings(
+    findings: &[FindingV2],
+    fail_on: FailOn,
+    ignores: &[Severity],
+) -> VerdictStatus {
+    let counts = |severity: Severity, v2: SeverityV2| {
+        !ignores.contains(&severity) && findings.iter().any(|f| f.severity == v2)
+    };
+    if counts(Severity::Error, SeverityV2::Error) {
         return VerdictStatus::Fail;
     }
 
-    let has_warn = findings.iter().any(|f| f.severity == SeverityV2::Warn);
+    let has_warn = counts(Severity::Warning, SeverityV2::Warn);
     if has_warn {
         return match fail_on {
             FailOn::Error => VerdictStatus::Warn,
@@ -418,6 +436,7 @@ mod tests {
                 warn: 1,
                 error: 1,
                 suppressed: 0,
+                truncated: 0,
             },
             reasons: vec!["old_reason".to_string()],
         };
@@ -467,7 +486,7 @@ mod tests {
             findings: Vec::new(),
         };
 
-        let stats = apply_baseline(&mut report, &baseline, FailOn::Error);
+        let stats = apply_baseline(&mut report, &baseline, FailOn::Error, &[]);
         assert_eq!(
             stats,
             BaselineApplyResult {
@@ -579,7 +598,7 @@ mod tests {
             findings: Vec::new(),
         };
 
-        let stats = apply_baseline(&mut report, &baseline, FailOn::Error);
+        let stats = apply_baseline(&mut report, &baseline, FailOn::Error, &[]);
         assert_eq!(stats.suppressed, 0);
         assert_eq!(stats.remaining, 1);
     }
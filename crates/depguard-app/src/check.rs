@@ -3,8 +3,10 @@
 use anyhow::Context;
 use camino::Utf8Path;
 use depguard::policy::Scope as DomainScope;
+use depguard::report::FindingsSummary;
 use depguard_repo::ScopeInput;
 use depguard_settings::{Overrides, ResolvedConfig};
+use depguard_types::explain::lookup_explanation;
 use depguard_types::{
     Capabilities, CapabilityAvailability, CapabilityStatus, ReportEnvelope, ReportEnvelopeV2,
     RunMeta, SCHEMA_REPORT_V1, SCHEMA_REPORT_V2, SCHEMA_SENSOR_REPORT_V1, ToolMeta, ToolMetaV2,
@@ -13,7 +15,21 @@ use depguard_types::{
 use depguard_yanked::YankedIndex;
 use time::OffsetDateTime;
 
-use crate::report::{ReportVariant, ReportVersion};
+use crate::config::resolve_config_extends;
+use crate::findings_cache::FindingsCache;
+use crate::report::{ReportVariant, ReportVersion, empty_report};
+
+/// Appended to config errors so users know which source won.
+const CONFIG_PRECEDENCE: &str = "Config precedence: CLI overrides > depguard.toml > \
+    Cargo.toml [workspace.metadata.depguard] / [package.metadata.depguard] > profile defaults.";
+
+/// Workspaces with at least this many manifests evaluate manifest-scoped
+/// checks in parallel; below it the thread-pool overhead is not worth it.
+const PARALLEL_EVAL_MIN_MANIFESTS: usize = 32;
+
+/// Docs page linked from findings when `explain_findings` is set.
+const CHECKS_DOCS_URL: &str =
+    "https://github.com/EffortlessMetrics/depguard/blob/main/docs/checks.md#check-list";
 
 /// Input for the check use case.
 #[derive(Clone, Debug)]
@@ -22,6 +38,9 @@ pub struct CheckInput<'a> {
     pub repo_root: &'a Utf8Path,
     /// Config file contents (empty string if not found).
     pub config_text: &'a str,
+    /// Where `config_text` came from (file path or manifest table), used in
+    /// error messages. `None` means `<repo_root>/depguard.toml`.
+    pub config_source: Option<&'a str>,
     /// CLI overrides.
     pub overrides: Overrides,
     /// For diff scope: list of changed files (relative to repo root).
@@ -30,8 +49,11 @@ pub struct CheckInput<'a> {
     pub report_version: ReportVersion,
     /// Optional offline yanked-version index.
     pub yanked_index: Option<YankedIndex>,
-    /// Optional cache directory for incremental manifest parsing.
+    /// Optional cache directory for incremental runs: parsed manifests and
+    /// per-manifest findings are reused when the manifest text is unchanged.
     pub manifest_cache_dir: Option<&'a Utf8Path>,
+    /// Fill each finding's `help` and `url` from the explain registry.
+    pub explain_findings: bool,
 }
 
 /// Output from the check use case.
@@ -41,55 +63,40 @@ pub struct CheckOutput {
     pub report: ReportVariant,
     /// The resolved configuration used.
     pub resolved_config: ResolvedConfig,
+    /// Finding counts, so embedders need not re-walk the report. Taken before
+    /// baseline filtering; totals include findings cut by `max_findings`.
+    pub summary: FindingsSummary,
 }
 
 /// Run the check use case: parse config, discover workspace, evaluate policy, produce report.
+///
+/// A repo without a root `Cargo.toml` yields an empty passing report that still
+/// carries the resolved scope and profile. A diff that touches no manifest yields
+/// a `skip` verdict with reason `no_manifests_in_scope`.
 pub fn run_check(input: CheckInput<'_>) -> anyhow::Result<CheckOutput> {
     let started_at = OffsetDateTime::now_utc();
 
-    // Parse config (empty is allowed, defaults apply).
-    let cfg = if input.config_text.trim().is_empty() {
-        depguard_settings::DepguardConfigV1::default()
-    } else {
-        let config_path = input.repo_root.join("depguard.toml");
-        depguard_settings::parse_config_toml(input.config_text).context(format!(
-            "Failed to parse depguard configuration file '{}'. \
-             The config file contains invalid TOML syntax. \
-             \n\nCommon issues to check: \
-             \n  - Missing quotes around string values (e.g., profile = strict should be profile = \"strict\") \
-             \n  - Unescaped special characters in strings (e.g., use \\n for newline) \
-             \n  - Malformed section headers (e.g., [[rules]] should be [rules]) \
-             \n  - Trailing commas or missing commas between items \
-             \n  - Invalid boolean values (use true/false, not True/False) \
-             \n\nValid configuration keys include: \
-             \n  - profile (string): \"strict\", \"moderate\", or \"permissive\" \
-             \n  - scope (string): \"repo\" or \"diff\" \
-             \n  - fail_on (string): \"error\" or \"warning\" \
-             \n  - [[rules]]: Array of rule configurations with check_id, code, and suppress fields \
-             \n\nTo see all available options, run: depguard explain <check_id>",
-            config_path
-        ))?
-    };
-
-    let mut resolved =
-        depguard_settings::resolve_config(cfg, input.overrides.clone()).context(format!(
-            "Failed to resolve depguard configuration for repository at '{}'. \
-             \n\nThis error indicates a problem with configuration validation or conflicting settings. \
-             \n\nCommon causes: \
-             \n  - Invalid profile value (must be \"strict\", \"moderate\", or \"permissive\") \
-             \n  - Invalid scope value (must be \"repo\" or \"diff\") \
-             \n  - Invalid fail_on value (must be \"error\" or \"warning\") \
-             \n  - Conflicting settings between depguard.toml and CLI overrides \
-             \n  - Invalid check_id or code in [[rules]] suppressions \
-             \n\nTo debug: \
-             \n  1. Check your depguard.toml file at '{}/depguard.toml' \
-             \n  2. Verify CLI overrides match expected types \
-             \n  3. Run 'depguard explain <check_id>' to see valid check IDs \
-             \n  4. Review the configuration documentation at docs/config.md",
-            input.repo_root, input.repo_root
-        ))?;
+    let mut resolved = resolve_check_config(
+        input.repo_root,
+        input.config_text,
+        input.config_source,
+        input.overrides.clone(),
+    )?;
     resolved.effective.yanked_index = input.yanked_index.clone();
 
+    // No root manifest: nothing to scan, but still report the resolved scope and profile.
+    if !input.repo_root.join("Cargo.toml").exists() {
+        let scope = match resolved.effective.scope {
+            DomainScope::Repo => "repo",
+            DomainScope::Diff => "diff",
+        };
+        return Ok(CheckOutput {
+            report: empty_report(input.report_version, scope, &resolved.effective.profile),
+            resolved_config: resolved,
+            summary: FindingsSummary::default(),
+        });
+    }
+
     let scope_input = match resolved.effective.scope {
         DomainScope::Repo => ScopeInput::Repo,
         DomainScope::Diff => {
@@ -107,10 +114,11 @@ pub fn run_check(input: CheckInput<'_>) -> anyhow::Result<CheckOutput> {
         }
     };
 
-    let model = depguard_repo::build_workspace_model_with_cache(
+    let mut model = depguard_repo::build_workspace_model_with_cache(
         input.repo_root,
         scope_input,
         input.manifest_cache_dir,
+        &resolved.exclude,
     )
     .context(format!(
         "Failed to build workspace model for repository at '{}'. \
@@ -120,13 +128,44 @@ pub fn run_check(input: CheckInput<'_>) -> anyhow::Result<CheckOutput> {
         input.repo_root
     ))?;
 
-    let domain_report = depguard::evaluate(&model, &resolved.effective);
+    // Reading package sources is opt-in: only checks that need it pay for the scan.
+    if resolved
+        .effective
+        .check_policy(ids::CHECK_DEPS_DEV_DEP_LEAKAGE)
+        .is_some()
+    {
+        model.source_references = Some(depguard_repo::scan_source_references(
+            input.repo_root,
+            &model.manifests,
+        ));
+    }
+
+    let domain_report = match input.manifest_cache_dir {
+        _ if !has_manifests_in_scope(&model, input.changed_files.as_deref(), &resolved) => {
+            skipped_domain_report(&resolved)
+        }
+        Some(cache_dir) => {
+            let mut cache = FindingsCache::load(input.repo_root, cache_dir, &resolved)?;
+            let report = cache.evaluate(input.repo_root, &model, &resolved.effective);
+            cache.save_if_dirty()?;
+            report
+        }
+        None if model.manifests.len() >= PARALLEL_EVAL_MIN_MANIFESTS => {
+            depguard::evaluate_parallel(&model, &resolved.effective)
+        }
+        None => depguard::evaluate(&model, &resolved.effective),
+    };
     let depguard::report::DomainReport {
         verdict: domain_verdict,
-        findings: domain_findings,
+        findings: mut domain_findings,
         data: domain_data,
         counts: domain_counts,
+        reasons: domain_reasons,
+        summary,
     } = domain_report;
+    if input.explain_findings {
+        explain_findings(&mut domain_findings);
+    }
 
     let finished_at = OffsetDateTime::now_utc();
     let duration_ms = (finished_at - started_at).whole_milliseconds().max(0) as u64;
@@ -177,6 +216,9 @@ pub fn run_check(input: CheckInput<'_>) -> anyhow::Result<CheckOutput> {
                             None
                         },
                     }),
+                    lockfile: Some(lockfile_capability(input.repo_root, &model)),
+                    source_scan: Some(source_scan_capability(&model)),
+                    provenance: None,
                 })
             } else {
                 None
@@ -187,14 +229,16 @@ pub fn run_check(input: CheckInput<'_>) -> anyhow::Result<CheckOutput> {
                     Verdict::Pass => VerdictStatus::Pass,
                     Verdict::Warn => VerdictStatus::Warn,
                     Verdict::Fail => VerdictStatus::Fail,
+                    Verdict::Skip => VerdictStatus::Skip,
                 },
                 counts: VerdictCounts {
                     info: domain_counts.info,
                     warn: domain_counts.warning,
                     error: domain_counts.error,
                     suppressed: 0,
+                    truncated: summary.truncated(),
                 },
-                reasons: Vec::new(),
+                reasons: domain_reasons,
             };
 
             let run = RunMeta {
@@ -246,14 +290,170 @@ pub fn run_check(input: CheckInput<'_>) -> anyhow::Result<CheckOutput> {
     Ok(CheckOutput {
         report,
         resolved_config: resolved,
+        summary,
     })
 }
 
-/// Map verdict to exit code: 0 = pass/warn, 2 = fail.
+/// Parse and resolve config the way [`run_check`] does, without touching the workspace.
+///
+/// Used to show users the effective config (profile, overrides, per-check policy).
+pub fn resolve_check_config(
+    repo_root: &Utf8Path,
+    config_text: &str,
+    config_source: Option<&str>,
+    overrides: Overrides,
+) -> anyhow::Result<ResolvedConfig> {
+    // Parse config (empty is allowed, defaults apply).
+    let cfg = if config_text.trim().is_empty() {
+        depguard_settings::DepguardConfigV1::default()
+    } else {
+        let config_path = config_source
+            .map(str::to_string)
+            .unwrap_or_else(|| repo_root.join("depguard.toml").to_string());
+        depguard_settings::parse_config_toml(config_text).context(format!(
+            "Failed to parse depguard configuration file '{}'. \
+             The config file contains invalid TOML syntax. \
+             \n\nCommon issues to check: \
+             \n  - Missing quotes around string values (e.g., profile = strict should be profile = \"strict\") \
+             \n  - Unescaped special characters in strings (e.g., use \\n for newline) \
+             \n  - Malformed section headers (e.g., [[rules]] should be [rules]) \
+             \n  - Trailing commas or missing commas between items \
+             \n  - Invalid boolean values (use true/false, not True/False) \
+             \n\nValid configuration keys include: \
+             \n  - profile (string): \"strict\", \"moderate\", or \"permissive\" \
+             \n  - scope (string): \"repo\" or \"diff\" \
+             \n  - fail_on (string): \"error\" or \"warning\" \
+             \n  - [[rules]]: Array of rule configurations with check_id, code, and suppress fields \
+             \n\nTo see all available options, run: depguard explain <check_id> \
+             \n\n{CONFIG_PRECEDENCE}",
+            config_path
+        ))?
+    };
+    // Only a real file anchors relative `extends` paths; manifest metadata uses the repo root.
+    let origin = config_source
+        .map(Utf8Path::new)
+        .filter(|path| path.is_file());
+    let cfg = resolve_config_extends(cfg, origin, repo_root)?;
+
+    depguard_settings::resolve_config(cfg, overrides).context(format!(
+            "Failed to resolve depguard configuration for repository at '{}'. \
+             \n\nThis error indicates a problem with configuration validation or conflicting settings. \
+             \n\nCommon causes: \
+             \n  - Invalid profile value (must be \"strict\", \"moderate\", or \"permissive\") \
+             \n  - Invalid scope value (must be \"repo\" or \"diff\") \
+             \n  - Invalid fail_on value (must be \"error\" or \"warning\") \
+             \n  - Conflicting settings between depguard.toml and CLI overrides \
+             \n  - Invalid check_id or code in [[rules]] suppressions \
+             \n\nTo debug: \
+             \n  1. Check your depguard.toml file at '{}/depguard.toml' \
+             \n  2. Verify CLI overrides match expected types \
+             \n  3. Run 'depguard explain <check_id>' to see valid check IDs \
+             \n  4. Review the configuration documentation at docs/config.md \
+             \n\n{CONFIG_PRECEDENCE}",
+            repo_root, repo_root
+        ))
+}
+
+/// Report whether `Cargo.lock` was read, and why not when it wasn't.
+fn lockfile_capability(
+    repo_root: &Utf8Path,
+    model: &depguard::model::WorkspaceModel,
+) -> CapabilityStatus {
+    if model.resolved_versions.is_some() {
+        return CapabilityStatus {
+            status: CapabilityAvailability::Available,
+            reason: None,
+        };
+    }
+    let reason = if repo_root.join("Cargo.lock").exists() {
+        ids::REASON_LOCKFILE_UNPARSEABLE
+    } else {
+        ids::REASON_LOCKFILE_MISSING
+    };
+    CapabilityStatus {
+        status: CapabilityAvailability::Missing,
+        reason: Some(reason.to_string()),
+    }
+}
+
+/// Report whether package sources were scanned for crate references.
+fn source_scan_capability(model: &depguard::model::WorkspaceModel) -> CapabilityStatus {
+    if model.source_references.is_some() {
+        CapabilityStatus {
+            status: CapabilityAvailability::Available,
+            reason: None,
+        }
+    } else {
+        CapabilityStatus {
+            status: CapabilityAvailability::Missing,
+            reason: Some(ids::REASON_SOURCE_SCAN_DISABLED.to_string()),
+        }
+    }
+}
+
+/// Replace each finding's `help` with the registry remediation for its code
+/// (falling back to its check) and point `url` at the checks catalog.
+fn explain_findings(findings: &mut [depguard_types::Finding]) {
+    for finding in findings {
+        let Some(explanation) =
+            lookup_explanation(&finding.code).or_else(|| lookup_explanation(&finding.check_id))
+        else {
+            continue;
+        };
+        finding.help = Some(explanation.remediation.to_string());
+        finding.url = Some(CHECKS_DOCS_URL.to_string());
+    }
+}
+
+/// Whether the effective scope leaves any manifest to evaluate.
+///
+/// Diff scope always loads the root manifest for workspace context, so it only
+/// counts when the diff touches at least one manifest in the model.
+fn has_manifests_in_scope(
+    model: &depguard::model::WorkspaceModel,
+    changed_files: Option<&[depguard_types::RepoPath]>,
+    resolved: &ResolvedConfig,
+) -> bool {
+    match (resolved.effective.scope, changed_files) {
+        (DomainScope::Diff, Some(changed)) => model
+            .manifests
+            .iter()
+            .any(|m| changed.iter().any(|p| p.as_str() == m.path.as_str())),
+        _ => !model.manifests.is_empty(),
+    }
+}
+
+/// A `skip` verdict with no findings, so "nothing to check" is not reported as clean.
+fn skipped_domain_report(resolved: &ResolvedConfig) -> depguard::report::DomainReport {
+    depguard::report::DomainReport {
+        verdict: Verdict::Skip,
+        findings: Vec::new(),
+        data: depguard_types::DepguardData {
+            scope: match resolved.effective.scope {
+                DomainScope::Repo => "repo",
+                DomainScope::Diff => "diff",
+            }
+            .to_string(),
+            profile: resolved.effective.profile.clone(),
+            manifests_scanned: 0,
+            dependencies_scanned: 0,
+            findings_total: 0,
+            findings_emitted: 0,
+            findings_suppressed: 0,
+            truncated_reason: None,
+        },
+        counts: Default::default(),
+        reasons: vec![ids::REASON_NO_MANIFESTS_IN_SCOPE.to_string()],
+        summary: Default::default(),
+    }
+}
+
+/// Map verdict to exit code: 0 = pass/warn/skip, 2 = fail.
 pub fn verdict_exit_code(verdict: Verdict) -> i32 {
     match verdict {
         Verdict::Pass => 0,
         Verdict::Warn => 0,
+        Verdict::Skip => 0,
         Verdict::Fail => 2,
     }
 }
@@ -263,7 +463,8 @@ mod tests {
     use super::*;
     use crate::report::empty_report;
     use depguard_types::{
-        CapabilityAvailability, SCHEMA_REPORT_V2, SCHEMA_SENSOR_REPORT_V1, SeverityV2, ids,
+        CapabilityAvailability, RepoPath, SCHEMA_REPORT_V2, SCHEMA_SENSOR_REPORT_V1, SeverityV2,
+        ids,
     };
 
     #[test]
@@ -288,11 +489,13 @@ license.workspace = true
         let input = CheckInput {
             repo_root: root,
             config_text: "",
+            config_source: None,
             overrides: Overrides::default(),
             changed_files: None,
             report_version: ReportVersion::V1,
             yanked_index: None,
             manifest_cache_dir: None,
+            explain_findings: false,
         };
 
         let output = run_check(input).expect("run_check");
@@ -304,6 +507,7 @@ license.workspace = true
         assert_eq!(verdict_exit_code(Verdict::Pass), 0);
         assert_eq!(verdict_exit_code(Verdict::Warn), 0);
         assert_eq!(verdict_exit_code(Verdict::Fail), 2);
+        assert_eq!(verdict_exit_code(Verdict::Skip), 0);
     }
 
     fn write_manifest(root: &Utf8Path, deps: &str) {
@@ -322,6 +526,60 @@ edition = "2021"
         std::fs::write(root.join("Cargo.toml"), content).expect("write Cargo.toml");
     }
 
+    #[test]
+    fn incremental_run_reuses_cached_findings_until_inputs_change() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write_manifest(root, r#"serde = "*""#);
+        let cache_dir = camino::Utf8Path::new(".depguard-cache");
+
+        let run = |config_text: &str| {
+            let output = run_check(CheckInput {
+                repo_root: root,
+                config_text,
+                config_source: None,
+                overrides: Overrides::default(),
+                changed_files: None,
+                report_version: ReportVersion::V1,
+                yanked_index: None,
+                manifest_cache_dir: Some(cache_dir),
+                explain_findings: false,
+            })
+            .expect("run_check");
+            match output.report {
+                ReportVariant::V1(report) => report
+                    .findings
+                    .into_iter()
+                    .map(|f| f.message)
+                    .collect::<Vec<_>>(),
+                ReportVariant::V2(_) => panic!("expected v1 report"),
+            }
+        };
+
+        let first = run("");
+        assert_eq!(first.len(), 1);
+        assert!(first[0].contains("serde"));
+
+        // Mark the cached finding so a cache hit is observable.
+        let cache_path = root
+            .join(cache_dir)
+            .join(crate::findings_cache::FINDINGS_CACHE_FILENAME);
+        let text = std::fs::read_to_string(&cache_path).expect("read findings cache");
+        let mut cache: serde_json::Value = serde_json::from_str(&text).expect("parse cache");
+        cache["entries"]["Cargo.toml"]["findings"][0]["message"] = "from cache".into();
+        std::fs::write(&cache_path, cache.to_string()).expect("write findings cache");
+
+        assert_eq!(run(""), vec!["from cache"]);
+
+        // A config change invalidates the whole cache.
+        let changed_config = "[checks.\"deps.no_wildcards\"]\nseverity = \"warning\"\n";
+        assert_eq!(run(changed_config), first);
+
+        // So does a change to the manifest itself.
+        write_manifest(root, "serde = \"*\"\ntokio = \"*\"");
+        assert_eq!(run(changed_config).len(), 2);
+    }
+
     #[test]
     fn diff_scope_requires_changed_files() {
         let tmp = tempfile::tempdir().expect("create temp dir");
@@ -331,11 +589,13 @@ edition = "2021"
         let input = CheckInput {
             repo_root: root,
             config_text: r#"scope = "diff""#,
+            config_source: None,
             overrides: Overrides::default(),
             changed_files: None,
             report_version: ReportVersion::V1,
             yanked_index: None,
             manifest_cache_dir: None,
+            explain_findings: false,
         };
 
         let err = run_check(input).expect_err("expected diff scope error");
@@ -354,6 +614,48 @@ edition = "2021"
         );
     }
 
+    #[test]
+    fn diff_without_manifest_changes_is_skipped() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write_manifest(root, "serde = \"*\"");
+
+        let run = |version: ReportVersion, changed: &str| {
+            let input = CheckInput {
+                repo_root: root,
+                config_text: r#"scope = "diff""#,
+                config_source: None,
+                overrides: Overrides::default(),
+                changed_files: Some(vec![depguard_types::RepoPath::new(changed)]),
+                report_version: version,
+                yanked_index: None,
+                manifest_cache_dir: None,
+                explain_findings: false,
+            };
+            run_check(input).expect("run_check").report
+        };
+
+        let report = unwrap_v2(run(ReportVersion::V2, "README.md"));
+        assert_eq!(report.verdict.status, VerdictStatus::Skip);
+        assert_eq!(
+            report.verdict.reasons,
+            vec![ids::REASON_NO_MANIFESTS_IN_SCOPE.to_string()]
+        );
+        assert!(report.findings.is_empty());
+        assert_eq!(report.data.scope, "diff");
+        assert_eq!(report.data.manifests_scanned, 0);
+
+        match run(ReportVersion::V1, "README.md") {
+            ReportVariant::V1(r) => assert_eq!(r.verdict, Verdict::Skip),
+            ReportVariant::V2(_) => panic!("expected v1 report"),
+        }
+
+        // Touching the manifest brings it back into scope.
+        let report = unwrap_v2(run(ReportVersion::V2, "Cargo.toml"));
+        assert_eq!(report.verdict.status, VerdictStatus::Fail);
+        assert!(report.verdict.reasons.is_empty());
+    }
+
     #[test]
     fn invalid_config_provides_helpful_error() {
         let tmp = tempfile::tempdir().expect("create temp dir");
@@ -363,11 +665,13 @@ edition = "2021"
         let input = CheckInput {
             repo_root: root,
             config_text: r#"this is not valid toml"#,
+            config_source: None,
             overrides: Overrides::default(),
             changed_files: None,
             report_version: ReportVersion::V1,
             yanked_index: None,
             manifest_cache_dir: None,
+            explain_findings: false,
         };
 
         let err = run_check(input).expect_err("expected config parse error");
@@ -382,6 +686,170 @@ edition = "2021"
         );
     }
 
+    #[test]
+    fn empty_repo_report_populates_scope_profile_and_manifest_count() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+
+        let input = CheckInput {
+            repo_root: root,
+            config_text: "profile = \"warn\"\nscope = \"diff\"\n",
+            config_source: None,
+            overrides: Overrides::default(),
+            changed_files: None,
+            report_version: ReportVersion::V2,
+            yanked_index: None,
+            manifest_cache_dir: None,
+            explain_findings: false,
+        };
+
+        let output = run_check(input).expect("run_check without Cargo.toml");
+        let report = unwrap_v2(output.report);
+        assert_eq!(report.verdict.status, VerdictStatus::Pass);
+        assert_eq!(report.data.scope, "diff");
+        assert_eq!(report.data.profile, "warn");
+        assert_eq!(report.data.manifests_scanned, 0);
+        assert_eq!(report.data.dependencies_scanned, 0);
+    }
+
+    #[test]
+    fn workspace_report_populates_scope_profile_and_manifest_count() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write_manifest(root, "serde = \"1\"\nanyhow = \"1\"");
+
+        let input = CheckInput {
+            repo_root: root,
+            config_text: "",
+            config_source: None,
+            overrides: Overrides::default(),
+            changed_files: None,
+            report_version: ReportVersion::V2,
+            yanked_index: None,
+            manifest_cache_dir: None,
+            explain_findings: false,
+        };
+
+        let output = run_check(input).expect("run_check");
+        let report = unwrap_v2(output.report);
+        assert_eq!(report.data.scope, "repo");
+        assert_eq!(
+            report.data.profile,
+            output.resolved_config.effective.profile
+        );
+        assert!(!report.data.profile.is_empty());
+        assert_eq!(report.data.manifests_scanned, 1);
+        assert_eq!(report.data.dependencies_scanned, 2);
+    }
+
+    #[test]
+    fn excluded_manifests_are_not_evaluated_or_counted() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"vendor/*\"]\n",
+        )
+        .expect("write Cargo.toml");
+        std::fs::create_dir_all(root.join("vendor/gen")).expect("create vendor dir");
+        std::fs::write(
+            root.join("vendor/gen/Cargo.toml"),
+            "[package]\nname = \"gen\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"*\"\n",
+        )
+        .expect("write vendored Cargo.toml");
+
+        // Repo scope scans only the root; a diff touching just the vendored crate
+        // has nothing left in scope.
+        for (changed_files, scanned) in [
+            (None, 1),
+            (Some(vec![RepoPath::new("vendor/gen/Cargo.toml")]), 0),
+        ] {
+            let input = CheckInput {
+                repo_root: root,
+                config_text: "exclude = [\"vendor/**/Cargo.toml\"]\n",
+                config_source: None,
+                overrides: Overrides {
+                    scope: changed_files.as_ref().map(|_| "diff".to_string()),
+                    ..Overrides::default()
+                },
+                changed_files,
+                report_version: ReportVersion::V2,
+                yanked_index: None,
+                manifest_cache_dir: None,
+                explain_findings: false,
+            };
+
+            let report = unwrap_v2(run_check(input).expect("run_check").report);
+            assert_eq!(report.data.manifests_scanned, scanned);
+            assert!(report.findings.is_empty(), "{:?}", report.findings);
+        }
+    }
+
+    #[test]
+    fn summary_counts_every_finding_including_truncated_ones() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write_manifest(
+            root,
+            "serde = \"*\"\ntokio = \"*\"\nssl = { package = \"openssl\", version = \"0.10\" }",
+        );
+        let config = r#"
+max_findings = 2
+
+[checks."deps.no_rename"]
+enabled = true
+severity = "warning"
+"#;
+
+        let run = |config_text: &str| {
+            run_check(CheckInput {
+                repo_root: root,
+                config_text,
+                config_source: None,
+                overrides: Overrides::default(),
+                changed_files: None,
+                report_version: ReportVersion::V2,
+                yanked_index: None,
+                manifest_cache_dir: None,
+                explain_findings: false,
+            })
+            .expect("run_check")
+        };
+
+        // Without truncation the summary matches the findings one for one.
+        let output = run(&config.replace("max_findings = 2", ""));
+        let report = unwrap_v2(output.report);
+        let summary = output.summary;
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.emitted, report.findings.len() as u32);
+        assert_eq!(summary.by_severity.error, 2);
+        assert_eq!(summary.by_severity.warning, 1);
+        assert_eq!(summary.by_severity.info, 0);
+        for (check_id, count) in &summary.by_check {
+            let actual = report
+                .findings
+                .iter()
+                .filter(|f| f.check_id == *check_id)
+                .count();
+            assert_eq!(actual as u32, *count, "{check_id}");
+        }
+        assert_eq!(report.verdict.counts.truncated, 0);
+
+        // Truncation keeps the totals but reports fewer findings.
+        let output = run(config);
+        let report = unwrap_v2(output.report);
+        let summary = output.summary;
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.emitted, 2);
+        assert_eq!(summary.truncated(), 1);
+        assert_eq!(report.findings.len(), 2);
+        assert_eq!(summary.by_check[ids::CHECK_DEPS_NO_WILDCARDS], 2);
+        assert_eq!(summary.by_check[ids::CHECK_DEPS_NO_RENAME], 1);
+        let counts = &report.verdict.counts;
+        assert_eq!(counts.truncated, 1);
+        assert_eq!(counts.info + counts.warn + counts.error, 2);
+    }
+
     #[test]
     fn sensor_v1_capabilities_mark_missing() {
         let tmp = tempfile::tempdir().expect("create temp dir");
@@ -391,11 +859,13 @@ edition = "2021"
         let input = CheckInput {
             repo_root: root,
             config_text: "",
+            config_source: None,
             overrides: Overrides::default(),
             changed_files: None,
             report_version: ReportVersion::SensorV1,
             yanked_index: None,
             manifest_cache_dir: None,
+            explain_findings: false,
         };
 
         let output = run_check(input).expect("run_check");
@@ -411,6 +881,60 @@ edition = "2021"
             config.reason.as_deref(),
             Some(ids::REASON_CONFIG_MISSING_DEFAULTED)
         );
+        let lockfile = caps.lockfile.as_ref().expect("lockfile capability");
+        assert_eq!(lockfile.status, CapabilityAvailability::Missing);
+        assert_eq!(
+            lockfile.reason.as_deref(),
+            Some(ids::REASON_LOCKFILE_MISSING)
+        );
+        let source_scan = caps.source_scan.as_ref().expect("source scan capability");
+        assert_eq!(source_scan.status, CapabilityAvailability::Missing);
+        assert_eq!(
+            source_scan.reason.as_deref(),
+            Some(ids::REASON_SOURCE_SCAN_DISABLED)
+        );
+    }
+
+    #[test]
+    fn dev_dep_leakage_scans_sources_only_when_enabled() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"test\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+             [dev-dependencies]\nserde_json = \"1\"\n",
+        )
+        .expect("write Cargo.toml");
+        std::fs::create_dir_all(root.join("src")).expect("create src");
+        std::fs::write(root.join("src/lib.rs"), "use serde_json::Value;\n").expect("write lib.rs");
+
+        let input = CheckInput {
+            repo_root: root,
+            config_text: "[checks.\"deps.dev_dep_leakage\"]\nenabled = true\n",
+            config_source: None,
+            overrides: Overrides::default(),
+            changed_files: None,
+            report_version: ReportVersion::SensorV1,
+            yanked_index: None,
+            manifest_cache_dir: None,
+            explain_findings: false,
+        };
+
+        let output = run_check(input).expect("run_check");
+        let report = unwrap_v2(output.report);
+        let finding = report
+            .findings
+            .iter()
+            .find(|f| f.check_id == ids::CHECK_DEPS_DEV_DEP_LEAKAGE)
+            .expect("leakage finding");
+        assert_eq!(finding.code, ids::CODE_DEV_DEP_USED_IN_SRC);
+        assert_eq!(
+            finding.location.as_ref().map(|l| l.path.as_str()),
+            Some("src/lib.rs")
+        );
+        let caps = report.run.capabilities.as_ref().expect("capabilities");
+        let source_scan = caps.source_scan.as_ref().expect("source scan capability");
+        assert_eq!(source_scan.status, CapabilityAvailability::Available);
     }
 
     #[test]
@@ -422,11 +946,13 @@ edition = "2021"
         let input = CheckInput {
             repo_root: root,
             config_text: "",
+            config_source: None,
             overrides: Overrides::default(),
             changed_files: None,
             report_version: ReportVersion::V2,
             yanked_index: None,
             manifest_cache_dir: None,
+            explain_findings: false,
         };
 
         let output = run_check(input).expect("run_check");
@@ -445,15 +971,22 @@ edition = "2021"
         let tmp = tempfile::tempdir().expect("create temp dir");
         let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
         write_manifest(root, "");
+        std::fs::write(
+            root.join("Cargo.lock"),
+            "version = 4\n\n[[package]]\nname = \"test\"\nversion = \"0.1.0\"\n",
+        )
+        .expect("write Cargo.lock");
 
         let input = CheckInput {
             repo_root: root,
             config_text: r#"profile = "strict""#,
+            config_source: None,
             overrides: Overrides::default(),
             changed_files: Some(vec![depguard_types::RepoPath::new("Cargo.toml")]),
             report_version: ReportVersion::SensorV1,
             yanked_index: None,
             manifest_cache_dir: None,
+            explain_findings: false,
         };
 
         let output = run_check(input).expect("run_check");
@@ -465,6 +998,82 @@ edition = "2021"
         let config = caps.config.as_ref().expect("config capability");
         assert_eq!(config.status, CapabilityAvailability::Available);
         assert!(config.reason.is_none());
+        let lockfile = caps.lockfile.as_ref().expect("lockfile capability");
+        assert_eq!(lockfile.status, CapabilityAvailability::Available);
+        assert!(lockfile.reason.is_none());
+    }
+
+    #[test]
+    fn sensor_v1_capabilities_flag_unparseable_lockfile() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write_manifest(root, "");
+        std::fs::write(root.join("Cargo.lock"), "not [valid toml").expect("write Cargo.lock");
+
+        let input = CheckInput {
+            repo_root: root,
+            config_text: "",
+            config_source: None,
+            overrides: Overrides::default(),
+            changed_files: None,
+            report_version: ReportVersion::SensorV1,
+            yanked_index: None,
+            manifest_cache_dir: None,
+            explain_findings: false,
+        };
+
+        let output = run_check(input).expect("run_check");
+        let report = unwrap_v2(output.report);
+        let caps = report.run.capabilities.as_ref().expect("capabilities");
+        let lockfile = caps.lockfile.as_ref().expect("lockfile capability");
+        assert_eq!(lockfile.status, CapabilityAvailability::Missing);
+        assert_eq!(
+            lockfile.reason.as_deref(),
+            Some(ids::REASON_LOCKFILE_UNPARSEABLE)
+        );
+    }
+
+    #[test]
+    fn explain_findings_fills_help_and_url_from_registry() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write_manifest(root, r#"serde = "*""#);
+
+        let run = |explain_findings: bool| {
+            let output = run_check(CheckInput {
+                repo_root: root,
+                config_text: "",
+                config_source: None,
+                overrides: Overrides::default(),
+                changed_files: None,
+                report_version: ReportVersion::V2,
+                yanked_index: None,
+                manifest_cache_dir: None,
+                explain_findings,
+            })
+            .expect("run_check");
+            let report = unwrap_v2(output.report);
+            report
+                .findings
+                .into_iter()
+                .find(|f| f.code == ids::CODE_WILDCARD_VERSION)
+                .expect("wildcard finding")
+        };
+
+        let plain = run(false);
+        assert!(plain.url.is_none());
+        let remediation = lookup_explanation(ids::CODE_WILDCARD_VERSION)
+            .expect("registry entry")
+            .remediation;
+        assert_ne!(plain.help.as_deref(), Some(remediation));
+
+        let explained = run(true);
+        let help = explained.help.as_deref().expect("help");
+        assert!(!help.is_empty());
+        assert_eq!(help, remediation);
+        assert_eq!(explained.url.as_deref(), Some(CHECKS_DOCS_URL));
+        assert_eq!(explained.message, plain.message);
+        assert_eq!(explained.fingerprint, plain.fingerprint);
     }
 
     fn unwrap_v2(report: ReportVariant) -> depguard_types::DepguardReportV2 {
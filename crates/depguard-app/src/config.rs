@@ -0,0 +1,252 @@
+//! Config composition: folding `extends` chains and multi-file globs into a single config.
+//!
+//! `depguard-settings` stays IO-free; reading the referenced files happens here.
+
+use anyhow::Context;
+use camino::{Utf8Path, Utf8PathBuf};
+use depguard_settings::DepguardConfigV1;
+use globset::GlobBuilder;
+
+/// Follow `cfg.extends` until a config without one, merging each base under
+/// the configs that extend it.
+///
+/// `origin` is the file `cfg` was read from; `extends` paths resolve against
+/// its directory, or against `repo_root` when the config did not come from a
+/// file (e.g. `[workspace.metadata.depguard]`). Revisiting a file is an error.
+pub fn resolve_config_extends(
+    cfg: DepguardConfigV1,
+    origin: Option<&Utf8Path>,
+    repo_root: &Utf8Path,
+) -> anyhow::Result<DepguardConfigV1> {
+    let mut chain: Vec<Utf8PathBuf> = origin
+        .map(|path| {
+            path.canonicalize_utf8()
+                .unwrap_or_else(|_| path.to_path_buf())
+        })
+        .into_iter()
+        .collect();
+    let mut dir = origin
+        .and_then(Utf8Path::parent)
+        .unwrap_or(repo_root)
+        .to_path_buf();
+    let mut merged = cfg;
+
+    while let Some(extends) = merged.extends.take() {
+        let path = dir.join(&extends);
+        let text = std::fs::read_to_string(&path)
+            .with_context(|| format!("read extended config {path}"))?;
+        let canonical = path.canonicalize_utf8().unwrap_or_else(|_| path.clone());
+        if chain.contains(&canonical) {
+            let cycle: Vec<&str> = chain
+                .iter()
+                .chain(std::iter::once(&canonical))
+                .map(|path| path.as_str())
+                .collect();
+            anyhow::bail!("cyclic config extends: {}", cycle.join(" -> "));
+        }
+        let base = depguard_settings::parse_config_toml(&text)
+            .with_context(|| format!("parse extended config {path}"))?;
+
+        merged = depguard_settings::merge_configs(base, merged);
+        dir = path.parent().map(Utf8Path::to_path_buf).unwrap_or(dir);
+        chain.push(canonical);
+    }
+
+    Ok(merged)
+}
+
+/// Whether a `--config` value is a glob pattern rather than a single file.
+pub fn is_config_glob(path: &str) -> bool {
+    path.contains(['*', '?', '[', '{'])
+}
+
+/// Expand a config glob such as `.depguard/*.toml` into the matching files, sorted.
+///
+/// Components up to the first one containing a glob character name the directory
+/// to search; `*` does not cross `/` but `**` does. Matching nothing is an error.
+pub fn expand_config_glob(pattern: &Utf8Path) -> anyhow::Result<Vec<Utf8PathBuf>> {
+    let mut base = Utf8PathBuf::new();
+    let mut rest: Vec<&str> = Vec::new();
+    for component in pattern.components() {
+        if rest.is_empty() && !is_config_glob(component.as_str()) {
+            base.push(component);
+        } else {
+            rest.push(component.as_str());
+        }
+    }
+    let matcher = GlobBuilder::new(&rest.join("/"))
+        .literal_separator(true)
+        .build()
+        .with_context(|| format!("invalid config glob {pattern}"))?
+        .compile_matcher();
+
+    let max_depth = if rest.iter().any(|c| c.contains("**")) {
+        usize::MAX
+    } else {
+        rest.len()
+    };
+
+    let mut paths: Vec<Utf8PathBuf> = walkdir::WalkDir::new(&base)
+        .max_depth(max_depth)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| Utf8PathBuf::from_path_buf(entry.into_path()).ok())
+        .filter(|path| {
+            path.strip_prefix(&base)
+                .is_ok_and(|rel| matcher.is_match(rel.as_str()))
+        })
+        .collect();
+    paths.sort();
+    if paths.is_empty() {
+        anyhow::bail!("config glob {pattern} matched no files");
+    }
+    Ok(paths)
+}
+
+/// Parse and merge several config files in order: later files win and per-check
+/// lists are merged, as with `extends`. Each file's own `extends` chain is folded
+/// before it is merged. Errors name the file that failed.
+pub fn merge_config_files(
+    paths: &[Utf8PathBuf],
+    repo_root: &Utf8Path,
+) -> anyhow::Result<DepguardConfigV1> {
+    let mut merged: Option<DepguardConfigV1> = None;
+    for path in paths {
+        let text = std::fs::read_to_string(path).with_context(|| format!("read config {path}"))?;
+        let cfg = depguard_settings::parse_config_toml(&text)
+            .with_context(|| format!("parse config {path}"))?;
+        let cfg = resolve_config_extends(cfg, Some(path), repo_root)
+            .with_context(|| format!("resolve config {path}"))?;
+        merged = Some(match merged {
+            Some(base) => depguard_settings::merge_configs(base, cfg),
+            None => cfg,
+        });
+    }
+    Ok(merged.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Utf8Path, rel: &str, text: &str) -> Utf8PathBuf {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+        std::fs::write(&path, text).expect("write config");
+        path
+    }
+
+    fn load(path: &Utf8Path, root: &Utf8Path) -> anyhow::Result<DepguardConfigV1> {
+        let text = std::fs::read_to_string(path).expect("read config");
+        let cfg = depguard_settings::parse_config_toml(&text).expect("parse config");
+        resolve_config_extends(cfg, Some(path), root)
+    }
+
+    #[test]
+    fn two_level_chain_merges_with_child_precedence() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write(
+            root,
+            "depguard.org.toml",
+            "profile = \"warn\"\nfail_on = \"warning\"\n\n[checks.\"deps.no_wildcards\"]\nseverity = \"warning\"\nallow = [\"org-*\"]\n",
+        );
+        write(
+            root,
+            "policy/depguard.base.toml",
+            "extends = \"../depguard.org.toml\"\nscope = \"diff\"\n\n[checks.\"deps.no_wildcards\"]\nallow = [\"team-*\"]\n",
+        );
+        let child = write(
+            root,
+            "services/api/depguard.toml",
+            "extends = \"../../policy/depguard.base.toml\"\nprofile = \"strict\"\n\n[checks.\"deps.no_wildcards\"]\nseverity = \"error\"\nallow = [\"api-*\"]\n",
+        );
+
+        let merged = load(&child, root).expect("resolve extends");
+
+        assert_eq!(merged.extends, None);
+        assert_eq!(merged.profile.as_deref(), Some("strict"));
+        assert_eq!(merged.scope.as_deref(), Some("diff"));
+        assert_eq!(merged.fail_on.as_deref(), Some("warning"));
+        let wildcards = &merged.checks["deps.no_wildcards"];
+        assert_eq!(wildcards.severity.as_deref(), Some("error"));
+        assert_eq!(wildcards.allow, vec!["org-*", "team-*", "api-*"]);
+    }
+
+    #[test]
+    fn cyclic_extends_is_an_error() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write(root, "a.toml", "extends = \"b.toml\"\n");
+        write(root, "b.toml", "extends = \"a.toml\"\n");
+
+        let err = load(&root.join("a.toml"), root).expect_err("cycle should fail");
+        let msg = err.to_string();
+
+        assert!(msg.starts_with("cyclic config extends: "), "{msg}");
+        assert!(msg.ends_with("a.toml"), "{msg}");
+    }
+
+    #[test]
+    fn config_glob_merges_files_in_sorted_order() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write(
+            root,
+            ".depguard/10-repo.toml",
+            "fail_on = \"warning\"\n\n[checks.\"deps.no_wildcards\"]\nseverity = \"warning\"\nallow = [\"repo-*\"]\n",
+        );
+        write(
+            root,
+            ".depguard/00-base.toml",
+            "profile = \"strict\"\nfail_on = \"error\"\n\n[checks.\"deps.no_wildcards\"]\nseverity = \"error\"\nallow = [\"org-*\"]\n",
+        );
+        write(root, ".depguard/README.md", "not a config\n");
+        write(
+            root,
+            ".depguard/nested/99-ignored.toml",
+            "profile = \"compat\"\n",
+        );
+
+        let paths = expand_config_glob(&root.join(".depguard/*.toml")).expect("expand glob");
+        let names: Vec<&str> = paths.iter().filter_map(|p| p.file_name()).collect();
+        assert_eq!(names, vec!["00-base.toml", "10-repo.toml"]);
+
+        let merged = merge_config_files(&paths, root).expect("merge configs");
+        assert_eq!(merged.profile.as_deref(), Some("strict"));
+        assert_eq!(merged.fail_on.as_deref(), Some("warning"));
+        let wildcards = &merged.checks["deps.no_wildcards"];
+        assert_eq!(wildcards.severity.as_deref(), Some("warning"));
+        assert_eq!(wildcards.allow, vec!["org-*", "repo-*"]);
+    }
+
+    #[test]
+    fn config_glob_errors_name_the_file() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        write(root, "cfg/00-base.toml", "profile = \"strict\"\n");
+        write(root, "cfg/10-broken.toml", "profile = \n");
+
+        let paths = expand_config_glob(&root.join("cfg/*.toml")).expect("expand glob");
+        let err = merge_config_files(&paths, root).expect_err("broken file should fail");
+        assert!(err.to_string().contains("10-broken.toml"), "{err}");
+
+        let err = expand_config_glob(&root.join("none/*.toml")).expect_err("no match");
+        assert!(err.to_string().contains("matched no files"), "{err}");
+        assert!(is_config_glob(".depguard/*.toml"));
+        assert!(!is_config_glob("depguard.toml"));
+    }
+
+    #[test]
+    fn missing_base_names_the_path() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        let child = write(root, "depguard.toml", "extends = \"missing.toml\"\n");
+
+        let err = load(&child, root).expect_err("missing base should fail");
+
+        assert!(err.to_string().contains("missing.toml"));
+    }
+}
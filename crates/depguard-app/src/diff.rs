@@ -0,0 +1,222 @@
+//! Diff use case: compare the findings of two reports by fingerprint.
+
+use crate::baseline::legacy_finding_key;
+use crate::report::{ReportVariant, to_renderable};
+use depguard_render::{RenderableFinding, RenderableSeverity};
+use std::collections::BTreeSet;
+
+/// Findings introduced and resolved between a base and a head report.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReportDiff {
+    /// Present in head but not in base.
+    pub added: Vec<RenderableFinding>,
+    /// Present in base but not in head.
+    pub removed: Vec<RenderableFinding>,
+    /// Present in both reports.
+    pub unchanged: usize,
+}
+
+impl ReportDiff {
+    /// Whether head introduced findings that base did not have.
+    pub fn has_new_findings(&self) -> bool {
+        !self.added.is_empty()
+    }
+}
+
+/// Compare two reports by finding fingerprint.
+///
+/// Findings without a fingerprint fall back to the same `check_id`/`code`/location
+/// key the baseline uses. Reports may mix v1 and v2 schemas. Added and removed
+/// findings keep the order of the report they come from.
+pub fn diff_reports(base: &ReportVariant, head: &ReportVariant) -> ReportDiff {
+    let base = to_renderable(base).findings;
+    let head = to_renderable(head).findings;
+    let base_keys: BTreeSet<String> = base.iter().map(finding_key).collect();
+    let head_keys: BTreeSet<String> = head.iter().map(finding_key).collect();
+
+    let only_in = |findings: Vec<RenderableFinding>, other: &BTreeSet<String>| {
+        let mut seen = BTreeSet::new();
+        findings
+            .into_iter()
+            .filter(|f| {
+                let key = finding_key(f);
+                !other.contains(&key) && seen.insert(key)
+            })
+            .collect()
+    };
+
+    ReportDiff {
+        unchanged: base_keys.intersection(&head_keys).count(),
+        added: only_in(head, &base_keys),
+        removed: only_in(base, &head_keys),
+    }
+}
+
+/// Plain-text summary for terminals.
+pub fn render_diff_text(diff: &ReportDiff) -> String {
+    let mut out = format!(
+        "depguard diff: {} new, {} resolved, {} unchanged\n",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.unchanged
+    );
+    for (label, findings) in [("new", &diff.added), ("resolved", &diff.removed)] {
+        if findings.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n{label}:\n"));
+        for f in findings {
+            out.push_str(&format!(
+                "  {} {}/{}: {}{}\n",
+                severity_label(f.severity),
+                f.check_id.as_deref().unwrap_or(""),
+                f.code,
+                f.message,
+                location_suffix(f)
+            ));
+        }
+    }
+    out
+}
+
+/// Markdown summary suitable for a PR comment.
+pub fn render_diff_markdown(diff: &ReportDiff) -> String {
+    let mut out = String::from("# Depguard diff\n\n");
+    out.push_str(&format!(
+        "- New findings: **{}**\n- Resolved findings: {}\n- Unchanged findings: {}\n",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.unchanged
+    ));
+    for (heading, findings) in [
+        ("New findings", &diff.added),
+        ("Resolved findings", &diff.removed),
+    ] {
+        if findings.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n## {heading}\n\n"));
+        for f in findings {
+            out.push_str(&format!(
+                "- **{}** `{}` / `{}` — {}{}\n",
+                severity_label(f.severity),
+                f.check_id.as_deref().unwrap_or(""),
+                f.code,
+                f.message,
+                location_suffix(f)
+            ));
+        }
+    }
+    out
+}
+
+/// Identity of a finding across runs.
+fn finding_key(f: &RenderableFinding) -> String {
+    f.fingerprint.clone().unwrap_or_else(|| {
+        legacy_finding_key(
+            f.check_id.as_deref().unwrap_or(""),
+            &f.code,
+            f.location
+                .as_ref()
+                .map(|loc| (loc.path.as_str(), loc.line, loc.col)),
+        )
+    })
+}
+
+fn severity_label(severity: RenderableSeverity) -> &'static str {
+    match severity {
+        RenderableSeverity::Error => "error",
+        RenderableSeverity::Warning => "warning",
+        RenderableSeverity::Info => "info",
+    }
+}
+
+fn location_suffix(f: &RenderableFinding) -> String {
+    match &f.location {
+        Some(loc) => match loc.line {
+            Some(line) => format!(" ({}:{})", loc.path, line),
+            None => format!(" ({})", loc.path),
+        },
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{ReportVersion, empty_report};
+    use depguard_types::{FindingV2, Location, RepoPath, SeverityV2};
+
+    fn report_with(findings: &[(&str, Option<&str>, u32)]) -> ReportVariant {
+        let mut report = empty_report(ReportVersion::V2, "repo", "strict");
+        let ReportVariant::V2(r) = &mut report else {
+            unreachable!("v2 report requested");
+        };
+        r.findings = findings
+            .iter()
+            .map(|(message, fingerprint, line)| FindingV2 {
+                severity: SeverityV2::Error,
+                check_id: "deps.no_wildcards".to_string(),
+                code: "wildcard_version".to_string(),
+                message: message.to_string(),
+                location: Some(Location {
+                    path: RepoPath::new("Cargo.toml"),
+                    line: Some(*line),
+                    col: None,
+                }),
+                help: None,
+                url: None,
+                fingerprint: fingerprint.map(str::to_string),
+                data: serde_json::Value::Null,
+            })
+            .collect();
+        report
+    }
+
+    #[test]
+    fn diff_matches_findings_by_fingerprint() {
+        let base = report_with(&[
+            ("kept", Some("fp-kept"), 3),
+            ("fixed", Some("fp-fixed"), 4),
+            ("legacy", None, 9),
+        ]);
+        // Moving a finding to another line keeps its fingerprint.
+        let head = report_with(&[
+            ("kept", Some("fp-kept"), 7),
+            ("introduced", Some("fp-new"), 5),
+            ("legacy", None, 9),
+            ("introduced", Some("fp-new"), 5),
+        ]);
+
+        let diff = diff_reports(&base, &head);
+        let messages = |findings: &[RenderableFinding]| {
+            findings
+                .iter()
+                .map(|f| f.message.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(messages(&diff.added), vec!["introduced"]);
+        assert_eq!(messages(&diff.removed), vec!["fixed"]);
+        assert_eq!(diff.unchanged, 2);
+        assert!(diff.has_new_findings());
+        assert!(!diff_reports(&head, &head).has_new_findings());
+    }
+
+    #[test]
+    fn diff_renders_text_and_markdown_summaries() {
+        let base = report_with(&[("fixed", Some("fp-fixed"), 4)]);
+        let head = report_with(&[("introduced", Some("fp-new"), 5)]);
+        let diff = diff_reports(&base, &head);
+
+        let text = render_diff_text(&diff);
+        assert!(text.starts_with("depguard diff: 1 new, 1 resolved, 0 unchanged\n"));
+        assert!(
+            text.contains("  error deps.no_wildcards/wildcard_version: introduced (Cargo.toml:5)")
+        );
+
+        let md = render_diff_markdown(&diff);
+        assert!(md.starts_with("# Depguard diff\n\n- New findings: **1**\n"));
+        assert!(md.contains("## New findings\n\n- **error** `deps.no_wildcards` / `wildcard_version` — introduced (Cargo.toml:5)\n"));
+        assert!(md.contains("## Resolved findings\n\n- **error** `deps.no_wildcards` / `wildcard_version` — fixed (Cargo.toml:4)\n"));
+    }
+}
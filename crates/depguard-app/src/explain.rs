@@ -1,6 +1,7 @@
 //! The `explain` use case: look up check/code documentation.
 
-use depguard_types::explain::{self, Explanation};
+use depguard_types::explain::{self, Explanation, SCHEMA_EXPLAIN_V1};
+use serde_json::{Value, json};
 
 /// Output from the explain use case.
 #[derive(Clone, Debug)]
@@ -10,6 +11,8 @@ pub enum ExplainOutput {
     /// Unknown identifier; includes available check_ids and codes.
     NotFound {
         identifier: String,
+        /// Closest known check_id or code, if one is near enough to be a likely typo.
+        suggestion: Option<&'static str>,
         available_check_ids: &'static [&'static str],
         available_codes: &'static [&'static str],
     },
@@ -21,12 +24,66 @@ pub fn run_explain(identifier: &str) -> ExplainOutput {
         Some(exp) => ExplainOutput::Found(exp),
         None => ExplainOutput::NotFound {
             identifier: identifier.to_string(),
+            suggestion: suggest_identifier(identifier),
             available_check_ids: explain::all_check_ids(),
             available_codes: explain::all_codes(),
         },
     }
 }
 
+/// Find the known check_id or code closest to `identifier`.
+///
+/// Prefix matches win (so `no_wildcard` or `deps.no_wild` resolve to
+/// `deps.no_wildcards`); otherwise the candidate with the smallest edit distance
+/// is returned if it is within a third of the identifier's length (at most 3).
+pub fn suggest_identifier(identifier: &str) -> Option<&'static str> {
+    let needle = identifier.trim().to_ascii_lowercase();
+    if needle.is_empty() {
+        return None;
+    }
+    let candidates = || {
+        explain::all_check_ids()
+            .iter()
+            .chain(explain::all_codes())
+            .copied()
+    };
+
+    let qualified = format!("deps.{needle}");
+    if let Some(found) = candidates()
+        .filter(|c| c.starts_with(needle.as_str()) || c.starts_with(qualified.as_str()))
+        .min_by_key(|c| c.len())
+    {
+        return Some(found);
+    }
+
+    let threshold = (needle.chars().count() / 3).clamp(1, 3);
+    candidates()
+        .map(|c| {
+            let bare = c.strip_prefix("deps.").unwrap_or(c);
+            let distance = edit_distance(&needle, c).min(edit_distance(&needle, bare));
+            (distance, c)
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
 /// Format an explanation for terminal display.
 pub fn format_explanation(exp: &Explanation) -> String {
     let mut out = String::new();
@@ -57,15 +114,79 @@ pub fn format_explanation(exp: &Explanation) -> String {
     out
 }
 
+/// Serialize one explanation for `depguard explain --json`.
+pub fn explanation_json(identifier: &str, exp: &Explanation) -> Value {
+    json!({
+        "schema": SCHEMA_EXPLAIN_V1,
+        "entry": explain_entry(identifier, exp),
+    })
+}
+
+/// Serialize every check_id and code in the explain registry, in registry order.
+pub fn explain_catalog_json() -> Value {
+    let entries = |identifiers: &[&str]| -> Vec<Value> {
+        identifiers
+            .iter()
+            .filter_map(|id| explain::lookup_explanation(id).map(|exp| explain_entry(id, &exp)))
+            .collect()
+    };
+    json!({
+        "schema": SCHEMA_EXPLAIN_V1,
+        "check_ids": entries(explain::all_check_ids()),
+        "codes": entries(explain::all_codes()),
+    })
+}
+
+/// Serialize the "not found" error for `depguard explain --json`.
+pub fn not_found_json(
+    identifier: &str,
+    suggestion: Option<&str>,
+    check_ids: &[&'static str],
+    codes: &[&'static str],
+) -> Value {
+    json!({
+        "schema": SCHEMA_EXPLAIN_V1,
+        "error": {
+            "code": "unknown_identifier",
+            "message": format!("Unknown check_id or code: '{}'", identifier),
+            "identifier": identifier,
+            "suggestion": suggestion,
+            "available_check_ids": check_ids,
+            "available_codes": codes,
+        },
+    })
+}
+
+fn explain_entry(identifier: &str, exp: &Explanation) -> Value {
+    let kind = if explain::all_check_ids().contains(&identifier) {
+        "check"
+    } else {
+        "code"
+    };
+    json!({
+        "id": identifier,
+        "kind": kind,
+        "title": exp.title,
+        "description": exp.description,
+        "remediation": exp.remediation,
+        "examples": exp.examples,
+    })
+}
+
 /// Format the "not found" error message for terminal display.
 pub fn format_not_found(
     identifier: &str,
+    suggestion: Option<&str>,
     check_ids: &[&'static str],
     codes: &[&'static str],
 ) -> String {
     let mut out = String::new();
 
-    out.push_str(&format!("Unknown check_id or code: '{}'\n\n", identifier));
+    out.push_str(&format!("Unknown check_id or code: '{}'\n", identifier));
+    if let Some(suggestion) = suggestion {
+        out.push_str(&format!("Did you mean '{}'?\n", suggestion));
+    }
+    out.push('\n');
     out.push_str(
         "The identifier you provided does not match any known check ID or error code.\n\n",
     );
@@ -108,8 +229,10 @@ mod tests {
     #[test]
     fn explain_unknown() {
         let output = run_explain("not_a_real_thing");
-        let (identifier, available_check_ids, available_codes) = unwrap_not_found(output);
+        let (identifier, suggestion, available_check_ids, available_codes) =
+            unwrap_not_found(output);
         assert_eq!(identifier, "not_a_real_thing");
+        assert_eq!(suggestion, None);
         assert!(!available_check_ids.is_empty());
         assert!(!available_codes.is_empty());
     }
@@ -126,8 +249,10 @@ mod tests {
 
     #[test]
     fn format_not_found_output() {
-        let formatted = format_not_found("missing", &["check.one", "check.two"], &["code.one"]);
+        let formatted =
+            format_not_found("missing", None, &["check.one", "check.two"], &["code.one"]);
         assert!(formatted.contains("Unknown check_id or code: 'missing'"));
+        assert!(!formatted.contains("Did you mean"));
         assert!(formatted.contains("Available check_ids:"));
         assert!(formatted.contains("check.one"));
         assert!(formatted.contains("check.two"));
@@ -136,6 +261,93 @@ mod tests {
         assert!(formatted.contains("To find the correct identifier"));
     }
 
+    #[test]
+    fn explanation_json_has_stable_shape() {
+        let exp = unwrap_found(run_explain("wildcard_version"));
+        let value = explanation_json("wildcard_version", &exp);
+        assert_eq!(value["schema"], SCHEMA_EXPLAIN_V1);
+        let entry = &value["entry"];
+        assert_eq!(entry["id"], "wildcard_version");
+        assert_eq!(entry["kind"], "code");
+        assert_eq!(entry["title"], exp.title);
+        assert_eq!(entry["examples"]["before"], exp.examples.before);
+        assert_eq!(entry["examples"]["after"], exp.examples.after);
+    }
+
+    #[test]
+    fn explain_catalog_json_covers_registry() {
+        let value = explain_catalog_json();
+        let ids = |key: &str| -> Vec<String> {
+            value[key]
+                .as_array()
+                .expect("array")
+                .iter()
+                .map(|e| e["id"].as_str().expect("id").to_string())
+                .collect()
+        };
+        assert_eq!(ids("check_ids"), explain::all_check_ids());
+        assert_eq!(ids("codes"), explain::all_codes());
+        assert!(
+            value["check_ids"]
+                .as_array()
+                .expect("array")
+                .iter()
+                .all(|e| e["kind"] == "check")
+        );
+    }
+
+    #[test]
+    fn not_found_json_lists_available_identifiers() {
+        let value = not_found_json("missing", None, &["check.one"], &["code.one"]);
+        assert_eq!(value["error"]["code"], "unknown_identifier");
+        assert!(value["error"]["suggestion"].is_null());
+        assert_eq!(value["error"]["identifier"], "missing");
+        assert_eq!(value["error"]["available_check_ids"][0], "check.one");
+        assert_eq!(value["error"]["available_codes"][0], "code.one");
+    }
+
+    #[test]
+    fn explain_near_miss_suggests_closest_identifier() {
+        let output = run_explain("no_wildcard");
+        let (_, suggestion, check_ids, codes) = unwrap_not_found(output);
+        assert_eq!(suggestion, Some("deps.no_wildcards"));
+
+        let formatted = format_not_found("no_wildcard", suggestion, check_ids, codes);
+        assert!(formatted.contains("Did you mean 'deps.no_wildcards'?"));
+        let value = not_found_json("no_wildcard", suggestion, check_ids, codes);
+        assert_eq!(value["error"]["suggestion"], "deps.no_wildcards");
+    }
+
+    #[test]
+    fn suggest_identifier_handles_typos_and_prefixes() {
+        assert_eq!(
+            suggest_identifier("deps.no_wildcrads"),
+            Some("deps.no_wildcards")
+        );
+        assert_eq!(
+            suggest_identifier("wildcard_verison"),
+            Some("wildcard_version")
+        );
+        assert_eq!(
+            suggest_identifier("deps.no_wild"),
+            Some("deps.no_wildcards")
+        );
+    }
+
+    #[test]
+    fn suggest_identifier_returns_none_for_unrelated_input() {
+        assert_eq!(suggest_identifier("completely unrelated string"), None);
+        assert_eq!(suggest_identifier("xyzzy"), None);
+        assert_eq!(suggest_identifier(""), None);
+    }
+
+    #[test]
+    fn edit_distance_counts_single_char_edits() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
     fn unwrap_found(output: ExplainOutput) -> Explanation {
         match output {
             ExplainOutput::Found(exp) => exp,
@@ -145,13 +357,19 @@ mod tests {
 
     fn unwrap_not_found(
         output: ExplainOutput,
-    ) -> (String, &'static [&'static str], &'static [&'static str]) {
+    ) -> (
+        String,
+        Option<&'static str>,
+        &'static [&'static str],
+        &'static [&'static str],
+    ) {
         match output {
             ExplainOutput::NotFound {
                 identifier,
+                suggestion,
                 available_check_ids,
                 available_codes,
-            } => (identifier, available_check_ids, available_codes),
+            } => (identifier, suggestion, available_check_ids, available_codes),
             _ => panic!("expected NotFound"),
         }
     }
@@ -0,0 +1,168 @@
+//! Per-manifest findings cache for incremental runs.
+//!
+//! Manifest-scoped check results are stored under the manifest's repo-relative
+//! path, keyed by a SHA-256 of the manifest text. The whole file is discarded
+//! when its context hash changes: the depguard version, the resolved config and
+//! the root manifest (which supplies `[workspace.dependencies]` and inherited
+//! package fields). Workspace-scoped checks always run.
+
+use anyhow::Context;
+use camino::{Utf8Path, Utf8PathBuf};
+use depguard::model::WorkspaceModel;
+use depguard::report::DomainReport;
+use depguard_settings::{ConfigDump, ResolvedConfig};
+use depguard_types::Finding;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+pub(crate) const FINDINGS_CACHE_FILENAME: &str = "findings.v1.json";
+const FINDINGS_CACHE_VERSION: u32 = 2;
+
+#[derive(Clone, Debug)]
+struct CachedManifest {
+    hash: String,
+    findings: Vec<Finding>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FindingsCache {
+    path: Utf8PathBuf,
+    context: String,
+    entries: BTreeMap<String, CachedManifest>,
+    dirty: bool,
+}
+
+impl FindingsCache {
+    /// Load the cache from `cache_dir`, starting empty when it is missing,
+    /// unreadable as JSON, from another cache version, or for another context.
+    pub(crate) fn load(
+        repo_root: &Utf8Path,
+        cache_dir: &Utf8Path,
+        resolved: &ResolvedConfig,
+    ) -> anyhow::Result<Self> {
+        let abs_cache_dir = if cache_dir.is_absolute() {
+            cache_dir.to_path_buf()
+        } else {
+            repo_root.join(cache_dir)
+        };
+        let path = abs_cache_dir.join(FINDINGS_CACHE_FILENAME);
+        let context = context_hash(repo_root, resolved)?;
+
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(text) => parse_entries(&text, &context).unwrap_or_default(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("read findings cache {}", path));
+            }
+        };
+
+        Ok(Self {
+            path,
+            context,
+            entries,
+            dirty: false,
+        })
+    }
+
+    /// Evaluate `model`, reusing cached findings for manifests whose text is unchanged.
+    pub(crate) fn evaluate(
+        &mut self,
+        repo_root: &Utf8Path,
+        model: &WorkspaceModel,
+        cfg: &depguard::policy::EffectiveConfig,
+    ) -> DomainReport {
+        let mut findings = Vec::new();
+        for (index, manifest) in model.manifests.iter().enumerate() {
+            let key = manifest.path.as_str();
+            let Ok(text) = std::fs::read(repo_root.join(key)) else {
+                findings.extend(depguard::evaluate_manifest(model, index, cfg));
+                continue;
+            };
+            let hash = sha256_hex(&text);
+            match self.entries.get(key) {
+                Some(entry) if entry.hash == hash => findings.extend(entry.findings.clone()),
+                _ => {
+                    let fresh = depguard::evaluate_manifest(model, index, cfg);
+                    findings.extend(fresh.clone());
+                    self.entries.insert(
+                        key.to_string(),
+                        CachedManifest {
+                            hash,
+                            findings: fresh,
+                        },
+                    );
+                    self.dirty = true;
+                }
+            }
+        }
+        findings.extend(depguard::evaluate_workspace(model, cfg));
+        depguard::report_from_findings(model, cfg, findings)
+    }
+
+    pub(crate) fn save_if_dirty(&self) -> anyhow::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("create findings cache directory {}", parent))?;
+        }
+        let entries: serde_json::Map<String, Value> = self
+            .entries
+            .iter()
+            .map(|(path, entry)| {
+                (
+                    path.clone(),
+                    json!({ "hash": entry.hash, "findings": entry.findings }),
+                )
+            })
+            .collect();
+        let file = json!({
+            "version": FINDINGS_CACHE_VERSION,
+            "context": self.context,
+            "entries": entries,
+        });
+        let text = serde_json::to_string(&file).context("serialize findings cache")?;
+        std::fs::write(&self.path, text)
+            .with_context(|| format!("write findings cache {}", self.path))
+    }
+}
+
+fn parse_entries(text: &str, context: &str) -> Option<BTreeMap<String, CachedManifest>> {
+    let value: Value = serde_json::from_str(text).ok()?;
+    if value.get("version")?.as_u64()? != u64::from(FINDINGS_CACHE_VERSION)
+        || value.get("context")?.as_str()? != context
+    {
+        return None;
+    }
+    value
+        .get("entries")?
+        .as_object()?
+        .iter()
+        .map(|(path, entry)| {
+            let hash = entry.get("hash")?.as_str()?.to_string();
+            let findings = serde_json::from_value(entry.get("findings")?.clone()).ok()?;
+            Some((path.clone(), CachedManifest { hash, findings }))
+        })
+        .collect()
+}
+
+/// Hash of everything besides the manifest text that manifest-scoped findings depend on.
+fn context_hash(repo_root: &Utf8Path, resolved: &ResolvedConfig) -> anyhow::Result<String> {
+    let config = serde_json::to_string(&ConfigDump::from(resolved))
+        .context("serialize resolved config for findings cache")?;
+    let root_manifest = std::fs::read(repo_root.join("Cargo.toml")).unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.update([0]);
+    hasher.update(config.as_bytes());
+    hasher.update([0]);
+    hasher.update(&root_manifest);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
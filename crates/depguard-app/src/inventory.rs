@@ -0,0 +1,201 @@
+//! The `inventory` use case: list every declared dependency without running checks.
+
+use camino::Utf8Path;
+use depguard::model::{DepKind, DependencyDecl, WorkspaceModel};
+use depguard_repo::ScopeInput;
+use serde_json::{Value, json};
+
+/// One dependency declaration, with workspace inheritance resolved.
+///
+/// Field order is the sort order: manifest, then crate name, then kind.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InventoryEntry {
+    pub manifest: String,
+    /// Crate name (the `package` of a renamed dependency, not its alias).
+    pub name: String,
+    /// `normal`, `dev`, or `build`.
+    pub kind: &'static str,
+    pub version_req: Option<String>,
+    /// Alternate registry name; `None` for crates.io and non-registry sources.
+    pub registry: Option<String>,
+    /// `registry`, `git`, or `path`.
+    pub source: &'static str,
+}
+
+/// Collect the dependency inventory of every manifest in the workspace.
+///
+/// Entries are sorted and exact duplicates (e.g. the same dependency under two
+/// target tables) are collapsed, so the output is stable across runs.
+pub fn run_inventory(repo_root: &Utf8Path) -> anyhow::Result<Vec<InventoryEntry>> {
+    let model = depguard_repo::build_workspace_model(repo_root, ScopeInput::Repo)?;
+    Ok(collect_inventory(&model))
+}
+
+fn collect_inventory(model: &WorkspaceModel) -> Vec<InventoryEntry> {
+    let mut entries: Vec<InventoryEntry> = model
+        .manifests
+        .iter()
+        .flat_map(|manifest| {
+            manifest
+                .dependencies
+                .iter()
+                .map(move |dep| entry(model, manifest.path.as_str(), dep))
+        })
+        .collect();
+    entries.sort();
+    entries.dedup();
+    entries
+}
+
+fn entry(model: &WorkspaceModel, manifest: &str, dep: &DependencyDecl) -> InventoryEntry {
+    let spec = &dep.spec;
+    let inherited = spec
+        .workspace
+        .then(|| model.workspace_dependencies.get(&dep.name))
+        .flatten();
+    let (version_req, path) = match inherited {
+        Some(ws) => (ws.version.clone(), ws.path.as_ref()),
+        None => (spec.version.clone(), spec.path.as_ref()),
+    };
+    let source = if spec.git.is_some() {
+        "git"
+    } else if path.is_some() {
+        "path"
+    } else {
+        "registry"
+    };
+
+    InventoryEntry {
+        manifest: manifest.to_string(),
+        name: spec.rename_of.clone().unwrap_or_else(|| dep.name.clone()),
+        kind: match dep.kind {
+            DepKind::Normal => "normal",
+            DepKind::Dev => "dev",
+            DepKind::Build => "build",
+        },
+        version_req,
+        registry: spec.registry.clone(),
+        source,
+    }
+}
+
+/// Serialize the inventory for `depguard inventory`.
+pub fn inventory_json(entries: &[InventoryEntry]) -> Value {
+    let entries: Vec<Value> = entries
+        .iter()
+        .map(|e| {
+            json!({
+                "manifest": e.manifest,
+                "name": e.name,
+                "kind": e.kind,
+                "version_req": e.version_req,
+                "registry": e.registry,
+                "source": e.source,
+            })
+        })
+        .collect();
+    Value::Array(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn inventory_resolves_sources_and_sorts() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        std::fs::write(
+            root.join("Cargo.toml"),
+            r#"[workspace]
+members = ["crates/b"]
+
+[workspace.dependencies]
+serde = "1.0"
+local = { path = "crates/local" }
+
+[package]
+name = "root"
+version = "0.1.0"
+
+[dependencies]
+zeta = { version = "2", registry = "internal" }
+alias = { package = "real-name", git = "https://example.com/real" }
+
+[build-dependencies]
+cc = "1"
+"#,
+        )
+        .expect("write root manifest");
+        std::fs::create_dir_all(root.join("crates/b")).expect("mkdir");
+        std::fs::write(
+            root.join("crates/b/Cargo.toml"),
+            r#"[package]
+name = "b"
+version = "0.1.0"
+
+[dependencies]
+serde = { workspace = true }
+local = { workspace = true }
+
+[dev-dependencies]
+serde = { workspace = true }
+"#,
+        )
+        .expect("write member manifest");
+
+        let entries = run_inventory(root).expect("inventory");
+        let rows: Vec<_> = entries
+            .iter()
+            .map(|e| {
+                (
+                    e.manifest.as_str(),
+                    e.name.as_str(),
+                    e.kind,
+                    e.version_req.as_deref(),
+                    e.registry.as_deref(),
+                    e.source,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            rows,
+            vec![
+                ("Cargo.toml", "cc", "build", Some("1"), None, "registry"),
+                ("Cargo.toml", "real-name", "normal", None, None, "git"),
+                (
+                    "Cargo.toml",
+                    "zeta",
+                    "normal",
+                    Some("2"),
+                    Some("internal"),
+                    "registry"
+                ),
+                ("crates/b/Cargo.toml", "local", "normal", None, None, "path"),
+                (
+                    "crates/b/Cargo.toml",
+                    "serde",
+                    "dev",
+                    Some("1.0"),
+                    None,
+                    "registry"
+                ),
+                (
+                    "crates/b/Cargo.toml",
+                    "serde",
+                    "normal",
+                    Some("1.0"),
+                    None,
+                    "registry"
+                ),
+            ]
+        );
+
+        let json = inventory_json(&entries);
+        assert_eq!(json[0]["kind"], "build");
+        assert_eq!(json[2]["registry"], "internal");
+        assert!(json[0]["registry"].is_null());
+    }
+}
@@ -9,19 +9,37 @@
 
 mod baseline;
 mod check;
+mod config;
+mod diff;
 mod explain;
+mod findings_cache;
 mod fix;
+mod inventory;
+mod list_checks;
 mod render;
 mod report;
 
 pub use baseline::{
     BaselineApplyResult, apply_baseline, generate_baseline, parse_baseline_json, serialize_baseline,
 };
-pub use check::{CheckInput, CheckOutput, run_check, verdict_exit_code};
-pub use explain::{ExplainOutput, format_explanation, format_not_found, run_explain};
+pub use check::{CheckInput, CheckOutput, resolve_check_config, run_check, verdict_exit_code};
+pub use config::{expand_config_glob, is_config_glob, merge_config_files, resolve_config_extends};
+pub use diff::{ReportDiff, diff_reports, render_diff_markdown, render_diff_text};
+pub use explain::{
+    ExplainOutput, explain_catalog_json, explanation_json, format_explanation, format_not_found,
+    not_found_json, run_explain, suggest_identifier,
+};
 pub use fix::{FixApplyResult, apply_safe_fixes, generate_buildfix_plan, serialize_buildfix_plan};
-pub use render::{render_annotations, render_jsonl, render_junit, render_markdown, render_sarif};
+pub use inventory::{InventoryEntry, inventory_json, run_inventory};
+pub use list_checks::{
+    CheckListing, ListChecksOutput, check_list_json, format_check_list, run_list_checks,
+};
+pub use render::{
+    render_annotations, render_azure_annotations, render_checkstyle, render_gitlab_codequality,
+    render_html, render_job_summary, render_jsonl, render_junit, render_markdown, render_ndjson,
+    render_sarif, render_teamcity, render_terminal,
+};
 pub use report::{
-    ReportVariant, ReportVersion, add_artifact, empty_report, parse_report_json,
-    runtime_error_report, serialize_report, to_renderable,
+    ReportVariant, ReportVersion, add_artifact, empty_report, parse_report_json, report_verdict,
+    retain_min_severity, runtime_error_report, serialize_report, to_renderable,
 };
@@ -0,0 +1,163 @@
+//! The `list-checks` use case: enumerate registered checks and their profile defaults.
+
+use depguard_settings::{DepguardConfigV1, Overrides, resolve_config};
+use depguard_types::{Severity, explain};
+use serde_json::{Value, json};
+
+/// One registered check with its defaults under a profile.
+#[derive(Clone, Debug)]
+pub struct CheckListing {
+    pub id: String,
+    /// One-line title from the explain registry.
+    pub title: &'static str,
+    pub enabled: bool,
+    pub severity: Severity,
+}
+
+/// Output from the list-checks use case.
+#[derive(Clone, Debug)]
+pub struct ListChecksOutput {
+    /// Canonical profile name (aliases such as `team` are resolved).
+    pub profile: String,
+    /// Checks sorted by id.
+    pub checks: Vec<CheckListing>,
+}
+
+/// List every registered check with its default enablement and severity for `profile`.
+///
+/// `None` resolves to the default (`strict`) profile. Unknown profiles are rejected
+/// with the same validation error as `depguard check`.
+pub fn run_list_checks(profile: Option<&str>) -> anyhow::Result<ListChecksOutput> {
+    let resolved = resolve_config(
+        DepguardConfigV1::default(),
+        Overrides {
+            profile: profile.map(str::to_string),
+            ..Overrides::default()
+        },
+    )?;
+
+    let checks = resolved
+        .effective
+        .checks
+        .iter()
+        .map(|(id, policy)| CheckListing {
+            id: id.clone(),
+            title: explain::lookup_explanation(id)
+                .map(|exp| exp.title)
+                .unwrap_or(""),
+            enabled: policy.enabled,
+            severity: policy.severity,
+        })
+        .collect();
+
+    Ok(ListChecksOutput {
+        profile: resolved.effective.profile,
+        checks,
+    })
+}
+
+/// Format the check list as an aligned table for terminal display.
+pub fn format_check_list(output: &ListChecksOutput) -> String {
+    let id_width = output
+        .checks
+        .iter()
+        .map(|c| c.id.len())
+        .max()
+        .unwrap_or(0)
+        .max("CHECK".len());
+
+    let mut out = format!("Profile: {}\n\n", output.profile);
+    out.push_str(&format!(
+        "{:<id_width$}  {:<8}  {:<8}  TITLE\n",
+        "CHECK", "ENABLED", "SEVERITY"
+    ));
+    for check in &output.checks {
+        out.push_str(&format!(
+            "{:<id_width$}  {:<8}  {:<8}  {}\n",
+            check.id,
+            if check.enabled { "yes" } else { "no" },
+            severity_str(check.severity),
+            check.title
+        ));
+    }
+    out
+}
+
+/// Serialize the check list for `depguard list-checks --json`.
+pub fn check_list_json(output: &ListChecksOutput) -> Value {
+    let checks: Vec<Value> = output
+        .checks
+        .iter()
+        .map(|c| {
+            json!({
+                "id": c.id,
+                "title": c.title,
+                "enabled": c.enabled,
+                "severity": c.severity,
+            })
+        })
+        .collect();
+    json!({
+        "profile": output.profile,
+        "checks": checks,
+    })
+}
+
+fn severity_str(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "info",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_checks_defaults_to_strict_and_is_sorted() {
+        let output = run_list_checks(None).expect("list checks");
+        assert_eq!(output.profile, "strict");
+        assert!(!output.checks.is_empty());
+        assert!(output.checks.windows(2).all(|w| w[0].id < w[1].id));
+        assert!(output.checks.iter().all(|c| !c.title.is_empty()));
+    }
+
+    #[test]
+    fn list_checks_reflects_profile_defaults() {
+        let strict = run_list_checks(Some("strict")).expect("strict");
+        let warn = run_list_checks(Some("team")).expect("team alias");
+        assert_eq!(warn.profile, "warn");
+
+        let severity = |out: &ListChecksOutput, id: &str| {
+            out.checks
+                .iter()
+                .find(|c| c.id == id)
+                .map(|c| c.severity)
+                .expect("check listed")
+        };
+        assert_eq!(severity(&strict, "deps.no_wildcards"), Severity::Error);
+        assert_eq!(severity(&warn, "deps.no_wildcards"), Severity::Warning);
+    }
+
+    #[test]
+    fn list_checks_rejects_unknown_profile() {
+        assert!(run_list_checks(Some("nope")).is_err());
+    }
+
+    #[test]
+    fn format_and_json_include_every_check() {
+        let output = run_list_checks(Some("compat")).expect("compat");
+        let text = format_check_list(&output);
+        assert!(text.starts_with("Profile: compat\n"));
+        let value = check_list_json(&output);
+        assert_eq!(value["profile"], "compat");
+        let listed = value["checks"].as_array().expect("checks array");
+        assert_eq!(listed.len(), output.checks.len());
+        for check in &output.checks {
+            assert!(text.contains(&check.id));
+        }
+        assert!(listed[0]["severity"].is_string());
+    }
+}
@@ -1,6 +1,6 @@
-//! Render use cases: markdown and GitHub annotations from in-memory reports.
+//! Render use cases: markdown and CI annotations from in-memory reports.
 
-use depguard_render::RenderableReport;
+use depguard_render::{AnnotationMode, RenderableReport};
 
 pub fn render_markdown(report: &RenderableReport) -> String {
     depguard_render::render_markdown(report)
@@ -14,15 +14,52 @@ pub fn render_junit(report: &RenderableReport) -> String {
     depguard_render::render_junit(report)
 }
 
+pub fn render_html(report: &RenderableReport) -> String {
+    depguard_render::render_html(report)
+}
+
+pub fn render_checkstyle(report: &RenderableReport) -> String {
+    depguard_render::render_checkstyle(report)
+}
+
+pub fn render_gitlab_codequality(report: &RenderableReport) -> String {
+    depguard_render::render_gitlab_codequality(report)
+}
+
 pub fn render_jsonl(report: &RenderableReport) -> String {
     depguard_render::render_jsonl(report)
 }
 
-pub fn render_annotations(report: &RenderableReport, max: usize) -> Vec<String> {
-    depguard_render::render_github_annotations(report)
-        .into_iter()
-        .take(max)
-        .collect()
+pub fn render_ndjson(report: &RenderableReport) -> String {
+    depguard_render::render_ndjson(report)
+}
+
+pub fn render_job_summary(report: &RenderableReport) -> String {
+    depguard_render::render_job_summary(report)
+}
+
+pub fn render_terminal(report: &RenderableReport, no_color: bool) -> String {
+    depguard_render::render_terminal(report, no_color)
+}
+
+pub fn render_annotations(
+    report: &RenderableReport,
+    max: usize,
+    mode: AnnotationMode,
+) -> Vec<String> {
+    depguard_render::render_github_annotations(report, max, mode)
+}
+
+pub fn render_azure_annotations(
+    report: &RenderableReport,
+    max: usize,
+    mode: AnnotationMode,
+) -> Vec<String> {
+    depguard_render::render_azure_annotations(report, max, mode)
+}
+
+pub fn render_teamcity(report: &RenderableReport) -> Vec<String> {
+    depguard_render::render_teamcity(report)
 }
 
 #[cfg(test)]
@@ -49,6 +86,7 @@ mod tests {
                     }),
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Info,
@@ -58,6 +96,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -65,13 +104,17 @@ mod tests {
                 findings_total: 2,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         }
     }
 
     #[test]
     fn render_annotations_respects_max() {
         let report = sample_report();
-        let annotations = render_annotations(&report, 1);
+        let annotations = render_annotations(&report, 1, AnnotationMode::Individual);
         assert_eq!(annotations.len(), 1);
     }
 
@@ -97,6 +140,30 @@ mod tests {
         assert!(junit.contains("depguard"));
     }
 
+    #[test]
+    fn render_checkstyle_smoke() {
+        let report = sample_report();
+        let xml = render_checkstyle(&report);
+        assert!(xml.contains("<checkstyle"));
+        assert!(xml.contains("<file name="));
+    }
+
+    #[test]
+    fn render_html_smoke() {
+        let report = sample_report();
+        let html = render_html(&report);
+        assert!(html.contains("<!DOCTYPE html>"));
+        assert!(html.contains("Verdict:"));
+    }
+
+    #[test]
+    fn render_gitlab_codequality_smoke() {
+        let report = sample_report();
+        let gitlab = render_gitlab_codequality(&report);
+        assert!(gitlab.contains("\"check_name\": \"deps.no_wildcards\""));
+        assert!(gitlab.contains("\"fingerprint\""));
+    }
+
     #[test]
     fn render_jsonl_smoke() {
         let report = sample_report();
@@ -104,4 +171,27 @@ mod tests {
         assert!(jsonl.contains("\"kind\":\"finding\""));
         assert!(jsonl.contains("\"kind\":\"summary\""));
     }
+
+    #[test]
+    fn render_ndjson_smoke() {
+        let report = sample_report();
+        let ndjson = render_ndjson(&report);
+        assert_eq!(ndjson.lines().count(), report.findings.len());
+        assert!(!ndjson.contains("\"kind\":\"summary\""));
+    }
+
+    #[test]
+    fn render_job_summary_smoke() {
+        let report = sample_report();
+        let summary = render_job_summary(&report);
+        assert!(summary.contains("| Severity | Location | Check | Message |"));
+    }
+
+    #[test]
+    fn render_terminal_smoke() {
+        let report = sample_report();
+        let terminal = render_terminal(&report, true);
+        assert!(terminal.starts_with("depguard: PASS"));
+        assert!(terminal.contains("Cargo.toml:1 warning"));
+    }
 }
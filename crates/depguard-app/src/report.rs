@@ -9,6 +9,7 @@ use depguard_types::{
     SCHEMA_SENSOR_REPORT_V1, Severity, SeverityV2, Verdict, VerdictStatus,
 };
 use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ReportVersion {
@@ -112,6 +113,11 @@ pub fn parse_report_json(text: &str) -> anyhow::Result<ReportVariant> {
     }
 }
 
+/// Pretty-printed JSON for a report.
+///
+/// Keys are written in struct declaration order, which serde guarantees, so the
+/// envelope layout (`schema`, `tool`, `run`, `verdict`, `findings`, `artifacts`,
+/// `data`) is stable across serde versions and safe to compare byte-for-byte.
 pub fn serialize_report(report: &ReportVariant) -> anyhow::Result<Vec<u8>> {
     match report {
         ReportVariant::V1(r) => serde_json::to_vec_pretty(r).context(
@@ -127,6 +133,19 @@ pub fn serialize_report(report: &ReportVariant) -> anyhow::Result<Vec<u8>> {
     }
 }
 
+/// Overall verdict of a parsed report; v1 verdicts map onto the v2 statuses.
+pub fn report_verdict(report: &ReportVariant) -> VerdictStatus {
+    match report {
+        ReportVariant::V1(r) => match r.verdict {
+            Verdict::Pass => VerdictStatus::Pass,
+            Verdict::Warn => VerdictStatus::Warn,
+            Verdict::Fail => VerdictStatus::Fail,
+            Verdict::Skip => VerdictStatus::Skip,
+        },
+        ReportVariant::V2(r) => r.verdict.status,
+    }
+}
+
 pub fn to_renderable(report: &ReportVariant) -> RenderableReport {
     match report {
         ReportVariant::V1(r) => RenderableReport {
@@ -134,6 +153,7 @@ pub fn to_renderable(report: &ReportVariant) -> RenderableReport {
                 Verdict::Pass => RenderableVerdictStatus::Pass,
                 Verdict::Warn => RenderableVerdictStatus::Warn,
                 Verdict::Fail => RenderableVerdictStatus::Fail,
+                Verdict::Skip => RenderableVerdictStatus::Skip,
             },
             findings: r.findings.iter().map(renderable_from_v1).collect(),
             data: RenderableData {
@@ -141,6 +161,10 @@ pub fn to_renderable(report: &ReportVariant) -> RenderableReport {
                 findings_total: r.data.findings_total,
                 truncated_reason: r.data.truncated_reason.clone(),
             },
+            remediation_hints: true,
+            hidden_findings: 0,
+            tool_version: Some(r.tool.version.clone()),
+            started_at: format_timestamp(r.started_at),
         },
         ReportVariant::V2(r) => RenderableReport {
             verdict: match r.verdict.status {
@@ -155,10 +179,28 @@ pub fn to_renderable(report: &ReportVariant) -> RenderableReport {
                 findings_total: r.data.findings_total,
                 truncated_reason: r.data.truncated_reason.clone(),
             },
+            remediation_hints: true,
+            hidden_findings: 0,
+            tool_version: Some(r.tool.version.clone()),
+            started_at: format_timestamp(r.run.started_at),
         },
     }
 }
 
+/// Drop findings below `min` before rendering.
+///
+/// Verdict and `data` counts keep describing the full report; the number of
+/// dropped findings is recorded in `hidden_findings` so renderers can say so.
+pub fn retain_min_severity(report: &mut RenderableReport, min: RenderableSeverity) {
+    let before = report.findings.len();
+    report.findings.retain(|f| f.severity >= min);
+    report.hidden_findings += (before - report.findings.len()) as u32;
+}
+
+fn format_timestamp(at: OffsetDateTime) -> Option<String> {
+    at.format(&Rfc3339).ok()
+}
+
 fn renderable_from_v1(f: &depguard_types::Finding) -> RenderableFinding {
     RenderableFinding {
         severity: match f.severity {
@@ -176,6 +218,7 @@ fn renderable_from_v1(f: &depguard_types::Finding) -> RenderableFinding {
         }),
         help: f.help.clone(),
         url: f.url.clone(),
+        fingerprint: f.fingerprint.clone(),
     }
 }
 
@@ -196,6 +239,7 @@ fn renderable_from_v2(f: &FindingV2) -> RenderableFinding {
         }),
         help: f.help.clone(),
         url: f.url.clone(),
+        fingerprint: f.fingerprint.clone(),
     }
 }
 
@@ -207,6 +251,7 @@ pub fn empty_report(version: ReportVersion, scope: &str, profile: &str) -> Repor
         dependencies_scanned: 0,
         findings_total: 0,
         findings_emitted: 0,
+        findings_suppressed: 0,
         truncated_reason: None,
     };
 
@@ -240,6 +285,9 @@ pub fn empty_report(version: ReportVersion, scope: &str, profile: &str) -> Repor
                         status: CapabilityAvailability::Available,
                         reason: None,
                     }),
+                    lockfile: None,
+                    source_scan: None,
+                    provenance: None,
                 })
             } else {
                 None
@@ -267,6 +315,7 @@ pub fn empty_report(version: ReportVersion, scope: &str, profile: &str) -> Repor
                         warn: 0,
                         error: 0,
                         suppressed: 0,
+                        truncated: 0,
                     },
                     reasons: Vec::new(),
                 },
@@ -287,6 +336,7 @@ pub fn runtime_error_report(version: ReportVersion, message: &str) -> ReportVari
         dependencies_scanned: 0,
         findings_total: 1,
         findings_emitted: 1,
+        findings_suppressed: 0,
         truncated_reason: None,
     };
 
@@ -328,6 +378,9 @@ pub fn runtime_error_report(version: ReportVersion, message: &str) -> ReportVari
                         status: CapabilityAvailability::Missing,
                         reason: Some(depguard_types::ids::REASON_RUNTIME_ERROR.to_string()),
                     }),
+                    lockfile: None,
+                    source_scan: None,
+                    provenance: None,
                 })
             } else {
                 None
@@ -355,6 +408,7 @@ pub fn runtime_error_report(version: ReportVersion, message: &str) -> ReportVari
                         warn: 0,
                         error: 1,
                         suppressed: 0,
+                        truncated: 0,
                     },
                     reasons: vec!["tool_error".to_string()],
                 },
@@ -400,6 +454,7 @@ mod tests {
             dependencies_scanned: 1,
             findings_total: 1,
             findings_emitted: 1,
+            findings_suppressed: 0,
             truncated_reason: None,
         }
     }
@@ -465,6 +520,7 @@ mod tests {
                         0
                     },
                     suppressed: 0,
+                    truncated: 0,
                 },
                 reasons: Vec::new(),
             },
@@ -507,6 +563,20 @@ mod tests {
         assert!(matches!(parsed, ReportVariant::V2(_)));
     }
 
+    #[test]
+    fn report_verdict_reads_v1_and_v2() {
+        assert_eq!(
+            report_verdict(&ReportVariant::V1(sample_v1_with(
+                "receipt.envelope.v1",
+                Verdict::Fail,
+                Severity::Error
+            ))),
+            VerdictStatus::Fail
+        );
+        let v2 = sample_v2(SCHEMA_REPORT_V2, VerdictStatus::Warn, SeverityV2::Warn);
+        assert_eq!(report_verdict(&ReportVariant::V2(v2)), VerdictStatus::Warn);
+    }
+
     #[test]
     fn parse_report_json_fallbacks_work() {
         let mut v2 = sample_v2("custom.schema", VerdictStatus::Pass, SeverityV2::Info);
@@ -548,6 +618,68 @@ mod tests {
         assert!(text.contains(SCHEMA_REPORT_V2));
     }
 
+    #[test]
+    fn serialize_report_v2_byte_layout() {
+        let mut report = sample_v2(SCHEMA_REPORT_V2, VerdictStatus::Fail, SeverityV2::Error);
+        report.artifacts = Some(vec![ArtifactPointer {
+            artifact_type: depguard_types::ArtifactType::Comment,
+            path: "comment.md".to_string(),
+            format: Some("text/markdown".to_string()),
+        }]);
+        let text =
+            String::from_utf8(serialize_report(&ReportVariant::V2(report)).unwrap()).unwrap();
+
+        let expected = r#"{
+  "schema": "depguard.report.v2",
+  "tool": {
+    "name": "depguard",
+    "version": "0.0.0"
+  },
+  "run": {
+    "started_at": "1970-01-01T00:00:00Z",
+    "ended_at": "1970-01-01T00:00:00Z",
+    "duration_ms": 0
+  },
+  "verdict": {
+    "status": "fail",
+    "counts": {
+      "info": 0,
+      "warn": 0,
+      "error": 1
+    },
+    "reasons": []
+  },
+  "findings": [
+    {
+      "severity": "error",
+      "check_id": "deps.no_wildcards",
+      "code": "wildcard_version",
+      "message": "bad",
+      "location": {
+        "path": "Cargo.toml",
+        "line": 1
+      }
+    }
+  ],
+  "artifacts": [
+    {
+      "type": "comment",
+      "path": "comment.md",
+      "format": "text/markdown"
+    }
+  ],
+  "data": {
+    "scope": "repo",
+    "profile": "strict",
+    "manifests_scanned": 1,
+    "dependencies_scanned": 1,
+    "findings_total": 1,
+    "findings_emitted": 1
+  }
+}"#;
+        assert_eq!(text, expected);
+    }
+
     #[test]
     fn to_renderable_maps_severity_and_verdict() {
         let v1 = ReportVariant::V1(sample_v1(SCHEMA_REPORT_V1));
@@ -607,6 +739,38 @@ mod tests {
         assert_eq!(renderable.findings[0].severity, RenderableSeverity::Error);
     }
 
+    #[test]
+    fn retain_min_severity_hides_lower_findings_but_keeps_verdict() {
+        let mut v2 = sample_v2(SCHEMA_REPORT_V2, VerdictStatus::Fail, SeverityV2::Error);
+        let mut warning = v2.findings[0].clone();
+        warning.severity = SeverityV2::Warn;
+        let mut info = v2.findings[0].clone();
+        info.severity = SeverityV2::Info;
+        v2.findings.extend([warning, info]);
+        let report = ReportVariant::V2(v2);
+
+        let mut renderable = to_renderable(&report);
+        let data = renderable.data.clone();
+        retain_min_severity(&mut renderable, RenderableSeverity::Warning);
+        let severities: Vec<_> = renderable.findings.iter().map(|f| f.severity).collect();
+        assert_eq!(
+            severities,
+            vec![RenderableSeverity::Error, RenderableSeverity::Warning]
+        );
+        assert_eq!(renderable.hidden_findings, 1);
+        assert_eq!(renderable.verdict, RenderableVerdictStatus::Fail);
+        assert_eq!(renderable.data, data);
+
+        retain_min_severity(&mut renderable, RenderableSeverity::Error);
+        assert_eq!(renderable.findings.len(), 1);
+        assert_eq!(renderable.hidden_findings, 2);
+
+        let mut unfiltered = to_renderable(&report);
+        retain_min_severity(&mut unfiltered, RenderableSeverity::Info);
+        assert_eq!(unfiltered.findings.len(), 3);
+        assert_eq!(unfiltered.hidden_findings, 0);
+    }
+
     #[test]
     fn empty_report_versions() {
         let v1 = unwrap_v1(empty_report(ReportVersion::V1, "repo", "strict"));
@@ -23,6 +23,8 @@ pub struct CheckCatalogEntry {
     pub feature: CheckFeature,
     /// Primary BDD feature file that should exercise this check.
     pub bdd_feature_file: &'static str,
+    /// Keys that must be present in the check's `options` table when it is enabled.
+    pub required_options: &'static [&'static str],
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -44,6 +46,47 @@ pub enum CheckFeature {
     NoMultipleVersions,
     OptionalUnused,
     YankedVersions,
+    BannedCrates,
+    AllowedRegistries,
+    GitPinned,
+    DuplicateAcrossKinds,
+    Sorted,
+    MsrvFloor,
+    NoPatchOverride,
+    NoDefaultFeatures,
+    NoPrerelease,
+    VersionPinning,
+    DevDepLeakage,
+    CrateDirName,
+    WorkspaceVersionOverride,
+    RestrictBuildDeps,
+    VersionRequired,
+    MisleadingEmptyFeatures,
+    ExplicitDepFeature,
+    MaxDirectDeps,
+    NoOpenRanges,
+    PublishReachability,
+    SecureGitUrl,
+    ValidTargetCfg,
+    NoZeroverInStable,
+    LicensePresent,
+    NoRename,
+    ConsistentVersions,
+    NoOptionalDevDeps,
+    GitVersionConflict,
+    ConsistentMsrv,
+    LockInSync,
+    MembersExist,
+    NoNestedWorkspace,
+    DeprecatedCrates,
+    PublishMetadata,
+    BannedFeatures,
+    FeaturesAfterDefaultOff,
+    PathWithinWorkspace,
+    BuildScriptConsistency,
+    NoDependencyCycles,
+    LintsConfigured,
+    NoStdDefaultFeatures,
 }
 
 const CHECK_CATALOG: &[CheckCatalogEntry] = &[
@@ -56,6 +99,7 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::NoWildcards,
         bdd_feature_file: "rules_no_wildcards.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_PATH_REQUIRES_VERSION,
@@ -66,6 +110,7 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::PathRequiresVersion,
         bdd_feature_file: "rules_path_requires_version.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_PATH_SAFETY,
@@ -76,6 +121,7 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::PathSafety,
         bdd_feature_file: "rules_path_safety.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_WORKSPACE_INHERITANCE,
@@ -86,6 +132,7 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::WorkspaceInheritance,
         bdd_feature_file: "rules_workspace_inheritance.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_GIT_REQUIRES_VERSION,
@@ -96,6 +143,7 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::GitRequiresVersion,
         bdd_feature_file: "checks.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_DEFAULT_FEATURES_EXPLICIT,
@@ -106,26 +154,35 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::DefaultFeaturesExplicit,
         bdd_feature_file: "checks.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS,
-        codes: &[ids::CODE_DUPLICATE_DIFFERENT_VERSIONS],
+        codes: &[
+            ids::CODE_DUPLICATE_DIFFERENT_VERSIONS,
+            ids::CODE_MULTIPLE_VERSIONS,
+        ],
         strict_enabled: false,
         strict_severity: Severity::Warning,
         warn_enabled: false,
         warn_severity: Severity::Warning,
         feature: CheckFeature::NoMultipleVersions,
         bdd_feature_file: "checks.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_OPTIONAL_UNUSED,
-        codes: &[ids::CODE_OPTIONAL_NOT_IN_FEATURES],
+        codes: &[
+            ids::CODE_OPTIONAL_NOT_IN_FEATURES,
+            ids::CODE_OPTIONAL_NEVER_ENABLED,
+        ],
         strict_enabled: false,
         strict_severity: Severity::Warning,
         warn_enabled: false,
         warn_severity: Severity::Warning,
         feature: CheckFeature::OptionalUnused,
         bdd_feature_file: "checks.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_DEV_ONLY_IN_NORMAL,
@@ -136,6 +193,7 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Warning,
         feature: CheckFeature::DevOnlyInNormal,
         bdd_feature_file: "checks.feature",
+        required_options: &[],
     },
     CheckCatalogEntry {
         id: ids::CHECK_DEPS_YANKED_VERSIONS,
@@ -146,6 +204,468 @@ const CHECK_CATALOG: &[CheckCatalogEntry] = &[
         warn_severity: Severity::Error,
         feature: CheckFeature::YankedVersions,
         bdd_feature_file: "roadmap.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_BANNED_CRATES,
+        codes: &[ids::CODE_BANNED_CRATE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::BannedCrates,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_ALLOWED_REGISTRIES,
+        codes: &[ids::CODE_DISALLOWED_REGISTRY],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::AllowedRegistries,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_GIT_PINNED,
+        codes: &[ids::CODE_GIT_BRANCH_UNPINNED],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::GitPinned,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS,
+        codes: &[ids::CODE_DUPLICATE_DEPENDENCY_KINDS],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::DuplicateAcrossKinds,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_SORTED,
+        codes: &[ids::CODE_DEPS_UNSORTED],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::Sorted,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_MSRV_FLOOR,
+        codes: &[
+            ids::CODE_MSRV_BELOW_FLOOR,
+            ids::CODE_EDITION_BELOW_FLOOR,
+            ids::CODE_MISSING_RUST_VERSION,
+        ],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::MsrvFloor,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_PATCH_OVERRIDE,
+        codes: &[
+            ids::CODE_PATCH_SECTION_PRESENT,
+            ids::CODE_REPLACE_SECTION_PRESENT,
+        ],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoPatchOverride,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_DEFAULT_FEATURES,
+        codes: &[ids::CODE_DEFAULT_FEATURES_ENABLED],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoDefaultFeatures,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_PRERELEASE,
+        codes: &[ids::CODE_PRERELEASE_VERSION],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoPrerelease,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_VERSION_PINNING,
+        codes: &[ids::CODE_EXACT_PIN_FORBIDDEN, ids::CODE_EXACT_PIN_REQUIRED],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::VersionPinning,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_DEV_DEP_LEAKAGE,
+        codes: &[ids::CODE_DEV_DEP_USED_IN_SRC],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::DevDepLeakage,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_CRATE_DIR_NAME,
+        codes: &[ids::CODE_CRATE_NAME_DIR_MISMATCH],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::CrateDirName,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+        codes: &[ids::CODE_SHOULD_INHERIT_WORKSPACE_DEP],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::WorkspaceVersionOverride,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_RESTRICT_BUILD_DEPS,
+        codes: &[ids::CODE_BUILD_DEPENDENCY_PRESENT],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::RestrictBuildDeps,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_VERSION_REQUIRED,
+        codes: &[ids::CODE_DEPENDENCY_WITHOUT_VERSION],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::VersionRequired,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES,
+        codes: &[ids::CODE_EMPTY_FEATURES_WITH_DEFAULTS],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::MisleadingEmptyFeatures,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE,
+        codes: &[ids::CODE_IMPLICIT_OPTIONAL_FEATURE],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::ExplicitDepFeature,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_MAX_DIRECT_DEPS,
+        codes: &[ids::CODE_TOO_MANY_DEPENDENCIES],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::MaxDirectDeps,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_OPEN_RANGES,
+        codes: &[ids::CODE_OPEN_ENDED_RANGE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoOpenRanges,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_PUBLISH_REACHABILITY,
+        codes: &[ids::CODE_DEPENDS_ON_UNPUBLISHABLE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::PublishReachability,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_SECURE_GIT_URL,
+        codes: &[ids::CODE_INSECURE_GIT_URL],
+        strict_enabled: true,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::SecureGitUrl,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_VALID_TARGET_CFG,
+        codes: &[ids::CODE_INVALID_TARGET_CFG],
+        strict_enabled: true,
+        strict_severity: Severity::Error,
+        warn_enabled: true,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::ValidTargetCfg,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE,
+        codes: &[ids::CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoZeroverInStable,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_LICENSE_PRESENT,
+        codes: &[ids::CODE_MISSING_LICENSE, ids::CODE_DISALLOWED_LICENSE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::LicensePresent,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_RENAME,
+        codes: &[ids::CODE_DEPENDENCY_RENAMED],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Info,
+        feature: CheckFeature::NoRename,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_CONSISTENT_VERSIONS,
+        codes: &[ids::CODE_DIVERGENT_VERSION_REQUIREMENTS],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::ConsistentVersions,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS,
+        codes: &[ids::CODE_OPTIONAL_DEV_DEPENDENCY],
+        strict_enabled: true,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoOptionalDevDeps,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_GIT_VERSION_CONFLICT,
+        codes: &[ids::CODE_GIT_WITH_VERSION],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::GitVersionConflict,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_CONSISTENT_MSRV,
+        codes: &[ids::CODE_INCONSISTENT_MSRV, ids::CODE_MSRV_NOT_INHERITED],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::ConsistentMsrv,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_LOCK_IN_SYNC,
+        codes: &[ids::CODE_DEPENDENCY_MISSING_FROM_LOCK],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::LockInSync,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_MEMBERS_EXIST,
+        codes: &[ids::CODE_WORKSPACE_MEMBER_NOT_FOUND],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::MembersExist,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_NESTED_WORKSPACE,
+        codes: &[ids::CODE_NESTED_WORKSPACE_DETECTED],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoNestedWorkspace,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_DEPRECATED_CRATES,
+        codes: &[ids::CODE_DEPRECATED_CRATE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::DeprecatedCrates,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_PUBLISH_METADATA,
+        codes: &[ids::CODE_MISSING_PUBLISH_METADATA],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::PublishMetadata,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_BANNED_FEATURES,
+        codes: &[ids::CODE_BANNED_FEATURE_ENABLED],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::BannedFeatures,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+        codes: &[ids::CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Info,
+        feature: CheckFeature::FeaturesAfterDefaultOff,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+        codes: &[ids::CODE_PATH_OUTSIDE_WORKSPACE],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::PathWithinWorkspace,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY,
+        codes: &[
+            ids::CODE_BUILD_SCRIPT_WITHOUT_DEPS,
+            ids::CODE_BUILD_DEPS_WITHOUT_SCRIPT,
+        ],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Info,
+        feature: CheckFeature::BuildScriptConsistency,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+        codes: &[ids::CODE_DEPENDENCY_CYCLE, ids::CODE_SELF_DEPENDENCY],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoDependencyCycles,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_LINTS_CONFIGURED,
+        codes: &[ids::CODE_MISSING_LINTS_TABLE, ids::CODE_LINTS_NOT_INHERITED],
+        strict_enabled: false,
+        strict_severity: Severity::Warning,
+        warn_enabled: false,
+        warn_severity: Severity::Info,
+        feature: CheckFeature::LintsConfigured,
+        bdd_feature_file: "checks.feature",
+        required_options: &[],
+    },
+    CheckCatalogEntry {
+        id: ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES,
+        codes: &[ids::CODE_DEFAULT_FEATURES_IN_NO_STD],
+        strict_enabled: false,
+        strict_severity: Severity::Error,
+        warn_enabled: false,
+        warn_severity: Severity::Warning,
+        feature: CheckFeature::NoStdDefaultFeatures,
+        bdd_feature_file: "checks.feature",
+        required_options: &["no_std_crates"],
     },
 ];
 
@@ -162,6 +682,47 @@ impl CheckFeature {
             Self::NoMultipleVersions => "check-no-multiple-versions",
             Self::OptionalUnused => "check-optional-unused",
             Self::YankedVersions => "check-yanked-versions",
+            Self::BannedCrates => "check-banned-crates",
+            Self::AllowedRegistries => "check-allowed-registries",
+            Self::GitPinned => "check-git-pinned",
+            Self::DuplicateAcrossKinds => "check-duplicate-across-kinds",
+            Self::Sorted => "check-sorted",
+            Self::MsrvFloor => "check-msrv-floor",
+            Self::NoPatchOverride => "check-no-patch-override",
+            Self::NoDefaultFeatures => "check-no-default-features",
+            Self::NoPrerelease => "check-no-prerelease",
+            Self::VersionPinning => "check-version-pinning",
+            Self::DevDepLeakage => "check-dev-dep-leakage",
+            Self::CrateDirName => "check-crate-dir-name",
+            Self::WorkspaceVersionOverride => "check-workspace-version-override",
+            Self::RestrictBuildDeps => "check-restrict-build-deps",
+            Self::VersionRequired => "check-version-required",
+            Self::MisleadingEmptyFeatures => "check-misleading-empty-features",
+            Self::ExplicitDepFeature => "check-explicit-dep-feature",
+            Self::MaxDirectDeps => "check-max-direct-deps",
+            Self::NoOpenRanges => "check-no-open-ranges",
+            Self::PublishReachability => "check-publish-reachability",
+            Self::SecureGitUrl => "check-secure-git-url",
+            Self::ValidTargetCfg => "check-valid-target-cfg",
+            Self::NoZeroverInStable => "check-no-zerover-in-stable",
+            Self::LicensePresent => "check-license-present",
+            Self::NoRename => "check-no-rename",
+            Self::ConsistentVersions => "check-consistent-versions",
+            Self::NoOptionalDevDeps => "check-no-optional-dev-deps",
+            Self::GitVersionConflict => "check-git-version-conflict",
+            Self::ConsistentMsrv => "check-consistent-msrv",
+            Self::LockInSync => "check-lock-in-sync",
+            Self::MembersExist => "check-members-exist",
+            Self::NoNestedWorkspace => "check-no-nested-workspace",
+            Self::DeprecatedCrates => "check-deprecated-crates",
+            Self::PublishMetadata => "check-publish-metadata",
+            Self::BannedFeatures => "check-banned-features",
+            Self::FeaturesAfterDefaultOff => "check-features-after-default-off",
+            Self::PathWithinWorkspace => "check-path-within-workspace",
+            Self::BuildScriptConsistency => "check-build-script-consistency",
+            Self::NoDependencyCycles => "check-no-dependency-cycles",
+            Self::LintsConfigured => "check-lints-configured",
+            Self::NoStdDefaultFeatures => "check-no-std-default-features",
         }
     }
 
@@ -177,6 +738,47 @@ impl CheckFeature {
             Self::NoMultipleVersions => cfg!(feature = "check-no-multiple-versions"),
             Self::OptionalUnused => cfg!(feature = "check-optional-unused"),
             Self::YankedVersions => cfg!(feature = "check-yanked-versions"),
+            Self::BannedCrates => cfg!(feature = "check-banned-crates"),
+            Self::AllowedRegistries => cfg!(feature = "check-allowed-registries"),
+            Self::GitPinned => cfg!(feature = "check-git-pinned"),
+            Self::DuplicateAcrossKinds => cfg!(feature = "check-duplicate-across-kinds"),
+            Self::Sorted => cfg!(feature = "check-sorted"),
+            Self::MsrvFloor => cfg!(feature = "check-msrv-floor"),
+            Self::NoPatchOverride => cfg!(feature = "check-no-patch-override"),
+            Self::NoDefaultFeatures => cfg!(feature = "check-no-default-features"),
+            Self::NoPrerelease => cfg!(feature = "check-no-prerelease"),
+            Self::VersionPinning => cfg!(feature = "check-version-pinning"),
+            Self::DevDepLeakage => cfg!(feature = "check-dev-dep-leakage"),
+            Self::CrateDirName => cfg!(feature = "check-crate-dir-name"),
+            Self::WorkspaceVersionOverride => cfg!(feature = "check-workspace-version-override"),
+            Self::RestrictBuildDeps => cfg!(feature = "check-restrict-build-deps"),
+            Self::VersionRequired => cfg!(feature = "check-version-required"),
+            Self::MisleadingEmptyFeatures => cfg!(feature = "check-misleading-empty-features"),
+            Self::ExplicitDepFeature => cfg!(feature = "check-explicit-dep-feature"),
+            Self::MaxDirectDeps => cfg!(feature = "check-max-direct-deps"),
+            Self::NoOpenRanges => cfg!(feature = "check-no-open-ranges"),
+            Self::PublishReachability => cfg!(feature = "check-publish-reachability"),
+            Self::SecureGitUrl => cfg!(feature = "check-secure-git-url"),
+            Self::ValidTargetCfg => cfg!(feature = "check-valid-target-cfg"),
+            Self::NoZeroverInStable => cfg!(feature = "check-no-zerover-in-stable"),
+            Self::LicensePresent => cfg!(feature = "check-license-present"),
+            Self::NoRename => cfg!(feature = "check-no-rename"),
+            Self::ConsistentVersions => cfg!(feature = "check-consistent-versions"),
+            Self::NoOptionalDevDeps => cfg!(feature = "check-no-optional-dev-deps"),
+            Self::GitVersionConflict => cfg!(feature = "check-git-version-conflict"),
+            Self::ConsistentMsrv => cfg!(feature = "check-consistent-msrv"),
+            Self::LockInSync => cfg!(feature = "check-lock-in-sync"),
+            Self::MembersExist => cfg!(feature = "check-members-exist"),
+            Self::NoNestedWorkspace => cfg!(feature = "check-no-nested-workspace"),
+            Self::DeprecatedCrates => cfg!(feature = "check-deprecated-crates"),
+            Self::PublishMetadata => cfg!(feature = "check-publish-metadata"),
+            Self::BannedFeatures => cfg!(feature = "check-banned-features"),
+            Self::FeaturesAfterDefaultOff => cfg!(feature = "check-features-after-default-off"),
+            Self::PathWithinWorkspace => cfg!(feature = "check-path-within-workspace"),
+            Self::BuildScriptConsistency => cfg!(feature = "check-build-script-consistency"),
+            Self::NoDependencyCycles => cfg!(feature = "check-no-dependency-cycles"),
+            Self::LintsConfigured => cfg!(feature = "check-lints-configured"),
+            Self::NoStdDefaultFeatures => cfg!(feature = "check-no-std-default-features"),
         }
     }
 }
@@ -216,6 +818,10 @@ pub fn bdd_feature_file(check_id: &str) -> Option<&'static str> {
     entry(check_id).map(|entry| entry.bdd_feature_file)
 }
 
+pub fn required_options(check_id: &str) -> &'static [&'static str] {
+    entry(check_id).map_or(&[], |entry| entry.required_options)
+}
+
 pub fn checks_for_profile(profile: &str) -> Vec<ProfileCheck> {
     let profile_is_warnish = matches!(profile, "warn" | "team" | "compat" | "oss");
 
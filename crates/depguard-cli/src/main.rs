@@ -5,26 +5,37 @@
 
 #![allow(unexpected_cfgs)]
 
+mod run_meta;
+mod verbosity;
+
 use anyhow::Context;
 use camino::{Utf8Path, Utf8PathBuf};
 use clap::{Parser, Subcommand, ValueEnum};
 use depguard_app::{
     CheckInput, ExplainOutput, ReportVariant, ReportVersion, add_artifact, apply_baseline,
-    apply_safe_fixes, empty_report, generate_baseline, generate_buildfix_plan, parse_baseline_json,
-    parse_report_json, render_annotations, render_jsonl, render_junit, render_markdown,
-    render_sarif, run_check, run_explain, runtime_error_report, serialize_baseline,
-    serialize_buildfix_plan, serialize_report, to_renderable, verdict_exit_code,
+    apply_safe_fixes, diff_reports, generate_baseline, generate_buildfix_plan, parse_baseline_json,
+    parse_report_json, render_annotations, render_azure_annotations, render_checkstyle,
+    render_diff_markdown, render_diff_text, render_gitlab_codequality, render_html,
+    render_job_summary, render_jsonl, render_junit, render_markdown, render_ndjson, render_sarif,
+    render_teamcity, render_terminal, report_verdict, resolve_check_config, resolve_config_extends,
+    retain_min_severity, run_check, run_explain, runtime_error_report, serialize_baseline,
+    serialize_buildfix_plan, serialize_report, to_renderable,
 };
-use depguard_settings::Overrides;
+use depguard_render::{AnnotationMode, RenderableSeverity};
+use depguard_settings::{ConfigDump, Overrides};
 use depguard_types::RepoPath;
 use depguard_types::{ArtifactPointer, ArtifactType};
 use depguard_yanked::{YankedIndex, parse_yanked_index};
 use reqwest::blocking::Client;
+use run_meta::{attach_run_provenance, detect_run_provenance};
 use std::collections::BTreeSet;
-use std::io::Read;
+use std::io::{IsTerminal, Read};
 use std::process::Command;
 use std::time::Duration;
 
+/// Config file discovered upward from the repo root when `--config` is omitted.
+const DEFAULT_CONFIG_FILE: &str = "depguard.toml";
+
 #[cfg(test)]
 fn terminate(code: i32) -> ! {
     panic!("process exit: {code}");
@@ -46,6 +57,81 @@ fn terminate(code: i32) -> ! {
     std::process::exit(code)
 }
 
+/// How annotation renderers spend their cap.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum AnnotationModeArg {
+    /// One annotation per finding, truncated at the cap.
+    #[default]
+    Individual,
+    /// Over the cap, one annotation per check id with a count and first location.
+    Summarized,
+}
+
+/// CI system whose annotation syntax `annotations` emits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum AnnotationTarget {
+    /// GitHub Actions workflow commands (`::error file=...::`).
+    #[default]
+    Github,
+    /// Azure Pipelines logging commands (`##vso[task.logissue ...]`).
+    Azure,
+    /// TeamCity inspection service messages (`##teamcity[inspection ...]`); ignores `--max`.
+    Teamcity,
+}
+
+impl From<AnnotationModeArg> for AnnotationMode {
+    fn from(value: AnnotationModeArg) -> Self {
+        match value {
+            AnnotationModeArg::Individual => AnnotationMode::Individual,
+            AnnotationModeArg::Summarized => AnnotationMode::Summarized,
+        }
+    }
+}
+
+/// Lowest finding severity that render commands keep.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum MinSeverity {
+    /// Render every finding.
+    #[default]
+    Info,
+    /// Render warnings and errors.
+    Warn,
+    /// Render errors only.
+    Error,
+}
+
+impl From<MinSeverity> for RenderableSeverity {
+    fn from(value: MinSeverity) -> Self {
+        match value {
+            MinSeverity::Info => RenderableSeverity::Info,
+            MinSeverity::Warn => RenderableSeverity::Warning,
+            MinSeverity::Error => RenderableSeverity::Error,
+        }
+    }
+}
+
+/// Verdict level at which render commands exit non-zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum RenderFailOn {
+    /// Always exit 0 after rendering.
+    #[default]
+    Never,
+    /// Exit 2 on a warn or fail verdict.
+    Warn,
+    /// Exit 2 on a fail verdict, matching `depguard check`.
+    Fail,
+}
+
+/// Output format for `depguard diff`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum DiffFormat {
+    /// Plain-text summary for terminals.
+    #[default]
+    Text,
+    /// Markdown for PR comments.
+    Markdown,
+}
+
 /// Run mode for depguard check command.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
 enum RunMode {
@@ -56,10 +142,48 @@ enum RunMode {
     Cockpit,
 }
 
+/// Output surface for `check --report-out`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ReportOutFormat {
+    #[default]
+    Json,
+    Markdown,
+    Sarif,
+    Gitlab,
+    Junit,
+    Checkstyle,
+}
+
+impl ReportOutFormat {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Markdown => "markdown",
+            Self::Sarif => "sarif",
+            Self::Gitlab => "gitlab",
+            Self::Junit => "junit",
+            Self::Checkstyle => "checkstyle",
+        }
+    }
+
+    /// File name under `--out-dir` when `--report-out` is not given.
+    fn default_file_name(self) -> &'static str {
+        match self {
+            Self::Json => "report.json",
+            Self::Markdown => "report.md",
+            Self::Sarif => "report.sarif.json",
+            Self::Gitlab => "gl-code-quality-report.json",
+            Self::Junit => "report.junit.xml",
+            Self::Checkstyle => "report.checkstyle.xml",
+        }
+    }
+}
+
 /// Options for the check command (reduces function argument count).
 struct CheckOpts {
     base: Option<String>,
     head: Option<String>,
+    since: Option<String>,
     diff_file: Option<Utf8PathBuf>,
     yanked_index: Option<Utf8PathBuf>,
     yanked_live: bool,
@@ -70,13 +194,18 @@ struct CheckOpts {
     out_dir: Option<Utf8PathBuf>,
     report_out: Option<Utf8PathBuf>,
     report_version: String,
+    format: ReportOutFormat,
     write_markdown: bool,
     markdown_out: Option<Utf8PathBuf>,
     write_junit: bool,
     junit_out: Option<Utf8PathBuf>,
     write_jsonl: bool,
     jsonl_out: Option<Utf8PathBuf>,
+    ndjson: Option<Utf8PathBuf>,
+    explain_findings: bool,
     mode: RunMode,
+    no_color: bool,
+    config_dump: bool,
 }
 
 /// Options for the baseline command.
@@ -104,8 +233,10 @@ struct Cli {
     repo_root: Utf8PathBuf,
 
     /// Path to depguard config TOML.
-    #[arg(long, default_value = "depguard.toml")]
-    config: Utf8PathBuf,
+    ///
+    /// When omitted, the nearest `depguard.toml` at or above --repo-root is used.
+    #[arg(long)]
+    config: Option<Utf8PathBuf>,
 
     /// Override profile (strict|warn|compat or custom).
     #[arg(long)]
@@ -119,10 +250,24 @@ struct Cli {
     #[arg(long)]
     max_findings: Option<u32>,
 
+    /// Suppress notices on stderr; errors and warnings still print.
+    ///
+    /// Overrides `DEPGUARD_LOG` (quiet|normal|verbose).
+    #[arg(long, short, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Print extra diagnostics on stderr (config path, scope, manifests scanned).
+    ///
+    /// Overrides `DEPGUARD_LOG` (quiet|normal|verbose).
+    #[arg(long, short, global = true)]
+    verbose: bool,
+
     #[command(subcommand)]
     cmd: Commands,
 }
 
+// Parsed once per process; boxing `Check` options would only add noise.
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand, Debug, Clone)]
 enum Commands {
     /// Evaluate policy and write artifacts.
@@ -133,10 +278,13 @@ enum Commands {
         /// In diff scope: git head revision (e.g. HEAD).
         #[arg(long)]
         head: Option<String>,
+        /// In diff scope: diff HEAD against its merge-base with this ref (e.g. origin/main).
+        #[arg(long, conflicts_with_all = ["base", "head", "diff_file"])]
+        since: Option<String>,
         /// In diff scope: read changed file paths from file instead of calling git.
         ///
         /// Accepts plain newline-separated paths and GitHub Actions output formats.
-        #[arg(long)]
+        #[arg(long, visible_alias = "changed-files")]
         diff_file: Option<Utf8PathBuf>,
 
         /// Offline yanked-version index file used by deps.yanked_versions.
@@ -153,7 +301,7 @@ enum Commands {
         #[arg(long)]
         yanked_api_base_url: Option<String>,
 
-        /// Enable incremental mode by caching parsed manifests between runs.
+        /// Enable incremental mode by caching parsed manifests and findings between runs.
         #[arg(long)]
         incremental: bool,
 
@@ -169,9 +317,9 @@ enum Commands {
         #[arg(long)]
         out_dir: Option<Utf8PathBuf>,
 
-        /// Where to write the JSON report.
+        /// Where to write the report in the selected `--format`.
         ///
-        /// Defaults to `<out-dir>/report.json`.
+        /// Defaults to `<out-dir>/report.json` (or the format's usual file name).
         #[arg(long)]
         report_out: Option<Utf8PathBuf>,
 
@@ -179,10 +327,14 @@ enum Commands {
         #[arg(long)]
         baseline: Option<Utf8PathBuf>,
 
-        /// Report schema version to emit (v1, v2, or sensor-v1).
+        /// Report schema version to emit (v1, v2, sensor-v1, or latest).
         #[arg(long, default_value = "v2")]
         report_version: String,
 
+        /// What `--report-out` receives (json, markdown, sarif, gitlab, junit, or checkstyle).
+        #[arg(long, default_value = "json")]
+        format: String,
+
         /// Write a Markdown report alongside the JSON.
         #[arg(long)]
         write_markdown: bool,
@@ -213,9 +365,28 @@ enum Commands {
         #[arg(long)]
         jsonl_out: Option<Utf8PathBuf>,
 
+        /// Stream findings as NDJSON to this path: one self-contained object per
+        /// finding, in report order, with no summary line.
+        #[arg(long, value_name = "PATH")]
+        ndjson: Option<Utf8PathBuf>,
+
+        /// Fill each finding's `help` and `url` from the explain registry.
+        #[arg(long)]
+        explain_findings: bool,
+
         /// Run mode: standard (exit 2 on fail) or cockpit (exit 0 if receipt written).
         #[arg(long, value_enum, default_value = "standard")]
         mode: RunMode,
+
+        /// Disable ANSI colors in the terminal summary.
+        ///
+        /// Colors are also off when stdout is not a TTY or `NO_COLOR` is set.
+        #[arg(long)]
+        no_color: bool,
+
+        /// Print the resolved effective config as JSON and exit without running checks.
+        #[arg(long)]
+        config_dump: bool,
     },
 
     /// Generate a baseline file from current findings.
@@ -227,7 +398,7 @@ enum Commands {
         #[arg(long)]
         head: Option<String>,
         /// In diff scope: read changed file paths from file instead of calling git.
-        #[arg(long)]
+        #[arg(long, visible_alias = "changed-files")]
         diff_file: Option<Utf8PathBuf>,
         /// Offline yanked-version index file used by deps.yanked_versions.
         #[arg(long)]
@@ -240,7 +411,7 @@ enum Commands {
         /// Defaults to <https://crates.io>.
         #[arg(long)]
         yanked_api_base_url: Option<String>,
-        /// Enable incremental mode by caching parsed manifests between runs.
+        /// Enable incremental mode by caching parsed manifests and findings between runs.
         #[arg(long)]
         incremental: bool,
         /// Directory for incremental cache data.
@@ -249,7 +420,7 @@ enum Commands {
         #[arg(long)]
         cache_dir: Option<Utf8PathBuf>,
         /// Where to write the baseline JSON.
-        #[arg(long, default_value = ".depguard-baseline.json")]
+        #[arg(long, visible_alias = "out", default_value = ".depguard-baseline.json")]
         output: Utf8PathBuf,
     },
 
@@ -275,17 +446,45 @@ enum Commands {
         /// Where to write the Markdown output (if not specified, prints to stdout).
         #[arg(long, short)]
         output: Option<Utf8PathBuf>,
+
+        /// Omit the per-check remediation hint lines.
+        #[arg(long)]
+        no_remediation_hints: bool,
+
+        /// Hide findings below this severity; the verdict and counts still cover all findings.
+        #[arg(long, value_enum, default_value = "info")]
+        min_severity: MinSeverity,
+
+        /// Exit 2 when the report verdict reaches this level (default: never).
+        #[arg(long, value_enum, default_value = "never")]
+        fail_on: RenderFailOn,
     },
 
-    /// Render GitHub Actions annotations from an existing JSON report.
+    /// Render CI annotations (GitHub Actions, Azure Pipelines or TeamCity) from an existing JSON report.
     Annotations {
         /// Path to the JSON report file.
         #[arg(long, default_value = "artifacts/depguard/report.json")]
         report: Utf8PathBuf,
 
+        /// CI system to emit annotations for.
+        #[arg(long, value_enum, default_value = "github")]
+        target: AnnotationTarget,
+
         /// Maximum number of annotations to emit (default 10, per GHA best practices).
         #[arg(long, default_value = "10")]
         max: usize,
+
+        /// How to spend the `--max` budget when findings exceed it.
+        #[arg(long, value_enum, default_value = "individual")]
+        mode: AnnotationModeArg,
+
+        /// Hide findings below this severity; the verdict and counts still cover all findings.
+        #[arg(long, value_enum, default_value = "info")]
+        min_severity: MinSeverity,
+
+        /// Exit 2 when the report verdict reaches this level (default: never).
+        #[arg(long, value_enum, default_value = "never")]
+        fail_on: RenderFailOn,
     },
 
     /// Render SARIF from an existing JSON report.
@@ -310,6 +509,39 @@ enum Commands {
         output: Option<Utf8PathBuf>,
     },
 
+    /// Render Checkstyle XML from an existing JSON report.
+    Checkstyle {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the Checkstyle XML output (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
+    /// Render a self-contained HTML page from an existing JSON report.
+    Html {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the HTML output (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
+    /// Render a GitLab Code Quality report from an existing JSON report.
+    Gitlab {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the Code Quality JSON (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
     /// Render JSON Lines from an existing JSON report.
     Jsonl {
         /// Path to the JSON report file.
@@ -321,10 +553,77 @@ enum Commands {
         output: Option<Utf8PathBuf>,
     },
 
+    /// Render a GitHub Actions job summary from an existing JSON report.
+    Summary {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the summary (if not specified, prints to stdout so CI can
+        /// append it to `$GITHUB_STEP_SUMMARY`).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
+    /// Compare two JSON reports and list findings added or removed by fingerprint.
+    ///
+    /// Exits 2 when the head report introduces findings the base report lacks.
+    Diff {
+        /// Report from the base revision (e.g. the target branch).
+        #[arg(long)]
+        base: Utf8PathBuf,
+
+        /// Report from the head revision (e.g. the PR branch).
+        #[arg(long)]
+        head: Utf8PathBuf,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value = "text")]
+        format: DiffFormat,
+
+        /// Where to write the diff (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
     /// Explain a check_id or code with remediation guidance.
     Explain {
         /// The check_id (e.g., "deps.no_wildcards") or code (e.g., "wildcard_version") to explain.
-        identifier: String,
+        #[arg(required_unless_present = "all")]
+        identifier: Option<String>,
+
+        /// Explain every check_id and code in the registry (requires --json).
+        #[arg(long, conflicts_with = "identifier", requires = "json")]
+        all: bool,
+
+        /// Emit machine-readable JSON instead of text.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List available checks with their default enablement and severity.
+    ListChecks {
+        /// Profile whose defaults to show (strict|warn|compat). Falls back to the global --profile.
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Emit machine-readable JSON instead of a table.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List every declared dependency as JSON, without running checks.
+    Inventory {
+        /// Where to write the inventory (if not specified, prints to stdout).
+        #[arg(long)]
+        out: Option<Utf8PathBuf>,
+    },
+
+    /// Validate a depguard config without running checks.
+    ValidateConfig {
+        /// Config file to validate. Falls back to the global --config, then discovery.
+        #[arg(long)]
+        config: Option<Utf8PathBuf>,
     },
 
     /// Generate a buildfix plan and optionally apply safe fixes.
@@ -362,7 +661,7 @@ enum CiProvider {
         /// In diff scope, read changed files from file instead of calling git.
         ///
         /// Accepts plain newline-separated paths and GitHub Actions output formats.
-        #[arg(long)]
+        #[arg(long, visible_alias = "changed-files")]
         diff_file: Option<Utf8PathBuf>,
 
         /// Write a Markdown report alongside the JSON.
@@ -389,6 +688,10 @@ enum CiProvider {
         #[arg(long, default_value = "10")]
         max_annotations: usize,
 
+        /// How to spend the annotation budget when findings exceed it.
+        #[arg(long, value_enum, default_value = "individual")]
+        annotation_mode: AnnotationModeArg,
+
         /// Base directory for generated artifacts.
         ///
         /// Defaults to `artifacts/depguard` if not specified.
@@ -434,17 +737,45 @@ enum ReportFormat {
         /// Where to write the Markdown output (if not specified, prints to stdout).
         #[arg(long, short)]
         output: Option<Utf8PathBuf>,
+
+        /// Omit the per-check remediation hint lines.
+        #[arg(long)]
+        no_remediation_hints: bool,
+
+        /// Hide findings below this severity; the verdict and counts still cover all findings.
+        #[arg(long, value_enum, default_value = "info")]
+        min_severity: MinSeverity,
+
+        /// Exit 2 when the report verdict reaches this level (default: never).
+        #[arg(long, value_enum, default_value = "never")]
+        fail_on: RenderFailOn,
     },
 
-    /// Render GitHub Actions annotations from an existing JSON report.
+    /// Render CI annotations (GitHub Actions, Azure Pipelines or TeamCity) from an existing JSON report.
     Annotations {
         /// Path to the JSON report file.
         #[arg(long, default_value = "artifacts/depguard/report.json")]
         report: Utf8PathBuf,
 
+        /// CI system to emit annotations for.
+        #[arg(long, value_enum, default_value = "github")]
+        target: AnnotationTarget,
+
         /// Maximum number of annotations to emit (default 10, per GHA best practices).
         #[arg(long, default_value = "10")]
         max: usize,
+
+        /// How to spend the `--max` budget when findings exceed it.
+        #[arg(long, value_enum, default_value = "individual")]
+        mode: AnnotationModeArg,
+
+        /// Hide findings below this severity; the verdict and counts still cover all findings.
+        #[arg(long, value_enum, default_value = "info")]
+        min_severity: MinSeverity,
+
+        /// Exit 2 when the report verdict reaches this level (default: never).
+        #[arg(long, value_enum, default_value = "never")]
+        fail_on: RenderFailOn,
     },
 
     /// Render SARIF from an existing JSON report.
@@ -469,6 +800,39 @@ enum ReportFormat {
         output: Option<Utf8PathBuf>,
     },
 
+    /// Render Checkstyle XML from an existing JSON report.
+    Checkstyle {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the Checkstyle XML output (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
+    /// Render a self-contained HTML page from an existing JSON report.
+    Html {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the HTML output (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
+    /// Render a GitLab Code Quality report from an existing JSON report.
+    Gitlab {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the Code Quality JSON (if not specified, prints to stdout).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
+
     /// Render JSON Lines from an existing JSON report.
     Jsonl {
         /// Path to the JSON report file.
@@ -479,15 +843,36 @@ enum ReportFormat {
         #[arg(long, short)]
         output: Option<Utf8PathBuf>,
     },
+
+    /// Render a GitHub Actions job summary from an existing JSON report.
+    Summary {
+        /// Path to the JSON report file.
+        #[arg(long, default_value = "artifacts/depguard/report.json")]
+        report: Utf8PathBuf,
+
+        /// Where to write the summary (if not specified, prints to stdout so CI can
+        /// append it to `$GITHUB_STEP_SUMMARY`).
+        #[arg(long, short)]
+        output: Option<Utf8PathBuf>,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let log_env = std::env::var(verbosity::LOG_ENV).ok();
+    match verbosity::resolve_level(cli.quiet, cli.verbose, log_env.as_deref()) {
+        Ok(level) => verbosity::set_level(level),
+        Err(value) => eprintln!(
+            "depguard: warning: ignoring {}={value} (expected quiet, normal, or verbose)",
+            verbosity::LOG_ENV
+        ),
+    }
 
     match cli.cmd {
         Commands::Check {
             ref base,
             ref head,
+            ref since,
             ref diff_file,
             ref yanked_index,
             yanked_live,
@@ -498,18 +883,24 @@ fn main() -> anyhow::Result<()> {
             ref baseline,
             ref report_out,
             ref report_version,
+            ref format,
             write_markdown,
             ref markdown_out,
             write_junit,
             ref junit_out,
             write_jsonl,
             ref jsonl_out,
+            ref ndjson,
+            explain_findings,
             mode,
+            no_color,
+            config_dump,
         } => cmd_check(
             &cli,
             CheckOpts {
                 base: base.clone(),
                 head: head.clone(),
+                since: since.clone(),
                 diff_file: diff_file.clone(),
                 yanked_index: yanked_index.clone(),
                 yanked_live,
@@ -520,13 +911,18 @@ fn main() -> anyhow::Result<()> {
                 out_dir: out_dir.clone(),
                 report_out: report_out.clone(),
                 report_version: report_version.clone(),
+                format: parse_output_format(format)?,
                 write_markdown,
                 markdown_out: markdown_out.clone(),
                 write_junit,
                 junit_out: junit_out.clone(),
                 write_jsonl,
                 jsonl_out: jsonl_out.clone(),
+                ndjson: ndjson.clone(),
+                explain_findings,
                 mode,
+                no_color,
+                config_dump,
             },
         ),
         Commands::Baseline {
@@ -553,12 +949,62 @@ fn main() -> anyhow::Result<()> {
                 output: output.clone(),
             },
         ),
-        Commands::Md { report, output } => cmd_md(report, output),
-        Commands::Annotations { report, max } => cmd_annotations(report, max),
+        Commands::Md {
+            report,
+            output,
+            no_remediation_hints,
+            min_severity,
+            fail_on,
+        } => cmd_md(
+            report,
+            output,
+            !no_remediation_hints,
+            min_severity.into(),
+            fail_on,
+        ),
+        Commands::Annotations {
+            report,
+            target,
+            max,
+            mode,
+            min_severity,
+            fail_on,
+        } => cmd_annotations(
+            report,
+            target,
+            max,
+            mode.into(),
+            min_severity.into(),
+            fail_on,
+        ),
         Commands::Sarif { report, output } => cmd_sarif(report, output),
         Commands::Junit { report, output } => cmd_junit(report, output),
+        Commands::Checkstyle { report, output } => cmd_checkstyle(report, output),
+        Commands::Html { report, output } => cmd_html(report, output),
+        Commands::Gitlab { report, output } => cmd_gitlab(report, output),
         Commands::Jsonl { report, output } => cmd_jsonl(report, output),
-        Commands::Explain { identifier } => cmd_explain(&identifier),
+        Commands::Summary { report, output } => cmd_summary(report, output),
+        Commands::Diff {
+            base,
+            head,
+            format,
+            output,
+        } => cmd_diff(base, head, format, output),
+        Commands::Explain {
+            identifier,
+            all,
+            json,
+        } => match identifier {
+            Some(identifier) if !all => cmd_explain(&identifier, json),
+            _ => cmd_explain_all_json(),
+        },
+        Commands::ListChecks { ref profile, json } => {
+            cmd_list_checks(profile.as_deref().or(cli.profile.as_deref()), json)
+        }
+        Commands::Inventory { ref out } => cmd_inventory(&cli.repo_root, out.as_deref()),
+        Commands::ValidateConfig { ref config } => {
+            cmd_validate_config(&cli, config.as_deref().or(cli.config.as_deref()))
+        }
         Commands::Fix {
             report,
             plan_out,
@@ -576,6 +1022,7 @@ fn main() -> anyhow::Result<()> {
                 write_jsonl,
                 write_sarif,
                 max_annotations,
+                annotation_mode,
                 out_dir,
                 report_out,
             } => cmd_ci_github(
@@ -590,16 +1037,47 @@ fn main() -> anyhow::Result<()> {
                 *write_jsonl,
                 *write_sarif,
                 *max_annotations,
+                (*annotation_mode).into(),
                 out_dir.clone(),
                 report_out.clone(),
             ),
         },
         Commands::Report { format } => match format {
-            ReportFormat::Md { report, output } => cmd_md(report, output),
-            ReportFormat::Annotations { report, max } => cmd_annotations(report, max),
+            ReportFormat::Md {
+                report,
+                output,
+                no_remediation_hints,
+                min_severity,
+                fail_on,
+            } => cmd_md(
+                report,
+                output,
+                !no_remediation_hints,
+                min_severity.into(),
+                fail_on,
+            ),
+            ReportFormat::Annotations {
+                report,
+                target,
+                max,
+                mode,
+                min_severity,
+                fail_on,
+            } => cmd_annotations(
+                report,
+                target,
+                max,
+                mode.into(),
+                min_severity.into(),
+                fail_on,
+            ),
             ReportFormat::Sarif { report, output } => cmd_sarif(report, output),
             ReportFormat::Junit { report, output } => cmd_junit(report, output),
+            ReportFormat::Checkstyle { report, output } => cmd_checkstyle(report, output),
+            ReportFormat::Html { report, output } => cmd_html(report, output),
+            ReportFormat::Gitlab { report, output } => cmd_gitlab(report, output),
             ReportFormat::Jsonl { report, output } => cmd_jsonl(report, output),
+            ReportFormat::Summary { report, output } => cmd_summary(report, output),
         },
     }
 }
@@ -621,7 +1099,7 @@ fn resolve_output_paths(opts: &CheckOpts) -> OutputPaths {
         report_out: opts
             .report_out
             .clone()
-            .unwrap_or_else(|| out_dir.join("report.json")),
+            .unwrap_or_else(|| out_dir.join(opts.format.default_file_name())),
         markdown_out: opts
             .markdown_out
             .clone()
@@ -642,7 +1120,7 @@ fn write_optional_artifacts(
     opts: &CheckOpts,
     paths: &OutputPaths,
 ) -> anyhow::Result<()> {
-    if !(opts.write_markdown || opts.write_junit || opts.write_jsonl) {
+    if !(opts.write_markdown || opts.write_junit || opts.write_jsonl || opts.ndjson.is_some()) {
         return Ok(());
     }
 
@@ -687,6 +1165,19 @@ fn write_optional_artifacts(
         );
     }
 
+    if let Some(ndjson_out) = &opts.ndjson {
+        let ndjson = render_ndjson(&renderable);
+        write_text_file(ndjson_out, &ndjson).context("write ndjson")?;
+        add_artifact(
+            report,
+            ArtifactPointer {
+                artifact_type: ArtifactType::Extra,
+                path: ndjson_out.to_string(),
+                format: Some("application/x-ndjson".to_string()),
+            },
+        );
+    }
+
     Ok(())
 }
 
@@ -695,17 +1186,23 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
         .repo_root
         .canonicalize_utf8()
         .unwrap_or_else(|_| cli.repo_root.clone());
+    if opts.config_dump {
+        return dump_effective_config(cli, &repo_root, &opts);
+    }
     let paths = resolve_output_paths(&opts);
 
     let report_version = parse_report_version(&opts.report_version)?;
+    if let Some(note) = report_version_note(report_version) {
+        verbosity::note(note);
+    }
 
     let result = (|| -> anyhow::Result<i32> {
         if !repo_root.exists() {
             anyhow::bail!("repo root does not exist: {}", repo_root);
         }
         // Load config if present; missing file is allowed (defaults apply).
-        let cfg_path = repo_root.join(&cli.config);
-        let cfg_text = std::fs::read_to_string(&cfg_path).unwrap_or_default();
+        let loaded = load_config(&repo_root, cli.config.as_deref())?;
+        let cfg_text = loaded.text;
 
         let overrides = Overrides {
             profile: cli.profile.clone(),
@@ -714,27 +1211,32 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
             baseline: opts.baseline.as_ref().map(|p| p.to_string()),
         };
 
-        // Fast path: missing root Cargo.toml -> emit empty report.
+        // Fast path: missing root Cargo.toml -> emit empty report without touching git.
         let root_manifest = repo_root.join("Cargo.toml");
         if !root_manifest.exists() {
-            let cfg = if cfg_text.trim().is_empty() {
-                depguard_settings::DepguardConfigV1::default()
-            } else {
-                depguard_settings::parse_config_toml(&cfg_text).context("parse config")?
-            };
-            let resolved = depguard_settings::resolve_config(cfg, overrides.clone())
-                .context("resolve config")?;
-            let scope = match resolved.effective.scope {
-                depguard::policy::Scope::Repo => "repo",
-                depguard::policy::Scope::Diff => "diff",
-            };
-            let mut report = empty_report(report_version, scope, &resolved.effective.profile);
+            let output = run_check(CheckInput {
+                repo_root: &repo_root,
+                config_text: &cfg_text,
+                config_source: loaded.source.as_deref(),
+                overrides,
+                changed_files: None,
+                report_version,
+                yanked_index: None,
+                manifest_cache_dir: None,
+                explain_findings: opts.explain_findings,
+            })?;
+            warn_unknown_checks(&output.resolved_config);
+            let mut report = output.report;
+            attach_run_provenance(
+                &mut report,
+                detect_run_provenance(&repo_root, opts.diff_file.is_none()),
+            );
             write_optional_artifacts(&mut report, &opts, &paths)?;
-            write_report_file(&paths.report_out, &report).context("write report json")?;
-            eprintln!(
+            write_report_out(&paths.report_out, &report, opts.format)?;
+            verbosity::note(format_args!(
                 "depguard: no Cargo.toml found at {}; emitting empty report",
                 root_manifest
-            );
+            ));
             return Ok(0);
         }
 
@@ -744,9 +1246,11 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
             cli.scope.as_deref(),
             opts.base.as_deref(),
             opts.head.as_deref(),
+            opts.since.as_deref(),
             opts.diff_file.as_deref(),
         )
         .context("resolve diff scope inputs")?;
+        log_scope_decision(changed_files.as_deref());
         let scope_input = scope_input_from_changed_files(changed_files.as_ref());
         let yanked_index = load_yanked_index(
             &repo_root,
@@ -761,14 +1265,23 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
         let input = CheckInput {
             repo_root: &repo_root,
             config_text: &cfg_text,
+            config_source: loaded.source.as_deref(),
             overrides,
             changed_files,
             report_version,
             yanked_index,
             manifest_cache_dir: manifest_cache_dir.as_deref(),
+            explain_findings: opts.explain_findings,
         };
 
         let mut output = run_check(input)?;
+        warn_unknown_checks(&output.resolved_config);
+        log_manifests_scanned(&output.report);
+        // Changed files handed in explicitly mean git may be unavailable.
+        attach_run_provenance(
+            &mut output.report,
+            detect_run_provenance(&repo_root, opts.diff_file.is_none()),
+        );
 
         if let Some(baseline_path) = output.resolved_config.baseline_path.as_deref() {
             let baseline_path = normalize_input_path(&repo_root, baseline_path);
@@ -779,18 +1292,20 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
                 &mut output.report,
                 &baseline,
                 output.resolved_config.effective.fail_on,
+                &output.resolved_config.effective.verdict_ignores,
             );
             if stats.suppressed > 0 {
-                eprintln!(
+                verbosity::note(format_args!(
                     "depguard: suppressed {} findings using baseline {}",
                     stats.suppressed, baseline_path
-                );
+                ));
             }
         }
 
         write_optional_artifacts(&mut output.report, &opts, &paths)?;
 
-        write_report_file(&paths.report_out, &output.report).context("write report json")?;
+        write_report_out(&paths.report_out, &output.report, opts.format)?;
+        print_terminal_summary(&output.report, &opts);
 
         Ok(report_exit_code(&output.report))
     })();
@@ -809,7 +1324,7 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
         }
         Err(err) => {
             let report = runtime_error_report(report_version, &format!("{err:#}"));
-            let receipt_written = write_report_file(&paths.report_out, &report).is_ok();
+            let receipt_written = write_report_out(&paths.report_out, &report, opts.format).is_ok();
             eprintln!("depguard error: {err:#}");
 
             // In cockpit mode, exit 0 if we successfully wrote an error receipt.
@@ -821,6 +1336,37 @@ fn cmd_check(cli: &Cli, opts: CheckOpts) -> anyhow::Result<()> {
     }
 }
 
+/// Print the config a `check` run would use, after profile, file and CLI overrides.
+fn dump_effective_config(cli: &Cli, repo_root: &Utf8Path, opts: &CheckOpts) -> anyhow::Result<()> {
+    let loaded = load_config(repo_root, cli.config.as_deref())?;
+    let overrides = Overrides {
+        profile: cli.profile.clone(),
+        scope: cli.scope.clone(),
+        max_findings: cli.max_findings,
+        baseline: opts.baseline.as_ref().map(|p| p.to_string()),
+    };
+    let resolved =
+        resolve_check_config(repo_root, &loaded.text, loaded.source.as_deref(), overrides)?;
+    warn_unknown_checks(&resolved);
+    let dump = serde_json::to_string_pretty(&ConfigDump::from(&resolved))
+        .context("serialize effective config")?;
+    println!("{dump}");
+    Ok(())
+}
+
+/// Print a human-readable summary for local runs.
+///
+/// Skipped in cockpit mode and when `--report-out` is given explicitly, where a
+/// tool consumes the receipt and stdout should stay quiet.
+fn print_terminal_summary(report: &ReportVariant, opts: &CheckOpts) {
+    if opts.mode != RunMode::Standard || opts.report_out.is_some() {
+        return;
+    }
+    let no_color =
+        opts.no_color || std::env::var_os("NO_COLOR").is_some() || !std::io::stdout().is_terminal();
+    print!("{}", render_terminal(&to_renderable(report), no_color));
+}
+
 fn cmd_baseline(cli: &Cli, opts: BaselineOpts) -> anyhow::Result<()> {
     let repo_root = cli
         .repo_root
@@ -830,8 +1376,8 @@ fn cmd_baseline(cli: &Cli, opts: BaselineOpts) -> anyhow::Result<()> {
         anyhow::bail!("repo root does not exist: {}", repo_root);
     }
 
-    let cfg_path = repo_root.join(&cli.config);
-    let cfg_text = std::fs::read_to_string(&cfg_path).unwrap_or_default();
+    let loaded = load_config(&repo_root, cli.config.as_deref())?;
+    let cfg_text = loaded.text;
 
     let overrides = Overrides {
         profile: cli.profile.clone(),
@@ -846,6 +1392,7 @@ fn cmd_baseline(cli: &Cli, opts: BaselineOpts) -> anyhow::Result<()> {
         cli.scope.as_deref(),
         opts.base.as_deref(),
         opts.head.as_deref(),
+        None,
         opts.diff_file.as_deref(),
     )
     .context("resolve diff scope inputs")?;
@@ -863,22 +1410,25 @@ fn cmd_baseline(cli: &Cli, opts: BaselineOpts) -> anyhow::Result<()> {
     let input = CheckInput {
         repo_root: &repo_root,
         config_text: &cfg_text,
+        config_source: loaded.source.as_deref(),
         overrides,
         changed_files,
         report_version: ReportVersion::V2,
         yanked_index,
         manifest_cache_dir: manifest_cache_dir.as_deref(),
+        explain_findings: false,
     };
 
     let output = run_check(input).context("run check for baseline generation")?;
+    warn_unknown_checks(&output.resolved_config);
     let baseline = generate_baseline(&output.report);
     write_baseline_file(&opts.output, &baseline)?;
 
-    eprintln!(
+    verbosity::note(format_args!(
         "depguard: wrote baseline with {} fingerprints to {}",
         baseline.fingerprints.len(),
         opts.output
-    );
+    ));
     Ok(())
 }
 
@@ -1005,12 +1555,41 @@ fn git_changed_files(
     Ok(paths)
 }
 
+/// Resolve the commit where HEAD diverged from `since`.
+///
+/// Any failure is reported as an unreachable base: an unknown ref and a shallow
+/// clone without the common ancestor need the same remediation.
+fn git_merge_base(repo_root: &camino::Utf8Path, since: &str) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .current_dir(repo_root)
+        .args(["merge-base", since, "HEAD"])
+        .output()
+        .map_err(GitDiffError::SpawnFailed)?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        let stderr = if stderr.is_empty() {
+            format!("no merge base found between '{since}' and HEAD")
+        } else {
+            stderr
+        };
+        return Err(GitDiffError::BaseCommitNotReachable {
+            base: since.to_string(),
+            stderr,
+        }
+        .into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 fn resolve_changed_files(
     repo_root: &camino::Utf8Path,
     cfg_text: &str,
     cli_scope: Option<&str>,
     base: Option<&str>,
     head: Option<&str>,
+    since: Option<&str>,
     diff_file: Option<&camino::Utf8Path>,
 ) -> anyhow::Result<Option<Vec<RepoPath>>> {
     let diff_scope_enabled = cli_scope == Some("diff")
@@ -1020,6 +1599,9 @@ fn resolve_changed_files(
         if diff_file.is_some() {
             anyhow::bail!("--diff-file requires --scope diff");
         }
+        if since.is_some() {
+            anyhow::bail!("--since requires --scope diff");
+        }
         return Ok(None);
     }
 
@@ -1028,6 +1610,13 @@ fn resolve_changed_files(
         return Ok(Some(paths));
     }
 
+    if let Some(since) = since {
+        let base = git_merge_base(repo_root, since).context("git merge-base failed")?;
+        let changed =
+            git_changed_files(repo_root, &base, "HEAD").context("git diff --name-only failed")?;
+        return Ok(Some(changed));
+    }
+
     let base = base.context("diff scope requires --base (or --diff-file)")?;
     let head = head.context("diff scope requires --head (or --diff-file)")?;
 
@@ -1055,7 +1644,24 @@ fn read_changed_files_from_file(
         std::fs::read_to_string(&path).with_context(|| format!("read diff file: {path}"))?
     };
 
-    Ok(parse_changed_files_input(&content))
+    let paths = parse_changed_files_input(&content);
+    for path in &paths {
+        ensure_repo_relative(path)?;
+    }
+    Ok(paths)
+}
+
+/// Reject changed-file entries that would point outside the repository.
+fn ensure_repo_relative(path: &RepoPath) -> anyhow::Result<()> {
+    let p = path.as_str();
+    let absolute = p.starts_with('/') || p.as_bytes().get(1) == Some(&b':');
+    if absolute || p.split('/').any(|segment| segment == "..") {
+        anyhow::bail!(
+            "changed file path '{}' must be relative to the repo root (no absolute paths or '..' segments)",
+            p
+        );
+    }
+    Ok(())
 }
 
 fn parse_changed_files_input(input: &str) -> Vec<RepoPath> {
@@ -1186,37 +1792,167 @@ fn tokenize_paths(input: &str) -> Vec<String> {
     tokens
 }
 
+fn parse_output_format(v: &str) -> anyhow::Result<ReportOutFormat> {
+    match v {
+        "json" => Ok(ReportOutFormat::Json),
+        "markdown" | "md" => Ok(ReportOutFormat::Markdown),
+        "sarif" => Ok(ReportOutFormat::Sarif),
+        "gitlab" | "codequality" => Ok(ReportOutFormat::Gitlab),
+        "junit" => Ok(ReportOutFormat::Junit),
+        "checkstyle" => Ok(ReportOutFormat::Checkstyle),
+        other => anyhow::bail!(
+            "unknown output format: {other} (expected json, markdown, sarif, gitlab, junit, or checkstyle)"
+        ),
+    }
+}
+
+/// Newest depguard report schema; what `--report-version latest` selects.
+const LATEST_REPORT_VERSION: ReportVersion = ReportVersion::V2;
+
 fn parse_report_version(v: &str) -> anyhow::Result<ReportVersion> {
     match v {
+        "latest" => Ok(LATEST_REPORT_VERSION),
         "v1" | "1" | "depguard.report.v1" => Ok(ReportVersion::V1),
         "v2" | "2" | "depguard.report.v2" => Ok(ReportVersion::V2),
         "sensor-v1" | "sensor.report.v1" => Ok(ReportVersion::SensorV1),
-        other => anyhow::bail!("unknown report version: {other} (expected v1, v2, or sensor-v1)"),
+        other => {
+            anyhow::bail!("unknown report version: {other} (expected v1, v2, sensor-v1, or latest)")
+        }
+    }
+}
+
+/// One-line stderr note for a depguard report schema older than the latest.
+///
+/// `sensor-v1` is a separate format, not an older depguard schema, so it never
+/// gets a note.
+fn report_version_note(version: ReportVersion) -> Option<&'static str> {
+    match version {
+        ReportVersion::V1 => Some(
+            "depguard: note: report version v1 is older than the latest (v2); use --report-version latest to track new defaults",
+        ),
+        ReportVersion::V2 | ReportVersion::SensorV1 => None,
     }
 }
 
 fn report_exit_code(report: &ReportVariant) -> i32 {
-    match report {
-        ReportVariant::V1(r) => verdict_exit_code(r.verdict.clone()),
-        ReportVariant::V2(r) => match r.verdict.status {
-            depguard_types::VerdictStatus::Pass => 0,
-            depguard_types::VerdictStatus::Warn => 0,
-            depguard_types::VerdictStatus::Fail => 2,
-            depguard_types::VerdictStatus::Skip => 0,
-        },
+    render_exit_code(report, RenderFailOn::Fail)
+}
+
+/// Exit code for a render command that was asked to reflect the report verdict.
+fn render_exit_code(report: &ReportVariant, fail_on: RenderFailOn) -> i32 {
+    use depguard_types::VerdictStatus;
+    match (fail_on, report_verdict(report)) {
+        (RenderFailOn::Fail | RenderFailOn::Warn, VerdictStatus::Fail) => 2,
+        (RenderFailOn::Warn, VerdictStatus::Warn) => 2,
+        _ => 0,
     }
 }
 
-fn normalize_input_path(repo_root: &camino::Utf8Path, path: &str) -> Utf8PathBuf {
-    let path = Utf8PathBuf::from(path);
-    if path.is_absolute() {
-        path
-    } else {
-        repo_root.join(path)
+/// Resolve which config file to load.
+///
+/// An explicit `--config` is taken relative to the repo root. Otherwise the
+/// search walks up from the repo root and stops at the first `depguard.toml`.
+/// `None` means no config file was found and defaults apply.
+fn resolve_config_path(repo_root: &Utf8Path, explicit: Option<&Utf8Path>) -> Option<Utf8PathBuf> {
+    if let Some(path) = explicit {
+        return Some(normalize_input_path(repo_root, path.as_str()));
     }
+    repo_root
+        .ancestors()
+        .map(|dir| dir.join(DEFAULT_CONFIG_FILE))
+        .find(|candidate| candidate.is_file())
 }
 
-fn effective_cache_dir(enabled: bool, cache_dir: Option<Utf8PathBuf>) -> Option<Utf8PathBuf> {
+/// Config text plus a description of where it came from.
+struct LoadedConfig {
+    text: String,
+    source: Option<String>,
+}
+
+/// Load config following the documented precedence: an explicit or discovered
+/// `depguard.toml` wins, then `[workspace.metadata.depguard]` /
+/// `[package.metadata.depguard]` in the root `Cargo.toml`, then defaults.
+///
+/// An explicit `--config` glob (e.g. `.depguard/*.toml`) merges every matching
+/// file in sorted order; a file that fails to read or parse is an error.
+fn load_config(repo_root: &Utf8Path, explicit: Option<&Utf8Path>) -> anyhow::Result<LoadedConfig> {
+    if let Some(pattern) = explicit.filter(|path| depguard_app::is_config_glob(path.as_str())) {
+        let pattern = normalize_input_path(repo_root, pattern.as_str());
+        let paths = depguard_app::expand_config_glob(&pattern)?;
+        let merged = depguard_app::merge_config_files(&paths, repo_root)?;
+        let files: Vec<&str> = paths.iter().map(|path| path.as_str()).collect();
+        verbosity::verbose(format_args!("depguard: using config {}", files.join(", ")));
+        return Ok(LoadedConfig {
+            text: depguard_settings::render_config_toml(&merged)
+                .context("serialize merged config")?,
+            source: Some(pattern.to_string()),
+        });
+    }
+    if let Some(path) = resolve_config_path(repo_root, explicit)
+        && let Ok(text) = std::fs::read_to_string(&path)
+    {
+        verbosity::verbose(format_args!("depguard: using config {}", path));
+        return Ok(LoadedConfig {
+            text,
+            source: Some(path.to_string()),
+        });
+    }
+    if explicit.is_none()
+        && let Ok(Some(text)) = depguard_repo::read_metadata_config(repo_root)
+    {
+        let source = format!("{}/Cargo.toml [metadata.depguard]", repo_root);
+        verbosity::verbose(format_args!("depguard: using config {}", source));
+        return Ok(LoadedConfig {
+            text,
+            source: Some(source),
+        });
+    }
+    Ok(LoadedConfig {
+        text: String::new(),
+        source: None,
+    })
+}
+
+/// Verbose diagnostic: whether the run covers the whole repo or a diff.
+fn log_scope_decision(changed_files: Option<&[RepoPath]>) {
+    match changed_files {
+        Some(files) => verbosity::verbose(format_args!(
+            "depguard: scope diff ({} changed files)",
+            files.len()
+        )),
+        None => verbosity::verbose("depguard: scope repo"),
+    }
+}
+
+/// Verbose diagnostic: how many manifests the check discovered and scanned.
+fn log_manifests_scanned(report: &ReportVariant) {
+    let scanned = match report {
+        ReportVariant::V1(r) => r.data.manifests_scanned,
+        ReportVariant::V2(r) => r.data.manifests_scanned,
+    };
+    verbosity::verbose(format_args!("depguard: scanned {scanned} manifests"));
+}
+
+/// Print a stderr warning for each configured check id that matches no registered check.
+fn warn_unknown_checks(resolved: &depguard_settings::ResolvedConfig) {
+    for check_id in &resolved.unknown_checks {
+        eprintln!(
+            "depguard: warning: unknown check id '{}' in config is ignored (see `depguard list-checks`)",
+            check_id
+        );
+    }
+}
+
+fn normalize_input_path(repo_root: &camino::Utf8Path, path: &str) -> Utf8PathBuf {
+    let path = Utf8PathBuf::from(path);
+    if path.is_absolute() {
+        path
+    } else {
+        repo_root.join(path)
+    }
+}
+
+fn effective_cache_dir(enabled: bool, cache_dir: Option<Utf8PathBuf>) -> Option<Utf8PathBuf> {
     if !enabled {
         return None;
     }
@@ -1342,7 +2078,7 @@ fn collect_exact_pins(model: &depguard::model::WorkspaceModel) -> BTreeSet<(Stri
             let Some(pinned) = pinned_version(version_req) else {
                 continue;
             };
-            let canonical_name = dep.spec.package.as_deref().unwrap_or(&dep.name);
+            let canonical_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
             pins.insert((canonical_name.to_string(), pinned.to_string()));
         }
     }
@@ -1364,6 +2100,25 @@ fn write_report_file(path: &camino::Utf8Path, report: &ReportVariant) -> anyhow:
     Ok(())
 }
 
+/// Write the `check` report to `--report-out` in the selected format.
+fn write_report_out(
+    path: &camino::Utf8Path,
+    report: &ReportVariant,
+    format: ReportOutFormat,
+) -> anyhow::Result<()> {
+    let rendered = match format {
+        ReportOutFormat::Json => {
+            return write_report_file(path, report).context("write report json");
+        }
+        ReportOutFormat::Markdown => render_markdown(&to_renderable(report)),
+        ReportOutFormat::Sarif => render_sarif(&to_renderable(report)),
+        ReportOutFormat::Gitlab => render_gitlab_codequality(&to_renderable(report)),
+        ReportOutFormat::Junit => render_junit(&to_renderable(report)),
+        ReportOutFormat::Checkstyle => render_checkstyle(&to_renderable(report)),
+    };
+    write_text_file(path, &rendered).with_context(|| format!("write {} report", format.name()))
+}
+
 fn write_text_file(path: &camino::Utf8Path, text: &str) -> anyhow::Result<()> {
     if let Some(parent) = path.parent().filter(|p| !p.as_str().is_empty()) {
         std::fs::create_dir_all(parent).with_context(|| format!("create directory: {}", parent))?;
@@ -1409,11 +2164,11 @@ fn cmd_fix(
     let plan = generate_buildfix_plan(&report, report_path.as_str(), !apply);
     write_buildfix_plan_file(&plan_out, &plan)?;
 
-    eprintln!(
+    verbosity::note(format_args!(
         "depguard: wrote buildfix plan with {} safe fix actions to {}",
         plan.fixes.len(),
         plan_out
-    );
+    ));
 
     if !apply {
         return Ok(());
@@ -1424,10 +2179,10 @@ fn cmd_fix(
         .unwrap_or_else(|_| repo_root_arg.clone());
     let result = apply_safe_fixes(&repo_root, &report);
 
-    eprintln!(
+    verbosity::note(format_args!(
         "depguard: applied {} of {} planned safe fixes ({} skipped, {} failed)",
         result.applied, result.planned, result.skipped, result.failed
-    );
+    ));
 
     if result.failed > 0 {
         anyhow::bail!(
@@ -1439,11 +2194,19 @@ fn cmd_fix(
     Ok(())
 }
 
-fn cmd_md(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Result<()> {
+fn cmd_md(
+    report_path: Utf8PathBuf,
+    output: Option<Utf8PathBuf>,
+    remediation_hints: bool,
+    min_severity: RenderableSeverity,
+    fail_on: RenderFailOn,
+) -> anyhow::Result<()> {
     let report_text = std::fs::read_to_string(&report_path)
         .with_context(|| format!("read report: {}", report_path))?;
     let report = parse_report_json(&report_text)?;
-    let renderable = to_renderable(&report);
+    let mut renderable = to_renderable(&report);
+    renderable.remediation_hints = remediation_hints;
+    retain_min_severity(&mut renderable, min_severity);
     let md = render_markdown(&renderable);
 
     if let Some(out_path) = output {
@@ -1452,6 +2215,7 @@ fn cmd_md(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Resu
         print!("{}", md);
     }
 
+    exit_for_verdict(&report, fail_on);
     Ok(())
 }
 
@@ -1487,6 +2251,54 @@ fn cmd_junit(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::R
     Ok(())
 }
 
+fn cmd_checkstyle(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Result<()> {
+    let report_text = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("read report: {}", report_path))?;
+    let report = parse_report_json(&report_text)?;
+    let renderable = to_renderable(&report);
+    let xml = render_checkstyle(&renderable);
+
+    if let Some(out_path) = output {
+        write_text_file(&out_path, &xml).context("write checkstyle output")?;
+    } else {
+        print!("{}", xml);
+    }
+
+    Ok(())
+}
+
+fn cmd_html(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Result<()> {
+    let report_text = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("read report: {}", report_path))?;
+    let report = parse_report_json(&report_text)?;
+    let renderable = to_renderable(&report);
+    let html = render_html(&renderable);
+
+    if let Some(out_path) = output {
+        write_text_file(&out_path, &html).context("write html output")?;
+    } else {
+        print!("{}", html);
+    }
+
+    Ok(())
+}
+
+fn cmd_gitlab(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Result<()> {
+    let report_text = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("read report: {}", report_path))?;
+    let report = parse_report_json(&report_text)?;
+    let renderable = to_renderable(&report);
+    let codequality = render_gitlab_codequality(&renderable);
+
+    if let Some(out_path) = output {
+        write_text_file(&out_path, &codequality).context("write gitlab output")?;
+    } else {
+        print!("{}", codequality);
+    }
+
+    Ok(())
+}
+
 fn cmd_jsonl(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Result<()> {
     let report_text = std::fs::read_to_string(&report_path)
         .with_context(|| format!("read report: {}", report_path))?;
@@ -1503,13 +2315,79 @@ fn cmd_jsonl(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::R
     Ok(())
 }
 
-fn cmd_annotations(report_path: Utf8PathBuf, max: usize) -> anyhow::Result<()> {
-    let annotations = render_annotations_text(&report_path, max)?;
-    print!("{}", annotations);
+fn cmd_summary(report_path: Utf8PathBuf, output: Option<Utf8PathBuf>) -> anyhow::Result<()> {
+    let report_text = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("read report: {}", report_path))?;
+    let report = parse_report_json(&report_text)?;
+    let renderable = to_renderable(&report);
+    let summary = render_job_summary(&renderable);
+
+    if let Some(out_path) = output {
+        write_text_file(&out_path, &summary).context("write summary output")?;
+    } else {
+        print!("{}", summary);
+    }
+
+    Ok(())
+}
+
+fn cmd_diff(
+    base_path: Utf8PathBuf,
+    head_path: Utf8PathBuf,
+    format: DiffFormat,
+    output: Option<Utf8PathBuf>,
+) -> anyhow::Result<()> {
+    let read = |path: &Utf8PathBuf| -> anyhow::Result<ReportVariant> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("read report: {}", path))?;
+        parse_report_json(&text).with_context(|| format!("parse report: {}", path))
+    };
+    let diff = diff_reports(&read(&base_path)?, &read(&head_path)?);
+    let rendered = match format {
+        DiffFormat::Text => render_diff_text(&diff),
+        DiffFormat::Markdown => render_diff_markdown(&diff),
+    };
+
+    if let Some(out_path) = output {
+        write_text_file(&out_path, &rendered).context("write diff output")?;
+    } else {
+        print!("{}", rendered);
+    }
+
+    if diff.has_new_findings() {
+        terminate(2);
+    }
+    Ok(())
+}
+
+fn cmd_annotations(
+    report_path: Utf8PathBuf,
+    target: AnnotationTarget,
+    max: usize,
+    mode: AnnotationMode,
+    min_severity: RenderableSeverity,
+    fail_on: RenderFailOn,
+) -> anyhow::Result<()> {
+    let report_text = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("read report: {report_path}"))?;
+    let report = parse_report_json(&report_text)?;
+    print!(
+        "{}",
+        render_annotations_text(&report, target, max, mode, min_severity)
+    );
 
+    exit_for_verdict(&report, fail_on);
     Ok(())
 }
 
+/// Terminate with the render exit code once output has been written.
+fn exit_for_verdict(report: &ReportVariant, fail_on: RenderFailOn) {
+    let code = render_exit_code(report, fail_on);
+    if code != 0 {
+        terminate(code);
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn cmd_ci_github(
     cli: &Cli,
@@ -1523,6 +2401,7 @@ fn cmd_ci_github(
     write_jsonl: bool,
     write_sarif: bool,
     max_annotations: usize,
+    annotation_mode: AnnotationMode,
     out_dir: Option<Utf8PathBuf>,
     report_out: Option<Utf8PathBuf>,
 ) -> anyhow::Result<()> {
@@ -1554,6 +2433,7 @@ fn cmd_ci_github(
     let run_opts = CheckOpts {
         base,
         head,
+        since: None,
         diff_file,
         yanked_index: None,
         yanked_live: false,
@@ -1564,13 +2444,18 @@ fn cmd_ci_github(
         out_dir,
         report_out,
         report_version: "v2".to_string(),
+        format: ReportOutFormat::Json,
         write_markdown,
         markdown_out: None,
         write_junit,
         junit_out: None,
         write_jsonl,
         jsonl_out: None,
+        ndjson: None,
+        explain_findings: false,
         mode: RunMode::Cockpit,
+        no_color: true,
+        config_dump: false,
     };
 
     let run_paths = resolve_output_paths(&run_opts);
@@ -1582,7 +2467,16 @@ fn cmd_ci_github(
     let code = report_exit_code(&report);
 
     if emit_annotations {
-        cmd_annotations(report_path.clone(), max_annotations)?;
+        print!(
+            "{}",
+            render_annotations_text(
+                &report,
+                AnnotationTarget::Github,
+                max_annotations,
+                annotation_mode,
+                RenderableSeverity::Info,
+            )
+        );
     }
 
     if write_sarif {
@@ -1602,12 +2496,20 @@ fn cmd_ci_github(
     }
 }
 
-fn render_annotations_text(report_path: &Utf8PathBuf, max: usize) -> anyhow::Result<String> {
-    let report_text = std::fs::read_to_string(report_path)
-        .with_context(|| format!("read report: {report_path}"))?;
-    let report = parse_report_json(&report_text)?;
-    let renderable = to_renderable(&report);
-    let annotations = render_annotations(&renderable, max);
+fn render_annotations_text(
+    report: &ReportVariant,
+    target: AnnotationTarget,
+    max: usize,
+    mode: AnnotationMode,
+    min_severity: RenderableSeverity,
+) -> String {
+    let mut renderable = to_renderable(report);
+    retain_min_severity(&mut renderable, min_severity);
+    let annotations = match target {
+        AnnotationTarget::Github => render_annotations(&renderable, max, mode),
+        AnnotationTarget::Azure => render_azure_annotations(&renderable, max, mode),
+        AnnotationTarget::Teamcity => render_teamcity(&renderable),
+    };
 
     let mut out = String::new();
     for annotation in annotations {
@@ -1615,7 +2517,7 @@ fn render_annotations_text(report_path: &Utf8PathBuf, max: usize) -> anyhow::Res
         out.push('\n');
     }
 
-    Ok(out)
+    out
 }
 
 fn resolve_ci_github_event(event: CiEvent) -> anyhow::Result<CiEvent> {
@@ -1679,30 +2581,142 @@ fn is_hex_sha(value: &str) -> bool {
     value.len() == 40 && value.chars().all(|c| c.is_ascii_hexdigit())
 }
 
-fn cmd_explain(identifier: &str) -> anyhow::Result<()> {
+fn cmd_explain(identifier: &str, json: bool) -> anyhow::Result<()> {
     match run_explain(identifier) {
         ExplainOutput::Found(exp) => {
-            print!("{}", depguard_app::format_explanation(&exp));
+            if json {
+                print_json(&depguard_app::explanation_json(identifier, &exp))?;
+            } else {
+                print!("{}", depguard_app::format_explanation(&exp));
+            }
             Ok(())
         }
         ExplainOutput::NotFound {
             identifier,
+            suggestion,
             available_check_ids,
             available_codes,
         } => {
-            eprint!(
-                "{}",
-                depguard_app::format_not_found(&identifier, available_check_ids, available_codes)
-            );
+            if json {
+                print_json(&depguard_app::not_found_json(
+                    &identifier,
+                    suggestion,
+                    available_check_ids,
+                    available_codes,
+                ))?;
+            } else {
+                eprint!(
+                    "{}",
+                    depguard_app::format_not_found(
+                        &identifier,
+                        suggestion,
+                        available_check_ids,
+                        available_codes
+                    )
+                );
+            }
+            terminate(1);
+        }
+    }
+}
+
+fn cmd_explain_all_json() -> anyhow::Result<()> {
+    print_json(&depguard_app::explain_catalog_json())
+}
+
+fn cmd_list_checks(profile: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let output = depguard_app::run_list_checks(profile).context("resolve profile")?;
+    if json {
+        print_json(&depguard_app::check_list_json(&output))
+    } else {
+        print!("{}", depguard_app::format_check_list(&output));
+        Ok(())
+    }
+}
+
+fn cmd_inventory(repo_root: &Utf8Path, out: Option<&Utf8Path>) -> anyhow::Result<()> {
+    let entries = depguard_app::run_inventory(repo_root).context("collect dependency inventory")?;
+    let json = depguard_app::inventory_json(&entries);
+    match out {
+        Some(path) => {
+            let text = serde_json::to_string_pretty(&json).context("serialize inventory")?;
+            write_text_file(path, &format!("{text}\n")).context("write inventory")
+        }
+        None => print_json(&json),
+    }
+}
+
+fn cmd_validate_config(cli: &Cli, explicit: Option<&Utf8Path>) -> anyhow::Result<()> {
+    let loaded = match explicit {
+        Some(path) if depguard_app::is_config_glob(path.as_str()) => {
+            match load_config(&cli.repo_root, Some(path)) {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    eprintln!("depguard: {path}: {err:#}");
+                    terminate(1);
+                }
+            }
+        }
+        Some(path) => {
+            let path = normalize_input_path(&cli.repo_root, path.as_str());
+            let text =
+                std::fs::read_to_string(&path).with_context(|| format!("read config {}", path))?;
+            LoadedConfig {
+                text,
+                source: Some(path.to_string()),
+            }
+        }
+        None => load_config(&cli.repo_root, None)?,
+    };
+    let source = loaded.source.as_deref().unwrap_or("<defaults>");
+
+    let cfg = match depguard_settings::parse_config_toml(&loaded.text) {
+        Ok(cfg) => cfg,
+        Err(err) => {
+            eprintln!("depguard: {source}: invalid TOML: {err}");
+            terminate(1);
+        }
+    };
+    let origin = loaded
+        .source
+        .as_deref()
+        .map(Utf8Path::new)
+        .filter(|path| path.is_file());
+    let cfg = match resolve_config_extends(cfg, origin, &cli.repo_root) {
+        Ok(cfg) => cfg,
+        Err(err) => {
+            eprintln!("depguard: {source}: {err:#}");
             terminate(1);
         }
+    };
+    let validation = depguard_settings::validate_config(cfg);
+
+    for check_id in &validation.unknown_check_ids {
+        eprintln!("warning: {source}: unknown check id '{check_id}' (see `depguard list-checks`)");
+    }
+    if !validation.errors.is_empty() {
+        for err in validation.errors.iter() {
+            eprintln!("error: {source}: {err}");
+        }
+        eprintln!("depguard: {} error(s) in {source}", validation.errors.len());
+        terminate(1);
     }
+
+    println!("depguard: {source} is valid");
+    Ok(())
+}
+
+fn print_json(value: &serde_json::Value) -> anyhow::Result<()> {
+    let text = serde_json::to_string_pretty(value).context("serialize JSON output")?;
+    println!("{text}");
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use assert_cmd::Command;
+    use depguard_app::empty_report;
     use std::any::Any;
     use std::panic::{AssertUnwindSafe, catch_unwind};
     use tempfile::TempDir;
@@ -1834,6 +2848,36 @@ mod tests {
             std::fs::read_to_string(legacy_jsonl_out).expect("read legacy jsonl"),
             std::fs::read_to_string(report_jsonl_out).expect("read canonical jsonl"),
         );
+
+        let legacy_summary_out = root.join("legacy.summary.md");
+        let report_summary_out = root.join("report.summary.md");
+        Command::cargo_bin("depguard")
+            .unwrap()
+            .args([
+                "summary",
+                "--report",
+                report_path.as_str(),
+                "--output",
+                legacy_summary_out.as_str(),
+            ])
+            .assert()
+            .success();
+        Command::cargo_bin("depguard")
+            .unwrap()
+            .args([
+                "report",
+                "summary",
+                "--report",
+                report_path.as_str(),
+                "--output",
+                report_summary_out.as_str(),
+            ])
+            .assert()
+            .success();
+        assert_eq!(
+            std::fs::read_to_string(legacy_summary_out).expect("read legacy summary"),
+            std::fs::read_to_string(report_summary_out).expect("read canonical summary"),
+        );
     }
 
     #[test]
@@ -1874,6 +2918,68 @@ mod tests {
         assert_eq!(legacy.stdout, canonical.stdout);
     }
 
+    #[test]
+    fn annotations_summarized_mode_groups_findings_over_cap() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        let report_path = root.join("artifacts").join("report.json");
+
+        write_sample_report_with_finding(&report_path, "Cargo.toml");
+        let text = std::fs::read_to_string(&report_path).expect("read report");
+        let mut report = parse_report_json(&text).expect("parse report");
+        if let ReportVariant::V2(ref mut r) = report {
+            let first = r.findings[0].clone();
+            r.findings.push(first);
+        }
+        write_report_file(&report_path, &report).expect("write report");
+
+        let output = Command::cargo_bin("depguard")
+            .unwrap()
+            .args([
+                "annotations",
+                "--report",
+                report_path.as_str(),
+                "--max",
+                "1",
+                "--mode",
+                "summarized",
+            ])
+            .output()
+            .expect("run annotations");
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(stdout.lines().count(), 1);
+        assert!(stdout.contains("[deps.no_wildcards] 2 findings; first: Test wildcard dependency"));
+    }
+
+    #[test]
+    fn annotations_azure_target_emits_logissue_commands() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        let report_path = root.join("artifacts").join("report.json");
+        write_sample_report_with_finding(&report_path, "Cargo.toml");
+
+        let output = Command::cargo_bin("depguard")
+            .unwrap()
+            .args([
+                "annotations",
+                "--report",
+                report_path.as_str(),
+                "--target",
+                "azure",
+            ])
+            .output()
+            .expect("run annotations");
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(stdout.lines().count(), 1);
+        assert!(stdout.starts_with("##vso[task.logissue type="));
+        assert!(stdout.contains(";sourcepath=Cargo.toml"));
+        assert!(stdout.contains("][deps.no_wildcards:wildcard_version] Test wildcard dependency"));
+    }
+
     #[test]
     fn git_diff_error_display_base_unreachable() {
         let err = GitDiffError::BaseCommitNotReachable {
@@ -2018,6 +3124,7 @@ mod tests {
             Some("repo"),
             None,
             None,
+            None,
             Some(camino::Utf8Path::new("changed-files.txt")),
         )
         .expect_err("expected error");
@@ -2027,6 +3134,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_changed_files_rejects_paths_outside_repo() {
+        let tmp = tempfile::tempdir().expect("temp dir");
+        let root = camino::Utf8Path::from_path(tmp.path()).expect("utf8 temp dir");
+
+        for entry in [
+            "../other/Cargo.toml",
+            "crates/../../Cargo.toml",
+            "/etc/Cargo.toml",
+        ] {
+            std::fs::write(root.join("changed.txt"), format!("{entry}\n")).expect("write");
+            let err = read_changed_files_from_file(root, camino::Utf8Path::new("changed.txt"))
+                .expect_err("expected rejection");
+            assert!(
+                err.to_string()
+                    .contains("must be relative to the repo root"),
+                "unexpected error for {entry}: {err}"
+            );
+        }
+
+        std::fs::write(root.join("changed.txt"), "./crates/a/Cargo.toml\n").expect("write");
+        let paths = read_changed_files_from_file(root, camino::Utf8Path::new("changed.txt"))
+            .expect("clean paths");
+        assert_eq!(paths, vec![RepoPath::new("crates/a/Cargo.toml")]);
+    }
+
+    #[test]
+    fn cli_parses_changed_files_alias() {
+        let cli = Cli::parse_from(["depguard", "check", "--changed-files", "-"]);
+        let Commands::Check { diff_file, .. } = cli.cmd else {
+            panic!("expected check command");
+        };
+        assert_eq!(diff_file, Some(Utf8PathBuf::from("-")));
+    }
+
     #[test]
     fn cli_parses_diff_file_for_check_subcommand() {
         let cli = Cli::parse_from(["depguard", "check", "--diff-file", "changed-files.txt"]);
@@ -2103,12 +3245,59 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_report_version_latest_maps_to_newest() {
+        assert_eq!(parse_report_version("latest").unwrap(), ReportVersion::V2);
+        assert_eq!(
+            parse_report_version("latest").unwrap(),
+            LATEST_REPORT_VERSION
+        );
+    }
+
+    #[test]
+    fn report_version_note_only_for_older_versions() {
+        let note = report_version_note(parse_report_version("v1").unwrap()).expect("note for v1");
+        assert!(note.contains("--report-version latest"));
+        assert!(!note.contains('\n'));
+        for v in ["v2", "latest", "sensor-v1"] {
+            assert_eq!(report_version_note(parse_report_version(v).unwrap()), None);
+        }
+    }
+
     #[test]
     fn parse_report_version_rejects_unknown() {
         let err = parse_report_version("nope").unwrap_err();
         assert!(err.to_string().contains("unknown report version"));
     }
 
+    #[test]
+    fn parse_output_format_accepts_known_and_rejects_unknown() {
+        assert_eq!(parse_output_format("json").unwrap(), ReportOutFormat::Json);
+        assert_eq!(
+            parse_output_format("md").unwrap(),
+            ReportOutFormat::Markdown
+        );
+        assert_eq!(
+            parse_output_format("sarif").unwrap(),
+            ReportOutFormat::Sarif
+        );
+        assert_eq!(
+            parse_output_format("gitlab").unwrap(),
+            ReportOutFormat::Gitlab
+        );
+        assert_eq!(
+            parse_output_format("junit").unwrap(),
+            ReportOutFormat::Junit
+        );
+        assert_eq!(
+            parse_output_format("checkstyle").unwrap(),
+            ReportOutFormat::Checkstyle
+        );
+
+        let err = parse_output_format("html").unwrap_err();
+        assert!(err.to_string().contains("unknown output format: html"));
+    }
+
     #[test]
     fn ci_base_ref_defaults_prefix_origin() {
         assert_eq!(normalize_ci_ref_base("main"), "origin/main");
@@ -2151,8 +3340,35 @@ mod tests {
     }
 
     #[test]
-    fn classify_git_diff_error_variants() {
-        let err = classify_git_diff_error(
+    fn render_exit_code_honors_fail_on_level() {
+        let mut report = empty_report(ReportVersion::V2, "repo", "strict");
+        let mut codes = Vec::new();
+        for status in [
+            depguard_types::VerdictStatus::Pass,
+            depguard_types::VerdictStatus::Warn,
+            depguard_types::VerdictStatus::Fail,
+        ] {
+            if let ReportVariant::V2(ref mut r) = report {
+                r.verdict.status = status;
+            }
+            codes.push([
+                render_exit_code(&report, RenderFailOn::Never),
+                render_exit_code(&report, RenderFailOn::Warn),
+                render_exit_code(&report, RenderFailOn::Fail),
+            ]);
+        }
+        assert_eq!(codes, vec![[0, 0, 0], [0, 2, 0], [0, 2, 2]]);
+
+        let cli = Cli::parse_from(["depguard", "md", "--fail-on", "fail"]);
+        let Commands::Md { fail_on, .. } = cli.cmd else {
+            panic!("expected md command");
+        };
+        assert_eq!(fail_on, RenderFailOn::Fail);
+    }
+
+    #[test]
+    fn classify_git_diff_error_variants() {
+        let err = classify_git_diff_error(
             "origin/main",
             "HEAD",
             "fatal: ambiguous argument 'origin/main': unknown revision or path".to_string(),
@@ -2221,13 +3437,16 @@ edition = "2021"
     fn cli_for_root(root: &Utf8PathBuf) -> Cli {
         Cli {
             repo_root: root.clone(),
-            config: Utf8PathBuf::from("depguard.toml"),
+            config: None,
             profile: None,
             scope: None,
             max_findings: None,
+            quiet: false,
+            verbose: false,
             cmd: Commands::Check {
                 base: None,
                 head: None,
+                since: None,
                 diff_file: None,
                 yanked_index: None,
                 yanked_live: false,
@@ -2237,14 +3456,19 @@ edition = "2021"
                 out_dir: None,
                 report_out: Some(Utf8PathBuf::from("report.json")),
                 report_version: "v2".to_string(),
+                format: "json".to_string(),
                 write_markdown: false,
                 markdown_out: Some(Utf8PathBuf::from("comment.md")),
                 write_junit: false,
                 junit_out: None,
                 write_jsonl: false,
                 jsonl_out: None,
+                ndjson: None,
+                explain_findings: false,
                 mode: RunMode::Standard,
                 baseline: None,
+                no_color: false,
+                config_dump: false,
             },
         }
     }
@@ -2278,6 +3502,7 @@ edition = "2021"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2288,13 +3513,18 @@ edition = "2021"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: true,
             markdown_out: Some(markdown_out.clone()),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2314,6 +3544,7 @@ edition = "2021"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2324,13 +3555,18 @@ edition = "2021"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: true,
             markdown_out: Some(markdown_out.clone()),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2338,6 +3574,49 @@ edition = "2021"
         assert!(markdown_out.exists());
     }
 
+    #[test]
+    fn cmd_check_format_sarif_writes_sarif_to_default_path() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        write_manifest(&root, "");
+
+        let cli = cli_for_root(&root);
+        let out_dir = root.join("artifacts");
+        let opts = CheckOpts {
+            base: None,
+            head: None,
+            since: None,
+            diff_file: None,
+            yanked_index: None,
+            yanked_live: false,
+            yanked_api_base_url: None,
+            incremental: false,
+            cache_dir: None,
+            baseline: None,
+            out_dir: Some(out_dir.clone()),
+            report_out: None,
+            report_version: "v2".to_string(),
+            format: ReportOutFormat::Sarif,
+            write_markdown: false,
+            markdown_out: None,
+            write_junit: false,
+            junit_out: None,
+            write_jsonl: false,
+            jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
+            mode: RunMode::Standard,
+            no_color: true,
+            config_dump: false,
+        };
+
+        cmd_check(&cli, opts).expect("cmd_check");
+        assert!(!out_dir.join("report.json").exists());
+        let sarif = std::fs::read_to_string(out_dir.join("report.sarif.json")).expect("read sarif");
+        let value: serde_json::Value = serde_json::from_str(&sarif).expect("sarif json");
+        assert_eq!(value["version"], "2.1.0");
+    }
+
     #[test]
     fn cmd_check_diff_scope_uses_diff_file_without_git() {
         let tmp = TempDir::new().expect("temp dir");
@@ -2357,6 +3636,7 @@ edition = "2021"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: Some(Utf8PathBuf::from("changed-files.txt")),
             yanked_index: None,
             yanked_live: false,
@@ -2367,13 +3647,18 @@ edition = "2021"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(root.join("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Cockpit,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2412,6 +3697,7 @@ severity = "error"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: Some(Utf8PathBuf::from("yanked-index.txt")),
             yanked_live: false,
@@ -2422,13 +3708,18 @@ severity = "error"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(root.join("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Cockpit,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2446,6 +3737,186 @@ severity = "error"
         );
     }
 
+    #[test]
+    fn resolve_config_path_walks_up_from_nested_repo_root() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        let nested = root.join("crates").join("inner");
+        std::fs::create_dir_all(&nested).expect("create nested dirs");
+        std::fs::write(root.join("depguard.toml"), "profile = \"warn\"").expect("write config");
+
+        assert_eq!(
+            resolve_config_path(&nested, None),
+            Some(root.join("depguard.toml"))
+        );
+
+        // The nearest config wins over one further up.
+        std::fs::write(nested.join("depguard.toml"), "").expect("write nested config");
+        assert_eq!(
+            resolve_config_path(&nested, None),
+            Some(nested.join("depguard.toml"))
+        );
+    }
+
+    #[test]
+    fn resolve_config_path_prefers_explicit_config() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        std::fs::write(root.join("depguard.toml"), "").expect("write config");
+
+        assert_eq!(
+            resolve_config_path(&root, Some(Utf8Path::new("custom.toml"))),
+            Some(root.join("custom.toml"))
+        );
+    }
+
+    #[test]
+    fn cmd_check_uses_config_from_parent_directory() {
+        let tmp = TempDir::new().expect("temp dir");
+        let outer = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        let root = outer.join("member");
+        std::fs::create_dir_all(&root).expect("create member dir");
+        write_manifest(&root, r#"serde = "*""#);
+        std::fs::write(
+            outer.join("depguard.toml"),
+            "[checks.\"deps.no_wildcards\"]\nenabled = false\n",
+        )
+        .expect("write config");
+
+        let cli = cli_for_root(&root);
+        let report_out = root.join("report.json");
+        let opts = CheckOpts {
+            base: None,
+            head: None,
+            since: None,
+            diff_file: None,
+            yanked_index: None,
+            yanked_live: false,
+            yanked_api_base_url: None,
+            incremental: false,
+            cache_dir: None,
+            baseline: None,
+            out_dir: None,
+            report_out: Some(report_out.clone()),
+            report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
+            write_markdown: false,
+            markdown_out: Some(root.join("comment.md")),
+            write_junit: false,
+            junit_out: None,
+            write_jsonl: false,
+            jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
+            mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
+        };
+
+        cmd_check(&cli, opts).expect("cmd_check");
+
+        let report_text = std::fs::read_to_string(report_out).expect("read report");
+        let report = parse_report_json(&report_text).expect("parse report");
+        let ReportVariant::V2(report) = report else {
+            panic!("expected v2 report");
+        };
+        assert!(
+            report.findings.is_empty(),
+            "parent config should disable no_wildcards"
+        );
+    }
+
+    #[test]
+    fn load_config_falls_back_to_cargo_metadata() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"test\"\nversion = \"0.1.0\"\n\n\
+             [package.metadata.depguard]\nprofile = \"warn\"\n",
+        )
+        .expect("write Cargo.toml");
+
+        let loaded = load_config(&root, None).expect("load config");
+        let cfg = depguard_settings::parse_config_toml(&loaded.text).expect("parse config");
+        assert_eq!(cfg.profile.as_deref(), Some("warn"));
+        assert!(
+            loaded
+                .source
+                .as_deref()
+                .is_some_and(|s| s.contains("metadata.depguard"))
+        );
+
+        // depguard.toml takes precedence over manifest metadata.
+        std::fs::write(root.join("depguard.toml"), "profile = \"compat\"").expect("write config");
+        let loaded = load_config(&root, None).expect("load config");
+        let cfg = depguard_settings::parse_config_toml(&loaded.text).expect("parse config");
+        assert_eq!(cfg.profile.as_deref(), Some("compat"));
+    }
+
+    #[test]
+    fn cmd_check_uses_workspace_metadata_config() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        std::fs::write(
+            root.join("Cargo.toml"),
+            r#"[package]
+name = "test"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+serde = "*"
+
+[workspace.metadata.depguard.checks."deps.no_wildcards"]
+enabled = false
+"#,
+        )
+        .expect("write Cargo.toml");
+
+        let cli = cli_for_root(&root);
+        let report_out = root.join("report.json");
+        let opts = CheckOpts {
+            base: None,
+            head: None,
+            since: None,
+            diff_file: None,
+            yanked_index: None,
+            yanked_live: false,
+            yanked_api_base_url: None,
+            incremental: false,
+            cache_dir: None,
+            baseline: None,
+            out_dir: None,
+            report_out: Some(report_out.clone()),
+            report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
+            write_markdown: false,
+            markdown_out: Some(root.join("comment.md")),
+            write_junit: false,
+            junit_out: None,
+            write_jsonl: false,
+            jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
+            mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
+        };
+
+        cmd_check(&cli, opts).expect("cmd_check");
+
+        let report_text = std::fs::read_to_string(report_out).expect("read report");
+        let report = parse_report_json(&report_text).expect("parse report");
+        let ReportVariant::V2(report) = report else {
+            panic!("expected v2 report");
+        };
+        assert!(
+            report.findings.is_empty(),
+            "metadata config should disable no_wildcards"
+        );
+    }
+
     #[test]
     fn cmd_baseline_writes_file() {
         let tmp = TempDir::new().expect("temp dir");
@@ -2505,6 +3976,7 @@ severity = "error"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2515,13 +3987,18 @@ severity = "error"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(root.join("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2536,6 +4013,79 @@ severity = "error"
         assert_eq!(report.verdict.counts.suppressed, 1);
     }
 
+    #[test]
+    fn cmd_check_with_baseline_still_reports_new_findings() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        write_manifest(&root, r#"serde = "*""#);
+
+        let cli = cli_for_root(&root);
+        let baseline_path = root.join(".depguard-baseline.json");
+        cmd_baseline(
+            &cli,
+            BaselineOpts {
+                base: None,
+                head: None,
+                diff_file: None,
+                yanked_index: None,
+                yanked_live: false,
+                yanked_api_base_url: None,
+                incremental: false,
+                cache_dir: None,
+                output: baseline_path,
+            },
+        )
+        .expect("generate baseline");
+
+        // A wildcard introduced after the baseline was taken must still be reported.
+        write_manifest(&root, "serde = \"*\"\ntokio = \"*\"");
+
+        let report_out = root.join("report.json");
+        let opts = CheckOpts {
+            base: None,
+            head: None,
+            since: None,
+            diff_file: None,
+            yanked_index: None,
+            yanked_live: false,
+            yanked_api_base_url: None,
+            incremental: false,
+            cache_dir: None,
+            baseline: Some(Utf8PathBuf::from(".depguard-baseline.json")),
+            out_dir: None,
+            report_out: Some(report_out.clone()),
+            report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
+            write_markdown: false,
+            markdown_out: Some(root.join("comment.md")),
+            write_junit: false,
+            junit_out: None,
+            write_jsonl: false,
+            jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
+            mode: RunMode::Cockpit,
+            no_color: false,
+            config_dump: false,
+        };
+
+        cmd_check(&cli, opts).expect("cmd_check");
+
+        let report_text = std::fs::read_to_string(report_out).expect("read report");
+        let report = parse_report_json(&report_text).expect("parse report");
+        let ReportVariant::V2(report) = report else {
+            panic!("expected v2 report");
+        };
+        assert_eq!(report.findings.len(), 1);
+        assert!(
+            report.findings[0].message.contains("tokio"),
+            "expected only the new finding to remain"
+        );
+        assert_eq!(report.verdict.status, depguard_types::VerdictStatus::Fail);
+        assert_eq!(report.verdict.counts.suppressed, 1);
+        assert_eq!(report.data.findings_emitted, 1);
+    }
+
     #[test]
     fn cmd_check_cockpit_mode_suppresses_exit_on_fail() {
         let tmp = TempDir::new().expect("temp dir");
@@ -2547,6 +4097,7 @@ severity = "error"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2557,13 +4108,18 @@ severity = "error"
             out_dir: None,
             report_out: Some(report_out),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(root.join("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Cockpit,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2580,6 +4136,7 @@ severity = "error"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2590,13 +4147,18 @@ severity = "error"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(root.join("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
         };
 
         assert_exit_code(2, || {
@@ -2616,6 +4178,7 @@ severity = "error"
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2626,13 +4189,18 @@ severity = "error"
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(root.join("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Cockpit,
+            no_color: false,
+            config_dump: false,
         };
 
         cmd_check(&cli, opts).expect("cmd_check");
@@ -2650,7 +4218,14 @@ severity = "error"
         std::fs::write(&report_path, data).expect("write report");
 
         let output_path = root.join("report.md");
-        cmd_md(report_path, Some(output_path.clone())).expect("cmd_md");
+        cmd_md(
+            report_path,
+            Some(output_path.clone()),
+            true,
+            RenderableSeverity::Info,
+            RenderFailOn::Never,
+        )
+        .expect("cmd_md");
         assert!(output_path.exists());
     }
 
@@ -2690,6 +4265,36 @@ severity = "error"
         assert!(junit_text.contains("<testsuite"));
     }
 
+    #[test]
+    fn cmd_checkstyle_writes_output_file() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        let report_path = root.join("report.json");
+        write_sample_report_with_finding(&report_path, "crates/a/Cargo.toml");
+
+        let output_path = root.join("report.checkstyle.xml");
+        cmd_checkstyle(report_path, Some(output_path.clone())).expect("cmd_checkstyle");
+
+        let text = std::fs::read_to_string(output_path).expect("read checkstyle output");
+        assert!(text.contains("<checkstyle"));
+        assert!(text.contains("<file name=\"crates/a/Cargo.toml\">"));
+    }
+
+    #[test]
+    fn cmd_gitlab_writes_output_file() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        let report_path = root.join("report.json");
+        write_sample_report_with_finding(&report_path, "crates/a/Cargo.toml");
+
+        let output_path = root.join("gl-code-quality-report.json");
+        cmd_gitlab(report_path, Some(output_path.clone())).expect("cmd_gitlab");
+
+        let text = std::fs::read_to_string(output_path).expect("read gitlab output");
+        let issues: serde_json::Value = serde_json::from_str(&text).expect("valid json");
+        assert_eq!(issues[0]["location"]["path"], "crates/a/Cargo.toml");
+    }
+
     #[test]
     fn cmd_jsonl_writes_output_file() {
         let tmp = TempDir::new().expect("temp dir");
@@ -2708,6 +4313,23 @@ severity = "error"
         assert!(jsonl_text.contains("\"kind\":\"summary\""));
     }
 
+    #[test]
+    fn cmd_summary_writes_output_file() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+
+        let report = empty_report(ReportVersion::V2, "repo", "strict");
+        let data = serialize_report(&report).expect("serialize report");
+        let report_path = root.join("report.json");
+        std::fs::write(&report_path, data).expect("write report");
+
+        let output_path = root.join("summary.md");
+        cmd_summary(report_path, Some(output_path.clone())).expect("cmd_summary");
+
+        let summary = std::fs::read_to_string(output_path).expect("read summary");
+        assert!(summary.starts_with("## depguard: ✅ PASS"));
+    }
+
     #[test]
     fn cmd_fix_writes_buildfix_plan_without_applying() {
         let tmp = TempDir::new().expect("temp dir");
@@ -2841,6 +4463,7 @@ serde = { version = "1.0", optional = true }
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2851,13 +4474,18 @@ serde = { version = "1.0", optional = true }
             out_dir: Some(Utf8PathBuf::from("custom-artifacts")),
             report_out: None,
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: None,
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
         };
 
         let paths = resolve_output_paths(&opts);
@@ -2882,7 +4510,14 @@ serde = { version = "1.0", optional = true }
     #[test]
     fn cmd_explain_not_found_exits() {
         assert_exit_code(1, || {
-            let _ = cmd_explain("not-a-real-id");
+            let _ = cmd_explain("not-a-real-id", false);
+        });
+    }
+
+    #[test]
+    fn cmd_explain_json_not_found_exits() {
+        assert_exit_code(1, || {
+            let _ = cmd_explain("not-a-real-id", true);
         });
     }
 
@@ -2893,13 +4528,16 @@ serde = { version = "1.0", optional = true }
 
         let cli = Cli {
             repo_root: root.clone(),
-            config: Utf8PathBuf::from("depguard.toml"),
+            config: None,
             profile: None,
             scope: None,
             max_findings: None,
+            quiet: false,
+            verbose: false,
             cmd: Commands::Check {
                 base: None,
                 head: None,
+                since: None,
                 diff_file: None,
                 yanked_index: None,
                 yanked_live: false,
@@ -2910,13 +4548,18 @@ serde = { version = "1.0", optional = true }
                 out_dir: None,
                 report_out: Some(Utf8PathBuf::from("report.json")),
                 report_version: "v2".to_string(),
+                format: "json".to_string(),
                 write_markdown: false,
                 markdown_out: Some(Utf8PathBuf::from("comment.md")),
                 write_junit: false,
                 junit_out: None,
                 write_jsonl: false,
                 jsonl_out: None,
+                ndjson: None,
+                explain_findings: false,
                 mode: RunMode::Standard,
+                no_color: false,
+                config_dump: false,
             },
         };
 
@@ -2925,6 +4568,7 @@ serde = { version = "1.0", optional = true }
         let opts = CheckOpts {
             base: None,
             head: None,
+            since: None,
             diff_file: None,
             yanked_index: None,
             yanked_live: false,
@@ -2935,13 +4579,18 @@ serde = { version = "1.0", optional = true }
             out_dir: None,
             report_out: Some(report_out.clone()),
             report_version: "v2".to_string(),
+            format: ReportOutFormat::Json,
             write_markdown: false,
             markdown_out: Some(Utf8PathBuf::from("comment.md")),
             write_junit: false,
             junit_out: None,
             write_jsonl: false,
             jsonl_out: None,
+            ndjson: None,
+            explain_findings: false,
             mode: RunMode::Standard,
+            no_color: false,
+            config_dump: false,
         };
 
         assert_exit_code(1, || {
@@ -2991,6 +4640,74 @@ serde = { version = "1.0", optional = true }
         assert!(msg.contains("git diff") || msg.contains("failed to run git"));
     }
 
+    fn git(root: &Utf8Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(root)
+            .args(args)
+            .output()
+            .expect("run git");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn resolve_changed_files_with_since_diffs_against_merge_base() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8PathBuf::from_path_buf(tmp.path().to_path_buf()).expect("utf8 path");
+        git(&root, &["init", "-q"]);
+        git(&root, &["config", "user.email", "test@test.com"]);
+        git(&root, &["config", "user.name", "Test User"]);
+        std::fs::write(root.join("Cargo.toml"), "[workspace]\n").expect("write");
+        git(&root, &["add", "."]);
+        git(&root, &["commit", "-q", "-m", "initial"]);
+        git(&root, &["branch", "-M", "main"]);
+        let fork_point = git(&root, &["rev-parse", "HEAD"]);
+
+        git(&root, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(root.join("feature.toml"), "").expect("write");
+        git(&root, &["add", "."]);
+        git(&root, &["commit", "-q", "-m", "feature"]);
+
+        // Advance main after the fork; those changes must not appear in the diff.
+        git(&root, &["checkout", "-q", "main"]);
+        std::fs::write(root.join("main-only.toml"), "").expect("write");
+        git(&root, &["add", "."]);
+        git(&root, &["commit", "-q", "-m", "main"]);
+        git(&root, &["checkout", "-q", "feature"]);
+
+        assert_eq!(
+            git_merge_base(&root, "main").expect("merge-base"),
+            fork_point
+        );
+        let changed =
+            resolve_changed_files(&root, "", Some("diff"), None, None, Some("main"), None)
+                .expect("resolve")
+                .expect("diff scope");
+        assert_eq!(changed, vec![RepoPath::new("feature.toml")]);
+
+        let err = resolve_changed_files(&root, "", Some("diff"), None, None, Some("nope"), None)
+            .expect_err("unknown ref");
+        let msg = format!("{err:#}");
+        assert!(msg.contains("git base revision 'nope' is not reachable"));
+        assert!(msg.contains("git fetch --unshallow"));
+
+        let err = resolve_changed_files(&root, "", Some("repo"), None, None, Some("main"), None)
+            .expect_err("repo scope");
+        assert!(err.to_string().contains("--since requires --scope diff"));
+    }
+
+    #[test]
+    fn cli_rejects_since_with_explicit_base() {
+        let err = Cli::try_parse_from(["depguard", "check", "--since", "main", "--base", "main"])
+            .expect_err("conflicting args");
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn write_report_and_text_files_create_parent() {
         let tmp = TempDir::new().expect("temp dir");
@@ -0,0 +1,271 @@
+//! Best-effort CI and git provenance for v2 receipts.
+//!
+//! Detection reads well-known CI environment variables first and falls back to
+//! `git` in the repo root, unless the caller rules git out (changed files given
+//! via `--changed-files` mean a sandbox without git). Nothing here can fail a
+//! run: missing metadata just
+//! leaves `run.git` / `run.ci` unset and, for sensor reports, marks the
+//! `provenance` capability missing.
+
+use camino::Utf8Path;
+use depguard_app::ReportVariant;
+use depguard_types::{CapabilityAvailability, CapabilityStatus, RunCi, RunGit, ids};
+use std::process::Command;
+
+/// CI and git metadata detected for the current run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RunProvenance {
+    pub git: Option<RunGit>,
+    pub ci: Option<RunCi>,
+}
+
+/// Detect provenance from the process environment and, when `use_git` is set,
+/// the repo's git HEAD.
+pub(crate) fn detect_run_provenance(repo_root: &Utf8Path, use_git: bool) -> RunProvenance {
+    detect_with(
+        |key| std::env::var(key).ok(),
+        |args| use_git.then(|| git_output(repo_root, args)).flatten(),
+    )
+}
+
+fn detect_with(
+    env: impl Fn(&str) -> Option<String>,
+    git: impl Fn(&[&str]) -> Option<String>,
+) -> RunProvenance {
+    let var = |key: &str| env(key).filter(|v| !v.trim().is_empty());
+    let first = |keys: &[&str]| keys.iter().find_map(|k| var(k));
+
+    let ci = detect_ci(&var);
+
+    let head_sha = first(&[
+        "GITHUB_SHA",
+        "CI_COMMIT_SHA",
+        "BUILDKITE_COMMIT",
+        "CIRCLE_SHA1",
+        "GIT_COMMIT",
+    ])
+    .or_else(|| git(&["rev-parse", "HEAD"]));
+    let head_ref = first(&[
+        "GITHUB_HEAD_REF",
+        "GITHUB_REF_NAME",
+        "GITHUB_REF",
+        "CI_COMMIT_REF_NAME",
+        "BUILDKITE_BRANCH",
+        "CIRCLE_BRANCH",
+        "GIT_BRANCH",
+    ])
+    .or_else(|| {
+        // Detached HEAD reports the literal "HEAD"; that is not a ref name.
+        git(&["rev-parse", "--abbrev-ref", "HEAD"]).filter(|r| r != "HEAD")
+    });
+    let base_ref = first(&["GITHUB_BASE_REF", "CI_MERGE_REQUEST_TARGET_BRANCH_NAME"]);
+    let repo = first(&["GITHUB_REPOSITORY", "CI_PROJECT_PATH"]);
+
+    let git = (head_sha.is_some() || head_ref.is_some() || base_ref.is_some() || repo.is_some())
+        .then_some(RunGit {
+            repo,
+            base_ref,
+            head_ref,
+            base_sha: None,
+            head_sha,
+            merge_base: None,
+        });
+
+    RunProvenance { git, ci }
+}
+
+fn detect_ci(var: &impl Fn(&str) -> Option<String>) -> Option<RunCi> {
+    if var("GITHUB_ACTIONS").is_some() {
+        let run_id = var("GITHUB_RUN_ID");
+        let url = match (var("GITHUB_SERVER_URL"), var("GITHUB_REPOSITORY"), &run_id) {
+            (Some(server), Some(repo), Some(id)) => Some(format!(
+                "{}/{}/actions/runs/{}",
+                server.trim_end_matches('/'),
+                repo,
+                id
+            )),
+            _ => None,
+        };
+        return Some(RunCi {
+            provider: Some("github-actions".to_string()),
+            run_id,
+            job: var("GITHUB_JOB"),
+            url,
+        });
+    }
+    if var("GITLAB_CI").is_some() {
+        return Some(RunCi {
+            provider: Some("gitlab-ci".to_string()),
+            run_id: var("CI_PIPELINE_ID"),
+            job: var("CI_JOB_NAME"),
+            url: var("CI_PIPELINE_URL"),
+        });
+    }
+    if var("BUILDKITE").is_some() {
+        return Some(RunCi {
+            provider: Some("buildkite".to_string()),
+            run_id: var("BUILDKITE_BUILD_ID"),
+            job: var("BUILDKITE_LABEL"),
+            url: var("BUILDKITE_BUILD_URL"),
+        });
+    }
+    if var("CIRCLECI").is_some() {
+        return Some(RunCi {
+            provider: Some("circleci".to_string()),
+            run_id: var("CIRCLE_WORKFLOW_ID"),
+            job: var("CIRCLE_JOB"),
+            url: var("CIRCLE_BUILD_URL"),
+        });
+    }
+    if var("JENKINS_URL").is_some() {
+        return Some(RunCi {
+            provider: Some("jenkins".to_string()),
+            run_id: var("BUILD_ID"),
+            job: var("JOB_NAME"),
+            url: var("BUILD_URL"),
+        });
+    }
+    None
+}
+
+/// Run `git` in `repo_root` and return trimmed stdout, or `None` on any failure.
+fn git_output(repo_root: &Utf8Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .current_dir(repo_root)
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Copy detected provenance into a v2/sensor report's `run` block.
+///
+/// v1 reports have no `run` block and are left untouched. When the report
+/// carries a capabilities map, `provenance` records whether a head commit
+/// could be identified.
+pub(crate) fn attach_run_provenance(report: &mut ReportVariant, provenance: RunProvenance) {
+    let ReportVariant::V2(report) = report else {
+        return;
+    };
+    if let Some(caps) = report.run.capabilities.as_mut() {
+        let has_head = provenance
+            .git
+            .as_ref()
+            .is_some_and(|g| g.head_sha.is_some());
+        caps.provenance = Some(CapabilityStatus {
+            status: if has_head {
+                CapabilityAvailability::Available
+            } else {
+                CapabilityAvailability::Missing
+            },
+            reason: (!has_head).then(|| ids::REASON_GIT_METADATA_UNAVAILABLE.to_string()),
+        });
+    }
+    report.run.git = provenance.git;
+    report.run.ci = provenance.ci;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use depguard_app::ReportVersion;
+    use std::collections::BTreeMap;
+
+    fn detect_in_non_repo(vars: &[(&str, &str)]) -> RunProvenance {
+        let tmp = tempfile::TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 temp path");
+        let env: BTreeMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        detect_with(|key| env.get(key).cloned(), |args| git_output(root, args))
+    }
+
+    #[test]
+    fn github_actions_env_populates_git_and_ci() {
+        let provenance = detect_in_non_repo(&[
+            ("GITHUB_ACTIONS", "true"),
+            ("GITHUB_SHA", "0123abcd"),
+            ("GITHUB_REF", "refs/heads/main"),
+            ("GITHUB_REF_NAME", "main"),
+            ("GITHUB_REPOSITORY", "acme/widgets"),
+            ("GITHUB_SERVER_URL", "https://github.com/"),
+            ("GITHUB_RUN_ID", "42"),
+            ("GITHUB_JOB", "depguard"),
+        ]);
+
+        let git = provenance.git.expect("git");
+        assert_eq!(git.head_sha.as_deref(), Some("0123abcd"));
+        assert_eq!(git.head_ref.as_deref(), Some("main"));
+        assert_eq!(git.repo.as_deref(), Some("acme/widgets"));
+        assert_eq!(git.base_ref, None);
+
+        let ci = provenance.ci.expect("ci");
+        assert_eq!(ci.provider.as_deref(), Some("github-actions"));
+        assert_eq!(ci.run_id.as_deref(), Some("42"));
+        assert_eq!(ci.job.as_deref(), Some("depguard"));
+        assert_eq!(
+            ci.url.as_deref(),
+            Some("https://github.com/acme/widgets/actions/runs/42")
+        );
+    }
+
+    #[test]
+    fn generic_git_commit_env_is_used_outside_known_providers() {
+        let provenance =
+            detect_in_non_repo(&[("GIT_COMMIT", "feedface"), ("GIT_BRANCH", "release")]);
+
+        let git = provenance.git.expect("git");
+        assert_eq!(git.head_sha.as_deref(), Some("feedface"));
+        assert_eq!(git.head_ref.as_deref(), Some("release"));
+        assert_eq!(provenance.ci, None);
+    }
+
+    #[test]
+    fn unset_env_outside_a_git_repo_detects_nothing() {
+        let provenance = detect_in_non_repo(&[("GITHUB_SHA", "  ")]);
+        assert_eq!(provenance, RunProvenance::default());
+    }
+
+    #[test]
+    fn attach_marks_provenance_capability_missing_without_head() {
+        let mut report = depguard_app::empty_report(ReportVersion::SensorV1, "repo", "strict");
+        attach_run_provenance(&mut report, RunProvenance::default());
+
+        let ReportVariant::V2(report) = report else {
+            panic!("expected v2 report");
+        };
+        assert!(report.run.git.is_none());
+        let caps = report.run.capabilities.expect("capabilities");
+        let provenance = caps.provenance.expect("provenance capability");
+        assert_eq!(provenance.status, CapabilityAvailability::Missing);
+        assert_eq!(
+            provenance.reason.as_deref(),
+            Some(ids::REASON_GIT_METADATA_UNAVAILABLE)
+        );
+    }
+
+    #[test]
+    fn attach_copies_git_and_ci_into_v2_run() {
+        let mut report = depguard_app::empty_report(ReportVersion::V2, "repo", "strict");
+        let provenance = detect_in_non_repo(&[("GITLAB_CI", "true"), ("CI_COMMIT_SHA", "abc")]);
+        attach_run_provenance(&mut report, provenance);
+
+        let ReportVariant::V2(report) = report else {
+            panic!("expected v2 report");
+        };
+        assert_eq!(
+            report.run.git.and_then(|g| g.head_sha).as_deref(),
+            Some("abc")
+        );
+        assert_eq!(
+            report.run.ci.and_then(|c| c.provider).as_deref(),
+            Some("gitlab-ci")
+        );
+        assert!(report.run.capabilities.is_none());
+    }
+}
@@ -0,0 +1,107 @@
+//! Stderr verbosity for CLI notices and diagnostics.
+//!
+//! The level is chosen once at startup from `--quiet` / `--verbose`, falling
+//! back to `DEPGUARD_LOG`. Errors and warnings always print; notices (such as
+//! the empty-report message) are dropped by `quiet`, and diagnostics (config
+//! path, scope decision, manifest count) only appear under `verbose`.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable consulted when neither `--quiet` nor `--verbose` is given.
+pub(crate) const LOG_ENV: &str = "DEPGUARD_LOG";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Level {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+impl Level {
+    /// Parse a `DEPGUARD_LOG` value; `error`/`warn`, `info`, and `debug` are
+    /// accepted as aliases for the three levels.
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "quiet" | "error" | "warn" => Some(Self::Quiet),
+            "normal" | "info" => Some(Self::Normal),
+            "verbose" | "debug" => Some(Self::Verbose),
+            _ => None,
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+/// Resolve the level from the global flags, then `env` (the `DEPGUARD_LOG`
+/// value). An unrecognized env value is returned as the error.
+pub(crate) fn resolve_level(
+    quiet: bool,
+    verbose: bool,
+    env: Option<&str>,
+) -> Result<Level, String> {
+    if quiet {
+        return Ok(Level::Quiet);
+    }
+    if verbose {
+        return Ok(Level::Verbose);
+    }
+    match env.filter(|v| !v.trim().is_empty()) {
+        Some(value) => Level::parse(value).ok_or_else(|| value.to_string()),
+        None => Ok(Level::Normal),
+    }
+}
+
+pub(crate) fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Print a notice to stderr unless running quietly.
+pub(crate) fn note(message: impl Display) {
+    if enabled(Level::Normal) {
+        eprintln!("{message}");
+    }
+}
+
+/// Print a diagnostic to stderr only when running verbosely.
+pub(crate) fn verbose(message: impl Display) {
+    if enabled(Level::Verbose) {
+        eprintln!("{message}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_take_precedence_over_env() {
+        assert_eq!(
+            resolve_level(true, false, Some("verbose")),
+            Ok(Level::Quiet)
+        );
+        assert_eq!(
+            resolve_level(false, true, Some("quiet")),
+            Ok(Level::Verbose)
+        );
+        assert_eq!(resolve_level(false, false, Some("quiet")), Ok(Level::Quiet));
+        assert_eq!(resolve_level(false, false, None), Ok(Level::Normal));
+        assert_eq!(resolve_level(false, false, Some("  ")), Ok(Level::Normal));
+    }
+
+    #[test]
+    fn env_values_accept_aliases_and_reject_unknown() {
+        assert_eq!(Level::parse("WARN"), Some(Level::Quiet));
+        assert_eq!(Level::parse("info"), Some(Level::Normal));
+        assert_eq!(Level::parse("debug"), Some(Level::Verbose));
+        assert_eq!(
+            resolve_level(false, false, Some("loud")),
+            Err("loud".to_string())
+        );
+    }
+}
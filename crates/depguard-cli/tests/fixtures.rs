@@ -234,6 +234,279 @@ fn fixture_dev_only_in_normal_fails() {
     assert_reports_match(report, expected, "dev_only_in_normal");
 }
 
+#[test]
+fn fixture_duplicate_across_kinds_fails() {
+    let (exit_code, report) = run_check_on_fixture("duplicate_across_kinds");
+    let expected = load_expected_report("duplicate_across_kinds");
+
+    assert_eq!(
+        exit_code, 2,
+        "duplicate_across_kinds fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "duplicate_across_kinds");
+}
+
+#[test]
+fn fixture_no_patch_override_fails() {
+    let (exit_code, report) = run_check_on_fixture("no_patch_override");
+    let expected = load_expected_report("no_patch_override");
+
+    assert_eq!(
+        exit_code, 2,
+        "no_patch_override fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "no_patch_override");
+}
+
+#[test]
+fn fixture_crate_dir_name_fails() {
+    let (exit_code, report) = run_check_on_fixture("crate_dir_name");
+    let expected = load_expected_report("crate_dir_name");
+
+    assert_eq!(
+        exit_code, 2,
+        "crate_dir_name fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "crate_dir_name");
+}
+
+#[test]
+fn fixture_restrict_build_deps_fails() {
+    let (exit_code, report) = run_check_on_fixture("restrict_build_deps");
+    let expected = load_expected_report("restrict_build_deps");
+
+    assert_eq!(
+        exit_code, 2,
+        "restrict_build_deps fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "restrict_build_deps");
+}
+
+#[test]
+fn fixture_max_direct_deps_fails() {
+    let (exit_code, report) = run_check_on_fixture("max_direct_deps");
+    let expected = load_expected_report("max_direct_deps");
+
+    assert_eq!(
+        exit_code, 2,
+        "max_direct_deps fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "max_direct_deps");
+}
+
+#[test]
+fn fixture_publish_reachability_fails() {
+    let (exit_code, report) = run_check_on_fixture("publish_reachability");
+    let expected = load_expected_report("publish_reachability");
+
+    assert_eq!(
+        exit_code, 2,
+        "publish_reachability fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "publish_reachability");
+}
+
+#[test]
+fn fixture_valid_target_cfg_fails() {
+    let (exit_code, report) = run_check_on_fixture("valid_target_cfg");
+    let expected = load_expected_report("valid_target_cfg");
+
+    assert_eq!(
+        exit_code, 2,
+        "valid_target_cfg fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "valid_target_cfg");
+}
+
+#[test]
+fn fixture_license_present_fails() {
+    let (exit_code, report) = run_check_on_fixture("license_present");
+    let expected = load_expected_report("license_present");
+
+    assert_eq!(
+        exit_code, 2,
+        "license_present fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "license_present");
+}
+
+#[test]
+fn fixture_no_rename_fails() {
+    let (exit_code, report) = run_check_on_fixture("no_rename");
+    let expected = load_expected_report("no_rename");
+
+    assert_eq!(exit_code, 2, "no_rename fixture should exit with 2 (fail)");
+    assert_reports_match(report, expected, "no_rename");
+}
+
+#[test]
+fn fixture_consistent_versions_fails() {
+    let (exit_code, report) = run_check_on_fixture("consistent_versions");
+    let expected = load_expected_report("consistent_versions");
+
+    assert_eq!(
+        exit_code, 2,
+        "consistent_versions fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "consistent_versions");
+}
+
+#[test]
+fn fixture_no_optional_dev_deps_fails() {
+    let (exit_code, report) = run_check_on_fixture("no_optional_dev_deps");
+    let expected = load_expected_report("no_optional_dev_deps");
+
+    assert_eq!(
+        exit_code, 2,
+        "no_optional_dev_deps fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "no_optional_dev_deps");
+}
+
+#[test]
+fn fixture_git_version_conflict_fails() {
+    let (exit_code, report) = run_check_on_fixture("git_version_conflict");
+    let expected = load_expected_report("git_version_conflict");
+
+    assert_eq!(
+        exit_code, 2,
+        "git_version_conflict fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "git_version_conflict");
+}
+
+#[test]
+fn fixture_consistent_msrv_fails() {
+    let (exit_code, report) = run_check_on_fixture("consistent_msrv");
+    let expected = load_expected_report("consistent_msrv");
+
+    assert_eq!(
+        exit_code, 2,
+        "consistent_msrv fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "consistent_msrv");
+}
+
+#[test]
+fn fixture_members_exist_fails() {
+    let (exit_code, report) = run_check_on_fixture("members_exist");
+    let expected = load_expected_report("members_exist");
+
+    assert_eq!(
+        exit_code, 2,
+        "members_exist fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "members_exist");
+}
+
+#[test]
+fn fixture_no_nested_workspace_fails() {
+    let (exit_code, report) = run_check_on_fixture("no_nested_workspace");
+    let expected = load_expected_report("no_nested_workspace");
+
+    assert_eq!(
+        exit_code, 2,
+        "no_nested_workspace fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "no_nested_workspace");
+}
+
+#[test]
+fn fixture_publish_metadata_fails() {
+    let (exit_code, report) = run_check_on_fixture("publish_metadata");
+    let expected = load_expected_report("publish_metadata");
+
+    assert_eq!(
+        exit_code, 2,
+        "publish_metadata fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "publish_metadata");
+}
+
+#[test]
+fn fixture_banned_features_fails() {
+    let (exit_code, report) = run_check_on_fixture("banned_features");
+    let expected = load_expected_report("banned_features");
+
+    assert_eq!(
+        exit_code, 2,
+        "banned_features fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "banned_features");
+}
+
+#[test]
+fn fixture_features_after_default_off_fails() {
+    let (exit_code, report) = run_check_on_fixture("features_after_default_off");
+    let expected = load_expected_report("features_after_default_off");
+
+    assert_eq!(
+        exit_code, 2,
+        "features_after_default_off fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "features_after_default_off");
+}
+
+#[test]
+fn fixture_path_within_workspace_fails() {
+    let (exit_code, report) = run_check_on_fixture("path_within_workspace");
+    let expected = load_expected_report("path_within_workspace");
+
+    assert_eq!(
+        exit_code, 2,
+        "path_within_workspace fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "path_within_workspace");
+}
+
+#[test]
+fn fixture_build_script_consistency_fails() {
+    let (exit_code, report) = run_check_on_fixture("build_script_consistency");
+    let expected = load_expected_report("build_script_consistency");
+
+    assert_eq!(
+        exit_code, 2,
+        "build_script_consistency fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "build_script_consistency");
+}
+
+#[test]
+fn fixture_no_dependency_cycles_fails() {
+    let (exit_code, report) = run_check_on_fixture("no_dependency_cycles");
+    let expected = load_expected_report("no_dependency_cycles");
+
+    assert_eq!(
+        exit_code, 2,
+        "no_dependency_cycles fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "no_dependency_cycles");
+}
+
+#[test]
+fn fixture_lints_configured_fails() {
+    let (exit_code, report) = run_check_on_fixture("lints_configured");
+    let expected = load_expected_report("lints_configured");
+
+    assert_eq!(
+        exit_code, 2,
+        "lints_configured fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "lints_configured");
+}
+
+#[test]
+fn fixture_no_std_default_features_fails() {
+    let (exit_code, report) = run_check_on_fixture("no_std_default_features");
+    let expected = load_expected_report("no_std_default_features");
+
+    assert_eq!(
+        exit_code, 2,
+        "no_std_default_features fixture should exit with 2 (fail)"
+    );
+    assert_reports_match(report, expected, "no_std_default_features");
+}
+
 #[test]
 fn fixture_multi_violation_fails() {
     let (exit_code, report) = run_check_on_fixture("multi_violation");
@@ -477,6 +750,46 @@ fn check_with_junit_and_jsonl_outputs() {
     );
 }
 
+#[test]
+fn check_with_ndjson_streams_one_line_per_finding() {
+    let fixture_path = fixtures_dir().join("multi_violation");
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let report_path = temp_dir.path().join("report.json");
+    let ndjson_path = temp_dir.path().join("findings.ndjson");
+
+    depguard_cmd()
+        .arg("--repo-root")
+        .arg(&fixture_path)
+        .arg("check")
+        .arg("--report-out")
+        .arg(&report_path)
+        .arg("--ndjson")
+        .arg(&ndjson_path)
+        .assert()
+        .code(2);
+
+    let report_text = std::fs::read_to_string(&report_path).expect("read report");
+    let report: Value = serde_json::from_str(&report_text).expect("parse report");
+    let findings = report["findings"].as_array().expect("findings array");
+    assert!(
+        findings.len() > 1,
+        "fixture should produce several findings"
+    );
+
+    let ndjson = std::fs::read_to_string(&ndjson_path).expect("read ndjson");
+    let lines: Vec<&str> = ndjson.lines().collect();
+    assert_eq!(lines.len(), findings.len());
+    for (line, finding) in lines.iter().zip(findings) {
+        let value: Value = serde_json::from_str(line).expect("each line is valid JSON");
+        assert!(value.is_object());
+        assert_eq!(value["check_id"], finding["check_id"]);
+        assert_eq!(value["code"], finding["code"]);
+        assert_eq!(value["severity"], finding["severity"]);
+        assert_eq!(value["fingerprint"], finding["fingerprint"]);
+        assert_eq!(value["location"]["path"], finding["location"]["path"]);
+    }
+}
+
 #[test]
 fn md_command_renders_from_report() {
     // First, create a report
@@ -662,6 +975,106 @@ fn markdown_matches_golden_fixture() {
     assert_eq!(actual, expected, "Markdown output did not match golden");
 }
 
+#[test]
+fn markdown_no_remediation_hints_flag_omits_hints() {
+    let fixture_path = fixtures_dir().join("wildcards");
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let report_path = temp_dir.path().join("report.json");
+
+    depguard_cmd()
+        .arg("--repo-root")
+        .arg(&fixture_path)
+        .arg("check")
+        .arg("--report-out")
+        .arg(&report_path)
+        .assert()
+        .code(2);
+
+    let output = depguard_cmd()
+        .arg("md")
+        .arg("--report")
+        .arg(&report_path)
+        .arg("--no-remediation-hints")
+        .output()
+        .expect("Failed to run md command");
+
+    assert!(output.status.success(), "md command should succeed");
+    let actual = String::from_utf8_lossy(&output.stdout);
+    assert!(actual.contains("<summary><code>deps.no_wildcards</code>"));
+    assert!(!actual.contains("**Fix:**"));
+}
+
+#[test]
+fn min_severity_filters_md_and_annotations_output() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let repo = temp_dir.path();
+    std::fs::write(
+        repo.join("Cargo.toml"),
+        r#"[package]
+name = "min-severity"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+serde = "*"
+json = { package = "serde_json", version = "1" }
+"#,
+    )
+    .expect("write Cargo.toml");
+    std::fs::write(
+        repo.join("depguard.toml"),
+        "[checks.\"deps.no_rename\"]\nenabled = true\nseverity = \"warning\"\n",
+    )
+    .expect("write depguard.toml");
+    let report_path = repo.join("report.json");
+
+    depguard_cmd()
+        .arg("--repo-root")
+        .arg(repo)
+        .arg("check")
+        .arg("--report-out")
+        .arg(&report_path)
+        .assert()
+        .code(2);
+
+    let md = depguard_cmd()
+        .arg("md")
+        .arg("--report")
+        .arg(&report_path)
+        .arg("--min-severity")
+        .arg("error")
+        .output()
+        .expect("Failed to run md command");
+    assert!(md.status.success(), "md command should succeed");
+    let md = String::from_utf8_lossy(&md.stdout);
+    assert!(md.contains("- Verdict: **FAIL**"));
+    assert!(md.contains("deps.no_wildcards"));
+    assert!(!md.contains("deps.no_rename"));
+    assert!(md.contains("> 1 lower-severity finding hidden by `--min-severity`."));
+
+    let annotations = depguard_cmd()
+        .arg("annotations")
+        .arg("--report")
+        .arg(&report_path)
+        .arg("--min-severity")
+        .arg("error")
+        .output()
+        .expect("Failed to run annotations command");
+    assert!(annotations.status.success());
+    let annotations = String::from_utf8_lossy(&annotations.stdout);
+    assert_eq!(annotations.lines().count(), 1);
+    assert!(annotations.starts_with("::error"));
+
+    let all = depguard_cmd()
+        .arg("annotations")
+        .arg("--report")
+        .arg(&report_path)
+        .output()
+        .expect("Failed to run annotations command");
+    let all = String::from_utf8_lossy(&all.stdout);
+    assert!(all.lines().any(|l| l.starts_with("::warning")));
+}
+
 #[test]
 fn annotations_match_golden_fixture() {
     let fixture_path = fixtures_dir().join("wildcards");
@@ -696,6 +1109,45 @@ fn annotations_match_golden_fixture() {
     assert_eq!(actual, expected, "Annotations output did not match golden");
 }
 
+#[test]
+fn annotations_teamcity_target_emits_inspections() {
+    let fixture_path = fixtures_dir().join("wildcards");
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let report_path = temp_dir.path().join("report.json");
+
+    depguard_cmd()
+        .arg("--repo-root")
+        .arg(&fixture_path)
+        .arg("check")
+        .arg("--report-out")
+        .arg(&report_path)
+        .assert()
+        .code(2);
+
+    let output = depguard_cmd()
+        .arg("annotations")
+        .arg("--report")
+        .arg(&report_path)
+        .arg("--target")
+        .arg("teamcity")
+        .output()
+        .expect("Failed to run annotations command");
+
+    assert!(
+        output.status.success(),
+        "annotations command should succeed"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert!(lines[0].starts_with("##teamcity[inspectionType id='deps.no_wildcards' "));
+    assert!(lines.iter().all(|l| l.starts_with("##teamcity[")));
+    assert!(
+        lines
+            .iter()
+            .any(|l| l.starts_with("##teamcity[inspection typeId='deps.no_wildcards' "))
+    );
+}
+
 #[test]
 fn explain_command_shows_check_info() {
     let output = depguard_cmd()
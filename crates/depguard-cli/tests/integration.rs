@@ -3,8 +3,9 @@
 //! This test module covers:
 //! - Check command with various configurations and flags
 //! - Baseline command generation and application
-//! - Render commands (md, annotations, sarif, junit, jsonl)
+//! - Render commands (md, annotations, sarif, junit, jsonl, summary)
 //! - Explain command for all check IDs and codes
+//! - List-checks and validate-config commands
 //! - Error handling scenarios
 
 use assert_cmd::Command;
@@ -126,6 +127,62 @@ mod check_command {
         assert!(report_path.exists(), "Report file should be created");
     }
 
+    #[test]
+    fn check_warns_on_unknown_check_ids() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .expect("write manifest");
+        std::fs::write(
+            root.join("depguard.toml"),
+            "[checks.\"deps.no_wildcard\"]\nenabled = true\n",
+        )
+        .expect("write config");
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(root)
+            .arg("check")
+            .arg("--report-out")
+            .arg(root.join("report.json"))
+            .assert()
+            .success()
+            .stderr(predicate::str::contains(
+                "warning: unknown check id 'deps.no_wildcard'",
+            ));
+    }
+
+    #[test]
+    fn check_fails_on_unknown_check_ids_when_strict() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .expect("write manifest");
+        std::fs::write(
+            root.join("depguard.toml"),
+            "strict_unknown_checks = true\n[checks.\"deps.no_wildcard\"]\nenabled = true\n",
+        )
+        .expect("write config");
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(root)
+            .arg("check")
+            .arg("--report-out")
+            .arg(root.join("report.json"))
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "unknown check ID: 'deps.no_wildcard'",
+            ));
+    }
+
     #[test]
     fn check_with_output_format_json() {
         let fixture_path = fixtures_dir().join("clean");
@@ -198,6 +255,141 @@ mod check_command {
         }
     }
 
+    #[test]
+    fn check_prints_terminal_summary_unless_report_out_is_set() {
+        let fixture_path = fixtures_dir().join("wildcards");
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let output = depguard_cmd()
+            .current_dir(temp_dir.path())
+            .arg("--repo-root")
+            .arg(&fixture_path)
+            .arg("check")
+            .output()
+            .expect("Failed to execute command");
+        assert_eq!(output.status.code(), Some(2));
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.starts_with("depguard: FAIL ("), "stdout: {stdout}");
+        assert!(stdout.contains("Cargo.toml:"), "stdout: {stdout}");
+        assert!(
+            !stdout.contains('\x1b'),
+            "non-TTY output must not be colored"
+        );
+        assert!(
+            temp_dir
+                .path()
+                .join("artifacts/depguard/report.json")
+                .exists()
+        );
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(&fixture_path)
+            .arg("check")
+            .arg("--report-out")
+            .arg(temp_dir.path().join("report.json"))
+            .assert()
+            .code(2)
+            .stdout("");
+    }
+
+    #[test]
+    fn check_reads_changed_files_from_stdin() {
+        let fixture_path = fixtures_dir().join("wildcards");
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let report_path = temp_dir.path().join("report.json");
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(&fixture_path)
+            .arg("--scope")
+            .arg("diff")
+            .arg("check")
+            .arg("--changed-files")
+            .arg("-")
+            .arg("--report-out")
+            .arg(&report_path)
+            .write_stdin("Cargo.toml\n")
+            .assert()
+            .code(2);
+
+        let report: Value =
+            serde_json::from_str(&std::fs::read_to_string(&report_path).expect("read report"))
+                .expect("parse report");
+        assert_eq!(report["data"]["scope"], "diff");
+    }
+
+    /// Changed files passed in explicitly are the sandboxed-CI path: nothing,
+    /// including run provenance, may shell out to git.
+    #[cfg(unix)]
+    #[test]
+    fn check_with_changed_files_never_runs_git() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let fixture_path = fixtures_dir().join("wildcards");
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let report_path = temp_dir.path().join("report.json");
+        let bin_dir = temp_dir.path().join("bin");
+        let marker = temp_dir.path().join("git-was-called");
+        std::fs::create_dir(&bin_dir).expect("create bin dir");
+        let fake_git = bin_dir.join("git");
+        std::fs::write(
+            &fake_git,
+            format!("#!/bin/sh\ntouch '{}'\nexit 1\n", marker.display()),
+        )
+        .expect("write fake git");
+        std::fs::set_permissions(&fake_git, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod fake git");
+        let path = std::env::join_paths(std::iter::once(bin_dir).chain(std::env::split_paths(
+            &std::env::var_os("PATH").unwrap_or_default(),
+        )))
+        .expect("join PATH");
+
+        depguard_cmd()
+            .env("PATH", path)
+            .arg("--repo-root")
+            .arg(&fixture_path)
+            .arg("--scope")
+            .arg("diff")
+            .arg("check")
+            .arg("--report-version")
+            .arg("v2")
+            .arg("--changed-files")
+            .arg("-")
+            .arg("--report-out")
+            .arg(&report_path)
+            .write_stdin("Cargo.toml\n")
+            .assert()
+            .code(2);
+
+        assert!(report_path.exists());
+        assert!(!marker.exists(), "depguard spawned git");
+    }
+
+    #[test]
+    fn check_rejects_changed_files_outside_repo() {
+        let fixture_path = fixtures_dir().join("wildcards");
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let report_path = temp_dir.path().join("report.json");
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(&fixture_path)
+            .arg("--scope")
+            .arg("diff")
+            .arg("check")
+            .arg("--changed-files")
+            .arg("-")
+            .arg("--report-out")
+            .arg(&report_path)
+            .write_stdin("../clean/Cargo.toml\n")
+            .assert()
+            .code(1)
+            .stderr(predicate::str::contains(
+                "must be relative to the repo root",
+            ));
+    }
+
     #[test]
     fn check_with_report_version_v1() {
         let fixture_path = fixtures_dir().join("clean");
@@ -213,7 +405,10 @@ mod check_command {
             .arg("--report-out")
             .arg(&report_path)
             .assert()
-            .success();
+            .success()
+            .stderr(predicate::str::contains(
+                "report version v1 is older than the latest",
+            ));
 
         let report_content = std::fs::read_to_string(&report_path).expect("Failed to read report");
         let report: Value =
@@ -241,7 +436,8 @@ mod check_command {
             .arg("--report-out")
             .arg(&report_path)
             .assert()
-            .success();
+            .success()
+            .stderr(predicate::str::contains("older than the latest").not());
 
         let report_content = std::fs::read_to_string(&report_path).expect("Failed to read report");
         let report: Value =
@@ -254,6 +450,30 @@ mod check_command {
         );
     }
 
+    #[test]
+    fn check_with_report_version_latest() {
+        let fixture_path = fixtures_dir().join("clean");
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let report_path = temp_dir.path().join("report.json");
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(&fixture_path)
+            .arg("check")
+            .arg("--report-version")
+            .arg("latest")
+            .arg("--report-out")
+            .arg(&report_path)
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("older than the latest").not());
+
+        let report_content = std::fs::read_to_string(&report_path).expect("Failed to read report");
+        let report: Value =
+            serde_json::from_str(&report_content).expect("Failed to parse report JSON");
+        assert_eq!(report["schema"].as_str(), Some("depguard.report.v2"));
+    }
+
     #[test]
     fn check_with_mode_standard() {
         let fixture_path = fixtures_dir().join("wildcards");
@@ -660,6 +880,38 @@ mod render_commands {
         );
     }
 
+    #[test]
+    fn md_command_fail_on_exits_with_report_verdict() {
+        let (_temp_dir, report_path) = create_wildcards_report();
+
+        depguard_cmd()
+            .arg("md")
+            .arg("--report")
+            .arg(&report_path)
+            .assert()
+            .success();
+
+        depguard_cmd()
+            .arg("md")
+            .arg("--report")
+            .arg(&report_path)
+            .arg("--fail-on")
+            .arg("fail")
+            .assert()
+            .code(2)
+            .stdout(predicate::str::contains("wildcard"));
+
+        depguard_cmd()
+            .arg("annotations")
+            .arg("--report")
+            .arg(&report_path)
+            .arg("--fail-on")
+            .arg("warn")
+            .assert()
+            .code(2)
+            .stdout(predicate::str::contains("::error"));
+    }
+
     #[test]
     fn md_command_with_output_file() {
         let (_temp_dir, report_path) = create_wildcards_report();
@@ -973,6 +1225,121 @@ mod render_commands {
         );
     }
 
+    // CHECKSTYLE COMMAND TESTS
+
+    #[test]
+    fn checkstyle_command_groups_findings_by_file() {
+        let (_temp_dir, report_path) = create_wildcards_report();
+        let temp_dir2 = TempDir::new().expect("Failed to create temp dir");
+        let xml_path = temp_dir2.path().join("report.checkstyle.xml");
+
+        depguard_cmd()
+            .arg("report")
+            .arg("checkstyle")
+            .arg("--report")
+            .arg(&report_path)
+            .arg("--output")
+            .arg(&xml_path)
+            .assert()
+            .success();
+
+        let xml = std::fs::read_to_string(&xml_path).expect("read checkstyle output");
+        assert!(xml.contains("<checkstyle"));
+        assert_eq!(xml.matches("<file name=\"Cargo.toml\">").count(), 1);
+        assert!(xml.contains("source=\"deps.no_wildcards\""));
+    }
+
+    // HTML COMMAND TESTS
+
+    #[test]
+    fn html_command_writes_standalone_page() {
+        let (_temp_dir, report_path) = create_wildcards_report();
+        let temp_dir2 = TempDir::new().expect("Failed to create temp dir");
+        let html_path = temp_dir2.path().join("report.html");
+
+        depguard_cmd()
+            .arg("report")
+            .arg("html")
+            .arg("--report")
+            .arg(&report_path)
+            .arg("--output")
+            .arg(&html_path)
+            .assert()
+            .success();
+
+        let html = std::fs::read_to_string(&html_path).expect("read html output");
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("Verdict: FAIL"));
+        assert!(html.contains("deps.no_wildcards"));
+        assert!(!html.contains("<script"));
+    }
+
+    // SUMMARY COMMAND TESTS
+
+    #[test]
+    fn summary_command_renders_findings_table() {
+        let (_temp_dir, report_path) = create_wildcards_report();
+
+        let output = depguard_cmd()
+            .arg("summary")
+            .arg("--report")
+            .arg(&report_path)
+            .output()
+            .expect("Failed to run summary command");
+
+        assert!(output.status.success(), "summary command should succeed");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.starts_with("## depguard: ❌ FAIL"));
+        assert!(stdout.contains("| error | `Cargo.toml:9` | `deps.no_wildcards` |"));
+        assert!(stdout.contains("1 of 1 findings shown"));
+    }
+
+    // DIFF COMMAND TESTS
+
+    #[test]
+    fn diff_command_reports_new_findings_and_exits_2() {
+        let (temp_dir, head_path) = create_wildcards_report();
+        let base_path = temp_dir.path().join("base.json");
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(fixtures_dir().join("clean"))
+            .arg("check")
+            .arg("--report-out")
+            .arg(&base_path)
+            .assert()
+            .success();
+
+        let output = depguard_cmd()
+            .arg("diff")
+            .arg("--base")
+            .arg(&base_path)
+            .arg("--head")
+            .arg(&head_path)
+            .arg("--format")
+            .arg("markdown")
+            .output()
+            .expect("Failed to run diff command");
+
+        assert_eq!(output.status.code(), Some(2), "new findings should exit 2");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.starts_with("# Depguard diff"));
+        assert!(stdout.contains("- New findings: **1**"));
+        assert!(stdout.contains("`deps.no_wildcards` / `wildcard_version`"));
+
+        // Swapping the reports only resolves findings, which passes.
+        let output = depguard_cmd()
+            .arg("diff")
+            .arg("--base")
+            .arg(&head_path)
+            .arg("--head")
+            .arg(&base_path)
+            .output()
+            .expect("Failed to run diff command");
+        assert!(output.status.success(), "resolved findings should pass");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.starts_with("depguard diff: 0 new, 1 resolved, 0 unchanged"));
+    }
+
     // JSONL COMMAND TESTS
 
     #[test]
@@ -1383,6 +1750,416 @@ mod explain_command {
             );
         }
     }
+
+    #[test]
+    fn explain_json_serializes_explanation() {
+        let output = depguard_cmd()
+            .args(["explain", "--json", "deps.no_wildcards"])
+            .output()
+            .expect("Failed to run explain command");
+
+        assert!(output.status.success(), "explain --json should succeed");
+        let value: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+        assert_eq!(value["schema"], "depguard.explain.v1");
+        assert_eq!(value["entry"]["id"], "deps.no_wildcards");
+        assert_eq!(value["entry"]["kind"], "check");
+        for key in ["title", "description", "remediation"] {
+            assert!(value["entry"][key].is_string(), "missing {key}");
+        }
+        assert!(value["entry"]["examples"]["before"].is_string());
+        assert!(value["entry"]["examples"]["after"].is_string());
+    }
+
+    #[test]
+    fn explain_all_json_lists_every_check_and_code() {
+        let output = depguard_cmd()
+            .args(["explain", "--all", "--json"])
+            .output()
+            .expect("Failed to run explain command");
+
+        assert!(
+            output.status.success(),
+            "explain --all --json should succeed"
+        );
+        let value: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+        let ids = |key: &str| -> Vec<String> {
+            value[key]
+                .as_array()
+                .expect("array")
+                .iter()
+                .map(|e| e["id"].as_str().expect("id").to_string())
+                .collect()
+        };
+        let check_ids = ids("check_ids");
+        for check_id in check_catalog::all_check_ids() {
+            assert!(check_ids.contains(&check_id.to_string()), "{check_id}");
+        }
+        let codes = ids("codes");
+        for code in check_catalog::all_codes() {
+            assert!(codes.contains(&code.to_string()), "{code}");
+        }
+    }
+
+    #[test]
+    fn explain_near_miss_suggests_closest_identifier() {
+        depguard_cmd()
+            .args(["explain", "no_wildcard"])
+            .assert()
+            .code(1)
+            .stderr(predicate::str::contains(
+                "Did you mean 'deps.no_wildcards'?",
+            ));
+    }
+
+    #[test]
+    fn explain_all_requires_json() {
+        depguard_cmd().args(["explain", "--all"]).assert().failure();
+    }
+
+    #[test]
+    fn explain_json_unknown_identifier_prints_error_object() {
+        let output = depguard_cmd()
+            .args(["explain", "--json", "nonexistent.check"])
+            .output()
+            .expect("Failed to run explain command");
+
+        assert!(!output.status.success());
+        assert!(
+            output.stderr.is_empty(),
+            "errors go to stdout in --json mode"
+        );
+        let value: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+        assert_eq!(value["error"]["code"], "unknown_identifier");
+        assert_eq!(value["error"]["identifier"], "nonexistent.check");
+        assert!(value["error"]["available_check_ids"].is_array());
+    }
+}
+
+// =============================================================================
+// LIST-CHECKS COMMAND TESTS
+// =============================================================================
+
+mod list_checks_command {
+    use super::*;
+    use depguard_check_catalog as check_catalog;
+
+    fn list_checks_json(args: &[&str]) -> serde_json::Value {
+        let output = depguard_cmd()
+            .args(args)
+            .output()
+            .expect("Failed to run list-checks command");
+        assert!(output.status.success(), "list-checks should succeed");
+        serde_json::from_slice(&output.stdout).expect("stdout should be JSON")
+    }
+
+    #[test]
+    fn list_checks_prints_every_catalog_check() {
+        let output = depguard_cmd()
+            .arg("list-checks")
+            .output()
+            .expect("Failed to run list-checks command");
+
+        assert!(output.status.success(), "list-checks should succeed");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.starts_with("Profile: strict"));
+        for check_id in check_catalog::all_check_ids() {
+            assert!(stdout.contains(check_id), "missing {check_id}");
+        }
+    }
+
+    #[test]
+    fn list_checks_json_follows_profile() {
+        let strict = list_checks_json(&["list-checks", "--json"]);
+        let warn = list_checks_json(&["list-checks", "--profile", "warn", "--json"]);
+        let global = list_checks_json(&["--profile", "warn", "list-checks", "--json"]);
+
+        assert_eq!(strict["profile"], "strict");
+        assert_eq!(warn["profile"], "warn");
+        assert_eq!(warn, global, "global --profile is honored");
+
+        let severity = |value: &serde_json::Value, id: &str| {
+            value["checks"]
+                .as_array()
+                .expect("checks array")
+                .iter()
+                .find(|c| c["id"] == id)
+                .map(|c| c["severity"].clone())
+                .expect("check listed")
+        };
+        assert_eq!(severity(&strict, "deps.no_wildcards"), "error");
+        assert_eq!(severity(&warn, "deps.no_wildcards"), "warning");
+    }
+
+    #[test]
+    fn list_checks_unknown_profile_fails() {
+        depguard_cmd()
+            .args(["list-checks", "--profile", "bogus"])
+            .assert()
+            .failure();
+    }
+}
+
+// =============================================================================
+// CHECK --CONFIG-DUMP TESTS
+// =============================================================================
+
+mod config_dump {
+    use super::*;
+
+    #[test]
+    fn config_dump_prints_resolved_config_with_cli_overrides() {
+        let tmp = TempDir::new().expect("temp dir");
+        std::fs::write(
+            tmp.path().join("depguard.toml"),
+            "profile = \"warn\"\n[checks.\"deps.no_wildcards\"]\nallow = [\"vendor-*\"]\n",
+        )
+        .expect("write config");
+
+        let output = depguard_cmd()
+            .arg("--repo-root")
+            .arg(tmp.path())
+            .args(["--profile", "strict", "--max-findings", "7"])
+            .args(["check", "--config-dump"])
+            .output()
+            .expect("Failed to run check --config-dump");
+
+        assert!(output.status.success());
+        let dump: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("config dump is JSON");
+        assert_eq!(dump["profile"], "strict");
+        assert_eq!(dump["max_findings"], 7);
+        assert_eq!(dump["scope"], "repo");
+        let wildcards = &dump["checks"]["deps.no_wildcards"];
+        assert_eq!(wildcards["enabled"], true);
+        assert_eq!(wildcards["severity"], "error");
+        assert_eq!(wildcards["allow"], serde_json::json!(["vendor-*"]));
+        assert!(
+            !tmp.path().join("artifacts").exists(),
+            "config dump must not run checks or write artifacts"
+        );
+    }
+
+    #[test]
+    fn config_dump_follows_extends_chain() {
+        let tmp = TempDir::new().expect("temp dir");
+        let repo = tmp.path().join("services/api");
+        std::fs::create_dir_all(&repo).expect("mkdir");
+        std::fs::write(
+            tmp.path().join("depguard.base.toml"),
+            "profile = \"warn\"\n[checks.\"deps.no_wildcards\"]\nallow = [\"org-*\"]\n",
+        )
+        .expect("write base config");
+        std::fs::write(
+            repo.join("depguard.toml"),
+            "extends = \"../../depguard.base.toml\"\n[checks.\"deps.no_wildcards\"]\nallow = [\"api-*\"]\n",
+        )
+        .expect("write config");
+
+        let output = depguard_cmd()
+            .arg("--repo-root")
+            .arg(&repo)
+            .args(["check", "--config-dump"])
+            .output()
+            .expect("Failed to run check --config-dump");
+
+        assert!(output.status.success());
+        let dump: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("config dump is JSON");
+        assert_eq!(dump["profile"], "warn");
+        assert_eq!(
+            dump["checks"]["deps.no_wildcards"]["allow"],
+            serde_json::json!(["org-*", "api-*"])
+        );
+    }
+
+    #[test]
+    fn config_glob_merges_files_in_sorted_order() {
+        let tmp = TempDir::new().expect("temp dir");
+        let dir = tmp.path().join(".depguard");
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        std::fs::write(
+            dir.join("10-repo.toml"),
+            "[checks.\"deps.no_wildcards\"]\nseverity = \"warning\"\nallow = [\"repo-*\"]\n",
+        )
+        .expect("write repo config");
+        std::fs::write(
+            dir.join("00-base.toml"),
+            "profile = \"warn\"\n[checks.\"deps.no_wildcards\"]\nseverity = \"error\"\nallow = [\"org-*\"]\n",
+        )
+        .expect("write base config");
+
+        let output = depguard_cmd()
+            .arg("--verbose")
+            .arg("--repo-root")
+            .arg(tmp.path())
+            .args(["--config", ".depguard/*.toml"])
+            .args(["check", "--config-dump"])
+            .output()
+            .expect("Failed to run check --config-dump");
+
+        assert!(output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("00-base.toml"), "stderr: {stderr}");
+        assert!(stderr.contains("10-repo.toml"), "stderr: {stderr}");
+        let dump: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("config dump is JSON");
+        assert_eq!(dump["profile"], "warn");
+        let wildcards = &dump["checks"]["deps.no_wildcards"];
+        assert_eq!(wildcards["severity"], "warning");
+        assert_eq!(wildcards["allow"], serde_json::json!(["org-*", "repo-*"]));
+    }
+
+    #[test]
+    fn config_glob_parse_error_names_the_file() {
+        let tmp = TempDir::new().expect("temp dir");
+        let dir = tmp.path().join(".depguard");
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        std::fs::write(dir.join("00-base.toml"), "profile = \"warn\"\n").expect("write base");
+        std::fs::write(dir.join("10-repo.toml"), "profile = [\n").expect("write broken");
+
+        let output = depguard_cmd()
+            .arg("--repo-root")
+            .arg(tmp.path())
+            .args(["--config", ".depguard/*.toml"])
+            .args(["check", "--config-dump"])
+            .output()
+            .expect("Failed to run check --config-dump");
+
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("10-repo.toml"), "stderr: {stderr}");
+    }
+}
+
+// =============================================================================
+// INVENTORY COMMAND TESTS
+// =============================================================================
+
+mod inventory_command {
+    use super::*;
+
+    #[test]
+    fn inventory_writes_sorted_entries_without_running_checks() {
+        let tmp = TempDir::new().expect("temp dir");
+        std::fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"*\"\nanyhow = \"1\"\n",
+        )
+        .expect("write manifest");
+        let out = tmp.path().join("deps.json");
+
+        depguard_cmd()
+            .arg("--repo-root")
+            .arg(tmp.path())
+            .arg("inventory")
+            .arg("--out")
+            .arg(&out)
+            .assert()
+            .success();
+
+        let text = std::fs::read_to_string(&out).expect("read inventory");
+        let entries: serde_json::Value = serde_json::from_str(&text).expect("inventory is JSON");
+        let names: Vec<&str> = entries
+            .as_array()
+            .expect("array")
+            .iter()
+            .map(|e| e["name"].as_str().expect("name"))
+            .collect();
+        assert_eq!(names, vec!["anyhow", "serde"]);
+        assert_eq!(entries[1]["version_req"], "*");
+        assert_eq!(entries[1]["source"], "registry");
+        assert!(
+            !tmp.path().join("artifacts").exists(),
+            "inventory must not run checks or write a report"
+        );
+    }
+}
+
+// =============================================================================
+// VALIDATE-CONFIG COMMAND TESTS
+// =============================================================================
+
+mod validate_config_command {
+    use super::*;
+
+    #[test]
+    fn validate_config_accepts_valid_config() {
+        let tmp = TempDir::new().expect("temp dir");
+        std::fs::write(
+            tmp.path().join("depguard.toml"),
+            "profile = \"warn\"\n[checks.\"deps.no_wildcards\"]\nallow = [\"vendor-*\"]\n",
+        )
+        .expect("write config");
+
+        depguard_cmd()
+            .current_dir(tmp.path())
+            .args(["validate-config", "--config", "depguard.toml"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains("is valid"));
+    }
+
+    #[test]
+    fn validate_config_reports_all_errors_and_warns_on_unknown_checks() {
+        let tmp = TempDir::new().expect("temp dir");
+        std::fs::write(
+            tmp.path().join("depguard.toml"),
+            "scope = \"everywhere\"\nfail_on = \"never\"\n[checks.\"deps.no_wildcard\"]\nenabled = true\n",
+        )
+        .expect("write config");
+
+        let output = depguard_cmd()
+            .args(["--repo-root"])
+            .arg(tmp.path())
+            .arg("validate-config")
+            .output()
+            .expect("Failed to run validate-config");
+
+        assert_eq!(output.status.code(), Some(1));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("unknown scope: 'everywhere'"), "{stderr}");
+        assert!(stderr.contains("unknown fail_on: 'never'"), "{stderr}");
+        assert!(
+            stderr.contains("warning:") && stderr.contains("'deps.no_wildcard'"),
+            "{stderr}"
+        );
+        assert!(stderr.contains("2 error(s)"), "{stderr}");
+    }
+
+    #[test]
+    fn validate_config_unknown_check_alone_is_not_fatal() {
+        let tmp = TempDir::new().expect("temp dir");
+        std::fs::write(
+            tmp.path().join("depguard.toml"),
+            "[checks.\"deps.no_wildcard\"]\nenabled = true\n",
+        )
+        .expect("write config");
+
+        depguard_cmd()
+            .current_dir(tmp.path())
+            .arg("validate-config")
+            .assert()
+            .success()
+            .stderr(predicates::str::contains(
+                "unknown check id 'deps.no_wildcard'",
+            ));
+    }
+
+    #[test]
+    fn validate_config_rejects_malformed_toml() {
+        let tmp = TempDir::new().expect("temp dir");
+        std::fs::write(tmp.path().join("depguard.toml"), "profile = [").expect("write config");
+
+        depguard_cmd()
+            .current_dir(tmp.path())
+            .arg("validate-config")
+            .assert()
+            .code(1)
+            .stderr(predicates::str::contains("invalid TOML"));
+    }
 }
 
 // =============================================================================
@@ -1864,3 +2641,95 @@ mod version_and_help {
             .stdout(predicate::str::contains("check_id").or(predicate::str::contains("code")));
     }
 }
+
+// =============================================================================
+// VERBOSITY TESTS
+// =============================================================================
+
+mod verbosity {
+    use super::*;
+
+    const EMPTY_NOTICE: &str = "no Cargo.toml found";
+
+    fn check_empty_repo(args: &[&str], log_env: Option<&str>) -> String {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let report_path = temp_dir.path().join("report.json");
+
+        let mut cmd = depguard_cmd();
+        cmd.env_remove("DEPGUARD_LOG");
+        if let Some(value) = log_env {
+            cmd.env("DEPGUARD_LOG", value);
+        }
+        let output = cmd
+            .args(args)
+            .arg("--repo-root")
+            .arg(temp_dir.path())
+            .arg("check")
+            .arg("--report-out")
+            .arg(&report_path)
+            .output()
+            .expect("Failed to run check");
+
+        assert!(output.status.success(), "empty repo check should pass");
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    }
+
+    #[test]
+    fn quiet_suppresses_empty_report_notice() {
+        assert!(check_empty_repo(&[], None).contains(EMPTY_NOTICE));
+        assert!(!check_empty_repo(&["--quiet"], None).contains(EMPTY_NOTICE));
+        assert!(!check_empty_repo(&[], Some("quiet")).contains(EMPTY_NOTICE));
+    }
+
+    #[test]
+    fn quiet_flag_overrides_verbose_env() {
+        let stderr = check_empty_repo(&["-q"], Some("verbose"));
+        assert!(!stderr.contains(EMPTY_NOTICE));
+    }
+
+    #[test]
+    fn verbose_adds_config_path_and_scan_diagnostics() {
+        let fixture_path = fixtures_dir().join("build_script_consistency");
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let report_path = temp_dir.path().join("report.json");
+
+        let run = |verbose: bool| {
+            let mut cmd = depguard_cmd();
+            cmd.env_remove("DEPGUARD_LOG");
+            if verbose {
+                cmd.arg("-v");
+            }
+            let output = cmd
+                .arg("--repo-root")
+                .arg(&fixture_path)
+                .arg("check")
+                .arg("--report-out")
+                .arg(&report_path)
+                .output()
+                .expect("Failed to run check");
+            String::from_utf8_lossy(&output.stderr).into_owned()
+        };
+
+        let stderr = run(true);
+        assert!(
+            stderr.contains("depguard: using config ") && stderr.contains("depguard.toml"),
+            "verbose should name the config file: {stderr}"
+        );
+        assert!(stderr.contains("depguard: scope repo"));
+        assert!(stderr.contains("depguard: scanned 1 manifests"));
+
+        let stderr = run(false);
+        assert!(!stderr.contains("using config"), "stderr: {stderr}");
+    }
+
+    #[test]
+    fn quiet_and_verbose_conflict() {
+        depguard_cmd()
+            .arg("--quiet")
+            .arg("--verbose")
+            .arg("list-checks")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("cannot be used with"));
+    }
+}
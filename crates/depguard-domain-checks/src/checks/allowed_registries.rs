@@ -0,0 +1,95 @@
+use crate::checks::utils::{
+    build_allowlist, build_matchers, first_match, is_allowed, section_name, spec_to_json,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepSpec, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+/// Registry name Cargo uses for crates.io.
+const CRATES_IO: &str = "crates-io";
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_ALLOWED_REGISTRIES) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+    let deny = build_matchers(&policy.deny);
+    let crates_io_allowed = first_match(&deny, CRATES_IO).is_none();
+
+    let mut allowed_registries: Vec<&str> = policy.allow.iter().map(String::as_str).collect();
+    if crates_io_allowed {
+        allowed_registries.push(CRATES_IO);
+    }
+    allowed_registries.sort_unstable();
+    allowed_registries.dedup();
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let Some(registry) = registry_of(&dep.spec) else {
+                continue;
+            };
+            let permitted = first_match(&deny, registry).is_none()
+                && ((registry == CRATES_IO && crates_io_allowed)
+                    || is_allowed(allow.as_ref(), registry));
+            if permitted {
+                continue;
+            }
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_ALLOWED_REGISTRIES,
+                ids::CODE_DISALLOWED_REGISTRY,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(registry),
+            );
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_ALLOWED_REGISTRIES.to_string(),
+                code: ids::CODE_DISALLOWED_REGISTRY.to_string(),
+                message: format!(
+                    "dependency '{}' uses registry '{}', which is not allowed",
+                    dep.name, registry
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Source the dependency from an allowed registry, or add the registry to the allowlist."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "allowed_registries": allowed_registries,
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_USE_ALLOWED_REGISTRY,
+                        "fix_hint": "Switch to an allowed registry",
+                        "manifest": manifest.path.as_str(),
+                        "registry": registry,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
+
+/// Returns the registry a dependency resolves from, or `None` when it is
+/// not registry-sourced (path-only, git, or workspace-inherited).
+fn registry_of(spec: &DepSpec) -> Option<&str> {
+    if spec.workspace || spec.git.is_some() {
+        return None;
+    }
+    if let Some(r) = spec.registry.as_deref() {
+        return Some(r);
+    }
+    if spec.path.is_some() && spec.version.is_none() {
+        return None;
+    }
+    Some(CRATES_IO)
+}
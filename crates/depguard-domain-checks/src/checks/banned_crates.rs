@@ -0,0 +1,81 @@
+use crate::checks::utils::{
+    build_allowlist, build_matchers, first_match, is_allowed, section_name, spec_to_json,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_BANNED_CRATES) else {
+        return;
+    };
+    let deny = build_matchers(&policy.deny);
+    if deny.is_empty() {
+        return;
+    }
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // Match the crate actually fetched so `package = "..."` renames cannot hide it.
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            let Some(pattern) =
+                first_match(&deny, crate_name).or_else(|| first_match(&deny, &dep.name))
+            else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_BANNED_CRATES,
+                ids::CODE_BANNED_CRATE,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_BANNED_CRATES.to_string(),
+                code: ids::CODE_BANNED_CRATE.to_string(),
+                message: if crate_name == dep.name {
+                    format!(
+                        "dependency '{}' is banned by policy (matched '{}')",
+                        dep.name, pattern
+                    )
+                } else {
+                    format!(
+                        "dependency '{}' (crate '{}') is banned by policy (matched '{}')",
+                        dep.name, crate_name, pattern
+                    )
+                },
+                location: dep.location.clone(),
+                help: Some(
+                    "Remove the dependency or replace it with an approved alternative.".to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REPLACE_BANNED_CRATE,
+                        "fix_hint": "Remove or replace the banned crate",
+                        "manifest": manifest.path.as_str(),
+                        "matched_pattern": pattern,
+                        "section": section_name(dep.kind),
+                    });
+                    if dep.spec.rename_of.is_some() {
+                        d["crate"] = json!(crate_name);
+                    }
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
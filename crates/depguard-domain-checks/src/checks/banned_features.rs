@@ -0,0 +1,115 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::{BannedFeaturesOptions, EffectiveConfig};
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_BANNED_FEATURES) else {
+        return;
+    };
+    // Malformed options are rejected when the config is resolved.
+    let Ok(options) = policy.options_as::<BannedFeaturesOptions>() else {
+        return;
+    };
+    if options.features.is_empty() {
+        return;
+    }
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // Match the crate actually fetched so `package = "..."` renames are still caught.
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            let Some(banned) = options.features.get(crate_name) else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            // Inherited entries contribute their own features and default-features.
+            let ws = dep
+                .spec
+                .workspace
+                .then(|| model.workspace_dependencies.get(&dep.name))
+                .flatten();
+            let mut enabled: Vec<&str> = dep
+                .spec
+                .features
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            if let Some(ws) = ws {
+                enabled.extend(ws.features.iter().map(String::as_str));
+            }
+            let default_features = ws
+                .and_then(|ws| ws.default_features)
+                .or(dep.spec.default_features);
+
+            for feature in &banned.features {
+                let explicit = enabled.contains(&feature.as_str());
+                let implicit_default =
+                    !explicit && feature == "default" && default_features != Some(false);
+                if !explicit && !implicit_default {
+                    continue;
+                }
+
+                let fingerprint = fingerprint_for_dep(
+                    ids::CHECK_DEPS_BANNED_FEATURES,
+                    ids::CODE_BANNED_FEATURE_ENABLED,
+                    manifest.path.as_str(),
+                    &dep.name,
+                    Some(feature),
+                );
+                let fix_hint = if implicit_default {
+                    "Set default-features = false and enable only the features you need".to_string()
+                } else {
+                    format!("Remove '{feature}' from the features list")
+                };
+                let mut message = format!(
+                    "dependency '{}' enables banned feature '{}' of crate '{}'",
+                    dep.name, feature, crate_name
+                );
+                if implicit_default {
+                    message.push_str(" (default-features is not disabled)");
+                }
+                if let Some(reason) = &banned.reason {
+                    message.push_str(&format!(": {reason}"));
+                }
+
+                out.push(Finding {
+                    severity: policy.severity,
+                    check_id: ids::CHECK_DEPS_BANNED_FEATURES.to_string(),
+                    code: ids::CODE_BANNED_FEATURE_ENABLED.to_string(),
+                    message,
+                    location: dep.location.clone(),
+                    help: Some(format!("{fix_hint}.")),
+                    url: None,
+                    fingerprint: Some(fingerprint),
+                    data: {
+                        let mut d = json!({
+                            "crate": crate_name,
+                            "current_spec": spec_to_json(&dep.spec),
+                            "dependency": dep.name,
+                            "feature": feature,
+                            "fix_action": ids::FIX_ACTION_REMOVE_BANNED_FEATURE,
+                            "fix_hint": fix_hint,
+                            "implicit": implicit_default,
+                            "inherited": ws.is_some(),
+                            "manifest": manifest.path.as_str(),
+                            "reason": banned.reason.as_deref().unwrap_or("banned by policy"),
+                            "section": section_name(dep.kind),
+                        });
+                        if let Some(ref t) = dep.target {
+                            d["target"] = json!(t);
+                        }
+                        d
+                    },
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{BuildSetting, DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+        // Without `build`, Cargo auto-detects build.rs on disk; nothing to compare.
+        let Some(build) = pkg.build.as_ref() else {
+            continue;
+        };
+        if is_allowed(allow.as_ref(), &pkg.name) {
+            continue;
+        }
+        let build_deps: Vec<_> = manifest
+            .dependencies
+            .iter()
+            .filter(|dep| dep.kind == DepKind::Build)
+            .collect();
+
+        let (code, message, location, fix_hint) = match (build.runs_script(), build_deps.first()) {
+            (true, None) => (
+                ids::CODE_BUILD_SCRIPT_WITHOUT_DEPS,
+                format!(
+                    "package '{}' sets `build = {}` but declares no [build-dependencies]",
+                    pkg.name,
+                    build_value(build)
+                ),
+                pkg.location.clone(),
+                "Remove the build setting if the script is unused",
+            ),
+            (false, Some(first)) => (
+                ids::CODE_BUILD_DEPS_WITHOUT_SCRIPT,
+                format!(
+                    "package '{}' sets `build = false` but declares [build-dependencies]",
+                    pkg.name
+                ),
+                first.location.clone().or_else(|| pkg.location.clone()),
+                "Remove the [build-dependencies] or enable the build script",
+            ),
+            _ => continue,
+        };
+
+        let fingerprint = fingerprint_for_dep(
+            ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY,
+            code,
+            manifest.path.as_str(),
+            &pkg.name,
+            None,
+        );
+        let build_dependencies: Vec<&str> =
+            build_deps.iter().map(|dep| dep.name.as_str()).collect();
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY.to_string(),
+            code: code.to_string(),
+            message,
+            location: location.or_else(|| {
+                Some(Location {
+                    path: manifest.path.clone(),
+                    line: None,
+                    col: None,
+                })
+            }),
+            help: Some("Keep `package.build` and [build-dependencies] in agreement.".to_string()),
+            url: None,
+            fingerprint: Some(fingerprint),
+            data: json!({
+                "build": build,
+                "build_dependencies": build_dependencies,
+                "fix_action": ids::FIX_ACTION_ALIGN_BUILD_SCRIPT,
+                "fix_hint": fix_hint,
+                "manifest": manifest.path.as_str(),
+                "package": pkg.name,
+            }),
+        });
+    }
+}
+
+fn build_value(build: &BuildSetting) -> String {
+    match build {
+        BuildSetting::Enabled(enabled) => enabled.to_string(),
+        BuildSetting::Script(path) => format!("\"{path}\""),
+    }
+}
@@ -0,0 +1,157 @@
+use crate::checks::utils::{build_allowlist, is_allowed, parse_version};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{ManifestModel, PackageMeta, WorkspaceModel};
+use crate::policy::{CheckPolicy, EffectiveConfig, MsrvMode};
+use depguard_types::{Finding, Location, ids};
+use serde_json::{Value, json};
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_CONSISTENT_MSRV) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+    let packages = model
+        .manifests
+        .iter()
+        .filter_map(|m| m.package.as_ref().map(|pkg| (m, pkg)))
+        .filter(|(_, pkg)| !is_allowed(allow.as_ref(), &pkg.name));
+
+    match policy.msrv_mode {
+        MsrvMode::Consistent => {
+            let members: Vec<_> = packages
+                .filter_map(|(m, pkg)| pkg.rust_version.as_deref().map(|rv| (m, pkg, rv)))
+                .collect();
+            let Some(expected) = reference_msrv(&members) else {
+                return;
+            };
+            let listing: Vec<_> = members
+                .iter()
+                .map(|(m, pkg, rv)| {
+                    json!({
+                        "inherited": pkg.is_inherited("rust-version"),
+                        "manifest": m.path.as_str(),
+                        "package": pkg.name,
+                        "rust_version": rv,
+                    })
+                })
+                .collect();
+
+            for (manifest, pkg, current) in &members {
+                if parse_version(current) == parse_version(expected) {
+                    continue;
+                }
+                let mut finding = package_finding(
+                    policy,
+                    manifest,
+                    pkg,
+                    ids::CODE_INCONSISTENT_MSRV,
+                    format!(
+                        "package '{}' has rust-version {} but the workspace uses {}",
+                        pkg.name, current, expected
+                    ),
+                    ids::FIX_ACTION_ALIGN_RUST_VERSION,
+                    "Set rust-version to the workspace MSRV",
+                );
+                finding.data["current"] = json!(current);
+                finding.data["expected"] = json!(expected);
+                finding.data["members"] = Value::Array(listing.clone());
+                out.push(finding);
+            }
+        }
+        MsrvMode::RequireInherit => {
+            // A lone manifest has no `[workspace.package]` to inherit from.
+            if model.manifests.len() < 2 {
+                return;
+            }
+            for (manifest, pkg) in packages {
+                if pkg.is_inherited("rust-version") {
+                    continue;
+                }
+                let mut finding = package_finding(
+                    policy,
+                    manifest,
+                    pkg,
+                    ids::CODE_MSRV_NOT_INHERITED,
+                    format!(
+                        "package '{}' does not inherit rust-version from [workspace.package]",
+                        pkg.name
+                    ),
+                    ids::FIX_ACTION_INHERIT_RUST_VERSION,
+                    "Use rust-version.workspace = true",
+                );
+                if let Some(current) = pkg.rust_version.as_deref() {
+                    finding.data["current"] = json!(current);
+                }
+                out.push(finding);
+            }
+        }
+    }
+}
+
+/// The MSRV members are measured against, or `None` when they all agree.
+///
+/// Inherited members carry the `[workspace.package]` value, which wins; otherwise
+/// the most common declared value does, ties going to the first one seen.
+fn reference_msrv<'a>(members: &[(&ManifestModel, &PackageMeta, &'a str)]) -> Option<&'a str> {
+    let mut counts: Vec<([u64; 3], &str, usize)> = Vec::new();
+    for (_, _, rv) in members {
+        let key = parse_version(rv);
+        match counts.iter_mut().find(|(k, _, _)| *k == key) {
+            Some(entry) => entry.2 += 1,
+            None => counts.push((key, rv, 1)),
+        }
+    }
+    if counts.len() < 2 {
+        return None;
+    }
+    if let Some((_, _, rv)) = members
+        .iter()
+        .find(|(_, pkg, _)| pkg.is_inherited("rust-version"))
+    {
+        return Some(rv);
+    }
+    let max = counts.iter().map(|(_, _, n)| *n).max()?;
+    counts
+        .iter()
+        .find(|(_, _, n)| *n == max)
+        .map(|(_, rv, _)| *rv)
+}
+
+fn package_finding(
+    policy: &CheckPolicy,
+    manifest: &ManifestModel,
+    pkg: &PackageMeta,
+    code: &str,
+    message: String,
+    fix_action: &str,
+    fix_hint: &str,
+) -> Finding {
+    Finding {
+        severity: policy.severity,
+        check_id: ids::CHECK_DEPS_CONSISTENT_MSRV.to_string(),
+        code: code.to_string(),
+        message,
+        location: pkg.location.clone().or_else(|| {
+            Some(Location {
+                path: manifest.path.clone(),
+                line: None,
+                col: None,
+            })
+        }),
+        help: Some(format!("{fix_hint}.")),
+        url: None,
+        fingerprint: Some(fingerprint_for_dep(
+            ids::CHECK_DEPS_CONSISTENT_MSRV,
+            code,
+            manifest.path.as_str(),
+            &pkg.name,
+            None,
+        )),
+        data: json!({
+            "fix_action": fix_action,
+            "fix_hint": fix_hint,
+            "manifest": manifest.path.as_str(),
+            "package": pkg.name,
+        }),
+    }
+}
@@ -0,0 +1,130 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DependencyDecl, ManifestModel, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use semver::{Op, VersionReq};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// The semver-compatible line a requirement selects: `1.x`, `0.3.x`, `0.0.7`.
+type CompatLine = (u64, Option<u64>, Option<u64>);
+
+struct Declaration<'a> {
+    manifest: &'a ManifestModel,
+    dep: &'a DependencyDecl,
+    requirement: &'a str,
+    line: CompatLine,
+}
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_CONSISTENT_VERSIONS) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+    let ignore = build_allowlist(&policy.ignore);
+
+    let mut by_crate: BTreeMap<&str, Vec<Declaration<'_>>> = BTreeMap::new();
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // `{ workspace = true }` takes its requirement from `[workspace.dependencies]`.
+            let requirement = if dep.spec.version.is_none() && dep.spec.workspace {
+                model
+                    .workspace_dependencies
+                    .get(&dep.name)
+                    .and_then(|ws| ws.version.as_deref())
+            } else {
+                dep.spec.version.as_deref()
+            };
+            let Some(requirement) = requirement else {
+                continue;
+            };
+            let Some(line) = compat_line(requirement) else {
+                continue;
+            };
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            by_crate.entry(crate_name).or_default().push(Declaration {
+                manifest,
+                dep,
+                requirement,
+                line,
+            });
+        }
+    }
+
+    for (crate_name, decls) in &by_crate {
+        let first_line = decls[0].line;
+        let Some(divergent) = decls.iter().find(|d| d.line != first_line) else {
+            continue;
+        };
+        if is_allowed(allow.as_ref(), crate_name) || is_allowed(ignore.as_ref(), crate_name) {
+            continue;
+        }
+
+        let declarations: Vec<_> = decls
+            .iter()
+            .map(|d| {
+                let mut entry = json!({
+                    "manifest": d.manifest.path.as_str(),
+                    "requirement": d.requirement,
+                    "section": section_name(d.dep.kind),
+                });
+                if let Some(ref t) = d.dep.target {
+                    entry["target"] = json!(t);
+                }
+                entry
+            })
+            .collect();
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_CONSISTENT_VERSIONS.to_string(),
+            code: ids::CODE_DIVERGENT_VERSION_REQUIREMENTS.to_string(),
+            message: format!(
+                "crate '{}' is required as '{}' in {} but '{}' in {}",
+                crate_name,
+                divergent.requirement,
+                divergent.manifest.path.as_str(),
+                decls[0].requirement,
+                decls[0].manifest.path.as_str()
+            ),
+            location: divergent.dep.location.clone(),
+            help: Some(
+                "Align the requirement across manifests, e.g. via [workspace.dependencies], or add the crate to `ignore`."
+                    .to_string(),
+            ),
+            url: None,
+            fingerprint: Some(fingerprint_for_dep(
+                ids::CHECK_DEPS_CONSISTENT_VERSIONS,
+                ids::CODE_DIVERGENT_VERSION_REQUIREMENTS,
+                divergent.manifest.path.as_str(),
+                crate_name,
+                None,
+            )),
+            data: json!({
+                "crate": crate_name,
+                "declarations": declarations,
+                "fix_action": ids::FIX_ACTION_ALIGN_VERSION_REQUIREMENTS,
+                "fix_hint": "Align the requirement across manifests",
+                "manifest": divergent.manifest.path.as_str(),
+            }),
+        });
+    }
+}
+
+/// Compatible line of a requirement's first comparator.
+///
+/// Only caret, tilde and exact comparators select a single line; open ranges,
+/// wildcards and unparseable requirements are left out of the comparison.
+fn compat_line(requirement: &str) -> Option<CompatLine> {
+    let req = VersionReq::parse(requirement).ok()?;
+    let c = req.comparators.first()?;
+    if !matches!(c.op, Op::Caret | Op::Tilde | Op::Exact) {
+        return None;
+    }
+    Some(match (c.major, c.minor, c.patch) {
+        (0, Some(0), patch) => (0, Some(0), patch),
+        (0, minor, _) => (0, minor, None),
+        (major, _, _) => (major, None, None),
+    })
+}
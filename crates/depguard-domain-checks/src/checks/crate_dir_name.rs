@@ -0,0 +1,80 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_CRATE_DIR_NAME) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+        // A root package's directory is the checkout, whose name is arbitrary.
+        let Some((dir, expected)) = manifest_dir(manifest.path.as_str()) else {
+            continue;
+        };
+        if normalize(&pkg.name) == normalize(expected) {
+            continue;
+        }
+        if is_allowed(allow.as_ref(), &pkg.name) || is_allowed(allow.as_ref(), dir) {
+            continue;
+        }
+
+        let fingerprint = fingerprint_for_dep(
+            ids::CHECK_DEPS_CRATE_DIR_NAME,
+            ids::CODE_CRATE_NAME_DIR_MISMATCH,
+            manifest.path.as_str(),
+            &pkg.name,
+            None,
+        );
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_CRATE_DIR_NAME.to_string(),
+            code: ids::CODE_CRATE_NAME_DIR_MISMATCH.to_string(),
+            message: format!(
+                "package '{}' lives in directory '{}'; expected it to be named '{}'",
+                pkg.name, dir, expected
+            ),
+            location: pkg.location.clone().or_else(|| {
+                Some(Location {
+                    path: manifest.path.clone(),
+                    line: None,
+                    col: None,
+                })
+            }),
+            help: Some(
+                "Rename the package or its directory so the names match, or add it to `allow`."
+                    .to_string(),
+            ),
+            url: None,
+            fingerprint: Some(fingerprint),
+            data: json!({
+                "actual_name": pkg.name,
+                "directory": dir,
+                "expected_name": expected,
+                "fix_action": ids::FIX_ACTION_RENAME_CRATE_OR_DIRECTORY,
+                "fix_hint": format!("Rename the package to '{expected}' or move it to a matching directory"),
+                "manifest": manifest.path.as_str(),
+            }),
+        });
+    }
+}
+
+/// Directory holding the manifest and its last component, or `None` at the repo root.
+fn manifest_dir(path: &str) -> Option<(&str, &str)> {
+    let (dir, _) = path.rsplit_once('/')?;
+    let name = dir.rsplit('/').next().unwrap_or(dir);
+    Some((dir, name))
+}
+
+/// Cargo treats `-` and `_` in package names as equivalent.
+fn normalize(name: &str) -> String {
+    name.replace('_', "-")
+}
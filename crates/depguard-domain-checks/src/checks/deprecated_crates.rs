@@ -0,0 +1,78 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::{DeprecatedCratesOptions, EffectiveConfig};
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_DEPRECATED_CRATES) else {
+        return;
+    };
+    // Malformed options are rejected when the config is resolved.
+    let Ok(options) = policy.options_as::<DeprecatedCratesOptions>() else {
+        return;
+    };
+    if options.deprecated.is_empty() {
+        return;
+    }
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // Match the crate actually fetched so `package = "..."` renames are still caught.
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            let Some(replacement) = options.deprecated.get(crate_name) else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_DEPRECATED_CRATES,
+                ids::CODE_DEPRECATED_CRATE,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_DEPRECATED_CRATES.to_string(),
+                code: ids::CODE_DEPRECATED_CRATE.to_string(),
+                message: if crate_name == dep.name {
+                    format!(
+                        "dependency '{}' is deprecated; use '{}' instead",
+                        dep.name, replacement
+                    )
+                } else {
+                    format!(
+                        "dependency '{}' (crate '{}') is deprecated; use '{}' instead",
+                        dep.name, crate_name, replacement
+                    )
+                },
+                location: dep.location.clone(),
+                help: Some(format!("Replace '{crate_name}' with '{replacement}'.")),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REPLACE_DEPRECATED_CRATE,
+                        "fix_hint": format!("Migrate from '{crate_name}' to '{replacement}'"),
+                        "manifest": manifest.path.as_str(),
+                        "replacement": replacement,
+                        "section": section_name(dep.kind),
+                    });
+                    if dep.spec.rename_of.is_some() {
+                        d["crate"] = json!(crate_name);
+                    }
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+use std::collections::BTreeSet;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_DEV_DEP_LEAKAGE) else {
+        return;
+    };
+    // Source scan did not run; nothing to compare against.
+    let Some(references) = model.source_references.as_ref() else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        let Some(refs) = references.get(manifest.path.as_str()) else {
+            continue;
+        };
+
+        let normal: BTreeSet<&str> = manifest
+            .dependencies
+            .iter()
+            .filter(|d| d.kind == DepKind::Normal)
+            .map(|d| d.name.as_str())
+            .collect();
+        let mut seen = BTreeSet::new();
+
+        for dep in &manifest.dependencies {
+            if dep.kind != DepKind::Dev || normal.contains(dep.name.as_str()) {
+                continue;
+            }
+            // Target-specific tables can declare the same dev-dependency twice.
+            if !seen.insert(dep.name.as_str()) {
+                continue;
+            }
+            // Source code names the dependency key, with `-` mapped to `_`.
+            let ident = dep.name.replace('-', "_");
+            let Some(location) = refs.get(&ident) else {
+                continue;
+            };
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_DEV_DEP_LEAKAGE,
+                ids::CODE_DEV_DEP_USED_IN_SRC,
+                manifest.path.as_str(),
+                &dep.name,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_DEV_DEP_LEAKAGE.to_string(),
+                code: ids::CODE_DEV_DEP_USED_IN_SRC.to_string(),
+                message: format!(
+                    "dev-dependency '{}' is used in non-test source {}",
+                    dep.name,
+                    location.path.as_str()
+                ),
+                location: Some(location.clone()),
+                help: Some(
+                    "Move the crate to [dependencies], or gate the code using it behind #[cfg(test)]."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "crate": crate_name,
+                        "dependency": dep.name,
+                        "declared_at": dep.location.as_ref().and_then(|l| l.line),
+                        "fix_action": ids::FIX_ACTION_MOVE_TO_NORMAL_DEPS,
+                        "fix_hint": "Move the dependency from [dev-dependencies] to [dependencies]",
+                        "manifest": manifest.path.as_str(),
+                        "source_file": location.path.as_str(),
+                        "source_line": location.line,
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// Table order used for the `kinds` list and for picking the entry to keep.
+const KIND_ORDER: [DepKind; 3] = [DepKind::Normal, DepKind::Build, DepKind::Dev];
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        // Group by (target, name) so target-specific tables are compared only
+        // with their own target.
+        let mut kinds_by_dep: BTreeMap<(Option<&str>, &str), Vec<DepKind>> = BTreeMap::new();
+        for dep in &manifest.dependencies {
+            let kinds = kinds_by_dep
+                .entry((dep.target.as_deref(), dep.name.as_str()))
+                .or_default();
+            if !kinds.contains(&dep.kind) {
+                kinds.push(dep.kind);
+            }
+        }
+
+        for dep in &manifest.dependencies {
+            let kinds = &kinds_by_dep[&(dep.target.as_deref(), dep.name.as_str())];
+            if kinds.len() < 2 {
+                continue;
+            }
+            // Keep the entry in the first table by KIND_ORDER; flag the rest.
+            let kept = KIND_ORDER
+                .into_iter()
+                .find(|k| kinds.contains(k))
+                .expect("kinds is non-empty");
+            if dep.kind == kept {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let sections: Vec<&str> = KIND_ORDER
+                .into_iter()
+                .filter(|k| kinds.contains(k))
+                .map(section_name)
+                .collect();
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS,
+                ids::CODE_DUPLICATE_DEPENDENCY_KINDS,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(section_name(dep.kind)),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS.to_string(),
+                code: ids::CODE_DUPLICATE_DEPENDENCY_KINDS.to_string(),
+                message: format!(
+                    "dependency '{}' in [{}] is also declared in [{}]",
+                    dep.name,
+                    section_name(dep.kind),
+                    section_name(kept)
+                ),
+                location: dep.location.clone(),
+                help: Some(format!(
+                    "Remove the [{}] entry and keep a single declaration in [{}].",
+                    section_name(dep.kind),
+                    section_name(kept)
+                )),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REMOVE_DUPLICATE_DEP,
+                        "fix_hint": "Remove the redundant declaration",
+                        "kinds": sections,
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
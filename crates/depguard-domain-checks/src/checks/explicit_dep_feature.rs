@@ -0,0 +1,90 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+use std::collections::BTreeSet;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        // Any `dep:foo` reference suppresses the implicit `foo` feature entirely.
+        let explicit: BTreeSet<&str> = manifest
+            .features
+            .values()
+            .flatten()
+            .filter_map(|value| value.strip_prefix("dep:"))
+            .collect();
+
+        for dep in &manifest.dependencies {
+            if !dep.spec.optional || explicit.contains(dep.name.as_str()) {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            for (feature, values) in &manifest.features {
+                let Some(value) = values
+                    .iter()
+                    .find(|value| enables_implicitly(value, &dep.name))
+                else {
+                    continue;
+                };
+
+                let fingerprint = fingerprint_for_dep(
+                    ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE,
+                    ids::CODE_IMPLICIT_OPTIONAL_FEATURE,
+                    manifest.path.as_str(),
+                    &dep.name,
+                    Some(feature),
+                );
+
+                out.push(Finding {
+                    severity: policy.severity,
+                    check_id: ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE.to_string(),
+                    code: ids::CODE_IMPLICIT_OPTIONAL_FEATURE.to_string(),
+                    message: format!(
+                        "feature '{}' enables optional dependency '{}' via '{}' instead of 'dep:{}'",
+                        feature, dep.name, value, dep.name
+                    ),
+                    location: dep.location.clone(),
+                    help: Some(
+                        "Use `dep:<name>` so the optional dependency does not become a public feature."
+                            .to_string(),
+                    ),
+                    url: None,
+                    fingerprint: Some(fingerprint),
+                    data: {
+                        let mut d = json!({
+                            "dependency": dep.name,
+                            "feature": feature,
+                            "fix_action": ids::FIX_ACTION_USE_DEP_PREFIX,
+                            "fix_hint": format!("Replace '{}' with 'dep:{}' in feature '{}'", dep.name, dep.name, feature),
+                            "manifest": manifest.path.as_str(),
+                            "section": section_name(dep.kind),
+                            "value": value,
+                        });
+                        if let Some(ref t) = dep.target {
+                            d["target"] = json!(t);
+                        }
+                        d
+                    },
+                });
+            }
+        }
+    }
+}
+
+/// A bare `name` or a strong `name/feat` turns on the implicit feature; `name?/feat` does not.
+fn enables_implicitly(value: &str, name: &str) -> bool {
+    match value.split_once('/') {
+        Some((dep, _)) => dep == name,
+        None => value == name,
+    }
+}
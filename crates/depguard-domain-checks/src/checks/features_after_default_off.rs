@@ -0,0 +1,87 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // Inherited deps take default-features from the workspace entry and
+            // combine its features with their own.
+            let ws = dep
+                .spec
+                .workspace
+                .then(|| model.workspace_dependencies.get(&dep.name))
+                .flatten();
+            let default_features = match ws {
+                Some(ws) => ws.default_features,
+                None => dep.spec.default_features,
+            };
+            if default_features != Some(false) {
+                continue;
+            }
+            let has_features = dep.spec.features.as_ref().is_some_and(|f| !f.is_empty())
+                || ws.is_some_and(|ws| !ws.features.is_empty());
+            if has_features {
+                continue;
+            }
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+                ids::CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+            let fix_hint = if ws.is_some() {
+                "List the needed features on the member or [workspace.dependencies] entry"
+            } else {
+                "List the needed features alongside default-features = false"
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF.to_string(),
+                code: ids::CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED.to_string(),
+                message: format!(
+                    "dependency '{}' sets `default-features = false` but enables no features",
+                    dep.name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Add the features you need to `features`, or allowlist the dependency if the minimal build is intended."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "crate": crate_name,
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_ENABLE_NEEDED_FEATURES,
+                        "fix_hint": fix_hint,
+                        "inherited": ws.is_some(),
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
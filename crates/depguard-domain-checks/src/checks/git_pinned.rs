@@ -0,0 +1,84 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_GIT_PINNED) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let Some(git) = dep.spec.git.as_deref() else {
+                continue;
+            };
+            let pinned =
+                dep.spec.branch.is_none() && (dep.spec.rev.is_some() || dep.spec.tag.is_some());
+            if pinned || dep.spec.workspace {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let git_refs: Vec<&str> = [
+                ("branch", dep.spec.branch.is_some()),
+                ("rev", dep.spec.rev.is_some()),
+                ("tag", dep.spec.tag.is_some()),
+            ]
+            .into_iter()
+            .filter_map(|(key, present)| present.then_some(key))
+            .collect();
+
+            let message = match dep.spec.branch.as_deref() {
+                Some(branch) => format!(
+                    "git dependency '{}' tracks branch '{}' instead of a pinned rev or tag",
+                    dep.name, branch
+                ),
+                None => format!("git dependency '{}' does not pin a rev or tag", dep.name),
+            };
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_GIT_PINNED,
+                ids::CODE_GIT_BRANCH_UNPINNED,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(git),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_GIT_PINNED.to_string(),
+                code: ids::CODE_GIT_BRANCH_UNPINNED.to_string(),
+                message,
+                location: dep.location.clone(),
+                help: Some(
+                    "Replace `branch = ...` with `rev = \"<commit>\"` or `tag = \"<release>\"`."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_PIN_GIT_REV,
+                        "fix_hint": "Pin the git dependency to a rev or tag",
+                        "git": git,
+                        "git_refs": git_refs,
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_GIT_VERSION_CONFLICT) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let (Some(git), Some(version)) = (dep.spec.git.as_deref(), dep.spec.version.as_deref())
+            else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_GIT_VERSION_CONFLICT,
+                ids::CODE_GIT_WITH_VERSION,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(git),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_GIT_VERSION_CONFLICT.to_string(),
+                code: ids::CODE_GIT_WITH_VERSION.to_string(),
+                message: format!(
+                    "git dependency '{}' also declares version '{}'; the git source is not checked against it",
+                    dep.name, version
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Remove `version` and pin the git source with `rev` or `tag`, or depend on the published release."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REMOVE_GIT_VERSION,
+                        "fix_hint": "Remove version from the git dependency",
+                        "git": git,
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                        "version": version,
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
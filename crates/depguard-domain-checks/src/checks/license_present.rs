@@ -0,0 +1,177 @@
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_LICENSE_PRESENT) else {
+        return;
+    };
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+
+        let (code, message, fix_action, fix_hint) = match pkg.license.as_deref() {
+            None if pkg.license_file.is_none() => (
+                ids::CODE_MISSING_LICENSE,
+                format!(
+                    "package '{}' declares neither license nor license-file",
+                    pkg.name
+                ),
+                ids::FIX_ACTION_ADD_LICENSE,
+                "Add license or license-file to [package]",
+            ),
+            Some(license)
+                if !policy.allowed_licenses.is_empty()
+                    && !is_satisfiable(license, &policy.allowed_licenses) =>
+            {
+                (
+                    ids::CODE_DISALLOWED_LICENSE,
+                    format!(
+                        "package '{}' uses license '{}' outside allowed_licenses",
+                        pkg.name, license
+                    ),
+                    ids::FIX_ACTION_CHANGE_LICENSE,
+                    "Change license to one satisfiable by allowed_licenses",
+                )
+            }
+            _ => continue,
+        };
+
+        let fingerprint = fingerprint_for_dep(
+            ids::CHECK_DEPS_LICENSE_PRESENT,
+            code,
+            manifest.path.as_str(),
+            &pkg.name,
+            None,
+        );
+        let mut data = json!({
+            "fix_action": fix_action,
+            "fix_hint": fix_hint,
+            "license": pkg.license.as_deref().unwrap_or("none"),
+            "manifest": manifest.path.as_str(),
+            "package": pkg.name,
+        });
+        if code == ids::CODE_DISALLOWED_LICENSE {
+            data["allowed_licenses"] = json!(policy.allowed_licenses);
+        }
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_LICENSE_PRESENT.to_string(),
+            code: code.to_string(),
+            message,
+            location: pkg.location.clone().or_else(|| {
+                Some(Location {
+                    path: manifest.path.clone(),
+                    line: None,
+                    col: None,
+                })
+            }),
+            help: Some(format!("{fix_hint}.")),
+            url: None,
+            fingerprint: Some(fingerprint),
+            data,
+        });
+    }
+}
+
+/// Whether an SPDX `expression` can be satisfied using only `allowed` licenses.
+///
+/// `AND` binds tighter than `OR`, and the legacy `/` separator reads as `OR`.
+/// `X WITH Y` is allowed when either the full term or the bare `X` is listed.
+/// Identifiers compare case-insensitively; malformed expressions never match.
+fn is_satisfiable(expression: &str, allowed: &[String]) -> bool {
+    let spaced = expression
+        .replace('(', " ( ")
+        .replace(')', " ) ")
+        .replace('/', " OR ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+    let mut parser = SpdxParser {
+        tokens: &tokens,
+        pos: 0,
+        allowed,
+    };
+    matches!(parser.or_expr(), Some(ok) if parser.pos == tokens.len() && ok)
+}
+
+struct SpdxParser<'a> {
+    tokens: &'a [&'a str],
+    pos: usize,
+    allowed: &'a [String],
+}
+
+impl SpdxParser<'_> {
+    fn or_expr(&mut self) -> Option<bool> {
+        let mut ok = self.and_expr()?;
+        while self.eat_keyword("OR") {
+            ok |= self.and_expr()?;
+        }
+        Some(ok)
+    }
+
+    fn and_expr(&mut self) -> Option<bool> {
+        let mut ok = self.term()?;
+        while self.eat_keyword("AND") {
+            ok &= self.term()?;
+        }
+        Some(ok)
+    }
+
+    fn term(&mut self) -> Option<bool> {
+        let token = *self.tokens.get(self.pos)?;
+        self.pos += 1;
+        if token == "(" {
+            let ok = self.or_expr()?;
+            return (self.tokens.get(self.pos) == Some(&")")).then(|| {
+                self.pos += 1;
+                ok
+            });
+        }
+        if token == ")" || is_keyword(token) {
+            return None;
+        }
+        if self.eat_keyword("WITH") {
+            let exception = *self.tokens.get(self.pos)?;
+            if exception == "(" || exception == ")" || is_keyword(exception) {
+                return None;
+            }
+            self.pos += 1;
+            return Some(
+                self.is_allowed(&format!("{token} WITH {exception}")) || self.is_allowed(token),
+            );
+        }
+        Some(self.is_allowed(token))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let matched = self
+            .tokens
+            .get(self.pos)
+            .is_some_and(|t| t.eq_ignore_ascii_case(keyword));
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn is_allowed(&self, license: &str) -> bool {
+        self.allowed.iter().any(|a| {
+            a.split_whitespace()
+                .map(str::to_ascii_lowercase)
+                .eq(license.split_whitespace().map(str::to_ascii_lowercase))
+        })
+    }
+}
+
+fn is_keyword(token: &str) -> bool {
+    ["AND", "OR", "WITH"]
+        .iter()
+        .any(|k| token.eq_ignore_ascii_case(k))
+}
@@ -0,0 +1,89 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::{EffectiveConfig, LintsConfiguredOptions};
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_LINTS_CONFIGURED) else {
+        return;
+    };
+    // Malformed options are rejected when the config is resolved.
+    let Ok(LintsConfiguredOptions { require_workspace }) = policy.options_as() else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        // `[lints]` only applies to packages; a virtual root has nothing to lint.
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+        if is_allowed(allow.as_ref(), &pkg.name) {
+            continue;
+        }
+
+        let (code, message, location, fix_action, fix_hint) = match &manifest.lints {
+            None => (
+                ids::CODE_MISSING_LINTS_TABLE,
+                format!("package '{}' has no [lints] table", pkg.name),
+                pkg.location.clone(),
+                ids::FIX_ACTION_ADD_LINTS_TABLE,
+                "Add `[lints] workspace = true` or declare the package's lint levels",
+            ),
+            Some(lints) if require_workspace && !lints.workspace => (
+                ids::CODE_LINTS_NOT_INHERITED,
+                format!(
+                    "package '{}' declares its own [lints] instead of inheriting the workspace lints",
+                    pkg.name
+                ),
+                lints.location.clone(),
+                ids::FIX_ACTION_INHERIT_WORKSPACE_LINTS,
+                "Replace the package lint tables with `[lints] workspace = true`",
+            ),
+            Some(_) => continue,
+        };
+
+        let fingerprint = fingerprint_for_dep(
+            ids::CHECK_DEPS_LINTS_CONFIGURED,
+            code,
+            manifest.path.as_str(),
+            &pkg.name,
+            None,
+        );
+        let tools: Vec<&str> = manifest
+            .lints
+            .iter()
+            .flat_map(|lints| lints.tools.iter().map(String::as_str))
+            .collect();
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_LINTS_CONFIGURED.to_string(),
+            code: code.to_string(),
+            message,
+            location: location.or_else(|| {
+                Some(Location {
+                    path: manifest.path.clone(),
+                    line: None,
+                    col: None,
+                })
+            }),
+            help: Some(
+                "Configure lints in the manifest, preferably by inheriting [workspace.lints]."
+                    .to_string(),
+            ),
+            url: None,
+            fingerprint: Some(fingerprint),
+            data: json!({
+                "fix_action": fix_action,
+                "fix_hint": fix_hint,
+                "lint_tools": tools,
+                "manifest": manifest.path.as_str(),
+                "package": pkg.name,
+                "require_workspace": require_workspace,
+            }),
+        });
+    }
+}
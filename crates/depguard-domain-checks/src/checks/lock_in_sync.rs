@@ -0,0 +1,77 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, RepoPath, ids};
+use serde_json::json;
+use std::collections::BTreeSet;
+
+/// The finding points at the root manifest; the lockfile is its sibling.
+const ROOT_MANIFEST: &str = "Cargo.toml";
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_LOCK_IN_SYNC) else {
+        return;
+    };
+    // No lockfile: the capability is reported as missing, not as "in sync".
+    let Some(resolved) = &model.resolved_versions else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    let missing: BTreeSet<&str> = model
+        .manifests
+        .iter()
+        .flat_map(|m| &m.dependencies)
+        .map(|dep| dep.spec.rename_of.as_deref().unwrap_or(&dep.name))
+        .filter(|name| !resolved.contains_key(*name))
+        .filter(|name| !is_allowed(allow.as_ref(), name))
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+
+    let root = model
+        .manifests
+        .iter()
+        .map(|m| &m.path)
+        .find(|p| p.as_str() == ROOT_MANIFEST)
+        .cloned()
+        .unwrap_or_else(|| RepoPath::new(ROOT_MANIFEST));
+    let names: Vec<&str> = missing.into_iter().collect();
+
+    out.push(Finding {
+        severity: policy.severity,
+        check_id: ids::CHECK_DEPS_LOCK_IN_SYNC.to_string(),
+        code: ids::CODE_DEPENDENCY_MISSING_FROM_LOCK.to_string(),
+        message: format!(
+            "Cargo.lock has no entry for {} declared dependenc{}: {}",
+            names.len(),
+            if names.len() == 1 { "y" } else { "ies" },
+            names.join(", ")
+        ),
+        location: Some(Location {
+            path: root.clone(),
+            line: None,
+            col: None,
+        }),
+        help: Some(
+            "Regenerate Cargo.lock (e.g. `cargo update --workspace`) and commit it with the manifest change."
+                .to_string(),
+        ),
+        url: None,
+        fingerprint: Some(fingerprint_for_dep(
+            ids::CHECK_DEPS_LOCK_IN_SYNC,
+            ids::CODE_DEPENDENCY_MISSING_FROM_LOCK,
+            root.as_str(),
+            "Cargo.lock",
+            None,
+        )),
+        data: json!({
+            "fix_action": ids::FIX_ACTION_REGENERATE_LOCKFILE,
+            "fix_hint": "Regenerate Cargo.lock and commit it",
+            "manifest": root.as_str(),
+            "missing": names,
+        }),
+    });
+}
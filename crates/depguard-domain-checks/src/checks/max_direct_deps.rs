@@ -0,0 +1,84 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+use std::collections::BTreeSet;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_MAX_DIRECT_DEPS) else {
+        return;
+    };
+    let limits = policy.dependency_limits;
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+        if is_allowed(allow.as_ref(), &pkg.name) {
+            continue;
+        }
+
+        for (kind, limit) in [
+            (DepKind::Normal, limits.normal),
+            (DepKind::Dev, limits.dev),
+            (DepKind::Build, limits.build),
+        ] {
+            let Some(limit) = limit else {
+                continue;
+            };
+            // A crate declared under several target tables is still one dependency.
+            let count = manifest
+                .dependencies
+                .iter()
+                .filter(|dep| dep.kind == kind)
+                .map(|dep| dep.name.as_str())
+                .collect::<BTreeSet<_>>()
+                .len() as u32;
+            if count <= limit {
+                continue;
+            }
+
+            let section = section_name(kind);
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_MAX_DIRECT_DEPS,
+                ids::CODE_TOO_MANY_DEPENDENCIES,
+                manifest.path.as_str(),
+                section,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_MAX_DIRECT_DEPS.to_string(),
+                code: ids::CODE_TOO_MANY_DEPENDENCIES.to_string(),
+                message: format!(
+                    "package '{}' declares {} entries in [{}] (limit {})",
+                    pkg.name, count, section, limit
+                ),
+                location: Some(Location {
+                    path: manifest.path.clone(),
+                    line: Some(1),
+                    col: None,
+                }),
+                help: Some(
+                    "Remove unneeded dependencies, or raise the limit if the additions are justified."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: json!({
+                    "count": count,
+                    "fix_action": ids::FIX_ACTION_REDUCE_DEPENDENCIES,
+                    "fix_hint": format!("Remove {} dependencies or raise the limit", count - limit),
+                    "limit": limit,
+                    "manifest": manifest.path.as_str(),
+                    "package": pkg.name,
+                    "section": section,
+                }),
+            });
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, RepoPath, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_MEMBERS_EXIST) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for member in &model.unresolved_members {
+        if is_allowed(allow.as_ref(), &member.pattern) {
+            continue;
+        }
+        let location = member.location.clone().unwrap_or_else(|| Location {
+            path: RepoPath::new("Cargo.toml"),
+            line: None,
+            col: None,
+        });
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_MEMBERS_EXIST.to_string(),
+            code: ids::CODE_WORKSPACE_MEMBER_NOT_FOUND.to_string(),
+            message: format!(
+                "workspace member '{}' matches no Cargo.toml",
+                member.pattern
+            ),
+            help: Some("Fix the path in [workspace] members or remove the entry.".to_string()),
+            url: None,
+            fingerprint: Some(fingerprint_for_dep(
+                ids::CHECK_DEPS_MEMBERS_EXIST,
+                ids::CODE_WORKSPACE_MEMBER_NOT_FOUND,
+                location.path.as_str(),
+                &member.pattern,
+                None,
+            )),
+            data: json!({
+                "fix_action": ids::FIX_ACTION_FIX_WORKSPACE_MEMBER,
+                "fix_hint": "Correct the members entry or remove it",
+                "manifest": location.path.as_str(),
+                "pattern": member.pattern,
+            }),
+            location: Some(location),
+        });
+    }
+}
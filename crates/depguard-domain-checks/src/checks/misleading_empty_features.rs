@@ -0,0 +1,80 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            if !dep.spec.features.as_ref().is_some_and(Vec::is_empty) {
+                continue;
+            }
+            // Inherited deps take default-features from the workspace entry.
+            let default_features = if dep.spec.workspace {
+                model
+                    .workspace_dependencies
+                    .get(&dep.name)
+                    .and_then(|ws| ws.default_features)
+            } else {
+                dep.spec.default_features
+            };
+            if default_features == Some(false) {
+                continue;
+            }
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES,
+                ids::CODE_EMPTY_FEATURES_WITH_DEFAULTS,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+            let fix_hint = if dep.spec.workspace {
+                "Set default-features = false on the [workspace.dependencies] entry"
+            } else {
+                "Add default-features = false, or remove the empty features list"
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES.to_string(),
+                code: ids::CODE_EMPTY_FEATURES_WITH_DEFAULTS.to_string(),
+                message: format!(
+                    "dependency '{}' sets `features = []` but still enables default features",
+                    dep.name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Add `default-features = false`; an empty `features` list does not disable defaults."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_DISABLE_DEFAULT_FEATURES,
+                        "fix_hint": fix_hint,
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
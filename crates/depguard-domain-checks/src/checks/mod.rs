@@ -3,16 +3,57 @@ use crate::policy::EffectiveConfig;
 use depguard_check_catalog as check_catalog;
 use depguard_types::Finding;
 
+mod allowed_registries;
+mod banned_crates;
+mod banned_features;
+mod build_script_consistency;
+mod consistent_msrv;
+mod consistent_versions;
+mod crate_dir_name;
 mod default_features_explicit;
+mod deprecated_crates;
+mod dev_dep_leakage;
 mod dev_only_in_normal;
+mod duplicate_across_kinds;
+mod explicit_dep_feature;
+mod features_after_default_off;
+mod git_pinned;
 mod git_requires_version;
+mod git_version_conflict;
+mod license_present;
+mod lints_configured;
+mod lock_in_sync;
+mod max_direct_deps;
+mod members_exist;
+mod misleading_empty_features;
+mod msrv_floor;
+mod no_default_features;
+mod no_dependency_cycles;
 mod no_multiple_versions;
+mod no_nested_workspace;
+mod no_open_ranges;
+mod no_optional_dev_deps;
+mod no_patch_override;
+mod no_prerelease;
+mod no_rename;
+mod no_std_default_features;
 mod no_wildcards;
+mod no_zerover_in_stable;
 mod optional_unused;
 mod path_requires_version;
 mod path_safety;
+mod path_within_workspace;
+mod publish_metadata;
+mod publish_reachability;
+mod restrict_build_deps;
+mod secure_git_url;
+mod sorted;
 mod utils;
+mod valid_target_cfg;
+mod version_pinning;
+mod version_required;
 mod workspace_inheritance;
+mod workspace_version_override;
 mod yanked_versions;
 
 type CheckRunner = fn(&WorkspaceModel, &EffectiveConfig, &mut Vec<Finding>);
@@ -58,11 +99,208 @@ const RUNNERS: &[(&str, CheckRunner)] = &[
         depguard_types::ids::CHECK_DEPS_YANKED_VERSIONS,
         yanked_versions::run,
     ),
+    (
+        depguard_types::ids::CHECK_DEPS_BANNED_CRATES,
+        banned_crates::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_ALLOWED_REGISTRIES,
+        allowed_registries::run,
+    ),
+    (depguard_types::ids::CHECK_DEPS_GIT_PINNED, git_pinned::run),
+    (
+        depguard_types::ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS,
+        duplicate_across_kinds::run,
+    ),
+    (depguard_types::ids::CHECK_DEPS_SORTED, sorted::run),
+    (depguard_types::ids::CHECK_DEPS_MSRV_FLOOR, msrv_floor::run),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_PATCH_OVERRIDE,
+        no_patch_override::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_DEFAULT_FEATURES,
+        no_default_features::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_PRERELEASE,
+        no_prerelease::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_VERSION_PINNING,
+        version_pinning::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_DEV_DEP_LEAKAGE,
+        dev_dep_leakage::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_CRATE_DIR_NAME,
+        crate_dir_name::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+        workspace_version_override::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_RESTRICT_BUILD_DEPS,
+        restrict_build_deps::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_VERSION_REQUIRED,
+        version_required::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES,
+        misleading_empty_features::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE,
+        explicit_dep_feature::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_MAX_DIRECT_DEPS,
+        max_direct_deps::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_OPEN_RANGES,
+        no_open_ranges::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_PUBLISH_REACHABILITY,
+        publish_reachability::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_SECURE_GIT_URL,
+        secure_git_url::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_VALID_TARGET_CFG,
+        valid_target_cfg::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE,
+        no_zerover_in_stable::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_LICENSE_PRESENT,
+        license_present::run,
+    ),
+    (depguard_types::ids::CHECK_DEPS_NO_RENAME, no_rename::run),
+    (
+        depguard_types::ids::CHECK_DEPS_CONSISTENT_VERSIONS,
+        consistent_versions::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS,
+        no_optional_dev_deps::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_GIT_VERSION_CONFLICT,
+        git_version_conflict::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_CONSISTENT_MSRV,
+        consistent_msrv::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_LOCK_IN_SYNC,
+        lock_in_sync::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_MEMBERS_EXIST,
+        members_exist::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_NESTED_WORKSPACE,
+        no_nested_workspace::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_DEPRECATED_CRATES,
+        deprecated_crates::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_PUBLISH_METADATA,
+        publish_metadata::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_BANNED_FEATURES,
+        banned_features::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+        features_after_default_off::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+        path_within_workspace::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY,
+        build_script_consistency::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+        no_dependency_cycles::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_LINTS_CONFIGURED,
+        lints_configured::run,
+    ),
+    (
+        depguard_types::ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES,
+        no_std_default_features::run,
+    ),
 ];
 
+/// Checks whose findings for one manifest depend on other manifests, the
+/// lockfile, the yanked index, scanned sources, workspace discovery or the
+/// root manifest's resolver. Every other check can run against a single manifest (plus
+/// `[workspace.dependencies]`) in isolation.
+const WORKSPACE_CHECKS: &[&str] = &[
+    depguard_types::ids::CHECK_DEPS_WORKSPACE_INHERITANCE,
+    depguard_types::ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS,
+    depguard_types::ids::CHECK_DEPS_YANKED_VERSIONS,
+    depguard_types::ids::CHECK_DEPS_DEV_DEP_LEAKAGE,
+    depguard_types::ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+    depguard_types::ids::CHECK_DEPS_PUBLISH_REACHABILITY,
+    depguard_types::ids::CHECK_DEPS_CONSISTENT_VERSIONS,
+    depguard_types::ids::CHECK_DEPS_CONSISTENT_MSRV,
+    depguard_types::ids::CHECK_DEPS_LOCK_IN_SYNC,
+    depguard_types::ids::CHECK_DEPS_MEMBERS_EXIST,
+    depguard_types::ids::CHECK_DEPS_NO_NESTED_WORKSPACE,
+    depguard_types::ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+    depguard_types::ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+    depguard_types::ids::CHECK_DEPS_OPTIONAL_UNUSED,
+];
+
+/// Whether `check_id` needs the whole workspace rather than one manifest.
+pub fn is_workspace_check(check_id: &str) -> bool {
+    WORKSPACE_CHECKS.contains(&check_id)
+}
+
 pub fn run_all(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    run_matching(model, cfg, out, |_| true);
+}
+
+/// Run only the checks that evaluate each manifest independently.
+pub fn run_manifest_checks(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    run_matching(model, cfg, out, |id| !is_workspace_check(id));
+}
+
+/// Run only the checks listed in [`WORKSPACE_CHECKS`].
+pub fn run_workspace_checks(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    run_matching(model, cfg, out, is_workspace_check);
+}
+
+fn run_matching(
+    model: &WorkspaceModel,
+    cfg: &EffectiveConfig,
+    out: &mut Vec<Finding>,
+    include: impl Fn(&str) -> bool,
+) {
     for (check_id, run) in RUNNERS {
-        if check_catalog::is_check_available(check_id) {
+        if include(check_id) && check_catalog::is_check_available(check_id) {
             run(model, cfg, out);
         }
     }
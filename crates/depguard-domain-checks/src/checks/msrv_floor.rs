@@ -0,0 +1,126 @@
+use crate::checks::utils::parse_version;
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{ManifestModel, PackageMeta, WorkspaceModel};
+use crate::policy::{CheckPolicy, EffectiveConfig};
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+
+/// Cargo's edition when `package.edition` is omitted.
+const DEFAULT_EDITION: &str = "2015";
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_MSRV_FLOOR) else {
+        return;
+    };
+
+    for manifest in &model.manifests {
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+
+        match pkg.rust_version.as_deref() {
+            None => out.push(package_finding(
+                policy,
+                manifest,
+                pkg,
+                ids::CODE_MISSING_RUST_VERSION,
+                format!("package '{}' does not declare rust-version", pkg.name),
+                ids::FIX_ACTION_ADD_RUST_VERSION,
+                "Add rust-version to [package]",
+                None,
+                policy.min_rust_version.as_deref(),
+            )),
+            Some(current) => {
+                if let Some(required) = policy.min_rust_version.as_deref()
+                    && parse_version(current) < parse_version(required)
+                {
+                    out.push(package_finding(
+                        policy,
+                        manifest,
+                        pkg,
+                        ids::CODE_MSRV_BELOW_FLOOR,
+                        format!(
+                            "package '{}' has rust-version {} below the required {}",
+                            pkg.name, current, required
+                        ),
+                        ids::FIX_ACTION_RAISE_RUST_VERSION,
+                        "Raise rust-version to the workspace floor",
+                        Some(current),
+                        Some(required),
+                    ));
+                }
+            }
+        }
+
+        if let Some(required) = policy.min_edition.as_deref() {
+            let current = pkg.edition.as_deref().unwrap_or(DEFAULT_EDITION);
+            if parse_version(current) < parse_version(required) {
+                out.push(package_finding(
+                    policy,
+                    manifest,
+                    pkg,
+                    ids::CODE_EDITION_BELOW_FLOOR,
+                    format!(
+                        "package '{}' uses edition {} below the required {}",
+                        pkg.name, current, required
+                    ),
+                    ids::FIX_ACTION_RAISE_EDITION,
+                    "Raise edition to the workspace floor",
+                    Some(current),
+                    Some(required),
+                ));
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn package_finding(
+    policy: &CheckPolicy,
+    manifest: &ManifestModel,
+    pkg: &PackageMeta,
+    code: &str,
+    message: String,
+    fix_action: &str,
+    fix_hint: &str,
+    current: Option<&str>,
+    required: Option<&str>,
+) -> Finding {
+    let fingerprint = fingerprint_for_dep(
+        ids::CHECK_DEPS_MSRV_FLOOR,
+        code,
+        manifest.path.as_str(),
+        &pkg.name,
+        None,
+    );
+    let mut data = json!({
+        "fix_action": fix_action,
+        "fix_hint": fix_hint,
+        "manifest": manifest.path.as_str(),
+        "package": pkg.name,
+    });
+    if let Some(current) = current {
+        data["current"] = json!(current);
+    }
+    if let Some(required) = required {
+        data["required"] = json!(required);
+    }
+
+    Finding {
+        severity: policy.severity,
+        check_id: ids::CHECK_DEPS_MSRV_FLOOR.to_string(),
+        code: code.to_string(),
+        message,
+        location: pkg.location.clone().or_else(|| {
+            Some(Location {
+                path: manifest.path.clone(),
+                line: None,
+                col: None,
+            })
+        }),
+        help: Some(format!("{fix_hint}.")),
+        url: None,
+        fingerprint: Some(fingerprint),
+        data,
+    }
+}
@@ -0,0 +1,99 @@
+use crate::checks::utils::{
+    build_allowlist, build_matchers, first_match, is_allowed, section_name, spec_to_json,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_DEFAULT_FEATURES) else {
+        return;
+    };
+    let deny = build_matchers(&policy.deny);
+    if deny.is_empty() {
+        return;
+    }
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // Match the package, not the alias it was renamed to.
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            let Some(pattern) = first_match(&deny, crate_name) else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            // Inherited deps take default-features from the workspace entry; Cargo
+            // ignores a member-side `default-features = false` when it is enabled there.
+            let default_features = if dep.spec.workspace {
+                model
+                    .workspace_dependencies
+                    .get(&dep.name)
+                    .and_then(|ws| ws.default_features)
+            } else {
+                dep.spec.default_features
+            };
+            if default_features == Some(false) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_DEFAULT_FEATURES,
+                ids::CODE_DEFAULT_FEATURES_ENABLED,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+            let how = if default_features.is_some() {
+                "sets `default-features = true`"
+            } else {
+                "does not set `default-features = false`"
+            };
+            let fix_hint = if dep.spec.workspace {
+                "Set default-features = false on the [workspace.dependencies] entry"
+            } else {
+                "Set default-features = false and list the needed features"
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_DEFAULT_FEATURES.to_string(),
+                code: ids::CODE_DEFAULT_FEATURES_ENABLED.to_string(),
+                message: format!(
+                    "dependency '{}' {} (policy matched '{}')",
+                    dep.name, how, pattern
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Add `default-features = false` and enable only the features you need."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "crate": crate_name,
+                        "current_spec": spec_to_json(&dep.spec),
+                        "default_features": if default_features.is_some() { "explicit_true" } else { "omitted" },
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_DISABLE_DEFAULT_FEATURES,
+                        "fix_hint": fix_hint,
+                        "inherited": dep.spec.workspace,
+                        "manifest": manifest.path.as_str(),
+                        "matched_pattern": pattern,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
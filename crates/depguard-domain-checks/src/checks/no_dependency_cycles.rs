@@ -0,0 +1,286 @@
+use crate::checks::utils::{
+    build_allowlist, dep_path_base, is_allowed, is_outside_repo, manifest_in, resolve_dep_path,
+    section_name,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, DependencyDecl, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, Location, Severity, ids};
+use serde_json::json;
+use std::collections::{BTreeMap, VecDeque};
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    // Graph nodes are members with a [package], keyed by manifest path.
+    let index: BTreeMap<&str, usize> = model
+        .manifests
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| {
+            m.package
+                .as_ref()
+                .is_some_and(|pkg| !is_allowed(allow.as_ref(), &pkg.name))
+        })
+        .map(|(i, m)| (m.path.as_str(), i))
+        .collect();
+    let mut edges: Vec<Vec<(usize, &DependencyDecl)>> = vec![Vec::new(); model.manifests.len()];
+
+    for (&manifest_path, &from) in &index {
+        let manifest = &model.manifests[from];
+        for dep in &manifest.dependencies {
+            // Cargo permits cycles through dev-dependencies.
+            if dep.kind == DepKind::Dev {
+                continue;
+            }
+            let Some((base, path)) = dep_path_base(model, manifest, dep) else {
+                continue;
+            };
+            let resolved = resolve_dep_path(base, path);
+            if is_outside_repo(&resolved) {
+                continue;
+            }
+            let target = manifest_in(&resolved);
+            if target == manifest_path {
+                push_self_dependency(model, from, dep, path, policy.severity, out);
+            } else if let Some(&to) = index.get(target.as_str()) {
+                edges[from].push((to, dep));
+            }
+        }
+    }
+
+    for component in strongly_connected(&edges, index.values().copied()) {
+        if component.len() < 2 {
+            continue;
+        }
+        // Report once per cycle, anchored at the member with the smallest path.
+        let Some(&start) = component
+            .iter()
+            .min_by_key(|&&i| model.manifests[i].path.as_str())
+        else {
+            continue;
+        };
+        let Some(cycle) = shortest_cycle(&edges, start, &component) else {
+            continue;
+        };
+        push_cycle(model, &edges, &cycle, &component, policy.severity, out);
+    }
+}
+
+fn package_name(model: &WorkspaceModel, idx: usize) -> &str {
+    model.manifests[idx]
+        .package
+        .as_ref()
+        .map(|pkg| pkg.name.as_str())
+        .unwrap_or_default()
+}
+
+fn push_self_dependency(
+    model: &WorkspaceModel,
+    idx: usize,
+    dep: &DependencyDecl,
+    path: &str,
+    severity: Severity,
+    out: &mut Vec<Finding>,
+) {
+    let manifest = &model.manifests[idx];
+    let package = package_name(model, idx);
+    let fingerprint = fingerprint_for_dep(
+        ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+        ids::CODE_SELF_DEPENDENCY,
+        manifest.path.as_str(),
+        &dep.name,
+        Some(path),
+    );
+
+    out.push(Finding {
+        severity,
+        check_id: ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES.to_string(),
+        code: ids::CODE_SELF_DEPENDENCY.to_string(),
+        message: format!(
+            "package '{}' depends on itself via '{}' (path = \"{}\")",
+            package, dep.name, path
+        ),
+        location: dep.location.clone(),
+        help: Some("A package cannot depend on itself; Cargo rejects the cycle.".to_string()),
+        url: None,
+        fingerprint: Some(fingerprint),
+        data: {
+            let mut d = json!({
+                "cycle": [package, package],
+                "dependency": dep.name,
+                "fix_action": ids::FIX_ACTION_BREAK_DEPENDENCY_CYCLE,
+                "fix_hint": "Remove the dependency on the package itself",
+                "manifest": manifest.path.as_str(),
+                "package": package,
+                "path": path,
+                "section": section_name(dep.kind),
+            });
+            if let Some(ref t) = dep.target {
+                d["target"] = json!(t);
+            }
+            d
+        },
+    });
+}
+
+fn push_cycle(
+    model: &WorkspaceModel,
+    edges: &[Vec<(usize, &DependencyDecl)>],
+    cycle: &[usize],
+    component: &[usize],
+    severity: Severity,
+    out: &mut Vec<Finding>,
+) {
+    let start = cycle[0];
+    let manifest = &model.manifests[start];
+    let Some(&(_, dep)) = edges[start].iter().find(|(to, _)| *to == cycle[1]) else {
+        return;
+    };
+    let names: Vec<&str> = cycle.iter().map(|&i| package_name(model, i)).collect();
+    let manifests: Vec<&str> = cycle[..cycle.len() - 1]
+        .iter()
+        .map(|&i| model.manifests[i].path.as_str())
+        .collect();
+    let mut members: Vec<&str> = component.iter().map(|&i| package_name(model, i)).collect();
+    members.sort_unstable();
+    let fingerprint = fingerprint_for_dep(
+        ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+        ids::CODE_DEPENDENCY_CYCLE,
+        manifest.path.as_str(),
+        &dep.name,
+        Some(&names.join("->")),
+    );
+
+    out.push(Finding {
+        severity,
+        check_id: ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES.to_string(),
+        code: ids::CODE_DEPENDENCY_CYCLE.to_string(),
+        message: format!("workspace members form a dependency cycle: {}", names.join(" -> ")),
+        location: dep.location.clone().or_else(|| {
+            Some(Location {
+                path: manifest.path.clone(),
+                line: None,
+                col: None,
+            })
+        }),
+        help: Some(
+            "Cargo rejects cyclic package dependencies. Move the shared code into a crate both members can depend on."
+                .to_string(),
+        ),
+        url: None,
+        fingerprint: Some(fingerprint),
+        data: {
+            let mut d = json!({
+                "cycle": names,
+                "cycle_manifests": manifests,
+                "dependency": dep.name,
+                "fix_action": ids::FIX_ACTION_BREAK_DEPENDENCY_CYCLE,
+                "fix_hint": "Remove one edge of the cycle or extract the shared code into a new crate",
+                "manifest": manifest.path.as_str(),
+                "members": members,
+                "section": section_name(dep.kind),
+            });
+            if let Some(ref t) = dep.target {
+                d["target"] = json!(t);
+            }
+            d
+        },
+    });
+}
+
+/// Tarjan's algorithm over `nodes`; each component is returned sorted.
+fn strongly_connected(
+    edges: &[Vec<(usize, &DependencyDecl)>],
+    nodes: impl Iterator<Item = usize>,
+) -> Vec<Vec<usize>> {
+    struct State {
+        counter: usize,
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        components: Vec<Vec<usize>>,
+    }
+
+    fn visit(v: usize, edges: &[Vec<(usize, &DependencyDecl)>], st: &mut State) {
+        st.index[v] = Some(st.counter);
+        st.low[v] = st.counter;
+        st.counter += 1;
+        st.stack.push(v);
+        st.on_stack[v] = true;
+
+        for &(w, _) in &edges[v] {
+            match st.index[w] {
+                None => {
+                    visit(w, edges, st);
+                    st.low[v] = st.low[v].min(st.low[w]);
+                }
+                Some(idx) if st.on_stack[w] => st.low[v] = st.low[v].min(idx),
+                Some(_) => {}
+            }
+        }
+
+        if Some(st.low[v]) == st.index[v] {
+            let mut component = Vec::new();
+            while let Some(w) = st.stack.pop() {
+                st.on_stack[w] = false;
+                component.push(w);
+                if w == v {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            st.components.push(component);
+        }
+    }
+
+    let n = edges.len();
+    let mut st = State {
+        counter: 0,
+        index: vec![None; n],
+        low: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        components: Vec::new(),
+    };
+    for v in nodes {
+        if st.index[v].is_none() {
+            visit(v, edges, &mut st);
+        }
+    }
+    st.components
+}
+
+/// Shortest path from `start` back to itself within `component`, as
+/// `[start, ..., start]`.
+fn shortest_cycle(
+    edges: &[Vec<(usize, &DependencyDecl)>],
+    start: usize,
+    component: &[usize],
+) -> Option<Vec<usize>> {
+    let mut parent: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(v) = queue.pop_front() {
+        for &(w, _) in &edges[v] {
+            if w == start {
+                let mut path = vec![start, v];
+                let mut cur = v;
+                while let Some(&p) = parent.get(&cur) {
+                    path.push(p);
+                    cur = p;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if component.binary_search(&w).is_ok() && w != start && !parent.contains_key(&w) {
+                parent.insert(w, v);
+                queue.push_back(w);
+            }
+        }
+    }
+    None
+}
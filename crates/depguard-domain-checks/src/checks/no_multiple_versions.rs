@@ -1,16 +1,25 @@
 use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
 use crate::model::WorkspaceModel;
-use crate::policy::EffectiveConfig;
-use depguard_types::{Finding, ids};
+use crate::policy::{CheckPolicy, EffectiveConfig};
+use depguard_types::{Finding, Location, RepoPath, ids};
+use globset::GlobSet;
 use serde_json::json;
 use std::collections::{BTreeMap, BTreeSet};
 
+/// Resolved-version findings point at the root lockfile.
+const LOCKFILE: &str = "Cargo.lock";
+
 pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
     let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS) else {
         return;
     };
     let allow = build_allowlist(&policy.allow);
 
+    if let Some(resolved) = &model.resolved_versions {
+        check_resolved(resolved, policy, allow.as_ref(), out);
+    }
+
     // Build a map of crate_name -> set of (version, manifest_path, section)
     let mut version_map: BTreeMap<String, BTreeSet<(String, String, String)>> = BTreeMap::new();
 
@@ -95,3 +104,57 @@ pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>
         });
     }
 }
+
+/// Report crates that `Cargo.lock` resolves at two or more versions.
+fn check_resolved(
+    resolved: &BTreeMap<String, BTreeSet<String>>,
+    policy: &CheckPolicy,
+    allow: Option<&GlobSet>,
+    out: &mut Vec<Finding>,
+) {
+    let ignore = build_allowlist(&policy.ignore);
+
+    for (crate_name, versions) in resolved {
+        if versions.len() <= 1
+            || is_allowed(allow, crate_name)
+            || is_allowed(ignore.as_ref(), crate_name)
+        {
+            continue;
+        }
+
+        let version_list: Vec<&str> = versions.iter().map(String::as_str).collect();
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS.to_string(),
+            code: ids::CODE_MULTIPLE_VERSIONS.to_string(),
+            message: format!(
+                "crate '{}' resolves to multiple versions in Cargo.lock: {}",
+                crate_name,
+                version_list.join(", ")
+            ),
+            location: Some(Location {
+                path: RepoPath::new(LOCKFILE),
+                line: None,
+                col: None,
+            }),
+            help: Some(
+                "Run `cargo tree -d` to find the dependents holding older versions, or add the crate to `ignore`."
+                    .to_string(),
+            ),
+            url: None,
+            fingerprint: Some(fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS,
+                ids::CODE_MULTIPLE_VERSIONS,
+                LOCKFILE,
+                crate_name,
+                None,
+            )),
+            data: json!({
+                "crate": crate_name,
+                "fix_action": ids::FIX_ACTION_DEDUPE_RESOLVED_VERSIONS,
+                "fix_hint": "Upgrade dependents so a single version resolves",
+                "versions": version_list,
+            }),
+        });
+    }
+}
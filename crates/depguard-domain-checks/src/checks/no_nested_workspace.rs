@@ -0,0 +1,49 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_NESTED_WORKSPACE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for location in &model.nested_workspaces {
+        let manifest = location.path.as_str();
+        let workspace_root = manifest.rsplit_once('/').map_or(".", |(dir, _)| dir);
+        if is_allowed(allow.as_ref(), workspace_root) || is_allowed(allow.as_ref(), manifest) {
+            continue;
+        }
+
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_NO_NESTED_WORKSPACE.to_string(),
+            code: ids::CODE_NESTED_WORKSPACE_DETECTED.to_string(),
+            message: format!(
+                "'{manifest}' declares its own [workspace] nested inside the root workspace"
+            ),
+            location: Some(location.clone()),
+            help: Some(
+                "Remove the nested [workspace] table, or add its directory to the root's [workspace] exclude."
+                    .to_string(),
+            ),
+            url: None,
+            fingerprint: Some(fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_NESTED_WORKSPACE,
+                ids::CODE_NESTED_WORKSPACE_DETECTED,
+                manifest,
+                workspace_root,
+                None,
+            )),
+            data: json!({
+                "fix_action": ids::FIX_ACTION_MERGE_NESTED_WORKSPACE,
+                "fix_hint": "Remove the nested [workspace] table or exclude its directory",
+                "manifest": manifest,
+                "workspace_root": workspace_root,
+            }),
+        });
+    }
+}
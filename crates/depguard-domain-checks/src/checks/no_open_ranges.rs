@@ -0,0 +1,92 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use semver::{Op, VersionReq};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_OPEN_RANGES) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+
+        for dep in &manifest.dependencies {
+            let Some(requirement) = dep.spec.version.as_deref() else {
+                continue;
+            };
+            // Wildcards are deps.no_wildcards' concern; don't report them twice.
+            if requirement.contains('*') {
+                continue;
+            }
+            if is_open_ended(requirement) != Some(true) {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_OPEN_RANGES,
+                ids::CODE_OPEN_ENDED_RANGE,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_OPEN_RANGES.to_string(),
+                code: ids::CODE_OPEN_ENDED_RANGE.to_string(),
+                message: format!(
+                    "dependency '{}' uses open-ended requirement '{}' with no upper bound",
+                    dep.name, requirement
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Add an upper bound, e.g. use a caret requirement like \"1.0\" instead of \">=1.0\"."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_PIN_VERSION,
+                        "fix_hint": "Replace the open range with a bounded requirement",
+                        "manifest": manifest.path.as_str(),
+                        "requirement": requirement,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
+
+/// Whether `requirement` has a lower bound (`>` / `>=`) and nothing capping it.
+///
+/// Returns `None` for requirements that do not parse as semver.
+fn is_open_ended(requirement: &str) -> Option<bool> {
+    let req = VersionReq::parse(requirement).ok()?;
+    let lower = req
+        .comparators
+        .iter()
+        .any(|c| matches!(c.op, Op::Greater | Op::GreaterEq));
+    let upper = req
+        .comparators
+        .iter()
+        .any(|c| !matches!(c.op, Op::Greater | Op::GreaterEq));
+    Some(lower && !upper)
+}
@@ -0,0 +1,63 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            if dep.kind != DepKind::Dev || !dep.spec.optional {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS,
+                ids::CODE_OPTIONAL_DEV_DEPENDENCY,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS.to_string(),
+                code: ids::CODE_OPTIONAL_DEV_DEPENDENCY.to_string(),
+                message: format!(
+                    "dev-dependency '{}' is marked optional = true, which Cargo does not allow",
+                    dep.name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Remove `optional = true`, or move the crate to [dependencies] if a feature should enable it."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REMOVE_OPTIONAL_FLAG,
+                        "fix_hint": "Remove optional = true from the dev-dependency",
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
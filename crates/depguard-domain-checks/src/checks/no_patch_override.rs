@@ -0,0 +1,78 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{OverrideKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_PATCH_OVERRIDE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        // Overrides in workspace roots and unpublished packages are repository-local.
+        if !manifest.is_publishable() {
+            continue;
+        }
+        let package = manifest.package_name().unwrap_or_default();
+
+        for section in &manifest.overrides {
+            let entries: Vec<&str> = section
+                .entries
+                .iter()
+                .map(String::as_str)
+                .filter(|entry| !is_allowed(allow.as_ref(), override_crate(entry)))
+                .collect();
+            if entries.is_empty() {
+                continue;
+            }
+
+            let table = section.table_name();
+            let code = match section.kind {
+                OverrideKind::Patch => ids::CODE_PATCH_SECTION_PRESENT,
+                OverrideKind::Replace => ids::CODE_REPLACE_SECTION_PRESENT,
+            };
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_PATCH_OVERRIDE,
+                code,
+                manifest.path.as_str(),
+                &table,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_PATCH_OVERRIDE.to_string(),
+                code: code.to_string(),
+                message: format!(
+                    "publishable package '{}' declares [{}] overriding: {}",
+                    package,
+                    table,
+                    entries.join(", ")
+                ),
+                location: section.location.clone(),
+                help: Some(
+                    "Depend on a released version, or move the override to the workspace root manifest."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: json!({
+                    "entries": entries,
+                    "fix_action": ids::FIX_ACTION_REMOVE_DEPENDENCY_OVERRIDE,
+                    "fix_hint": format!("Remove [{table}] from the package manifest"),
+                    "manifest": manifest.path.as_str(),
+                    "package": package,
+                    "section": table,
+                }),
+            });
+        }
+    }
+}
+
+/// Crate name of an override key; `[replace]` keys are `name:version` specs.
+fn override_crate(entry: &str) -> &str {
+    entry.split(':').next().unwrap_or(entry)
+}
@@ -0,0 +1,97 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use semver::VersionReq;
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_PRERELEASE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+
+        for dep in &manifest.dependencies {
+            let requirement = if dep.spec.workspace {
+                model
+                    .workspace_dependencies
+                    .get(&dep.name)
+                    .and_then(|ws| ws.version.as_deref())
+            } else {
+                dep.spec.version.as_deref()
+            };
+            let Some(requirement) = requirement else {
+                continue;
+            };
+            let prerelease = prerelease_tags(requirement);
+            if prerelease.is_empty() {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_PRERELEASE,
+                ids::CODE_PRERELEASE_VERSION,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_PRERELEASE.to_string(),
+                code: ids::CODE_PRERELEASE_VERSION.to_string(),
+                message: format!(
+                    "dependency '{}' requires a prerelease version: {}",
+                    dep.name, requirement
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Depend on a stable release, or keep the package unpublished until one exists."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_USE_STABLE_VERSION,
+                        "fix_hint": "Replace the prerelease requirement with a stable release",
+                        "inherited": dep.spec.workspace,
+                        "manifest": manifest.path.as_str(),
+                        "prerelease": prerelease,
+                        "requirement": requirement,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
+
+/// Prerelease tags named by any comparator of `requirement`, in order.
+///
+/// Requirements that do not parse as semver are left to other checks.
+fn prerelease_tags(requirement: &str) -> Vec<String> {
+    let Ok(req) = VersionReq::parse(requirement) else {
+        return Vec::new();
+    };
+    req.comparators
+        .iter()
+        .filter(|c| !c.pre.is_empty())
+        .map(|c| c.pre.as_str().to_string())
+        .collect()
+}
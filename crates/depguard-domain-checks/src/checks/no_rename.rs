@@ -0,0 +1,64 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_RENAME) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let Some(crate_name) = dep.spec.rename_of.as_deref() else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), &dep.name) || is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_RENAME,
+                ids::CODE_DEPENDENCY_RENAMED,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_RENAME.to_string(),
+                code: ids::CODE_DEPENDENCY_RENAMED.to_string(),
+                message: format!(
+                    "dependency '{}' is a rename of crate '{}'",
+                    dep.name, crate_name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Depend on the crate under its own name, or add the rename to allow."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "crate": crate_name,
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REMOVE_DEPENDENCY_RENAME,
+                        "fix_hint": "Depend on the crate under its own name",
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::checks::utils::{
+    build_allowlist, build_matchers, first_match, is_allowed, section_name, spec_to_json,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::{EffectiveConfig, NoStdOptions};
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES) else {
+        return;
+    };
+    // Malformed options are rejected when the config is resolved.
+    let Ok(options) = policy.options_as::<NoStdOptions>() else {
+        return;
+    };
+    let no_std = build_matchers(&options.no_std_crates);
+    if no_std.is_empty() {
+        return;
+    }
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+        let Some(pattern) = first_match(&no_std, &pkg.name) else {
+            continue;
+        };
+
+        // Dev- and build-dependencies run on the host, where `std` is available.
+        for dep in manifest
+            .dependencies
+            .iter()
+            .filter(|d| d.kind == DepKind::Normal)
+        {
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            // Inherited deps take default-features from the workspace entry; Cargo
+            // ignores a member-side `default-features = false` when it is enabled there.
+            let default_features = if dep.spec.workspace {
+                model
+                    .workspace_dependencies
+                    .get(&dep.name)
+                    .and_then(|ws| ws.default_features)
+            } else {
+                dep.spec.default_features
+            };
+            if default_features == Some(false) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES,
+                ids::CODE_DEFAULT_FEATURES_IN_NO_STD,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+            let default_features_state = if default_features.is_some() {
+                "explicit_true"
+            } else {
+                "omitted"
+            };
+            let fix_hint = if dep.spec.workspace {
+                "Set default-features = false on the [workspace.dependencies] entry"
+            } else {
+                "Set default-features = false and list the needed no_std features"
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES.to_string(),
+                code: ids::CODE_DEFAULT_FEATURES_IN_NO_STD.to_string(),
+                message: format!(
+                    "no_std crate '{}' keeps default features of dependency '{}'",
+                    pkg.name, dep.name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Add `default-features = false` so the dependency does not pull in `std`."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "crate": crate_name,
+                        "current_spec": spec_to_json(&dep.spec),
+                        "default_features": default_features_state,
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_DISABLE_DEFAULT_FEATURES,
+                        "fix_hint": fix_hint,
+                        "inherited": dep.spec.workspace,
+                        "manifest": manifest.path.as_str(),
+                        "matched_pattern": pattern,
+                        "package": pkg.name,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use semver::{Op, Version, VersionReq};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+        let Some(package) = manifest.package.as_ref() else {
+            continue;
+        };
+        let Some(package_version) = package.version.as_deref().filter(|v| is_stable(v)) else {
+            continue;
+        };
+
+        for dep in &manifest.dependencies {
+            if dep.kind != DepKind::Normal {
+                continue;
+            }
+            // `{ workspace = true }` takes its requirement from `[workspace.dependencies]`.
+            let inherited = dep.spec.version.is_none() && dep.spec.workspace;
+            let requirement = if inherited {
+                model
+                    .workspace_dependencies
+                    .get(&dep.name)
+                    .and_then(|ws| ws.version.as_deref())
+            } else {
+                dep.spec.version.as_deref()
+            };
+            let Some(requirement) = requirement else {
+                continue;
+            };
+            if !is_zerover(requirement) || is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE,
+                ids::CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.spec.path.as_deref(),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE.to_string(),
+                code: ids::CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE.to_string(),
+                message: format!(
+                    "stable package '{}' ({}) depends on pre-1.0 '{}' with requirement '{}'",
+                    package.name, package_version, dep.name, requirement
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Upgrade to a 1.0+ release, keep the crate out of your public API, or add it to allow."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_UPGRADE_ZEROVER_DEPENDENCY,
+                        "fix_hint": "Upgrade the dependency to a 1.0+ release",
+                        "inherited": inherited,
+                        "manifest": manifest.path.as_str(),
+                        "package": package.name,
+                        "package_version": package_version,
+                        "requirement": requirement,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
+
+/// Whether a package version is `1.0.0` or later.
+fn is_stable(version: &str) -> bool {
+    Version::parse(version.trim()).is_ok_and(|v| v >= Version::new(1, 0, 0))
+}
+
+/// Whether `requirement` can only match `0.x` releases.
+///
+/// Every comparator must name major version 0, and at least one must cap the
+/// range: `>=0.9` alone also admits 1.x and is not reported.
+fn is_zerover(requirement: &str) -> bool {
+    let Ok(req) = VersionReq::parse(requirement) else {
+        return false;
+    };
+    !req.comparators.is_empty()
+        && req.comparators.iter().all(|c| c.major == 0)
+        && req
+            .comparators
+            .iter()
+            .any(|c| !matches!(c.op, Op::Greater | Op::GreaterEq))
+}
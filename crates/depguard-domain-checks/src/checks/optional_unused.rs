@@ -4,34 +4,23 @@ use crate::model::WorkspaceModel;
 use crate::policy::EffectiveConfig;
 use depguard_types::{Finding, ids};
 use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+
+const ROOT_MANIFEST: &str = "Cargo.toml";
 
 pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
     let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_OPTIONAL_UNUSED) else {
         return;
     };
+    // Resolver 1 unifies features across targets and dependency kinds, so the
+    // feature graph seen here is not what Cargo builds; report nothing.
+    if uses_resolver_v1(model) {
+        return;
+    }
     let allow = build_allowlist(&policy.allow);
 
     for manifest in &model.manifests {
-        // Collect all dependency names referenced in features
-        let mut referenced_deps: std::collections::HashSet<String> =
-            std::collections::HashSet::new();
-
-        for feature_deps in manifest.features.values() {
-            for dep in feature_deps {
-                // Features can reference deps as:
-                // - "dep:crate-name" (explicit dep syntax)
-                // - "crate-name/feature" (enable feature of dep)
-                // - "crate-name" (enable dep as feature, legacy)
-                if let Some(crate_name) = dep.strip_prefix("dep:") {
-                    referenced_deps.insert(crate_name.to_string());
-                } else if let Some((crate_name, _feature)) = dep.split_once('/') {
-                    referenced_deps.insert(crate_name.to_string());
-                } else {
-                    // Could be a feature name or a dep name
-                    referenced_deps.insert(dep.clone());
-                }
-            }
-        }
+        let refs = FeatureRefs::collect(&manifest.features);
 
         for dep in &manifest.dependencies {
             // Only check optional dependencies
@@ -39,8 +28,8 @@ pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>
                 continue;
             }
 
-            // Check if this optional dep is referenced in any feature
-            if referenced_deps.contains(&dep.name) {
+            // Check if any feature can activate this optional dep
+            if refs.enabled.contains(dep.name.as_str()) {
                 continue;
             }
 
@@ -49,9 +38,27 @@ pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>
                 continue;
             }
 
+            let weak_features = refs.weak.get(dep.name.as_str());
+            let (code, message) = match weak_features {
+                Some(_) => (
+                    ids::CODE_OPTIONAL_NEVER_ENABLED,
+                    format!(
+                        "optional dependency '{}' is only referenced weakly (`{}?/...`) and is never enabled",
+                        dep.name, dep.name
+                    ),
+                ),
+                None => (
+                    ids::CODE_OPTIONAL_NOT_IN_FEATURES,
+                    format!(
+                        "optional dependency '{}' is not referenced in any feature",
+                        dep.name
+                    ),
+                ),
+            };
+
             let fingerprint = fingerprint_for_dep(
                 ids::CHECK_DEPS_OPTIONAL_UNUSED,
-                ids::CODE_OPTIONAL_NOT_IN_FEATURES,
+                code,
                 manifest.path.as_str(),
                 &dep.name,
                 None,
@@ -60,11 +67,8 @@ pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>
             out.push(Finding {
                 severity: policy.severity,
                 check_id: ids::CHECK_DEPS_OPTIONAL_UNUSED.to_string(),
-                code: ids::CODE_OPTIONAL_NOT_IN_FEATURES.to_string(),
-                message: format!(
-                    "optional dependency '{}' is not referenced in any feature",
-                    dep.name
-                ),
+                code: code.to_string(),
+                message,
                 location: dep.location.clone(),
                 help: Some(
                     "Add a feature that enables this dependency, or remove `optional = true`."
@@ -84,9 +88,72 @@ pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>
                     if let Some(ref t) = dep.target {
                         d["target"] = json!(t);
                     }
+                    if let Some(features) = weak_features {
+                        d["weak_features"] = json!(features);
+                    }
                     d
                 },
             });
         }
     }
 }
+
+/// Whether the workspace resolves features with resolver 1: declared on the root
+/// as `workspace.resolver` or `package.resolver`, or implied by a root package
+/// on edition 2015/2018. A virtual root without `resolver` is not treated as v1.
+fn uses_resolver_v1(model: &WorkspaceModel) -> bool {
+    let Some(root) = model
+        .manifests
+        .iter()
+        .find(|m| m.path.as_str() == ROOT_MANIFEST)
+    else {
+        return false;
+    };
+    let package = root.package.as_ref();
+    let declared = root
+        .workspace_resolver
+        .as_deref()
+        .or_else(|| package.and_then(|p| p.resolver.as_deref()));
+    match declared {
+        Some(resolver) => resolver == "1",
+        None => package
+            .and_then(|p| p.edition.as_deref())
+            .is_some_and(|edition| matches!(edition, "2015" | "2018")),
+    }
+}
+
+/// Dependency references found in `[features]` values.
+#[derive(Default)]
+struct FeatureRefs<'a> {
+    /// Names a feature can activate: `dep:foo`, `foo/feat`, or a bare `foo`.
+    enabled: BTreeSet<&'a str>,
+    /// Names referenced only as `foo?/feat`, mapped to the features doing so.
+    weak: BTreeMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> FeatureRefs<'a> {
+    fn collect(features: &'a BTreeMap<String, Vec<String>>) -> Self {
+        let mut refs = Self::default();
+        for (feature, values) in features {
+            for value in values {
+                if let Some(name) = value.strip_prefix("dep:") {
+                    refs.enabled.insert(name);
+                } else if let Some((name, _)) = value.split_once("?/") {
+                    // Weak reference: enables `name`'s feature only if `name` is already on.
+                    refs.weak.entry(name).or_default().push(feature);
+                } else if let Some((name, _)) = value.split_once('/') {
+                    refs.enabled.insert(name);
+                } else {
+                    // A bare name is either another feature or the implicit feature of
+                    // an optional dep; treat it as enabling rather than guess.
+                    refs.enabled.insert(value);
+                }
+            }
+        }
+        refs.weak.retain(|name, _| !refs.enabled.contains(name));
+        for features in refs.weak.values_mut() {
+            features.dedup();
+        }
+        refs
+    }
+}
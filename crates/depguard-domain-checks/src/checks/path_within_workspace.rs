@@ -0,0 +1,99 @@
+use crate::checks::utils::{
+    build_allowlist, dep_path_base, is_allowed, is_outside_repo, manifest_in, resolve_dep_path,
+    section_name, spec_to_json,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::{EffectiveConfig, Scope};
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+    // Diff scope only models the changed manifests, so membership is unknowable.
+    let check_membership = cfg.scope == Scope::Repo;
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let Some((base, path)) = dep_path_base(model, manifest, dep) else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), &dep.name) || is_allowed(allow.as_ref(), path) {
+                continue;
+            }
+
+            let resolved = resolve_dep_path(base, path);
+            let reason = if is_outside_repo(&resolved) {
+                "outside_repo"
+            } else if check_membership {
+                let target = manifest_in(&resolved);
+                if model.manifests.iter().any(|m| m.path.as_str() == target) {
+                    continue;
+                }
+                "not_a_member"
+            } else {
+                continue;
+            };
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+                ids::CODE_PATH_OUTSIDE_WORKSPACE,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(path),
+            );
+            let (message, fix_hint) = if reason == "outside_repo" {
+                (
+                    format!(
+                        "dependency '{}' points outside the repository: {} (resolves to {})",
+                        dep.name, path, resolved
+                    ),
+                    "Vendor the crate into the workspace or depend on a published/git version",
+                )
+            } else {
+                (
+                    format!(
+                        "dependency '{}' points at {}, which is not a workspace member",
+                        dep.name, resolved
+                    ),
+                    "Add the crate to [workspace] members or depend on a published/git version",
+                )
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE.to_string(),
+                code: ids::CODE_PATH_OUTSIDE_WORKSPACE.to_string(),
+                message,
+                location: dep.location.clone(),
+                help: Some(
+                    "Path dependencies outside the workspace make builds depend on the local checkout layout."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_USE_WORKSPACE_MEMBER_PATH,
+                        "fix_hint": fix_hint,
+                        "inherited": dep.spec.workspace,
+                        "manifest": manifest.path.as_str(),
+                        "path": path,
+                        "reason": reason,
+                        "resolved_path": resolved,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -110,7 +110,7 @@ fn dep_spec_strategy() -> impl ::proptest::strategy::Strategy<Value = DepSpec> {
                 optional,
                 default_features,
                 workspace,
-                package,
+                rename_of,
             )| {
                 // Use wildcard if provided, otherwise use version
                 let final_version = wildcard.or(version);
@@ -125,11 +125,13 @@ fn dep_spec_strategy() -> impl ::proptest::strategy::Strategy<Value = DepSpec> {
                     optional,
                     default_features: if default_features { Some(true) } else { None },
                     workspace,
-                    package,
+                    rename_of,
+                    registry: None,
                     branch: None,
                     tag: None,
                     rev: None,
                     inline_suppressions: Vec::new(),
+                    features: None,
                 }
             },
         )
@@ -183,9 +185,14 @@ fn manifest_strategy() -> impl ::proptest::strategy::Strategy<Value = ManifestMo
             package: Some(PackageMeta {
                 name: "pkg".to_string(),
                 publish,
+                ..PackageMeta::default()
             }),
             dependencies: deps,
             features,
+            overrides: Vec::new(),
+            targets: Vec::new(),
+            lints: None,
+            workspace_resolver: None,
         })
 }
 
@@ -206,6 +213,9 @@ fn workspace_model_strategy() -> impl ::proptest::strategy::Strategy<Value = Wor
                             version: None,
                             path: None,
                             workspace: true,
+                            default_features: None,
+                            features: Vec::new(),
+                            location: None,
                         },
                     )
                 })
@@ -214,7 +224,11 @@ fn workspace_model_strategy() -> impl ::proptest::strategy::Strategy<Value = Wor
             WorkspaceModel {
                 repo_root: RepoPath::new("."),
                 workspace_dependencies,
+                resolved_versions: None,
                 manifests,
+                source_references: None,
+                unresolved_members: Vec::new(),
+                nested_workspaces: Vec::new(),
             }
         })
 }
@@ -231,6 +245,7 @@ fn config_for_check(check_id: &str, severity: Severity, max_findings: usize) ->
         max_findings,
         yanked_index: None,
         checks,
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -771,7 +786,11 @@ fn severity_strategy() -> impl ::proptest::strategy::Strategy<Value = Severity>
         let empty_model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: Vec::new(),
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let checks = [
@@ -0,0 +1,75 @@
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::{EffectiveConfig, PublishMetadataOptions};
+use depguard_types::{Finding, Location, ids};
+use serde_json::json;
+
+/// Keys required when the check's `require` option is empty.
+const DEFAULT_REQUIRE: &[&str] = &["description", "repository"];
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_PUBLISH_METADATA) else {
+        return;
+    };
+    // Malformed options are rejected when the config is resolved.
+    let Ok(options) = policy.options_as::<PublishMetadataOptions>() else {
+        return;
+    };
+    let require: Vec<&str> = if options.require.is_empty() {
+        DEFAULT_REQUIRE.to_vec()
+    } else {
+        options.require.iter().map(String::as_str).collect()
+    };
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+        let Some(pkg) = manifest.package.as_ref() else {
+            continue;
+        };
+        let missing: Vec<&str> = require
+            .iter()
+            .copied()
+            .filter(|key| !pkg.has_metadata(key))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        let fingerprint = fingerprint_for_dep(
+            ids::CHECK_DEPS_PUBLISH_METADATA,
+            ids::CODE_MISSING_PUBLISH_METADATA,
+            manifest.path.as_str(),
+            &pkg.name,
+            None,
+        );
+        out.push(Finding {
+            severity: policy.severity,
+            check_id: ids::CHECK_DEPS_PUBLISH_METADATA.to_string(),
+            code: ids::CODE_MISSING_PUBLISH_METADATA.to_string(),
+            message: format!(
+                "package '{}' is missing publish metadata: {}",
+                pkg.name,
+                missing.join(", ")
+            ),
+            location: pkg.location.clone().or_else(|| {
+                Some(Location {
+                    path: manifest.path.clone(),
+                    line: None,
+                    col: None,
+                })
+            }),
+            help: Some(format!("Add {} to [package].", missing.join(", "))),
+            url: None,
+            fingerprint: Some(fingerprint),
+            data: json!({
+                "fix_action": ids::FIX_ACTION_ADD_PUBLISH_METADATA,
+                "fix_hint": "Add the missing keys to [package]",
+                "manifest": manifest.path.as_str(),
+                "missing": missing,
+                "package": pkg.name,
+            }),
+        });
+    }
+}
@@ -0,0 +1,94 @@
+use crate::checks::utils::{
+    build_allowlist, dep_path_base, is_allowed, is_outside_repo, manifest_in, resolve_dep_path,
+    section_name,
+};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_PUBLISH_REACHABILITY) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+
+        for dep in &manifest.dependencies {
+            // Cargo strips dev-dependencies on publish.
+            if dep.kind == DepKind::Dev {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let Some((base, path)) = dep_path_base(model, manifest, dep) else {
+                continue;
+            };
+            let resolved = resolve_dep_path(base, path);
+            if is_outside_repo(&resolved) {
+                continue;
+            }
+            let target_manifest = manifest_in(&resolved);
+            let Some(member) = model
+                .manifests
+                .iter()
+                .find(|m| m.path.as_str() == target_manifest)
+            else {
+                continue;
+            };
+            let Some(package) = member.package.as_ref() else {
+                continue;
+            };
+            if package.publish {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_PUBLISH_REACHABILITY,
+                ids::CODE_DEPENDS_ON_UNPUBLISHABLE,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(path),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_PUBLISH_REACHABILITY.to_string(),
+                code: ids::CODE_DEPENDS_ON_UNPUBLISHABLE.to_string(),
+                message: format!(
+                    "publishable package depends on '{}', which is marked publish = false",
+                    dep.name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "`cargo publish` cannot resolve this dependency. Make it publishable, move it to [dev-dependencies], or mark this package publish = false."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "dependency": dep.name,
+                        "dependency_manifest": member.path.as_str(),
+                        "fix_action": ids::FIX_ACTION_PUBLISH_PATH_DEPENDENCY,
+                        "fix_hint": "Make the dependency publishable or stop depending on it",
+                        "manifest": manifest.path.as_str(),
+                        "path": path,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_RESTRICT_BUILD_DEPS) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            if dep.kind != DepKind::Build {
+                continue;
+            }
+            let crate_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
+            if is_allowed(allow.as_ref(), crate_name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_RESTRICT_BUILD_DEPS,
+                ids::CODE_BUILD_DEPENDENCY_PRESENT,
+                manifest.path.as_str(),
+                &dep.name,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_RESTRICT_BUILD_DEPS.to_string(),
+                code: ids::CODE_BUILD_DEPENDENCY_PRESENT.to_string(),
+                message: format!(
+                    "build-dependency '{}' is not on the allowlist",
+                    crate_name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Remove the build dependency, or add it to `allow` if the build script needs it."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "crate": crate_name,
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_REMOVE_BUILD_DEPENDENCY,
+                        "fix_hint": "Remove the build dependency or add it to allow",
+                        "kind": "build",
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_SECURE_GIT_URL) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let Some(git) = dep.spec.git.as_deref() else {
+                continue;
+            };
+            let Some(scheme) = insecure_scheme(git) else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_SECURE_GIT_URL,
+                ids::CODE_INSECURE_GIT_URL,
+                manifest.path.as_str(),
+                &dep.name,
+                Some(git),
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_SECURE_GIT_URL.to_string(),
+                code: ids::CODE_INSECURE_GIT_URL.to_string(),
+                message: format!(
+                    "git dependency '{}' is fetched over insecure {}:// URL: {}",
+                    dep.name, scheme, git
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Use an https:// (or ssh://) URL so the fetched source is authenticated."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_USE_HTTPS_GIT_URL,
+                        "fix_hint": "Switch the git URL to https://",
+                        "manifest": manifest.path.as_str(),
+                        "scheme": scheme,
+                        "section": section_name(dep.kind),
+                        "url": git,
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
+
+/// The insecure scheme (`http` or `git`) of a git URL, if it uses one.
+///
+/// Schemes are case-insensitive; `git+http://` counts as `http`.
+fn insecure_scheme(url: &str) -> Option<&'static str> {
+    let (scheme, _) = url.trim().split_once("://")?;
+    let scheme = scheme.to_ascii_lowercase();
+    match scheme.strip_prefix("git+").unwrap_or(&scheme) {
+        "http" => Some("http"),
+        "git" => Some("git"),
+        _ => None,
+    }
+}
@@ -0,0 +1,95 @@
+use crate::checks::utils::{section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DependencyDecl, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_SORTED) else {
+        return;
+    };
+
+    for manifest in &model.manifests {
+        // One group per table: (section, target).
+        let mut tables: BTreeMap<(&str, Option<&str>), Vec<&DependencyDecl>> = BTreeMap::new();
+        for dep in &manifest.dependencies {
+            tables
+                .entry((section_name(dep.kind), dep.target.as_deref()))
+                .or_default()
+                .push(dep);
+        }
+
+        for deps in tables.values_mut() {
+            // Source order; declarations without a location keep parse order.
+            deps.sort_by_key(|d| d.location.as_ref().map(|l| l.line));
+
+            let Some(idx) = deps
+                .windows(2)
+                .position(|pair| pair[1].name < pair[0].name)
+                .map(|i| i + 1)
+            else {
+                continue;
+            };
+            let dep = deps[idx];
+            let actual_previous = deps[idx - 1].name.as_str();
+            let expected_previous = deps
+                .iter()
+                .map(|d| d.name.as_str())
+                .filter(|name| *name < dep.name.as_str())
+                .max();
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_SORTED,
+                ids::CODE_DEPS_UNSORTED,
+                manifest.path.as_str(),
+                &dep.name,
+                dep.target.as_deref(),
+            );
+
+            let message = match expected_previous {
+                Some(prev) => format!(
+                    "dependency '{}' is out of order in [{}]; it should come after '{}'",
+                    dep.name,
+                    section_name(dep.kind),
+                    prev
+                ),
+                None => format!(
+                    "dependency '{}' is out of order in [{}]; it should come first",
+                    dep.name,
+                    section_name(dep.kind)
+                ),
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_SORTED.to_string(),
+                code: ids::CODE_DEPS_UNSORTED.to_string(),
+                message,
+                location: dep.location.clone(),
+                help: Some("Sort the dependency table alphabetically.".to_string()),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "actual_previous": actual_previous,
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_SORT_DEPENDENCIES,
+                        "fix_hint": "Sort the dependency table alphabetically",
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(prev) = expected_previous {
+                        d["expected_previous"] = json!(prev);
+                    }
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
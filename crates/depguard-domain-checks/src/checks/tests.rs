@@ -1,15 +1,29 @@
 use super::{
-    default_features_explicit, dev_only_in_normal, git_requires_version, no_multiple_versions,
-    no_wildcards, optional_unused, path_requires_version, path_safety, utils,
-    workspace_inheritance, yanked_versions,
+    allowed_registries, banned_crates, banned_features, build_script_consistency, consistent_msrv,
+    consistent_versions, crate_dir_name, default_features_explicit, deprecated_crates,
+    dev_dep_leakage, dev_only_in_normal, duplicate_across_kinds, explicit_dep_feature,
+    features_after_default_off, git_pinned, git_requires_version, git_version_conflict,
+    license_present, lints_configured, lock_in_sync, max_direct_deps, members_exist,
+    misleading_empty_features, msrv_floor, no_default_features, no_dependency_cycles,
+    no_multiple_versions, no_nested_workspace, no_open_ranges, no_optional_dev_deps,
+    no_patch_override, no_prerelease, no_rename, no_std_default_features, no_wildcards,
+    no_zerover_in_stable, optional_unused, path_requires_version, path_safety,
+    path_within_workspace, publish_metadata, publish_reachability, restrict_build_deps,
+    secure_git_url, sorted, utils, valid_target_cfg, version_pinning, version_required,
+    workspace_inheritance, workspace_version_override, yanked_versions,
 };
-use crate::model::{DepKind, DepSpec};
+use crate::model::{
+    BuildSetting, DepKind, DepSpec, LintsDecl, OverrideKind, OverrideSection, PackageMeta,
+    TargetSpec, UnresolvedMember,
+};
+use crate::policy::{DependencyLimits, MsrvMode, PinningMode};
 use crate::test_support::{
     config_with_check, config_with_check_allow, dep_decl, manifest, model, workspace_dep,
 };
-use depguard_types::{Severity, ids};
+use depguard_types::{Location, RepoPath, Severity, ids};
 use depguard_yanked::parse_yanked_index;
-use std::collections::BTreeMap;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[test]
 fn no_wildcards_handles_missing_version_allowlist_and_target() {
@@ -723,6 +737,50 @@ fn no_multiple_versions_detects_duplicates_and_skips_allowlist() {
     assert!(out[0].message.contains("2.0"));
 }
 
+#[test]
+fn no_multiple_versions_reports_resolved_duplicates_and_skips_ignored() {
+    let mut model = model(
+        vec![manifest("Cargo.toml", true, Vec::new(), BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS, Severity::Warning);
+
+    let mut out = Vec::new();
+    no_multiple_versions::run(&model, &cfg, &mut out);
+    assert!(out.is_empty(), "no lockfile means no resolved findings");
+
+    let mut resolved = BTreeMap::new();
+    for (name, versions) in [
+        ("serde", vec!["1.0.1", "1.0.200"]),
+        ("syn", vec!["1.0.109", "2.0.48"]),
+        ("windows-sys", vec!["0.48.0", "0.52.0"]),
+        ("tokio", vec!["1.36.0"]),
+    ] {
+        resolved.insert(
+            name.to_string(),
+            versions.into_iter().map(str::to_string).collect(),
+        );
+    }
+    model.resolved_versions = Some(resolved);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS)
+        .expect("policy")
+        .ignore = vec!["windows-*".to_string()];
+
+    no_multiple_versions::run(&model, &cfg, &mut out);
+    let crates: Vec<&str> = out
+        .iter()
+        .map(|f| f.data["crate"].as_str().expect("crate"))
+        .collect();
+    assert_eq!(crates, vec!["serde", "syn"]);
+    assert!(out.iter().all(|f| f.code == ids::CODE_MULTIPLE_VERSIONS));
+    assert_eq!(out[0].data["versions"], json!(["1.0.1", "1.0.200"]));
+    assert_eq!(
+        out[0].location.as_ref().map(|l| l.path.as_str()),
+        Some("Cargo.lock")
+    );
+}
+
 #[test]
 fn optional_unused_detects_missing_feature_references() {
     let mut features = BTreeMap::new();
@@ -809,6 +867,91 @@ fn optional_unused_detects_missing_feature_references() {
     assert_eq!(out[0].data["target"], "cfg(unix)");
 }
 
+#[test]
+fn optional_unused_flags_deps_only_referenced_weakly() {
+    let mut features = BTreeMap::new();
+    features.insert(
+        "derive".to_string(),
+        vec!["serde?/derive".to_string(), "tokio?/macros".to_string()],
+    );
+    features.insert(
+        "rt".to_string(),
+        vec!["dep:tokio".to_string(), "tokio?/rt".to_string()],
+    );
+    features.insert("std".to_string(), vec!["serde?/std".to_string()]);
+
+    let optional = || DepSpec {
+        optional: true,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde", DepKind::Normal, optional(), None),
+        dep_decl("tokio", DepKind::Normal, optional(), None),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, features)],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check(ids::CHECK_DEPS_OPTIONAL_UNUSED, Severity::Warning);
+
+    let mut out = Vec::new();
+    optional_unused::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_OPTIONAL_NEVER_ENABLED);
+    assert_eq!(out[0].data["dependency"], "serde");
+    assert_eq!(
+        out[0].data["weak_features"],
+        serde_json::json!(["derive", "std"])
+    );
+}
+
+#[test]
+fn optional_unused_skips_workspaces_on_resolver_1() {
+    let deps = vec![dep_decl(
+        "serde",
+        DepKind::Normal,
+        DepSpec {
+            optional: true,
+            ..DepSpec::default()
+        },
+        None,
+    )];
+    let cfg = config_with_check(ids::CHECK_DEPS_OPTIONAL_UNUSED, Severity::Warning);
+    let run_with = |configure: &dyn Fn(&mut crate::model::ManifestModel)| {
+        let mut root = manifest("Cargo.toml", true, deps.clone(), BTreeMap::new());
+        configure(&mut root);
+        let mut out = Vec::new();
+        optional_unused::run(&model(vec![root], BTreeMap::new()), &cfg, &mut out);
+        out.len()
+    };
+
+    assert_eq!(run_with(&|_| {}), 1);
+    assert_eq!(
+        run_with(&|m| m.workspace_resolver = Some("1".to_string())),
+        0
+    );
+    assert_eq!(
+        run_with(&|m| m.package.as_mut().unwrap().resolver = Some("1".to_string())),
+        0
+    );
+    assert_eq!(
+        run_with(&|m| m.package.as_mut().unwrap().edition = Some("2018".to_string())),
+        0
+    );
+    assert_eq!(
+        run_with(&|m| {
+            let pkg = m.package.as_mut().unwrap();
+            pkg.edition = Some("2018".to_string());
+            pkg.resolver = Some("2".to_string());
+        }),
+        1
+    );
+    assert_eq!(
+        run_with(&|m| m.package.as_mut().unwrap().edition = Some("2021".to_string())),
+        1
+    );
+}
+
 #[test]
 fn yanked_versions_flags_pinned_yanked_and_respects_allowlist() {
     let deps = vec![
@@ -890,6 +1033,80 @@ fn yanked_versions_is_noop_without_index() {
     assert!(out.is_empty());
 }
 
+#[test]
+fn banned_crates_matches_exact_and_glob_patterns() {
+    let version = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("openssl-sys", DepKind::Build, version("0.9"), None),
+        dep_decl(
+            "native-tls",
+            DepKind::Normal,
+            version("0.2"),
+            Some("cfg(unix)"),
+        ),
+        dep_decl("openssl-probe", DepKind::Normal, version("0.1"), None),
+        dep_decl("rustls", DepKind::Normal, version("0.23"), None),
+        dep_decl(
+            "tls",
+            DepKind::Normal,
+            DepSpec {
+                rename_of: Some("openssl".to_string()),
+                ..version("0.10")
+            },
+            None,
+        ),
+    ];
+
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+
+    let mut cfg = config_with_check_allow(
+        ids::CHECK_DEPS_BANNED_CRATES,
+        Severity::Error,
+        vec!["openssl-probe"],
+        false,
+    );
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_BANNED_CRATES)
+        .expect("policy")
+        .deny = vec!["openssl*".to_string(), "native-tls".to_string()];
+
+    let mut out = Vec::new();
+    banned_crates::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 3);
+    assert!(out.iter().all(|f| f.code == ids::CODE_BANNED_CRATE));
+    assert_eq!(out[0].data["dependency"], "openssl-sys");
+    assert_eq!(out[0].data["matched_pattern"], "openssl*");
+    assert_eq!(out[0].data["section"], "build-dependencies");
+    assert_eq!(out[1].data["dependency"], "native-tls");
+    assert_eq!(out[1].data["matched_pattern"], "native-tls");
+    assert_eq!(out[1].data["target"], "cfg(unix)");
+    assert!(out[1].data.get("crate").is_none());
+    assert_eq!(out[2].data["dependency"], "tls");
+    assert_eq!(out[2].data["crate"], "openssl");
+    assert_eq!(out[2].data["matched_pattern"], "openssl*");
+}
+
+#[test]
+fn banned_crates_is_noop_without_deny_list() {
+    let deps = vec![dep_decl(
+        "openssl",
+        DepKind::Normal,
+        DepSpec::default(),
+        None,
+    )];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check(ids::CHECK_DEPS_BANNED_CRATES, Severity::Error);
+
+    let mut out = Vec::new();
+    banned_crates::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
 #[test]
 fn utils_allowlist_and_section_helpers() {
     let empty: Vec<String> = Vec::new();
@@ -918,7 +1135,9 @@ fn utils_spec_to_json_includes_all_fields() {
         default_features: Some(false),
         optional: true,
         inline_suppressions: Vec::new(),
-        package: None,
+        rename_of: Some("real-crate".to_string()),
+        registry: Some("internal".to_string()),
+        features: None,
     };
 
     let json = utils::spec_to_json(&spec);
@@ -931,6 +1150,8 @@ fn utils_spec_to_json_includes_all_fields() {
     assert_eq!(json["rev"], "deadbeef");
     assert_eq!(json["default-features"], false);
     assert_eq!(json["optional"], true);
+    assert_eq!(json["package"], "real-crate");
+    assert_eq!(json["registry"], "internal");
 }
 
 // ============================================================================
@@ -1245,6 +1466,47 @@ fn run_all_executes_checks_and_produces_findings() {
     );
 }
 
+#[test]
+fn manifest_and_workspace_runners_partition_run_all() {
+    let wildcard = DepSpec {
+        version: Some("*".to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde", DepKind::Normal, wildcard.clone(), None),
+        dep_decl("tokio", DepKind::Normal, wildcard, None),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::from([workspace_dep("serde")]),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_NO_WILDCARDS, Severity::Error);
+    cfg.checks.insert(
+        ids::CHECK_DEPS_WORKSPACE_INHERITANCE.to_string(),
+        crate::policy::CheckPolicy::enabled(Severity::Warning),
+    );
+
+    let mut all = Vec::new();
+    super::run_all(&model, &cfg, &mut all);
+    let mut manifest_only = Vec::new();
+    super::run_manifest_checks(&model, &cfg, &mut manifest_only);
+    let mut workspace_only = Vec::new();
+    super::run_workspace_checks(&model, &cfg, &mut workspace_only);
+
+    assert!(
+        manifest_only
+            .iter()
+            .all(|f| !super::is_workspace_check(&f.check_id))
+    );
+    assert!(
+        workspace_only
+            .iter()
+            .all(|f| super::is_workspace_check(&f.check_id))
+    );
+    assert!(!manifest_only.is_empty() && !workspace_only.is_empty());
+    assert_eq!(manifest_only.len() + workspace_only.len(), all.len());
+}
+
 /// Test that run_all respects check availability (skip disabled checks)
 #[test]
 fn run_all_respects_check_availability() {
@@ -1486,3 +1748,2817 @@ fn path_requires_version_respects_ignore_publish_false() {
         "Should skip path dep when ignore_publish_false is false and manifest is not publishable"
     );
 }
+
+#[test]
+fn allowed_registries_flags_registries_outside_allowlist() {
+    let registry = |r: &str| DepSpec {
+        version: Some("1".to_string()),
+        registry: Some(r.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl(
+            "internal-utils",
+            DepKind::Normal,
+            registry("internal"),
+            None,
+        ),
+        dep_decl("mirror-utils", DepKind::Dev, registry("my-mirror"), None),
+        dep_decl(
+            "serde",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "local",
+            DepKind::Normal,
+            DepSpec {
+                path: Some("../local".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_ALLOWED_REGISTRIES,
+        Severity::Error,
+        vec!["internal"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    allowed_registries::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_DISALLOWED_REGISTRY);
+    assert_eq!(out[0].data["dependency"], "mirror-utils");
+    assert_eq!(out[0].data["registry"], "my-mirror");
+    assert_eq!(
+        out[0].data["allowed_registries"],
+        serde_json::json!(["crates-io", "internal"])
+    );
+    assert_eq!(out[0].data["current_spec"]["registry"], "my-mirror");
+}
+
+#[test]
+fn allowed_registries_flags_crates_io_when_denied() {
+    let deps = vec![dep_decl(
+        "serde",
+        DepKind::Normal,
+        DepSpec {
+            version: Some("1".to_string()),
+            ..DepSpec::default()
+        },
+        None,
+    )];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let mut cfg = config_with_check(ids::CHECK_DEPS_ALLOWED_REGISTRIES, Severity::Error);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_ALLOWED_REGISTRIES)
+        .expect("policy")
+        .deny = vec!["crates-io".to_string()];
+
+    let mut out = Vec::new();
+    allowed_registries::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].data["registry"], "crates-io");
+    assert_eq!(out[0].data["allowed_registries"], serde_json::json!([]));
+}
+
+#[test]
+fn git_pinned_flags_branch_and_bare_git_deps() {
+    let git = |extra: fn(&mut DepSpec)| {
+        let mut spec = DepSpec {
+            git: Some("https://example.com/repo.git".to_string()),
+            ..DepSpec::default()
+        };
+        extra(&mut spec);
+        spec
+    };
+    let deps = vec![
+        dep_decl(
+            "on_branch",
+            DepKind::Normal,
+            git(|s| s.branch = Some("main".to_string())),
+            None,
+        ),
+        dep_decl("bare", DepKind::Dev, git(|_| {}), Some("cfg(unix)")),
+        dep_decl(
+            "by_rev",
+            DepKind::Normal,
+            git(|s| s.rev = Some("deadbeef".to_string())),
+            None,
+        ),
+        dep_decl(
+            "by_tag",
+            DepKind::Normal,
+            git(|s| s.tag = Some("v1.0.0".to_string())),
+            None,
+        ),
+        dep_decl(
+            "inherited",
+            DepKind::Normal,
+            git(|s| s.workspace = true),
+            None,
+        ),
+    ];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check(ids::CHECK_DEPS_GIT_PINNED, Severity::Error);
+
+    let mut out = Vec::new();
+    git_pinned::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+    assert!(out.iter().all(|f| f.code == ids::CODE_GIT_BRANCH_UNPINNED));
+    assert_eq!(out[0].data["dependency"], "on_branch");
+    assert_eq!(out[0].data["git"], "https://example.com/repo.git");
+    assert_eq!(out[0].data["git_refs"], serde_json::json!(["branch"]));
+    assert!(out[0].message.contains("branch 'main'"));
+    assert_eq!(out[1].data["dependency"], "bare");
+    assert_eq!(out[1].data["git_refs"], serde_json::json!([]));
+    assert_eq!(out[1].data["target"], "cfg(unix)");
+}
+
+#[test]
+fn git_pinned_respects_allowlist() {
+    let deps = vec![dep_decl(
+        "tracked",
+        DepKind::Normal,
+        DepSpec {
+            git: Some("https://example.com/repo.git".to_string()),
+            branch: Some("main".to_string()),
+            ..DepSpec::default()
+        },
+        None,
+    )];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_GIT_PINNED,
+        Severity::Error,
+        vec!["tracked"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    git_pinned::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn duplicate_across_kinds_flags_dev_and_build_entries() {
+    let version = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde", DepKind::Normal, version("1"), None),
+        dep_decl("serde", DepKind::Dev, version("1"), None),
+        dep_decl("cc", DepKind::Build, version("1"), None),
+        dep_decl("cc", DepKind::Dev, version("1"), None),
+        dep_decl("libc", DepKind::Normal, version("0.2"), Some("cfg(unix)")),
+        dep_decl("libc", DepKind::Dev, version("0.2"), None),
+    ];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check(ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS, Severity::Warning);
+
+    let mut out = Vec::new();
+    duplicate_across_kinds::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_DUPLICATE_DEPENDENCY_KINDS)
+    );
+    assert_eq!(out[0].data["dependency"], "serde");
+    assert_eq!(out[0].data["section"], "dev-dependencies");
+    assert_eq!(
+        out[0].data["kinds"],
+        serde_json::json!(["dependencies", "dev-dependencies"])
+    );
+    assert_eq!(out[1].data["dependency"], "cc");
+    assert_eq!(
+        out[1].data["kinds"],
+        serde_json::json!(["build-dependencies", "dev-dependencies"])
+    );
+}
+
+#[test]
+fn sorted_reports_first_out_of_order_entry_per_table() {
+    let deps = vec![
+        dep_decl("anyhow", DepKind::Normal, DepSpec::default(), None),
+        dep_decl("toml", DepKind::Normal, DepSpec::default(), None),
+        dep_decl("serde", DepKind::Normal, DepSpec::default(), None),
+        dep_decl("a-last", DepKind::Normal, DepSpec::default(), None),
+        // Target tables are sorted independently of [dependencies].
+        dep_decl(
+            "libc",
+            DepKind::Normal,
+            DepSpec::default(),
+            Some("cfg(unix)"),
+        ),
+        dep_decl(
+            "nix",
+            DepKind::Normal,
+            DepSpec::default(),
+            Some("cfg(unix)"),
+        ),
+        dep_decl("tempfile", DepKind::Dev, DepSpec::default(), None),
+        dep_decl("insta", DepKind::Dev, DepSpec::default(), None),
+    ];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check(ids::CHECK_DEPS_SORTED, Severity::Warning);
+
+    let mut out = Vec::new();
+    sorted::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+    assert!(out.iter().all(|f| f.code == ids::CODE_DEPS_UNSORTED));
+
+    let normal = out
+        .iter()
+        .find(|f| f.data["section"] == "dependencies")
+        .expect("dependencies finding");
+    assert_eq!(normal.data["dependency"], "serde");
+    assert_eq!(normal.data["actual_previous"], "toml");
+    assert_eq!(normal.data["expected_previous"], "anyhow");
+
+    let dev = out
+        .iter()
+        .find(|f| f.data["section"] == "dev-dependencies")
+        .expect("dev-dependencies finding");
+    assert_eq!(dev.data["dependency"], "insta");
+    assert!(dev.data.get("expected_previous").is_none());
+    assert!(dev.message.contains("should come first"));
+}
+
+#[test]
+fn msrv_floor_reports_missing_and_below_floor_packages() {
+    let package = |name: &str, edition: Option<&str>, rust_version: Option<&str>| {
+        let mut m = manifest(
+            &format!("crates/{name}/Cargo.toml"),
+            true,
+            Vec::new(),
+            BTreeMap::new(),
+        );
+        m.package = Some(PackageMeta {
+            name: name.to_string(),
+            publish: true,
+            edition: edition.map(str::to_string),
+            rust_version: rust_version.map(str::to_string),
+            ..PackageMeta::default()
+        });
+        m
+    };
+    let model = model(
+        vec![
+            package("current", Some("2021"), Some("1.80")),
+            package("old_msrv", Some("2021"), Some("1.70.1")),
+            package("no_msrv", Some("2021"), None),
+            package("old_edition", None, Some("1.74.0")),
+        ],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_MSRV_FLOOR, Severity::Error);
+    let policy = cfg
+        .checks
+        .get_mut(ids::CHECK_DEPS_MSRV_FLOOR)
+        .expect("policy");
+    policy.min_rust_version = Some("1.74".to_string());
+    policy.min_edition = Some("2021".to_string());
+
+    let mut out = Vec::new();
+    msrv_floor::run(&model, &cfg, &mut out);
+    let codes: Vec<(&str, &str)> = out
+        .iter()
+        .map(|f| (f.data["package"].as_str().unwrap(), f.code.as_str()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            ("old_msrv", ids::CODE_MSRV_BELOW_FLOOR),
+            ("no_msrv", ids::CODE_MISSING_RUST_VERSION),
+            ("old_edition", ids::CODE_EDITION_BELOW_FLOOR),
+        ]
+    );
+    assert_eq!(out[0].data["current"], "1.70.1");
+    assert_eq!(out[0].data["required"], "1.74");
+    assert!(out[1].data.get("current").is_none());
+    assert_eq!(out[2].data["current"], "2015");
+    assert_eq!(out[2].data["required"], "2021");
+}
+
+#[test]
+fn msrv_floor_without_floors_only_requires_rust_version() {
+    let mut with_msrv = manifest("Cargo.toml", true, Vec::new(), BTreeMap::new());
+    with_msrv.package.as_mut().unwrap().rust_version = Some("1.60".to_string());
+    let without_msrv = manifest("crates/b/Cargo.toml", true, Vec::new(), BTreeMap::new());
+    let model = model(vec![with_msrv, without_msrv], BTreeMap::new());
+    let cfg = config_with_check(ids::CHECK_DEPS_MSRV_FLOOR, Severity::Warning);
+
+    let mut out = Vec::new();
+    msrv_floor::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_MISSING_RUST_VERSION);
+    assert!(out[0].data.get("required").is_none());
+}
+
+#[test]
+fn no_patch_override_flags_publishable_packages_only() {
+    let overrides = || {
+        vec![
+            OverrideSection {
+                kind: OverrideKind::Patch,
+                source: Some("crates-io".to_string()),
+                entries: vec!["serde".to_string(), "vendored-foo".to_string()],
+                location: None,
+            },
+            OverrideSection {
+                kind: OverrideKind::Patch,
+                source: Some("https://github.com/org/repo".to_string()),
+                entries: vec!["vendored-bar".to_string()],
+                location: None,
+            },
+            OverrideSection {
+                kind: OverrideKind::Replace,
+                source: None,
+                entries: vec!["foo:0.1.0".to_string()],
+                location: None,
+            },
+        ]
+    };
+    let mut published = manifest("crates/a/Cargo.toml", true, Vec::new(), BTreeMap::new());
+    published.overrides = overrides();
+    let mut unpublished = manifest("crates/b/Cargo.toml", false, Vec::new(), BTreeMap::new());
+    unpublished.overrides = overrides();
+    let mut virtual_root = manifest("Cargo.toml", true, Vec::new(), BTreeMap::new());
+    virtual_root.package = None;
+    virtual_root.overrides = overrides();
+    let model = model(vec![virtual_root, published, unpublished], BTreeMap::new());
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_NO_PATCH_OVERRIDE,
+        Severity::Error,
+        vec!["vendored-*"],
+        false,
+    );
+    let mut out = Vec::new();
+    no_patch_override::run(&model, &cfg, &mut out);
+
+    let summary: Vec<(&str, &str, &str)> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["manifest"].as_str().unwrap(),
+                f.code.as_str(),
+                f.data["section"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "crates/a/Cargo.toml",
+                ids::CODE_PATCH_SECTION_PRESENT,
+                "patch.crates-io"
+            ),
+            (
+                "crates/a/Cargo.toml",
+                ids::CODE_REPLACE_SECTION_PRESENT,
+                "replace"
+            ),
+        ]
+    );
+    assert_eq!(out[0].data["entries"], json!(["serde"]));
+}
+
+#[test]
+fn no_default_features_flags_omitted_and_explicit_true_declarations() {
+    let spec = |default_features: Option<bool>| DepSpec {
+        version: Some("1".to_string()),
+        default_features,
+        ..DepSpec::default()
+    };
+    let inherited = DepSpec {
+        workspace: true,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("tokio", DepKind::Normal, spec(None), None),
+        dep_decl("reqwest", DepKind::Normal, spec(Some(true)), None),
+        dep_decl("hyper", DepKind::Normal, spec(Some(false)), None),
+        dep_decl(
+            "http_client",
+            DepKind::Dev,
+            DepSpec {
+                rename_of: Some("reqwest".to_string()),
+                ..spec(Some(false))
+            },
+            None,
+        ),
+        dep_decl("tokio-util", DepKind::Normal, inherited.clone(), None),
+        dep_decl("tokio-stream", DepKind::Normal, inherited, None),
+        dep_decl("serde", DepKind::Normal, spec(None), None),
+    ];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let mut disabled = workspace_dep("tokio-util");
+    disabled.1.default_features = Some(false);
+    let model = model(
+        vec![manifest],
+        BTreeMap::from([disabled, workspace_dep("tokio-stream")]),
+    );
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_NO_DEFAULT_FEATURES, Severity::Error);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_NO_DEFAULT_FEATURES)
+        .expect("policy")
+        .deny = vec![
+        "tokio*".to_string(),
+        "reqwest".to_string(),
+        "hyper".to_string(),
+    ];
+
+    let mut out = Vec::new();
+    no_default_features::run(&model, &cfg, &mut out);
+    let flagged: Vec<_> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["dependency"].as_str().unwrap_or_default(),
+                f.data["default_features"].as_str().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        flagged,
+        vec![
+            ("tokio", "omitted"),
+            ("reqwest", "explicit_true"),
+            ("tokio-stream", "omitted"),
+        ]
+    );
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_DEFAULT_FEATURES_ENABLED)
+    );
+    assert_eq!(out[0].data["crate"], "tokio");
+    assert_eq!(out[0].data["inherited"], false);
+    assert_eq!(out[2].data["inherited"], true);
+    assert_eq!(out[2].data["matched_pattern"], "tokio*");
+
+    let cfg = config_with_check(ids::CHECK_DEPS_NO_DEFAULT_FEATURES, Severity::Error);
+    let mut out = Vec::new();
+    no_default_features::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn no_prerelease_flags_prerelease_requirements_in_publishable_packages() {
+    let spec = |version: &str| DepSpec {
+        version: Some(version.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("tokio", DepKind::Normal, spec("1.0.0-beta.1"), None),
+        dep_decl("serde", DepKind::Normal, spec("1.0"), None),
+        dep_decl("axum", DepKind::Dev, spec(">=0.8.0-rc.1, <0.9"), None),
+        dep_decl("bogus", DepKind::Normal, spec("not a version"), None),
+        dep_decl("internal", DepKind::Normal, spec("0.1.0-alpha"), None),
+        dep_decl(
+            "hyper",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let mut ws_hyper = workspace_dep("hyper");
+    ws_hyper.1.version = Some("1.0.0-rc.4".to_string());
+    let model = model(
+        vec![
+            manifest("Cargo.toml", true, deps.clone(), BTreeMap::new()),
+            manifest("crates/private/Cargo.toml", false, deps, BTreeMap::new()),
+        ],
+        BTreeMap::from([ws_hyper]),
+    );
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_NO_PRERELEASE,
+        Severity::Error,
+        vec!["internal"],
+        false,
+    );
+    let mut out = Vec::new();
+    no_prerelease::run(&model, &cfg, &mut out);
+
+    let flagged: Vec<_> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["dependency"].as_str().unwrap_or_default(),
+                f.data["requirement"].as_str().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        flagged,
+        vec![
+            ("tokio", "1.0.0-beta.1"),
+            ("axum", ">=0.8.0-rc.1, <0.9"),
+            ("hyper", "1.0.0-rc.4"),
+        ]
+    );
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_PRERELEASE_VERSION && f.data["manifest"] == "Cargo.toml")
+    );
+    assert_eq!(out[0].data["prerelease"], json!(["beta.1"]));
+    assert_eq!(out[1].data["section"], "dev-dependencies");
+    assert_eq!(out[2].data["inherited"], true);
+    assert_eq!(
+        out[2].data["fix_action"],
+        ids::FIX_ACTION_USE_STABLE_VERSION
+    );
+}
+
+#[test]
+fn version_pinning_forbids_or_requires_exact_pins_by_mode() {
+    let spec = |version: &str| DepSpec {
+        version: Some(version.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde", DepKind::Normal, spec("=1.0.200"), None),
+        dep_decl("tokio", DepKind::Normal, spec("1.40"), None),
+        dep_decl("regex", DepKind::Normal, spec("=1.10"), None),
+        dep_decl("log", DepKind::Dev, spec(">=0.4, <0.5"), None),
+        dep_decl(
+            "local",
+            DepKind::Normal,
+            DepSpec {
+                path: Some("../local".to_string()),
+                ..spec("=0.1.0")
+            },
+            None,
+        ),
+        dep_decl(
+            "shared",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl("bogus", DepKind::Normal, spec("not a version"), None),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let run_with = |mode: Option<PinningMode>| {
+        let mut cfg = config_with_check(ids::CHECK_DEPS_VERSION_PINNING, Severity::Error);
+        cfg.checks
+            .get_mut(ids::CHECK_DEPS_VERSION_PINNING)
+            .expect("policy")
+            .pinning_mode = mode;
+        let mut out = Vec::new();
+        version_pinning::run(&model, &cfg, &mut out);
+        out
+    };
+
+    assert!(run_with(None).is_empty(), "no mode means no findings");
+
+    let out = run_with(Some(PinningMode::ForbidExact));
+    let flagged: Vec<_> = out
+        .iter()
+        .map(|f| f.data["dependency"].as_str().unwrap_or_default())
+        .collect();
+    assert_eq!(flagged, vec!["serde"]);
+    assert_eq!(out[0].code, ids::CODE_EXACT_PIN_FORBIDDEN);
+    assert_eq!(out[0].data["mode"], "forbid_exact");
+    assert_eq!(out[0].data["requirement"], "=1.0.200");
+    assert_eq!(out[0].data["fix_action"], ids::FIX_ACTION_RELAX_EXACT_PIN);
+
+    let out = run_with(Some(PinningMode::RequireExact));
+    let flagged: Vec<_> = out
+        .iter()
+        .map(|f| f.data["dependency"].as_str().unwrap_or_default())
+        .collect();
+    assert_eq!(flagged, vec!["tokio", "regex", "log"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_EXACT_PIN_REQUIRED && f.data["mode"] == "require_exact")
+    );
+}
+
+#[test]
+fn dev_dep_leakage_flags_dev_only_crates_used_in_src() {
+    let deps = vec![
+        dep_decl(
+            "serde-json",
+            DepKind::Dev,
+            DepSpec::default(),
+            Some("cfg(unix)"),
+        ),
+        dep_decl("proptest", DepKind::Dev, DepSpec::default(), None),
+        dep_decl("serde", DepKind::Dev, DepSpec::default(), None),
+        dep_decl("serde", DepKind::Normal, DepSpec::default(), None),
+        dep_decl("tempfile", DepKind::Dev, DepSpec::default(), None),
+    ];
+    let mut model = model(
+        vec![manifest("crates/a/Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_DEV_DEP_LEAKAGE,
+        Severity::Error,
+        vec!["tempfile"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    dev_dep_leakage::run(&model, &cfg, &mut out);
+    assert!(out.is_empty(), "no source scan means no findings");
+
+    let at = |line| Location {
+        path: RepoPath::new("crates/a/src/lib.rs"),
+        line: Some(line),
+        col: None,
+    };
+    let refs: BTreeMap<String, Location> =
+        [("serde_json", at(3)), ("serde", at(4)), ("tempfile", at(5))]
+            .into_iter()
+            .map(|(ident, loc)| (ident.to_string(), loc))
+            .collect();
+    model.source_references = Some(BTreeMap::from([("crates/a/Cargo.toml".to_string(), refs)]));
+
+    dev_dep_leakage::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_DEV_DEP_USED_IN_SRC);
+    assert_eq!(out[0].data["dependency"], "serde-json");
+    assert_eq!(out[0].data["source_file"], "crates/a/src/lib.rs");
+    assert_eq!(out[0].data["target"], "cfg(unix)");
+    assert_eq!(out[0].location.as_ref().and_then(|l| l.line), Some(3));
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_MOVE_TO_NORMAL_DEPS
+    );
+}
+
+#[test]
+fn crate_dir_name_flags_mismatched_members_and_honors_allow() {
+    let mut model = model(
+        vec![
+            manifest("Cargo.toml", true, Vec::new(), BTreeMap::new()),
+            manifest(
+                "crates/foo-bar/Cargo.toml",
+                true,
+                Vec::new(),
+                BTreeMap::new(),
+            ),
+            manifest(
+                "crates/widgets/Cargo.toml",
+                true,
+                Vec::new(),
+                BTreeMap::new(),
+            ),
+            manifest(
+                "vendor/patched/Cargo.toml",
+                true,
+                Vec::new(),
+                BTreeMap::new(),
+            ),
+        ],
+        BTreeMap::new(),
+    );
+    let names = ["root", "foo_bar", "gadgets", "upstream-name"];
+    for (m, name) in model.manifests.iter_mut().zip(names) {
+        m.package.as_mut().expect("package").name = name.to_string();
+    }
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_CRATE_DIR_NAME,
+        Severity::Error,
+        vec!["vendor/*"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    crate_dir_name::run(&model, &cfg, &mut out);
+
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_CRATE_NAME_DIR_MISMATCH);
+    assert_eq!(out[0].data["actual_name"], "gadgets");
+    assert_eq!(out[0].data["expected_name"], "widgets");
+    assert_eq!(out[0].data["directory"], "crates/widgets");
+    assert_eq!(
+        out[0].location.as_ref().map(|l| l.path.as_str()),
+        Some("crates/widgets/Cargo.toml")
+    );
+}
+
+#[test]
+fn workspace_version_override_flags_explicit_versions_only() {
+    let deps = vec![
+        dep_decl(
+            "serde",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1.0.150".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "tokio",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "local",
+            DepKind::Normal,
+            DepSpec {
+                path: Some("../local".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "rand",
+            DepKind::Dev,
+            DepSpec {
+                version: Some("0.7".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "unshared",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1.0".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let manifest = manifest("crates/a/Cargo.toml", true, deps, BTreeMap::new());
+    let mut workspace_deps: BTreeMap<_, _> = ["serde", "tokio", "local", "rand"]
+        .into_iter()
+        .map(workspace_dep)
+        .collect();
+    workspace_deps.get_mut("serde").expect("serde").version = Some("1.0.200".to_string());
+    let model = model(vec![manifest], workspace_deps);
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+        Severity::Warning,
+        vec!["rand"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    workspace_version_override::run(&model, &cfg, &mut out);
+
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_SHOULD_INHERIT_WORKSPACE_DEP);
+    assert_eq!(out[0].data["dependency"], "serde");
+    assert_eq!(out[0].data["declared_version"], "1.0.150");
+    assert_eq!(out[0].data["workspace_version"], "1.0.200");
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_USE_WORKSPACE_TRUE
+    );
+}
+
+#[test]
+fn restrict_build_deps_flags_unlisted_build_deps() {
+    let deps = vec![
+        dep_decl("cc", DepKind::Build, DepSpec::default(), None),
+        dep_decl(
+            "ossl",
+            DepKind::Build,
+            DepSpec {
+                rename_of: Some("openssl-src".to_string()),
+                ..DepSpec::default()
+            },
+            Some("cfg(unix)"),
+        ),
+        dep_decl("serde", DepKind::Normal, DepSpec::default(), None),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_RESTRICT_BUILD_DEPS,
+        Severity::Error,
+        vec!["cc"],
+        false,
+    );
+    let mut out = Vec::new();
+    restrict_build_deps::run(&model, &cfg, &mut out);
+
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_BUILD_DEPENDENCY_PRESENT);
+    assert_eq!(out[0].data["crate"], "openssl-src");
+    assert_eq!(out[0].data["dependency"], "ossl");
+    assert_eq!(out[0].data["kind"], "build");
+    assert_eq!(out[0].data["target"], "cfg(unix)");
+
+    // Without an allowlist every build dependency is flagged.
+    let cfg = config_with_check(ids::CHECK_DEPS_RESTRICT_BUILD_DEPS, Severity::Error);
+    let mut out = Vec::new();
+    restrict_build_deps::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+}
+
+#[test]
+fn version_required_flags_versionless_non_path_deps() {
+    let deps = vec![
+        dep_decl(
+            "git_only",
+            DepKind::Normal,
+            DepSpec {
+                git: Some("https://example.com/git_only".to_string()),
+                branch: Some("main".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "registry_only",
+            DepKind::Normal,
+            DepSpec {
+                registry: Some("internal".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "path_only",
+            DepKind::Normal,
+            DepSpec {
+                path: Some("../path_only".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "inherited",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "versioned",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl("dev_only", DepKind::Dev, DepSpec::default(), None),
+    ];
+    let publishable = manifest("Cargo.toml", true, deps.clone(), BTreeMap::new());
+    let private = manifest("crates/private/Cargo.toml", false, deps, BTreeMap::new());
+    let model = model(vec![publishable, private], BTreeMap::new());
+
+    let cfg = config_with_check(ids::CHECK_DEPS_VERSION_REQUIRED, Severity::Error);
+    let mut out = Vec::new();
+    version_required::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["git_only", "registry_only"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_DEPENDENCY_WITHOUT_VERSION)
+    );
+    assert_eq!(
+        out[0].data["present_fields"],
+        serde_json::json!(["branch", "git"])
+    );
+    assert_eq!(
+        out[1].data["present_fields"],
+        serde_json::json!(["registry"])
+    );
+}
+
+#[test]
+fn misleading_empty_features_flags_empty_list_with_defaults() {
+    let spec = |features: Option<Vec<String>>, default_features: Option<bool>| DepSpec {
+        version: Some("1".to_string()),
+        features,
+        default_features,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("empty", DepKind::Normal, spec(Some(vec![]), None), None),
+        dep_decl(
+            "empty_true",
+            DepKind::Dev,
+            spec(Some(vec![]), Some(true)),
+            None,
+        ),
+        dep_decl(
+            "empty_disabled",
+            DepKind::Normal,
+            spec(Some(vec![]), Some(false)),
+            None,
+        ),
+        dep_decl(
+            "listed",
+            DepKind::Normal,
+            spec(Some(vec!["std".to_string()]), None),
+            None,
+        ),
+        dep_decl("absent", DepKind::Normal, spec(None, None), None),
+        dep_decl(
+            "inherited",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                features: Some(vec![]),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let mut ws_dep = workspace_dep("inherited");
+    ws_dep.1.default_features = Some(false);
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::from([ws_dep]),
+    );
+
+    let cfg = config_with_check(ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES, Severity::Warning);
+    let mut out = Vec::new();
+    misleading_empty_features::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["empty", "empty_true"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_EMPTY_FEATURES_WITH_DEFAULTS)
+    );
+    assert_eq!(out[1].data["section"], "dev-dependencies");
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_DISABLE_DEFAULT_FEATURES
+    );
+}
+
+#[test]
+fn misleading_empty_features_allowlist_matches_real_crate_name() {
+    let renamed = |name: &str, real: &str| {
+        dep_decl(
+            name,
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                features: Some(vec![]),
+                rename_of: Some(real.to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        )
+    };
+    let deps = vec![renamed("http", "reqwest"), renamed("tls", "rustls")];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES,
+        Severity::Warning,
+        vec!["reqwest", "tls"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    misleading_empty_features::run(&model, &cfg, &mut out);
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["tls"]);
+}
+
+#[test]
+fn explicit_dep_feature_flags_bare_references_to_optional_deps() {
+    let optional = DepSpec {
+        version: Some("1".to_string()),
+        optional: true,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("bare", DepKind::Normal, optional.clone(), None),
+        dep_decl("explicit", DepKind::Normal, optional.clone(), None),
+        dep_decl("weak", DepKind::Normal, optional, None),
+        dep_decl(
+            "required",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let features = BTreeMap::from([
+        ("full".to_string(), vec!["bare/std".to_string()]),
+        (
+            "tls".to_string(),
+            vec![
+                "bare".to_string(),
+                "dep:explicit".to_string(),
+                "explicit/std".to_string(),
+                "weak?/std".to_string(),
+                "required".to_string(),
+            ],
+        ),
+    ]);
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, features)],
+        BTreeMap::new(),
+    );
+
+    let cfg = config_with_check(ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE, Severity::Warning);
+    let mut out = Vec::new();
+    explicit_dep_feature::run(&model, &cfg, &mut out);
+
+    let pairs: Vec<_> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["dependency"].as_str().unwrap_or_default(),
+                f.data["feature"].as_str().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(pairs, vec![("bare", "full"), ("bare", "tls")]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_IMPLICIT_OPTIONAL_FEATURE)
+    );
+    assert_eq!(out[0].data["value"], "bare/std");
+    assert_ne!(out[0].fingerprint, out[1].fingerprint);
+}
+
+#[test]
+fn max_direct_deps_counts_each_limited_section() {
+    let version = || DepSpec {
+        version: Some("1".to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("anyhow", DepKind::Normal, version(), None),
+        dep_decl("serde", DepKind::Normal, version(), None),
+        dep_decl("libc", DepKind::Normal, version(), Some("cfg(unix)")),
+        dep_decl(
+            "libc",
+            DepKind::Normal,
+            version(),
+            Some("cfg(target_os = \"linux\")"),
+        ),
+        dep_decl("proptest", DepKind::Dev, version(), None),
+        dep_decl("insta", DepKind::Dev, version(), None),
+        dep_decl("cc", DepKind::Build, version(), None),
+        dep_decl("prost-build", DepKind::Build, version(), None),
+    ];
+    let mut big = manifest("crates/big/Cargo.toml", true, deps.clone(), BTreeMap::new());
+    if let Some(pkg) = big.package.as_mut() {
+        pkg.name = "big".to_string();
+    }
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new()), big],
+        BTreeMap::new(),
+    );
+
+    let mut cfg = config_with_check_allow(
+        ids::CHECK_DEPS_MAX_DIRECT_DEPS,
+        Severity::Warning,
+        vec!["big"],
+        false,
+    );
+    let run_with = |cfg: &crate::policy::EffectiveConfig| {
+        let mut out = Vec::new();
+        max_direct_deps::run(&model, cfg, &mut out);
+        out
+    };
+
+    // No limits configured: inert.
+    assert!(run_with(&cfg).is_empty());
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_MAX_DIRECT_DEPS)
+        .expect("policy")
+        .dependency_limits = DependencyLimits {
+        normal: Some(2),
+        dev: Some(1),
+        build: None,
+    };
+    let out = run_with(&cfg);
+
+    let sections: Vec<_> = out.iter().map(|f| f.data["section"].clone()).collect();
+    assert_eq!(sections, vec!["dependencies", "dev-dependencies"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_TOO_MANY_DEPENDENCIES)
+    );
+    assert_eq!(out[0].data["count"], 3);
+    assert_eq!(out[0].data["limit"], 2);
+    assert_eq!(out[1].data["count"], 2);
+    let location = out[0].location.as_ref().expect("location");
+    assert_eq!(location.path.as_str(), "Cargo.toml");
+    assert_eq!(location.line, Some(1));
+}
+
+#[test]
+fn no_open_ranges_flags_lower_bound_without_upper_bound() {
+    let version = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("open", DepKind::Normal, version(">=1.0"), None),
+        dep_decl("open_pair", DepKind::Dev, version(">1.2, >=1.3"), None),
+        dep_decl("capped", DepKind::Normal, version(">=1.0, <2"), None),
+        dep_decl("caret", DepKind::Normal, version("1.0"), None),
+        dep_decl("wildcard", DepKind::Normal, version(">=1.*"), None),
+        dep_decl("invalid", DepKind::Normal, version(">=not-a-version"), None),
+    ];
+    let publishable = manifest("Cargo.toml", true, deps.clone(), BTreeMap::new());
+    let private = manifest("crates/private/Cargo.toml", false, deps, BTreeMap::new());
+    let model = model(vec![publishable, private], BTreeMap::new());
+
+    let cfg = config_with_check(ids::CHECK_DEPS_NO_OPEN_RANGES, Severity::Error);
+    let mut out = Vec::new();
+    no_open_ranges::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["open", "open_pair"]);
+    assert!(out.iter().all(|f| f.code == ids::CODE_OPEN_ENDED_RANGE));
+    assert_eq!(out[0].data["requirement"], ">=1.0");
+    assert_eq!(out[1].data["section"], "dev-dependencies");
+}
+
+#[test]
+fn publish_reachability_flags_path_deps_on_unpublishable_members() {
+    let path = |p: &str| DepSpec {
+        path: Some(p.to_string()),
+        version: Some("0.1".to_string()),
+        ..DepSpec::default()
+    };
+    let inherited = DepSpec {
+        workspace: true,
+        ..DepSpec::default()
+    };
+    let app_deps = vec![
+        dep_decl("internal", DepKind::Normal, path("../internal"), None),
+        dep_decl("codegen", DepKind::Build, path("./../internal/"), None),
+        dep_decl("shared", DepKind::Normal, inherited, Some("cfg(unix)")),
+        dep_decl("public", DepKind::Normal, path("../public"), None),
+        dep_decl("fixtures", DepKind::Dev, path("../internal"), None),
+        dep_decl("outside", DepKind::Normal, path("../../../elsewhere"), None),
+    ];
+    let app = manifest(
+        "crates/app/Cargo.toml",
+        true,
+        app_deps.clone(),
+        BTreeMap::new(),
+    );
+    let private_app = manifest("crates/tool/Cargo.toml", false, app_deps, BTreeMap::new());
+    let internal = manifest("crates/internal/Cargo.toml", false, vec![], BTreeMap::new());
+    let public = manifest("crates/public/Cargo.toml", true, vec![], BTreeMap::new());
+
+    let (name, mut shared) = workspace_dep("shared");
+    shared.path = Some("crates/internal".to_string());
+    let model = model(
+        vec![app, private_app, internal, public],
+        BTreeMap::from([(name, shared)]),
+    );
+
+    let cfg = config_with_check(ids::CHECK_DEPS_PUBLISH_REACHABILITY, Severity::Error);
+    let mut out = Vec::new();
+    publish_reachability::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["internal", "codegen", "shared"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_DEPENDS_ON_UNPUBLISHABLE
+                && f.data["dependency_manifest"] == "crates/internal/Cargo.toml")
+    );
+    assert_eq!(out[1].data["section"], "build-dependencies");
+    assert_eq!(out[2].data["path"], "crates/internal");
+    assert_eq!(out[2].data["target"], "cfg(unix)");
+}
+
+#[test]
+fn secure_git_url_flags_http_and_git_schemes() {
+    let git = |url: &str| DepSpec {
+        git: Some(url.to_string()),
+        rev: Some("abc123".to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl(
+            "plain",
+            DepKind::Normal,
+            git("http://example.com/plain"),
+            None,
+        ),
+        dep_decl(
+            "daemon",
+            DepKind::Build,
+            git("GIT://example.com/daemon.git"),
+            None,
+        ),
+        dep_decl(
+            "https",
+            DepKind::Normal,
+            git("https://example.com/https"),
+            None,
+        ),
+        dep_decl(
+            "ssh",
+            DepKind::Normal,
+            git("ssh://git@example.com/ssh"),
+            None,
+        ),
+        dep_decl(
+            "scp",
+            DepKind::Normal,
+            git("git@example.com:org/scp.git"),
+            None,
+        ),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", false, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+
+    let cfg = config_with_check(ids::CHECK_DEPS_SECURE_GIT_URL, Severity::Error);
+    let mut out = Vec::new();
+    secure_git_url::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["plain", "daemon"]);
+    assert!(out.iter().all(|f| f.code == ids::CODE_INSECURE_GIT_URL));
+    assert_eq!(out[0].data["url"], "http://example.com/plain");
+    assert_eq!(out[0].data["scheme"], "http");
+    assert_eq!(out[1].data["scheme"], "git");
+}
+
+#[test]
+fn valid_target_cfg_flags_unparseable_specs_with_their_dependencies() {
+    let target = |raw: &str, valid: bool, line: u32| TargetSpec {
+        raw: raw.to_string(),
+        valid,
+        location: Some(Location {
+            path: RepoPath::new("Cargo.toml"),
+            line: Some(line),
+            col: None,
+        }),
+    };
+    let version = || DepSpec {
+        version: Some("1".to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("libc", DepKind::Normal, version(), Some("cfg(unix)")),
+        dep_decl(
+            "winapi",
+            DepKind::Normal,
+            version(),
+            Some("cfg(feature = )"),
+        ),
+        dep_decl("helper", DepKind::Dev, version(), Some("cfg(feature = )")),
+    ];
+    let mut m = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    m.targets = vec![
+        target("cfg(unix)", true, 4),
+        target("cfg(feature = )", false, 7),
+        target("x86_64-unknown-linux-gnu", true, 10),
+    ];
+    let model = model(vec![m], BTreeMap::new());
+
+    let cfg = config_with_check(ids::CHECK_DEPS_VALID_TARGET_CFG, Severity::Warning);
+    let mut out = Vec::new();
+    valid_target_cfg::run(&model, &cfg, &mut out);
+
+    assert_eq!(out.len(), 1);
+    let finding = &out[0];
+    assert_eq!(finding.code, ids::CODE_INVALID_TARGET_CFG);
+    assert_eq!(finding.data["target"], "cfg(feature = )");
+    assert_eq!(finding.data["dependencies"], json!(["winapi", "helper"]));
+    assert_eq!(finding.location.as_ref().and_then(|l| l.line), Some(7));
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_VALID_TARGET_CFG,
+        Severity::Warning,
+        vec!["cfg(feature = )"],
+        false,
+    );
+    let mut out = Vec::new();
+    valid_target_cfg::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn no_zerover_in_stable_flags_pre_1_0_normal_deps_of_stable_packages() {
+    let version = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("bytes", DepKind::Normal, version("0.4"), None),
+        dep_decl(
+            "pinned",
+            DepKind::Normal,
+            version("=0.1.2"),
+            Some("cfg(unix)"),
+        ),
+        dep_decl("open", DepKind::Normal, version(">=0.9"), None),
+        dep_decl("serde", DepKind::Normal, version("1.0"), None),
+        dep_decl("libc", DepKind::Normal, version("0.2"), None),
+        dep_decl("rand", DepKind::Dev, version("0.8"), None),
+        dep_decl(
+            "inherited",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let mut stable = manifest("Cargo.toml", true, deps.clone(), BTreeMap::new());
+    stable.package.as_mut().unwrap().version = Some("1.2.0".to_string());
+    let mut unstable = manifest("crates/beta/Cargo.toml", true, deps, BTreeMap::new());
+    unstable.package.as_mut().unwrap().version = Some("0.9.0".to_string());
+    let (ws_name, mut ws_inherited) = workspace_dep("inherited");
+    ws_inherited.version = Some("0.3".to_string());
+    let model = model(
+        vec![stable, unstable],
+        BTreeMap::from([(ws_name, ws_inherited)]),
+    );
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE,
+        Severity::Error,
+        vec!["libc"],
+        false,
+    );
+    let mut out = Vec::new();
+    no_zerover_in_stable::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["bytes", "pinned", "inherited"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE)
+    );
+    assert_eq!(out[0].data["package_version"], "1.2.0");
+    assert_eq!(out[1].data["requirement"], "=0.1.2");
+    assert_eq!(out[1].data["target"], "cfg(unix)");
+    assert_eq!(out[2].data["requirement"], "0.3");
+    assert_eq!(out[2].data["inherited"], true);
+}
+
+#[test]
+fn license_present_flags_missing_and_disallowed_licenses() {
+    let packaged =
+        |path: &str, publish: bool, license: Option<&str>, license_file: Option<&str>| {
+            let mut m = manifest(path, publish, Vec::new(), BTreeMap::new());
+            let pkg = m.package.as_mut().unwrap();
+            pkg.license = license.map(str::to_string);
+            pkg.license_file = license_file.map(str::to_string);
+            m
+        };
+    let model = model(
+        vec![
+            packaged("crates/none/Cargo.toml", true, None, None),
+            packaged("crates/private/Cargo.toml", false, None, None),
+            packaged("crates/file/Cargo.toml", true, None, Some("LICENSE")),
+            packaged("crates/dual/Cargo.toml", true, Some("MIT OR GPL-3.0"), None),
+            packaged(
+                "crates/slash/Cargo.toml",
+                true,
+                Some("MIT/Apache-2.0"),
+                None,
+            ),
+            packaged(
+                "crates/both/Cargo.toml",
+                true,
+                Some("MIT AND GPL-3.0"),
+                None,
+            ),
+            packaged(
+                "crates/llvm/Cargo.toml",
+                true,
+                Some("(Apache-2.0 WITH LLVM-exception) OR GPL-3.0"),
+                None,
+            ),
+            packaged("crates/broken/Cargo.toml", true, Some("MIT OR"), None),
+        ],
+        BTreeMap::new(),
+    );
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_LICENSE_PRESENT, Severity::Error);
+    let mut out = Vec::new();
+    license_present::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_MISSING_LICENSE);
+    assert_eq!(out[0].data["license"], "none");
+    assert_eq!(out[0].data["manifest"], "crates/none/Cargo.toml");
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_LICENSE_PRESENT)
+        .expect("policy")
+        .allowed_licenses = vec!["mit".to_string(), "Apache-2.0".to_string()];
+    out.clear();
+    license_present::run(&model, &cfg, &mut out);
+    let flagged: Vec<_> = out
+        .iter()
+        .filter(|f| f.code == ids::CODE_DISALLOWED_LICENSE)
+        .map(|f| f.data["license"].clone())
+        .collect();
+    assert_eq!(flagged, vec!["MIT AND GPL-3.0", "MIT OR"]);
+    assert_eq!(
+        out[1].data["allowed_licenses"],
+        json!(["mit", "Apache-2.0"])
+    );
+}
+
+#[test]
+fn no_rename_reports_key_and_real_crate() {
+    let renamed = |name: &str, real: &str| {
+        dep_decl(
+            name,
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                rename_of: Some(real.to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        )
+    };
+    let deps = vec![
+        renamed("http-client", "reqwest"),
+        dep_decl("serde", DepKind::Normal, DepSpec::default(), None),
+        renamed("rand_old", "rand"),
+        renamed("log_compat", "log"),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_NO_RENAME,
+        Severity::Warning,
+        vec!["rand_old", "log"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    no_rename::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_DEPENDENCY_RENAMED);
+    assert_eq!(out[0].data["dependency"], "http-client");
+    assert_eq!(out[0].data["crate"], "reqwest");
+    assert_eq!(out[0].data["current_spec"]["package"], "reqwest");
+}
+
+#[test]
+fn consistent_versions_flags_incompatible_requirements_across_manifests() {
+    let version = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        ..DepSpec::default()
+    };
+    let root = manifest(
+        "Cargo.toml",
+        true,
+        vec![
+            dep_decl("rand", DepKind::Normal, version("0.8"), None),
+            dep_decl("serde", DepKind::Normal, version("1.0"), None),
+            dep_decl("log", DepKind::Normal, version("0.4"), None),
+            dep_decl("windows-sys", DepKind::Normal, version("0.48"), None),
+        ],
+        BTreeMap::new(),
+    );
+    let member = manifest(
+        "crates/sim/Cargo.toml",
+        true,
+        vec![
+            dep_decl("rand", DepKind::Dev, version("0.9"), Some("cfg(unix)")),
+            dep_decl("serde", DepKind::Normal, version("1.4"), None),
+            dep_decl(
+                "logger",
+                DepKind::Normal,
+                DepSpec {
+                    workspace: true,
+                    rename_of: Some("log".to_string()),
+                    ..DepSpec::default()
+                },
+                None,
+            ),
+            dep_decl("windows-sys", DepKind::Normal, version("0.52"), None),
+            dep_decl("open", DepKind::Normal, version(">=0.1"), None),
+        ],
+        BTreeMap::new(),
+    );
+    let (ws_name, mut ws_logger) = workspace_dep("logger");
+    ws_logger.version = Some("0.3".to_string());
+    let model = model(vec![root, member], BTreeMap::from([(ws_name, ws_logger)]));
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_CONSISTENT_VERSIONS, Severity::Warning);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_CONSISTENT_VERSIONS)
+        .expect("policy")
+        .ignore = vec!["windows-*".to_string()];
+
+    let mut out = Vec::new();
+    consistent_versions::run(&model, &cfg, &mut out);
+    let crates: Vec<_> = out.iter().map(|f| f.data["crate"].clone()).collect();
+    assert_eq!(crates, vec!["log", "rand"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_DIVERGENT_VERSION_REQUIREMENTS)
+    );
+
+    let rand = &out[1];
+    assert_eq!(rand.data["manifest"], "crates/sim/Cargo.toml");
+    assert_eq!(
+        rand.data["declarations"],
+        json!([
+            {"manifest": "Cargo.toml", "requirement": "0.8", "section": "dependencies"},
+            {
+                "manifest": "crates/sim/Cargo.toml",
+                "requirement": "0.9",
+                "section": "dev-dependencies",
+                "target": "cfg(unix)",
+            },
+        ])
+    );
+    assert_eq!(out[0].data["declarations"][1]["requirement"], "0.3");
+}
+
+#[test]
+fn no_optional_dev_deps_flags_only_optional_dev_dependencies() {
+    let optional = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        optional: true,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("proptest", DepKind::Dev, optional("1"), None),
+        dep_decl(
+            "criterion",
+            DepKind::Dev,
+            optional("0.5"),
+            Some("cfg(unix)"),
+        ),
+        dep_decl("serde", DepKind::Normal, optional("1"), None),
+        dep_decl(
+            "insta",
+            DepKind::Dev,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check(ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS, Severity::Error);
+
+    let mut out = Vec::new();
+    no_optional_dev_deps::run(&model, &cfg, &mut out);
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["proptest", "criterion"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_OPTIONAL_DEV_DEPENDENCY)
+    );
+    assert_eq!(out[0].data["section"], "dev-dependencies");
+    assert_eq!(out[1].data["target"], "cfg(unix)");
+}
+
+#[test]
+fn git_version_conflict_flags_git_dependencies_with_version() {
+    let git = |version: Option<&str>| DepSpec {
+        git: Some("https://github.com/org/foo".to_string()),
+        tag: Some("v2.0.0".to_string()),
+        version: version.map(str::to_string),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("foo", DepKind::Normal, git(Some("2.0")), None),
+        dep_decl("bar", DepKind::Normal, git(None), None),
+        dep_decl("forked", DepKind::Dev, git(Some("1")), None),
+        dep_decl(
+            "serde",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_GIT_VERSION_CONFLICT,
+        Severity::Warning,
+        vec!["forked"],
+        false,
+    );
+
+    let mut out = Vec::new();
+    git_version_conflict::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_GIT_WITH_VERSION);
+    assert_eq!(out[0].data["dependency"], "foo");
+    assert_eq!(out[0].data["git"], "https://github.com/org/foo");
+    assert_eq!(out[0].data["version"], "2.0");
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_REMOVE_GIT_VERSION
+    );
+}
+
+fn msrv_member(
+    name: &str,
+    rust_version: Option<&str>,
+    inherited: bool,
+) -> crate::model::ManifestModel {
+    let mut m = manifest(
+        &format!("crates/{name}/Cargo.toml"),
+        true,
+        Vec::new(),
+        BTreeMap::new(),
+    );
+    m.package = Some(PackageMeta {
+        name: name.to_string(),
+        publish: true,
+        rust_version: rust_version.map(str::to_string),
+        inherited: if inherited {
+            ["rust-version".to_string()].into()
+        } else {
+            Default::default()
+        },
+        ..PackageMeta::default()
+    });
+    m
+}
+
+#[test]
+fn consistent_msrv_flags_members_diverging_from_inherited_value() {
+    let model = model(
+        vec![
+            msrv_member("a", Some("1.74"), true),
+            msrv_member("b", Some("1.70"), false),
+            msrv_member("c", Some("1.70"), false),
+            msrv_member("d", Some("1.74.0"), false),
+            msrv_member("e", None, false),
+        ],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check(ids::CHECK_DEPS_CONSISTENT_MSRV, Severity::Warning);
+
+    let mut out = Vec::new();
+    consistent_msrv::run(&model, &cfg, &mut out);
+    let packages: Vec<_> = out.iter().map(|f| f.data["package"].clone()).collect();
+    assert_eq!(packages, vec!["b", "c"]);
+    assert!(out.iter().all(|f| f.code == ids::CODE_INCONSISTENT_MSRV));
+    assert_eq!(out[0].data["current"], "1.70");
+    assert_eq!(out[0].data["expected"], "1.74");
+    assert_eq!(out[0].data["members"].as_array().map(Vec::len), Some(4));
+    assert_eq!(out[0].data["members"][0]["inherited"], true);
+}
+
+#[test]
+fn consistent_msrv_uses_most_common_value_without_inheritance() {
+    let model = model(
+        vec![
+            msrv_member("a", Some("1.70"), false),
+            msrv_member("b", Some("1.74"), false),
+            msrv_member("c", Some("1.74"), false),
+        ],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check(ids::CHECK_DEPS_CONSISTENT_MSRV, Severity::Warning);
+
+    let mut out = Vec::new();
+    consistent_msrv::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].data["package"], "a");
+    assert_eq!(out[0].data["expected"], "1.74");
+
+    let agreeing = model_with_msrvs(&["1.74", "1.74.0"]);
+    let mut out = Vec::new();
+    consistent_msrv::run(&agreeing, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+fn model_with_msrvs(versions: &[&str]) -> crate::model::WorkspaceModel {
+    model(
+        versions
+            .iter()
+            .enumerate()
+            .map(|(i, v)| msrv_member(&format!("m{i}"), Some(v), false))
+            .collect(),
+        BTreeMap::new(),
+    )
+}
+
+#[test]
+fn consistent_msrv_require_inherit_flags_non_inheriting_members() {
+    let model = model(
+        vec![
+            msrv_member("a", Some("1.74"), true),
+            msrv_member("b", Some("1.74"), false),
+            msrv_member("c", None, false),
+        ],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_CONSISTENT_MSRV, Severity::Warning);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_CONSISTENT_MSRV)
+        .expect("policy")
+        .msrv_mode = MsrvMode::RequireInherit;
+
+    let mut out = Vec::new();
+    consistent_msrv::run(&model, &cfg, &mut out);
+    let packages: Vec<_> = out.iter().map(|f| f.data["package"].clone()).collect();
+    assert_eq!(packages, vec!["b", "c"]);
+    assert!(out.iter().all(|f| f.code == ids::CODE_MSRV_NOT_INHERITED));
+    assert_eq!(out[0].data["current"], "1.74");
+    assert!(out[1].data.get("current").is_none());
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_INHERIT_RUST_VERSION
+    );
+
+    let single = model_with_msrvs(&["1.74"]);
+    let mut out = Vec::new();
+    consistent_msrv::run(&single, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn lock_in_sync_reports_declared_deps_missing_from_lockfile() {
+    let renamed = DepSpec {
+        version: Some("0.10".to_string()),
+        rename_of: Some("openssl".to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde", DepKind::Normal, DepSpec::default(), None),
+        dep_decl("tokio", DepKind::Normal, DepSpec::default(), None),
+        dep_decl("ssl", DepKind::Normal, renamed, None),
+        dep_decl("internal-tool", DepKind::Dev, DepSpec::default(), None),
+    ];
+    let mut model = model(
+        vec![
+            manifest("Cargo.toml", true, Vec::new(), BTreeMap::new()),
+            manifest("crates/a/Cargo.toml", true, deps, BTreeMap::new()),
+        ],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_LOCK_IN_SYNC, Severity::Error);
+
+    let mut out = Vec::new();
+    lock_in_sync::run(&model, &cfg, &mut out);
+    assert!(out.is_empty(), "no lockfile means the check is skipped");
+
+    let resolved = ["serde", "ssl"]
+        .into_iter()
+        .map(|name| (name.to_string(), BTreeSet::from(["1.0.0".to_string()])))
+        .collect();
+    model.resolved_versions = Some(resolved);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_LOCK_IN_SYNC)
+        .expect("policy")
+        .allow = vec!["internal-*".to_string()];
+
+    lock_in_sync::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    let finding = &out[0];
+    assert_eq!(finding.code, ids::CODE_DEPENDENCY_MISSING_FROM_LOCK);
+    assert_eq!(
+        finding.location.as_ref().map(|l| l.path.as_str()),
+        Some("Cargo.toml")
+    );
+    // Renamed deps are looked up by their real package name.
+    assert_eq!(finding.data["missing"], json!(["openssl", "tokio"]));
+    assert_eq!(
+        finding.data["fix_action"],
+        ids::FIX_ACTION_REGENERATE_LOCKFILE
+    );
+}
+
+#[test]
+fn members_exist_reports_each_dangling_member_entry() {
+    let mut model = model(
+        vec![manifest("Cargo.toml", true, Vec::new(), BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_MEMBERS_EXIST, Severity::Error);
+
+    let mut out = Vec::new();
+    members_exist::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+
+    model.unresolved_members = ["crates/typo", "vendor/*"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, pattern)| UnresolvedMember {
+            pattern: pattern.to_string(),
+            location: Some(Location {
+                path: RepoPath::new("Cargo.toml"),
+                line: Some(3 + i as u32),
+                col: None,
+            }),
+        })
+        .collect();
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_MEMBERS_EXIST)
+        .expect("policy")
+        .allow = vec!["vendor/*".to_string()];
+
+    members_exist::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    let finding = &out[0];
+    assert_eq!(finding.code, ids::CODE_WORKSPACE_MEMBER_NOT_FOUND);
+    assert_eq!(finding.data["pattern"], "crates/typo");
+    assert_eq!(
+        finding.data["fix_action"],
+        ids::FIX_ACTION_FIX_WORKSPACE_MEMBER
+    );
+    let location = finding.location.as_ref().expect("location");
+    assert_eq!(location.path.as_str(), "Cargo.toml");
+    assert_eq!(location.line, Some(3));
+}
+
+#[test]
+fn no_nested_workspace_reports_nested_roots_and_honors_allow() {
+    let mut model = model(
+        vec![manifest("Cargo.toml", true, Vec::new(), BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check(ids::CHECK_DEPS_NO_NESTED_WORKSPACE, Severity::Error);
+
+    let mut out = Vec::new();
+    no_nested_workspace::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+
+    model.nested_workspaces = ["examples/demo/Cargo.toml", "tools/Cargo.toml"]
+        .into_iter()
+        .map(|path| Location {
+            path: RepoPath::new(path),
+            line: Some(1),
+            col: None,
+        })
+        .collect();
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_NO_NESTED_WORKSPACE)
+        .expect("policy")
+        .allow = vec!["examples/*".to_string()];
+
+    no_nested_workspace::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    let finding = &out[0];
+    assert_eq!(finding.code, ids::CODE_NESTED_WORKSPACE_DETECTED);
+    assert_eq!(finding.data["workspace_root"], "tools");
+    assert_eq!(finding.data["manifest"], "tools/Cargo.toml");
+    assert_eq!(
+        finding.location.as_ref().map(|l| (l.path.as_str(), l.line)),
+        Some(("tools/Cargo.toml", Some(1)))
+    );
+}
+
+#[test]
+fn deprecated_crates_suggests_configured_replacement() {
+    let version = |v: &str| DepSpec {
+        version: Some(v.to_string()),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde_yaml", DepKind::Normal, version("0.9"), None),
+        dep_decl("serde", DepKind::Normal, version("1"), None),
+        dep_decl(
+            "lazy",
+            DepKind::Dev,
+            DepSpec {
+                rename_of: Some("lazy_static".to_string()),
+                ..version("1")
+            },
+            None,
+        ),
+        dep_decl("structopt", DepKind::Normal, version("0.3"), None),
+    ];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+
+    let mut cfg = config_with_check_allow(
+        ids::CHECK_DEPS_DEPRECATED_CRATES,
+        Severity::Warning,
+        vec!["structopt"],
+        false,
+    );
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_DEPRECATED_CRATES)
+        .expect("policy")
+        .options
+        .insert(
+            "deprecated".to_string(),
+            json!({
+                "serde_yaml": "serde_yml",
+                "lazy_static": "std::sync::LazyLock",
+                "structopt": "clap",
+            }),
+        );
+
+    let mut out = Vec::new();
+    deprecated_crates::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+    assert!(out.iter().all(|f| f.code == ids::CODE_DEPRECATED_CRATE));
+    assert_eq!(out[0].data["dependency"], "serde_yaml");
+    assert_eq!(out[0].data["replacement"], "serde_yml");
+    assert_eq!(
+        out[0].help.as_deref(),
+        Some("Replace 'serde_yaml' with 'serde_yml'.")
+    );
+    assert!(out[0].data.get("crate").is_none());
+    assert_eq!(out[1].data["dependency"], "lazy");
+    assert_eq!(out[1].data["crate"], "lazy_static");
+    assert_eq!(out[1].data["replacement"], "std::sync::LazyLock");
+    assert_eq!(out[1].data["section"], "dev-dependencies");
+}
+
+#[test]
+fn deprecated_crates_is_noop_without_map() {
+    let deps = vec![dep_decl(
+        "serde_yaml",
+        DepKind::Normal,
+        DepSpec::default(),
+        None,
+    )];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(vec![manifest], BTreeMap::new());
+    let cfg = config_with_check(ids::CHECK_DEPS_DEPRECATED_CRATES, Severity::Error);
+
+    let mut out = Vec::new();
+    deprecated_crates::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn publish_metadata_reports_missing_required_keys() {
+    let packaged = |path: &str, publish: bool, description: Option<&str>, keywords: &[&str]| {
+        let mut m = manifest(path, publish, Vec::new(), BTreeMap::new());
+        let pkg = m.package.as_mut().unwrap();
+        pkg.description = description.map(str::to_string);
+        pkg.repository = Some("https://github.com/org/repo".to_string());
+        pkg.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        pkg.location = Some(Location {
+            path: RepoPath::new(path),
+            line: Some(1),
+            col: None,
+        });
+        m
+    };
+    let model = model(
+        vec![
+            packaged("crates/bare/Cargo.toml", true, None, &[]),
+            packaged("crates/blank/Cargo.toml", true, Some("  "), &["cli"]),
+            packaged("crates/private/Cargo.toml", false, None, &[]),
+            packaged(
+                "crates/full/Cargo.toml",
+                true,
+                Some("Parses widgets"),
+                &["cli"],
+            ),
+        ],
+        BTreeMap::new(),
+    );
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_PUBLISH_METADATA, Severity::Warning);
+    let mut out = Vec::new();
+    publish_metadata::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_MISSING_PUBLISH_METADATA)
+    );
+    assert_eq!(out[0].data["manifest"], "crates/bare/Cargo.toml");
+    assert_eq!(out[0].data["missing"], json!(["description"]));
+    assert_eq!(out[0].location.as_ref().unwrap().line, Some(1));
+    assert_eq!(out[1].data["manifest"], "crates/blank/Cargo.toml");
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_PUBLISH_METADATA)
+        .expect("policy")
+        .options
+        .insert("require".to_string(), json!(["repository", "keywords"]));
+    let mut out = Vec::new();
+    publish_metadata::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].data["manifest"], "crates/bare/Cargo.toml");
+    assert_eq!(out[0].data["missing"], json!(["keywords"]));
+    assert_eq!(
+        out[0].message,
+        "package 'pkg' is missing publish metadata: keywords"
+    );
+}
+
+#[test]
+fn banned_features_flags_explicit_inherited_and_implicit_default() {
+    let spec = |features: Option<&[&str]>, default_features: Option<bool>| DepSpec {
+        version: Some("1".to_string()),
+        features: features.map(|f| f.iter().map(|s| s.to_string()).collect()),
+        default_features,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl(
+            "tokio",
+            DepKind::Normal,
+            spec(Some(&["rt", "process"]), Some(false)),
+            None,
+        ),
+        dep_decl(
+            "reqwest",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..spec(None, None)
+            },
+            None,
+        ),
+        dep_decl("openssl", DepKind::Build, spec(None, Some(false)), None),
+        dep_decl(
+            "ring",
+            DepKind::Dev,
+            DepSpec {
+                rename_of: Some("openssl".to_string()),
+                ..spec(Some(&["vendored"]), Some(false))
+            },
+            None,
+        ),
+    ];
+    let (_, mut reqwest) = workspace_dep("reqwest");
+    reqwest.features = vec!["native-tls".to_string()];
+    let manifest = manifest("Cargo.toml", true, deps, BTreeMap::new());
+    let model = model(
+        vec![manifest],
+        BTreeMap::from([("reqwest".to_string(), reqwest)]),
+    );
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_BANNED_FEATURES, Severity::Error);
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_BANNED_FEATURES)
+        .expect("policy")
+        .options
+        .insert(
+            "features".to_string(),
+            json!({
+                "tokio": { "features": ["process"], "reason": "spawning processes needs review" },
+                "reqwest": ["native-tls", "default"],
+                "openssl": ["vendored", "default"],
+            }),
+        );
+
+    let mut out = Vec::new();
+    banned_features::run(&model, &cfg, &mut out);
+    let hits: Vec<(&str, &str, bool)> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["dependency"].as_str().unwrap(),
+                f.data["feature"].as_str().unwrap(),
+                f.data["implicit"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        hits,
+        vec![
+            ("tokio", "process", false),
+            ("reqwest", "native-tls", false),
+            ("reqwest", "default", true),
+            ("ring", "vendored", false),
+        ]
+    );
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_BANNED_FEATURE_ENABLED)
+    );
+    assert_eq!(out[0].data["reason"], "spawning processes needs review");
+    assert!(
+        out[0]
+            .message
+            .ends_with(": spawning processes needs review")
+    );
+    assert_eq!(out[1].data["reason"], "banned by policy");
+    assert_eq!(out[1].data["inherited"], true);
+    assert_eq!(out[3].data["crate"], "openssl");
+    assert_ne!(out[1].fingerprint, out[2].fingerprint);
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_BANNED_FEATURES)
+        .expect("policy")
+        .allow = vec!["reqwest".to_string()];
+    let mut out = Vec::new();
+    banned_features::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 2);
+}
+
+#[test]
+fn features_after_default_off_flags_disabled_defaults_without_features() {
+    let spec = |features: Option<Vec<String>>, default_features: Option<bool>| DepSpec {
+        version: Some("1".to_string()),
+        features,
+        default_features,
+        ..DepSpec::default()
+    };
+    let inherited = DepSpec {
+        workspace: true,
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("bare", DepKind::Normal, spec(None, Some(false)), None),
+        dep_decl(
+            "empty_list",
+            DepKind::Build,
+            spec(Some(vec![]), Some(false)),
+            None,
+        ),
+        dep_decl(
+            "listed",
+            DepKind::Normal,
+            spec(Some(vec!["std".to_string()]), Some(false)),
+            None,
+        ),
+        dep_decl("defaults_on", DepKind::Normal, spec(None, None), None),
+        dep_decl("ws_off", DepKind::Normal, inherited.clone(), None),
+        dep_decl("ws_off_listed", DepKind::Normal, inherited.clone(), None),
+        dep_decl(
+            "ws_off_member_listed",
+            DepKind::Normal,
+            DepSpec {
+                features: Some(vec!["derive".to_string()]),
+                ..inherited
+            },
+            None,
+        ),
+    ];
+    let mut ws_off = workspace_dep("ws_off");
+    ws_off.1.default_features = Some(false);
+    let mut ws_off_listed = workspace_dep("ws_off_listed");
+    ws_off_listed.1.default_features = Some(false);
+    ws_off_listed.1.features = vec!["std".to_string()];
+    let mut ws_off_member_listed = workspace_dep("ws_off_member_listed");
+    ws_off_member_listed.1.default_features = Some(false);
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::from([ws_off, ws_off_listed, ws_off_member_listed]),
+    );
+
+    let cfg = config_with_check(
+        ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+        Severity::Warning,
+    );
+    let mut out = Vec::new();
+    features_after_default_off::run(&model, &cfg, &mut out);
+
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["bare", "empty_list", "ws_off"]);
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED)
+    );
+    assert_eq!(out[1].data["section"], "build-dependencies");
+    assert_eq!(out[2].data["inherited"], true);
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_ENABLE_NEEDED_FEATURES
+    );
+}
+
+#[test]
+fn features_after_default_off_respects_allowlist() {
+    let deps = vec![dep_decl(
+        "serde",
+        DepKind::Normal,
+        DepSpec {
+            version: Some("1".to_string()),
+            default_features: Some(false),
+            ..DepSpec::default()
+        },
+        None,
+    )];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+        Severity::Warning,
+        vec!["serde"],
+        true,
+    );
+    let mut out = Vec::new();
+    features_after_default_off::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn resolve_dep_path_normalizes_and_keeps_escapes() {
+    assert_eq!(
+        utils::resolve_dep_path("crates/a/Cargo.toml", "../b"),
+        "crates/b"
+    );
+    assert_eq!(
+        utils::resolve_dep_path("crates/a/Cargo.toml", "./../../"),
+        "."
+    );
+    assert_eq!(
+        utils::resolve_dep_path("crates/a/Cargo.toml", "../../../external"),
+        "../external"
+    );
+    assert_eq!(utils::resolve_dep_path("Cargo.toml", "..\\x"), "../x");
+    assert_eq!(utils::resolve_dep_path("Cargo.toml", "/opt/x"), "/opt/x");
+    assert!(utils::is_outside_repo("../external"));
+    assert!(utils::is_outside_repo("C:/x"));
+    assert!(!utils::is_outside_repo("crates/b"));
+    assert_eq!(utils::manifest_in("."), "Cargo.toml");
+    assert_eq!(utils::manifest_in("crates/b"), "crates/b/Cargo.toml");
+}
+
+#[test]
+fn path_within_workspace_flags_escapes_and_non_members() {
+    let path = |p: &str| DepSpec {
+        path: Some(p.to_string()),
+        version: Some("0.1".to_string()),
+        ..DepSpec::default()
+    };
+    let app_deps = vec![
+        dep_decl("member", DepKind::Normal, path("../member"), None),
+        dep_decl("external", DepKind::Normal, path("../../../external"), None),
+        dep_decl("vendored", DepKind::Dev, path("../../vendor/foo"), None),
+        dep_decl(
+            "shared",
+            DepKind::Normal,
+            DepSpec {
+                workspace: true,
+                ..DepSpec::default()
+            },
+            Some("cfg(unix)"),
+        ),
+        dep_decl(
+            "registry",
+            DepKind::Normal,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let app = manifest("crates/app/Cargo.toml", true, app_deps, BTreeMap::new());
+    let member = manifest("crates/member/Cargo.toml", true, vec![], BTreeMap::new());
+    let (name, mut shared) = workspace_dep("shared");
+    shared.path = Some("../shared".to_string());
+    let mut model = model(vec![app, member], BTreeMap::from([(name, shared)]));
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE, Severity::Error);
+    let mut out = Vec::new();
+    path_within_workspace::run(&model, &cfg, &mut out);
+
+    let summary: Vec<_> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["dependency"].as_str().unwrap().to_string(),
+                f.data["reason"].as_str().unwrap().to_string(),
+                f.data["resolved_path"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "external".to_string(),
+                "outside_repo".to_string(),
+                "../external".to_string()
+            ),
+            (
+                "vendored".to_string(),
+                "not_a_member".to_string(),
+                "vendor/foo".to_string()
+            ),
+            (
+                "shared".to_string(),
+                "outside_repo".to_string(),
+                "../shared".to_string()
+            ),
+        ]
+    );
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_PATH_OUTSIDE_WORKSPACE)
+    );
+    assert_eq!(out[2].data["inherited"], true);
+    assert_eq!(out[2].data["target"], "cfg(unix)");
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_USE_WORKSPACE_MEMBER_PATH
+    );
+
+    // Diff scope cannot see every member, so only repo escapes are reported.
+    cfg.scope = crate::policy::Scope::Diff;
+    model.manifests.truncate(1);
+    out.clear();
+    path_within_workspace::run(&model, &cfg, &mut out);
+    let names: Vec<_> = out.iter().map(|f| f.data["dependency"].clone()).collect();
+    assert_eq!(names, vec!["external", "shared"]);
+}
+
+#[test]
+fn path_within_workspace_allowlist_matches_name_or_path() {
+    let deps = vec![
+        dep_decl(
+            "sibling",
+            DepKind::Normal,
+            DepSpec {
+                path: Some("../sibling".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+        dep_decl(
+            "tool",
+            DepKind::Normal,
+            DepSpec {
+                path: Some("../tools/tool".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        ),
+    ];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+        Severity::Error,
+        vec!["sibling", "../tools/*"],
+        true,
+    );
+    let mut out = Vec::new();
+    path_within_workspace::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn build_script_consistency_flags_mismatched_build_settings() {
+    let build_dep = |name: &str| {
+        dep_decl(
+            name,
+            DepKind::Build,
+            DepSpec {
+                version: Some("1".to_string()),
+                ..DepSpec::default()
+            },
+            None,
+        )
+    };
+    let with_build = |path: &str, build: Option<BuildSetting>, deps| {
+        let mut m = manifest(path, true, deps, BTreeMap::new());
+        m.package.as_mut().expect("package").build = build;
+        m
+    };
+    let manifests = vec![
+        with_build(
+            "crates/script/Cargo.toml",
+            Some(BuildSetting::Script("build.rs".to_string())),
+            vec![],
+        ),
+        with_build(
+            "crates/script_ok/Cargo.toml",
+            Some(BuildSetting::Script("build.rs".to_string())),
+            vec![build_dep("cc")],
+        ),
+        with_build(
+            "crates/disabled/Cargo.toml",
+            Some(BuildSetting::Enabled(false)),
+            vec![build_dep("cc"), build_dep("prost-build")],
+        ),
+        with_build(
+            "crates/disabled_ok/Cargo.toml",
+            Some(BuildSetting::Enabled(false)),
+            vec![],
+        ),
+        with_build("crates/auto/Cargo.toml", None, vec![]),
+        with_build("crates/auto_deps/Cargo.toml", None, vec![build_dep("cc")]),
+    ];
+    let model = model(manifests, BTreeMap::new());
+
+    let cfg = config_with_check(ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY, Severity::Warning);
+    let mut out = Vec::new();
+    build_script_consistency::run(&model, &cfg, &mut out);
+
+    let codes: Vec<_> = out
+        .iter()
+        .map(|f| (f.data["manifest"].as_str().unwrap(), f.code.as_str()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            (
+                "crates/script/Cargo.toml",
+                ids::CODE_BUILD_SCRIPT_WITHOUT_DEPS
+            ),
+            (
+                "crates/disabled/Cargo.toml",
+                ids::CODE_BUILD_DEPS_WITHOUT_SCRIPT
+            ),
+        ]
+    );
+    assert_eq!(out[0].data["build"], "build.rs");
+    assert!(out[0].message.contains("`build = \"build.rs\"`"));
+    assert_eq!(out[1].data["build"], false);
+    assert_eq!(
+        out[1].data["build_dependencies"],
+        json!(["cc", "prost-build"])
+    );
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_ALIGN_BUILD_SCRIPT
+    );
+}
+
+#[test]
+fn build_script_consistency_respects_allowlist() {
+    let mut m = manifest("Cargo.toml", true, vec![], BTreeMap::new());
+    m.package.as_mut().expect("package").build = Some(BuildSetting::Enabled(true));
+    let model = model(vec![m], BTreeMap::new());
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY,
+        Severity::Warning,
+        vec!["pkg"],
+        true,
+    );
+    let mut out = Vec::new();
+    build_script_consistency::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn no_dependency_cycles_reports_cycles_and_self_dependencies() {
+    let path = |p: &str| DepSpec {
+        path: Some(p.to_string()),
+        version: Some("0.1".to_string()),
+        ..DepSpec::default()
+    };
+    let member = |path: &str, name: &str, deps| {
+        let mut m = manifest(path, true, deps, BTreeMap::new());
+        m.package.as_mut().expect("package").name = name.to_string();
+        m
+    };
+    let manifests = vec![
+        member(
+            "crates/a/Cargo.toml",
+            "a",
+            vec![dep_decl("b", DepKind::Normal, path("../b"), None)],
+        ),
+        member(
+            "crates/b/Cargo.toml",
+            "b",
+            vec![
+                dep_decl("c", DepKind::Build, path("../c"), None),
+                dep_decl("d", DepKind::Normal, path("../d"), None),
+            ],
+        ),
+        member(
+            "crates/c/Cargo.toml",
+            "c",
+            vec![dep_decl(
+                "a",
+                DepKind::Normal,
+                DepSpec {
+                    workspace: true,
+                    ..DepSpec::default()
+                },
+                None,
+            )],
+        ),
+        member(
+            "crates/d/Cargo.toml",
+            "d",
+            vec![
+                // Cargo allows cycles through dev-dependencies.
+                dep_decl("b", DepKind::Dev, path("../b"), None),
+                dep_decl("d", DepKind::Normal, path("."), Some("cfg(unix)")),
+            ],
+        ),
+    ];
+    let (name, mut a) = workspace_dep("a");
+    a.path = Some("crates/a".to_string());
+    let model = model(manifests, BTreeMap::from([(name, a)]));
+
+    let cfg = config_with_check(ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES, Severity::Error);
+    let mut out = Vec::new();
+    no_dependency_cycles::run(&model, &cfg, &mut out);
+
+    assert_eq!(out.len(), 2);
+    let self_dep = &out[0];
+    assert_eq!(self_dep.code, ids::CODE_SELF_DEPENDENCY);
+    assert_eq!(self_dep.data["manifest"], "crates/d/Cargo.toml");
+    assert_eq!(self_dep.data["cycle"], serde_json::json!(["d", "d"]));
+    assert_eq!(self_dep.data["target"], "cfg(unix)");
+
+    let cycle = &out[1];
+    assert_eq!(cycle.code, ids::CODE_DEPENDENCY_CYCLE);
+    assert_eq!(cycle.data["manifest"], "crates/a/Cargo.toml");
+    assert_eq!(cycle.data["dependency"], "b");
+    assert_eq!(cycle.data["cycle"], serde_json::json!(["a", "b", "c", "a"]));
+    assert_eq!(
+        cycle.data["cycle_manifests"],
+        serde_json::json!([
+            "crates/a/Cargo.toml",
+            "crates/b/Cargo.toml",
+            "crates/c/Cargo.toml"
+        ])
+    );
+    assert_eq!(cycle.data["members"], serde_json::json!(["a", "b", "c"]));
+    assert_eq!(
+        cycle.data["fix_action"],
+        ids::FIX_ACTION_BREAK_DEPENDENCY_CYCLE
+    );
+    assert!(cycle.message.contains("a -> b -> c -> a"));
+}
+
+#[test]
+fn no_dependency_cycles_allowlist_removes_members_from_graph() {
+    let path = |p: &str| DepSpec {
+        path: Some(p.to_string()),
+        ..DepSpec::default()
+    };
+    let member = |path: &str, name: &str, deps| {
+        let mut m = manifest(path, true, deps, BTreeMap::new());
+        m.package.as_mut().expect("package").name = name.to_string();
+        m
+    };
+    let model = model(
+        vec![
+            member(
+                "a/Cargo.toml",
+                "a",
+                vec![dep_decl("b", DepKind::Normal, path("../b"), None)],
+            ),
+            member(
+                "b/Cargo.toml",
+                "b",
+                vec![dep_decl("a", DepKind::Normal, path("../a"), None)],
+            ),
+        ],
+        BTreeMap::new(),
+    );
+
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+        Severity::Error,
+        vec!["b"],
+        true,
+    );
+    let mut out = Vec::new();
+    no_dependency_cycles::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn lints_configured_flags_packages_without_lints() {
+    let with_lints = |path: &str, lints: Option<LintsDecl>| {
+        let mut m = manifest(path, true, vec![], BTreeMap::new());
+        m.lints = lints;
+        m
+    };
+    let own_tables = LintsDecl {
+        workspace: false,
+        tools: vec!["clippy".to_string()],
+        location: Some(Location {
+            path: RepoPath::new("crates/own/Cargo.toml"),
+            line: Some(6),
+            col: None,
+        }),
+    };
+    let inherited = LintsDecl {
+        workspace: true,
+        ..LintsDecl::default()
+    };
+    let mut root = with_lints("Cargo.toml", None);
+    root.package = None;
+    let model = model(
+        vec![
+            root,
+            with_lints("crates/bare/Cargo.toml", None),
+            with_lints("crates/own/Cargo.toml", Some(own_tables)),
+            with_lints("crates/inherits/Cargo.toml", Some(inherited)),
+        ],
+        BTreeMap::new(),
+    );
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_LINTS_CONFIGURED, Severity::Warning);
+    let mut out = Vec::new();
+    lints_configured::run(&model, &cfg, &mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].code, ids::CODE_MISSING_LINTS_TABLE);
+    assert_eq!(out[0].data["manifest"], "crates/bare/Cargo.toml");
+    assert_eq!(out[0].data["fix_action"], ids::FIX_ACTION_ADD_LINTS_TABLE);
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_LINTS_CONFIGURED)
+        .expect("policy")
+        .options
+        .insert("require_workspace".to_string(), json!(true));
+    let mut out = Vec::new();
+    lints_configured::run(&model, &cfg, &mut out);
+    let codes: Vec<_> = out
+        .iter()
+        .map(|f| (f.data["manifest"].as_str().unwrap(), f.code.as_str()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            ("crates/bare/Cargo.toml", ids::CODE_MISSING_LINTS_TABLE),
+            ("crates/own/Cargo.toml", ids::CODE_LINTS_NOT_INHERITED),
+        ]
+    );
+    assert_eq!(out[1].location.as_ref().and_then(|l| l.line), Some(6));
+    assert_eq!(out[1].data["lint_tools"], json!(["clippy"]));
+}
+
+#[test]
+fn lints_configured_respects_allowlist() {
+    let model = model(
+        vec![manifest("Cargo.toml", true, vec![], BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let cfg = config_with_check_allow(
+        ids::CHECK_DEPS_LINTS_CONFIGURED,
+        Severity::Warning,
+        vec!["pkg"],
+        true,
+    );
+    let mut out = Vec::new();
+    lints_configured::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn no_std_default_features_flags_normal_deps_of_listed_crates() {
+    let spec = |default_features: Option<bool>| DepSpec {
+        version: Some("1".to_string()),
+        default_features,
+        ..DepSpec::default()
+    };
+    let inherited = DepSpec {
+        workspace: true,
+        default_features: Some(false),
+        ..DepSpec::default()
+    };
+    let deps = vec![
+        dep_decl("serde", DepKind::Normal, spec(None), None),
+        dep_decl("heapless", DepKind::Normal, spec(Some(false)), None),
+        dep_decl("log", DepKind::Normal, spec(Some(true)), Some("cfg(unix)")),
+        dep_decl("libm", DepKind::Normal, inherited.clone(), None),
+        dep_decl("defmt", DepKind::Normal, inherited, None),
+        dep_decl("proptest", DepKind::Dev, spec(None), None),
+        dep_decl("cc", DepKind::Build, spec(None), None),
+    ];
+    let mut disabled = workspace_dep("defmt");
+    disabled.1.default_features = Some(false);
+    let model = model(
+        vec![manifest(
+            "crates/embedded/Cargo.toml",
+            true,
+            deps,
+            BTreeMap::new(),
+        )],
+        BTreeMap::from([disabled, workspace_dep("libm")]),
+    );
+
+    let mut cfg = config_with_check(ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES, Severity::Error);
+    let mut out = Vec::new();
+    no_std_default_features::run(&model, &cfg, &mut out);
+    assert!(out.is_empty(), "no crates listed means nothing to check");
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES)
+        .expect("policy")
+        .options
+        .insert("no_std_crates".to_string(), json!(["p*"]));
+    let mut out = Vec::new();
+    no_std_default_features::run(&model, &cfg, &mut out);
+    let flagged: Vec<_> = out
+        .iter()
+        .map(|f| {
+            (
+                f.data["dependency"].as_str().unwrap_or_default(),
+                f.data["default_features"].as_str().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        flagged,
+        vec![
+            ("serde", "omitted"),
+            ("log", "explicit_true"),
+            ("libm", "omitted"),
+        ]
+    );
+    assert!(
+        out.iter()
+            .all(|f| f.code == ids::CODE_DEFAULT_FEATURES_IN_NO_STD)
+    );
+    assert_eq!(out[0].data["package"], "pkg");
+    assert_eq!(out[0].data["matched_pattern"], "p*");
+    assert_eq!(
+        out[0].data["fix_action"],
+        ids::FIX_ACTION_DISABLE_DEFAULT_FEATURES
+    );
+    assert_eq!(out[1].data["target"], "cfg(unix)");
+    assert_eq!(out[2].data["inherited"], true);
+
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES)
+        .expect("policy")
+        .options
+        .insert("no_std_crates".to_string(), json!(["other-crate"]));
+    let mut out = Vec::new();
+    no_std_default_features::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn no_std_default_features_respects_allowlist() {
+    let deps = vec![dep_decl(
+        "critical-section",
+        DepKind::Normal,
+        DepSpec {
+            version: Some("1".to_string()),
+            ..DepSpec::default()
+        },
+        None,
+    )];
+    let model = model(
+        vec![manifest("Cargo.toml", true, deps, BTreeMap::new())],
+        BTreeMap::new(),
+    );
+    let mut cfg = config_with_check_allow(
+        ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES,
+        Severity::Error,
+        vec!["critical-*"],
+        false,
+    );
+    cfg.checks
+        .get_mut(ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES)
+        .expect("policy")
+        .options
+        .insert("no_std_crates".to_string(), json!(["pkg"]));
+    let mut out = Vec::new();
+    no_std_default_features::run(&model, &cfg, &mut out);
+    assert!(out.is_empty());
+}
@@ -1,5 +1,5 @@
-use crate::model::{DepKind, DepSpec};
-use globset::{Glob, GlobSet, GlobSetBuilder};
+use crate::model::{DepKind, DepSpec, DependencyDecl, ManifestModel, WorkspaceModel};
+use globset::{Glob, GlobMatcher, GlobSet, GlobSetBuilder};
 use serde_json::{Value, json};
 
 pub fn build_allowlist(allow: &[String]) -> Option<GlobSet> {
@@ -25,6 +25,36 @@ pub fn is_allowed(allow: Option<&GlobSet>, value: &str) -> bool {
     allow.map(|set| set.is_match(value)).unwrap_or(false)
 }
 
+/// Compile patterns individually so callers can report which one matched.
+pub fn build_matchers(patterns: &[String]) -> Vec<(&str, GlobMatcher)> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let matcher = Glob::new(pattern)
+                .expect("patterns must be validated in depguard-settings")
+                .compile_matcher();
+            (pattern.as_str(), matcher)
+        })
+        .collect()
+}
+
+/// Return the first pattern that matches `value`, if any.
+pub fn first_match<'a>(matchers: &'a [(&str, GlobMatcher)], value: &str) -> Option<&'a str> {
+    matchers
+        .iter()
+        .find(|(_, matcher)| matcher.is_match(value))
+        .map(|(pattern, _)| *pattern)
+}
+
+/// Parse `MAJOR[.MINOR[.PATCH]]`; unparseable components compare as zero.
+pub fn parse_version(value: &str) -> [u64; 3] {
+    let mut out = [0; 3];
+    for (slot, part) in out.iter_mut().zip(value.trim().split('.')) {
+        *slot = part.parse().unwrap_or(0);
+    }
+    out
+}
+
 pub fn section_name(kind: DepKind) -> &'static str {
     match kind {
         DepKind::Normal => "dependencies",
@@ -62,5 +92,85 @@ pub fn spec_to_json(spec: &DepSpec) -> Value {
     if spec.optional {
         obj.insert("optional".into(), json!(true));
     }
+    if let Some(p) = &spec.rename_of {
+        obj.insert("package".into(), json!(p));
+    }
+    if let Some(r) = &spec.registry {
+        obj.insert("registry".into(), json!(r));
+    }
     Value::Object(obj)
 }
+
+/// Resolve a relative dependency `path` against the directory of `manifest_path`.
+///
+/// Returns the normalized repo-relative directory (`"."` for the repo root).
+/// Leading `..` segments are kept when the path climbs above the repo root, e.g.
+/// `crates/a/Cargo.toml` + `../../../external` gives `../external`. Absolute paths
+/// (Unix or Windows) are returned as written.
+pub fn resolve_dep_path(manifest_path: &str, path: &str) -> String {
+    if path.starts_with('/') || path.starts_with('\\') || path.contains(':') {
+        return path.to_string();
+    }
+
+    let mut segments: Vec<&str> = manifest_path
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    segments.pop();
+    let mut escaped = 0;
+    for seg in path.split(['/', '\\']) {
+        match seg {
+            "" | "." => {}
+            ".." => {
+                if segments.pop().is_none() {
+                    escaped += 1;
+                }
+            }
+            _ => segments.push(seg),
+        }
+    }
+
+    let mut parts = vec![".."; escaped];
+    parts.extend(segments);
+    if parts.is_empty() {
+        ".".to_string()
+    } else {
+        parts.join("/")
+    }
+}
+
+/// The `(base manifest, path)` pair to feed [`resolve_dep_path`] for `dep`.
+///
+/// Inherited paths are relative to the workspace root, not the member. Returns
+/// `None` when the dependency (or its `[workspace.dependencies]` entry) has no
+/// `path`.
+pub fn dep_path_base<'a>(
+    model: &'a WorkspaceModel,
+    manifest: &'a ManifestModel,
+    dep: &'a DependencyDecl,
+) -> Option<(&'a str, &'a str)> {
+    if dep.spec.workspace {
+        let ws = model.workspace_dependencies.get(&dep.name)?;
+        Some(("Cargo.toml", ws.path.as_deref()?))
+    } else {
+        Some((manifest.path.as_str(), dep.spec.path.as_deref()?))
+    }
+}
+
+/// Whether a path returned by [`resolve_dep_path`] lies outside the repo root.
+pub fn is_outside_repo(resolved: &str) -> bool {
+    resolved == ".."
+        || resolved.starts_with("../")
+        || resolved.starts_with('/')
+        || resolved.starts_with('\\')
+        || resolved.contains(':')
+}
+
+/// The repo-relative `Cargo.toml` inside a directory from [`resolve_dep_path`].
+pub fn manifest_in(resolved: &str) -> String {
+    if resolved == "." {
+        "Cargo.toml".to_string()
+    } else {
+        format!("{resolved}/Cargo.toml")
+    }
+}
@@ -0,0 +1,61 @@
+use crate::checks::utils::{build_allowlist, is_allowed};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_VALID_TARGET_CFG) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        for target in &manifest.targets {
+            if target.valid || is_allowed(allow.as_ref(), &target.raw) {
+                continue;
+            }
+
+            // Dependencies Cargo silently drops because the spec never matches.
+            let dependencies: Vec<&str> = manifest
+                .dependencies
+                .iter()
+                .filter(|dep| dep.target.as_deref() == Some(target.raw.as_str()))
+                .map(|dep| dep.name.as_str())
+                .collect();
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_VALID_TARGET_CFG,
+                ids::CODE_INVALID_TARGET_CFG,
+                manifest.path.as_str(),
+                &target.raw,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_VALID_TARGET_CFG.to_string(),
+                code: ids::CODE_INVALID_TARGET_CFG.to_string(),
+                message: format!(
+                    "[target.'{}'] is not a valid cfg expression or target triple; Cargo ignores it",
+                    target.raw
+                ),
+                location: target.location.clone(),
+                help: Some(
+                    "Write the spec as cfg(<predicate>) or a target triple such as x86_64-unknown-linux-gnu."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: json!({
+                    "dependencies": dependencies,
+                    "fix_action": ids::FIX_ACTION_FIX_TARGET_CFG,
+                    "fix_hint": "Correct the [target] spec so Cargo can evaluate it",
+                    "manifest": manifest.path.as_str(),
+                    "target": target.raw,
+                }),
+            });
+        }
+    }
+}
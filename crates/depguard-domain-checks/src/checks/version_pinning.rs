@@ -0,0 +1,110 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::{EffectiveConfig, PinningMode};
+use depguard_types::{Finding, ids};
+use semver::{Op, VersionReq};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_VERSION_PINNING) else {
+        return;
+    };
+    let Some(mode) = policy.pinning_mode else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    let (code, fix_action, fix_hint, mode_name) = match mode {
+        PinningMode::ForbidExact => (
+            ids::CODE_EXACT_PIN_FORBIDDEN,
+            ids::FIX_ACTION_RELAX_EXACT_PIN,
+            "Drop the leading `=` to allow compatible upgrades",
+            "forbid_exact",
+        ),
+        PinningMode::RequireExact => (
+            ids::CODE_EXACT_PIN_REQUIRED,
+            ids::FIX_ACTION_PIN_EXACT_VERSION,
+            "Pin the requirement to `=MAJOR.MINOR.PATCH`",
+            "require_exact",
+        ),
+    };
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            // Inherited and path deps take their version from elsewhere in the repo.
+            if dep.spec.workspace || dep.spec.path.is_some() {
+                continue;
+            }
+            let Some(requirement) = dep.spec.version.as_deref() else {
+                continue;
+            };
+            let Some(exact) = is_exact_pin(requirement) else {
+                continue;
+            };
+            let violates = match mode {
+                PinningMode::ForbidExact => exact,
+                PinningMode::RequireExact => !exact,
+            };
+            if !violates || is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_VERSION_PINNING,
+                code,
+                manifest.path.as_str(),
+                &dep.name,
+                None,
+            );
+            let message = match mode {
+                PinningMode::ForbidExact => format!(
+                    "dependency '{}' uses an exact version pin: {}",
+                    dep.name, requirement
+                ),
+                PinningMode::RequireExact => format!(
+                    "dependency '{}' is not pinned to an exact version: {}",
+                    dep.name, requirement
+                ),
+            };
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_VERSION_PINNING.to_string(),
+                code: code.to_string(),
+                message,
+                location: dep.location.clone(),
+                help: Some(fix_hint.to_string()),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": spec_to_json(&dep.spec),
+                        "dependency": dep.name,
+                        "fix_action": fix_action,
+                        "fix_hint": fix_hint,
+                        "manifest": manifest.path.as_str(),
+                        "mode": mode_name,
+                        "requirement": requirement,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
+
+/// Whether `requirement` is a single `=MAJOR.MINOR.PATCH` comparator.
+///
+/// Returns `None` for requirements that do not parse as semver.
+fn is_exact_pin(requirement: &str) -> Option<bool> {
+    let req = VersionReq::parse(requirement).ok()?;
+    Some(match req.comparators.as_slice() {
+        [c] => c.op == Op::Exact && c.minor.is_some() && c.patch.is_some(),
+        _ => false,
+    })
+}
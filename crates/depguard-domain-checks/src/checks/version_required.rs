@@ -0,0 +1,78 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name, spec_to_json};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::{DepKind, WorkspaceModel};
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_VERSION_REQUIRED) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    for manifest in &model.manifests {
+        if !manifest.is_publishable() {
+            continue;
+        }
+
+        for dep in &manifest.dependencies {
+            if dep.kind != DepKind::Normal || dep.spec.version.is_some() || dep.spec.workspace {
+                continue;
+            }
+            // Pure path dependencies are deps.path_requires_version's concern.
+            if dep.spec.path.is_some() && dep.spec.git.is_none() {
+                continue;
+            }
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let current_spec = spec_to_json(&dep.spec);
+            let present_fields: Vec<&str> = current_spec
+                .as_object()
+                .map(|fields| fields.keys().map(String::as_str).collect())
+                .unwrap_or_default();
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_VERSION_REQUIRED,
+                ids::CODE_DEPENDENCY_WITHOUT_VERSION,
+                manifest.path.as_str(),
+                &dep.name,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_VERSION_REQUIRED.to_string(),
+                code: ids::CODE_DEPENDENCY_WITHOUT_VERSION.to_string(),
+                message: format!(
+                    "dependency '{}' declares no version requirement",
+                    dep.name
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Add an explicit `version = ...`, or use `workspace = true` with a workspace dependency."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "current_spec": current_spec,
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_ADD_VERSION,
+                        "fix_hint": "Add a version requirement",
+                        "manifest": manifest.path.as_str(),
+                        "present_fields": present_fields,
+                        "section": section_name(dep.kind),
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
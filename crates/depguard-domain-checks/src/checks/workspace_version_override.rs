@@ -0,0 +1,75 @@
+use crate::checks::utils::{build_allowlist, is_allowed, section_name};
+use crate::fingerprint::fingerprint_for_dep;
+use crate::model::WorkspaceModel;
+use crate::policy::EffectiveConfig;
+use depguard_types::{Finding, ids};
+use serde_json::json;
+
+pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    let Some(policy) = cfg.check_policy(ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE) else {
+        return;
+    };
+    let allow = build_allowlist(&policy.allow);
+
+    if model.workspace_dependencies.is_empty() {
+        return;
+    }
+
+    for manifest in &model.manifests {
+        for dep in &manifest.dependencies {
+            let Some(ws_dep) = model.workspace_dependencies.get(&dep.name) else {
+                continue;
+            };
+            if dep.spec.workspace {
+                continue;
+            }
+            // Path- and git-only declarations are deps.workspace_inheritance's concern.
+            let Some(declared) = dep.spec.version.as_deref() else {
+                continue;
+            };
+            if is_allowed(allow.as_ref(), &dep.name) {
+                continue;
+            }
+
+            let fingerprint = fingerprint_for_dep(
+                ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+                ids::CODE_SHOULD_INHERIT_WORKSPACE_DEP,
+                manifest.path.as_str(),
+                &dep.name,
+                None,
+            );
+
+            out.push(Finding {
+                severity: policy.severity,
+                check_id: ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE.to_string(),
+                code: ids::CODE_SHOULD_INHERIT_WORKSPACE_DEP.to_string(),
+                message: format!(
+                    "dependency '{}' declares version '{}' instead of inheriting it from [workspace.dependencies]",
+                    dep.name, declared
+                ),
+                location: dep.location.clone(),
+                help: Some(
+                    "Use `{ workspace = true }`, or add the dependency to `allow` if the override is intentional."
+                        .to_string(),
+                ),
+                url: None,
+                fingerprint: Some(fingerprint),
+                data: {
+                    let mut d = json!({
+                        "declared_version": declared,
+                        "dependency": dep.name,
+                        "fix_action": ids::FIX_ACTION_USE_WORKSPACE_TRUE,
+                        "fix_hint": "Use workspace = true",
+                        "manifest": manifest.path.as_str(),
+                        "section": section_name(dep.kind),
+                        "workspace_version": ws_dep.version,
+                    });
+                    if let Some(ref t) = dep.target {
+                        d["target"] = json!(t);
+                    }
+                    d
+                },
+            });
+        }
+    }
+}
@@ -28,7 +28,7 @@ pub fn run(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>
                 continue;
             };
 
-            let canonical_name = dep.spec.package.as_deref().unwrap_or(&dep.name);
+            let canonical_name = dep.spec.rename_of.as_deref().unwrap_or(&dep.name);
             if !index.is_yanked(canonical_name, pinned) {
                 continue;
             }
@@ -13,4 +13,4 @@ pub mod policy;
 #[cfg(test)]
 mod test_support;
 
-pub use checks::run_all;
+pub use checks::{is_workspace_check, run_all, run_manifest_checks, run_workspace_checks};
@@ -31,9 +31,14 @@ pub fn manifest(
         package: Some(PackageMeta {
             name: "pkg".to_string(),
             publish,
+            ..PackageMeta::default()
         }),
         dependencies: deps,
         features,
+        overrides: Vec::new(),
+        targets: Vec::new(),
+        lints: None,
+        workspace_resolver: None,
     }
 }
 
@@ -44,7 +49,11 @@ pub fn model(
     WorkspaceModel {
         repo_root: RepoPath::new("."),
         workspace_dependencies,
+        resolved_versions: None,
         manifests,
+        source_references: None,
+        unresolved_members: Vec::new(),
+        nested_workspaces: Vec::new(),
     }
 }
 
@@ -56,6 +65,9 @@ pub fn workspace_dep(name: &str) -> (String, WorkspaceDependency) {
             version: None,
             path: None,
             workspace: true,
+            default_features: None,
+            features: Vec::new(),
+            location: None,
         },
     )
 }
@@ -70,6 +82,7 @@ pub fn config_with_check(check_id: &str, severity: Severity) -> EffectiveConfig
         max_findings: 200,
         yanked_index: None,
         checks,
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -93,5 +106,6 @@ pub fn config_with_check_allow(
         max_findings: 200,
         yanked_index: None,
         checks,
+        verdict_ignores: Vec::new(),
     }
 }
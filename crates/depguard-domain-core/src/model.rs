@@ -1,6 +1,6 @@
 use depguard_types::{Location, RepoPath};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct WorkspaceModel {
@@ -9,8 +9,40 @@ pub struct WorkspaceModel {
     /// `[workspace.dependencies]` from the root manifest, if present.
     pub workspace_dependencies: BTreeMap<String, WorkspaceDependency>,
 
+    /// Resolved versions per crate name from the root `Cargo.lock`.
+    ///
+    /// `None` when the lockfile is missing or unparseable; checks that need
+    /// resolved versions should treat that as "skipped", not "clean".
+    pub resolved_versions: Option<BTreeMap<String, BTreeSet<String>>>,
+
+    /// Crates named by `use`/`extern crate` items in each package's non-test
+    /// `src/` files: manifest path -> crate identifier -> first reference.
+    ///
+    /// `None` when the opt-in source scan did not run; checks that need it
+    /// should treat that as "skipped", not "clean".
+    pub source_references: Option<BTreeMap<String, BTreeMap<String, Location>>>,
+
     /// All manifests in scope (root + members).
     pub manifests: Vec<ManifestModel>,
+
+    /// `[workspace] members` entries of the root manifest that match no manifest
+    /// on disk, in declaration order.
+    #[serde(default)]
+    pub unresolved_members: Vec<UnresolvedMember>,
+
+    /// `[workspace]` tables declared by manifests below the root, sorted by
+    /// manifest path. Directories in the root's `exclude` list are omitted.
+    #[serde(default)]
+    pub nested_workspaces: Vec<Location>,
+}
+
+/// A `[workspace] members` path or glob that matched no `Cargo.toml`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnresolvedMember {
+    /// The entry as written, minus any leading `./`.
+    pub pattern: String,
+    /// Entry line in the root manifest's `members` array.
+    pub location: Option<Location>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -19,6 +51,37 @@ pub struct WorkspaceDependency {
     pub version: Option<String>,
     pub path: Option<String>,
     pub workspace: bool,
+    /// `default-features` on the workspace entry (None = not specified).
+    #[serde(default)]
+    pub default_features: Option<bool>,
+    /// `features` on the workspace entry; members inheriting it add their own.
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Entry line in the root manifest's `[workspace.dependencies]` table.
+    #[serde(default)]
+    pub location: Option<Location>,
+}
+
+/// Inheritable `[workspace.package]` fields from the root manifest.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspacePackage {
+    pub version: Option<String>,
+    pub edition: Option<String>,
+    pub rust_version: Option<String>,
+    pub license: Option<String>,
+    pub license_file: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub repository: Option<String>,
+    #[serde(default)]
+    pub homepage: Option<String>,
+    #[serde(default)]
+    pub documentation: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub categories: Vec<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -28,14 +91,144 @@ pub struct ManifestModel {
     pub dependencies: Vec<DependencyDecl>,
     /// Features defined in `[features]` table, mapped to their dependencies.
     pub features: BTreeMap<String, Vec<String>>,
+    /// `[patch.<source>]` and `[replace]` tables, in source order.
+    #[serde(default)]
+    pub overrides: Vec<OverrideSection>,
+    /// `[target.<spec>]` tables, in source order.
+    #[serde(default)]
+    pub targets: Vec<TargetSpec>,
+    /// `[lints]` table, if declared.
+    #[serde(default)]
+    pub lints: Option<LintsDecl>,
+    /// `workspace.resolver` as declared; only meaningful on the root manifest.
+    #[serde(default)]
+    pub workspace_resolver: Option<String>,
+}
+
+/// A manifest's `[lints]` table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintsDecl {
+    /// `lints.workspace = true`: lints are inherited from `[workspace.lints]`.
+    pub workspace: bool,
+    /// Tool tables declared directly (e.g. `rust`, `clippy`), in source order.
+    pub tools: Vec<String>,
+    /// Line of the `[lints]` header, or of the first `[lints.<tool>]` header.
+    pub location: Option<Location>,
 }
 
+/// A `[target.<spec>]` table and whether Cargo can evaluate its spec.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TargetSpec {
+    /// Spec as written, e.g. `cfg(unix)` or `x86_64-unknown-linux-gnu`.
+    pub raw: String,
+    /// Whether `raw` parses as a `cfg(...)` expression or a bare target triple.
+    pub valid: bool,
+    /// Line of the first table declared under this spec.
+    pub location: Option<Location>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverrideKind {
+    Patch,
+    Replace,
+}
+
+/// A dependency-override table (`[patch.<source>]` or `[replace]`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OverrideSection {
+    pub kind: OverrideKind,
+    /// Patched source (`crates-io` or a registry/git URL); `None` for `[replace]`.
+    pub source: Option<String>,
+    /// Keys of the table: crate names for `[patch]`, package specs for `[replace]`.
+    pub entries: Vec<String>,
+    /// Line of the table header.
+    pub location: Option<Location>,
+}
+
+impl OverrideSection {
+    /// Table name as written in the manifest, e.g. `patch.crates-io` or `replace`.
+    pub fn table_name(&self) -> String {
+        match (self.kind, self.source.as_deref()) {
+            (OverrideKind::Patch, Some(source)) => format!("patch.{source}"),
+            (OverrideKind::Patch, None) => "patch".to_string(),
+            (OverrideKind::Replace, _) => "replace".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct PackageMeta {
     pub name: String,
     pub publish: bool,
+    /// `package.version`, declared or resolved from `[workspace.package]`.
+    pub version: Option<String>,
+    /// `package.edition`, declared or resolved from `[workspace.package]`.
+    pub edition: Option<String>,
+    /// `package.rust-version` (MSRV), declared or resolved from `[workspace.package]`.
+    pub rust_version: Option<String>,
+    /// `package.license` SPDX expression, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub license: Option<String>,
+    /// `package.license-file`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub license_file: Option<String>,
+    /// `package.description`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// `package.repository`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub repository: Option<String>,
+    /// `package.homepage`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub homepage: Option<String>,
+    /// `package.documentation`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub documentation: Option<String>,
+    /// `package.keywords`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// `package.categories`, declared or resolved from `[workspace.package]`.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Cargo keys (`version`, `edition`, `rust-version`, `license`, `license-file`,
+    /// and the publish metadata keys) declared as `{ workspace = true }`.
+    pub inherited: BTreeSet<String>,
+    /// `package.build` as declared; `None` leaves Cargo to auto-detect `build.rs`.
+    #[serde(default)]
+    pub build: Option<BuildSetting>,
+    /// `package.resolver` as declared (e.g. `"1"`, `"2"`).
+    #[serde(default)]
+    pub resolver: Option<String>,
+    /// Line of the `[package]` table header.
+    #[serde(default)]
+    pub location: Option<Location>,
 }
 
+/// Value of `package.build`: a script path, or `true`/`false`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BuildSetting {
+    Enabled(bool),
+    Script(String),
+}
+
+impl BuildSetting {
+    /// Whether Cargo runs a build script for this setting.
+    pub fn runs_script(&self) -> bool {
+        !matches!(self, Self::Enabled(false))
+    }
+}
+
+/// `[package]` metadata keys that deps.publish_metadata can require.
+pub const PUBLISH_METADATA_KEYS: &[&str] = &[
+    "description",
+    "repository",
+    "homepage",
+    "documentation",
+    "keywords",
+    "categories",
+];
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DepKind {
     Normal,
@@ -69,13 +262,17 @@ pub struct DepSpec {
     pub rev: Option<String>,
     /// Whether default-features is explicitly set (None = not specified)
     pub default_features: Option<bool>,
+    /// Explicit `features = [...]` list (None = not specified)
+    pub features: Option<Vec<String>>,
     /// Whether this dependency is marked as optional
     pub optional: bool,
     /// The canonical crate name when using a rename alias.
     ///
     /// When a dependency is declared as `alias = { package = "real-crate", ... }`,
     /// this field holds `"real-crate"` while `DependencyDecl.name` holds `"alias"`.
-    pub package: Option<String>,
+    pub rename_of: Option<String>,
+    /// Alternate registry name (`registry = "..."`); `None` means crates.io.
+    pub registry: Option<String>,
     /// Inline suppression tokens parsed from comments near this dependency.
     ///
     /// Each token can be either a `check_id` (e.g. `deps.no_wildcards`) or a
@@ -93,6 +290,28 @@ impl ManifestModel {
     }
 }
 
+impl PackageMeta {
+    /// Whether `key` (a Cargo key such as `rust-version`) is inherited from the workspace.
+    pub fn is_inherited(&self, key: &str) -> bool {
+        self.inherited.contains(key)
+    }
+
+    /// Whether the publish metadata `key` (one of [`PUBLISH_METADATA_KEYS`]) is set
+    /// to a non-empty value; unknown keys are never set.
+    pub fn has_metadata(&self, key: &str) -> bool {
+        let text = |v: &Option<String>| v.as_deref().is_some_and(|s| !s.trim().is_empty());
+        match key {
+            "description" => text(&self.description),
+            "repository" => text(&self.repository),
+            "homepage" => text(&self.homepage),
+            "documentation" => text(&self.documentation),
+            "keywords" => !self.keywords.is_empty(),
+            "categories" => !self.categories.is_empty(),
+            _ => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,6 +325,7 @@ mod tests {
         manifest.package = Some(PackageMeta {
             name: "depguard".to_string(),
             publish: true,
+            ..PackageMeta::default()
         });
         assert!(manifest.is_publishable());
         assert_eq!(manifest.package_name(), Some("depguard"));
@@ -113,6 +333,7 @@ mod tests {
         manifest.package = Some(PackageMeta {
             name: "private".to_string(),
             publish: false,
+            ..PackageMeta::default()
         });
         assert!(!manifest.is_publishable());
         assert_eq!(manifest.package_name(), Some("private"));
@@ -1,5 +1,7 @@
 use depguard_types::Severity;
 use depguard_yanked::YankedIndex;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use std::collections::BTreeMap;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -14,13 +16,148 @@ pub enum FailOn {
     Warning,
 }
 
+/// Exact-pin policy for deps.version_pinning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinningMode {
+    ForbidExact,
+    RequireExact,
+}
+
+/// What deps.consistent_msrv enforces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MsrvMode {
+    /// Members must agree on `rust-version`.
+    #[default]
+    Consistent,
+    /// Members must inherit `rust-version` from `[workspace.package]`.
+    RequireInherit,
+}
+
+/// Resolved settings for one check.
+///
+/// The typed fields below are the scalar and list settings that predate
+/// `options`; several are shared by more than one check (`ignore`, `mode`) and
+/// get dedicated validation messages. Check-specific settings beyond those live
+/// in `options` and are read through a typed struct with
+/// [`CheckPolicy::options_as`].
 #[derive(Clone, Debug)]
 pub struct CheckPolicy {
     pub enabled: bool,
     pub severity: Severity,
     pub allow: Vec<String>,
+    /// Deny-list patterns (semantics are check-specific, e.g. deps.banned_crates).
+    pub deny: Vec<String>,
     /// Check-specific option for deps.path_requires_version.
     pub ignore_publish_false: bool,
+    /// Check-specific option for deps.msrv_floor: minimum `rust-version`.
+    pub min_rust_version: Option<String>,
+    /// Check-specific option for deps.msrv_floor: minimum `edition`.
+    pub min_edition: Option<String>,
+    /// Check-specific option for deps.no_multiple_versions and deps.consistent_versions:
+    /// crates whose duplicates or divergent requirements are tolerated.
+    pub ignore: Vec<String>,
+    /// Check-specific option for deps.version_pinning; `None` leaves the check inert.
+    pub pinning_mode: Option<PinningMode>,
+    /// Check-specific option for deps.consistent_msrv.
+    pub msrv_mode: MsrvMode,
+    /// Check-specific option for deps.max_direct_deps: per-section dependency limits.
+    pub dependency_limits: DependencyLimits,
+    /// Check-specific option for deps.license_present: SPDX identifiers a package
+    /// license must be satisfiable with; empty accepts any license.
+    pub allowed_licenses: Vec<String>,
+    /// Free-form check-specific options from the check's `options` table; read
+    /// them with [`CheckPolicy::options_as`].
+    pub options: BTreeMap<String, serde_json::Value>,
+}
+
+/// Typed `options` for deps.banned_features.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BannedFeaturesOptions {
+    /// Crate name -> features it must not enable.
+    #[serde(default)]
+    pub features: BTreeMap<String, BannedFeatures>,
+}
+
+/// Features of one crate that deps.banned_features rejects, with an optional reason.
+///
+/// Accepts either a list (`tokio = ["process"]`) or a table
+/// (`openssl = { features = ["vendored"], reason = "use the system library" }`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(from = "BannedFeaturesEntry")]
+pub struct BannedFeatures {
+    pub features: Vec<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BannedFeaturesEntry {
+    List(Vec<String>),
+    Detailed {
+        features: Vec<String>,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
+impl From<BannedFeaturesEntry> for BannedFeatures {
+    fn from(entry: BannedFeaturesEntry) -> Self {
+        match entry {
+            BannedFeaturesEntry::List(features) => Self {
+                features,
+                reason: None,
+            },
+            BannedFeaturesEntry::Detailed { features, reason } => Self { features, reason },
+        }
+    }
+}
+
+/// Typed `options` for deps.deprecated_crates.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeprecatedCratesOptions {
+    /// Deprecated crate name -> suggested replacement.
+    #[serde(default)]
+    pub deprecated: BTreeMap<String, String>,
+}
+
+/// Typed `options` for deps.publish_metadata.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PublishMetadataOptions {
+    /// `[package]` keys publishable packages must set; empty means `description`
+    /// and `repository`.
+    #[serde(default)]
+    pub require: Vec<String>,
+}
+
+/// Typed `options` for deps.lints_configured.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintsConfiguredOptions {
+    /// Also flag packages with their own lint tables instead of
+    /// `[lints] workspace = true`.
+    #[serde(default)]
+    pub require_workspace: bool,
+}
+
+/// Typed `options` for deps.no_std_default_features.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoStdOptions {
+    /// Package names (or globs) of the workspace's `no_std` crates. Required
+    /// when the check is enabled.
+    #[serde(default)]
+    pub no_std_crates: Vec<String>,
+}
+
+/// Direct dependency limits for deps.max_direct_deps; `None` leaves a section uncounted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DependencyLimits {
+    pub normal: Option<u32>,
+    pub dev: Option<u32>,
+    pub build: Option<u32>,
 }
 
 impl CheckPolicy {
@@ -29,7 +166,16 @@ impl CheckPolicy {
             enabled: true,
             severity,
             allow: Vec::new(),
+            deny: Vec::new(),
             ignore_publish_false: false,
+            min_rust_version: None,
+            min_edition: None,
+            ignore: Vec::new(),
+            pinning_mode: None,
+            msrv_mode: MsrvMode::Consistent,
+            dependency_limits: DependencyLimits::default(),
+            allowed_licenses: Vec::new(),
+            options: BTreeMap::new(),
         }
     }
 
@@ -38,9 +184,28 @@ impl CheckPolicy {
             enabled: false,
             severity: Severity::Info,
             allow: Vec::new(),
+            deny: Vec::new(),
             ignore_publish_false: false,
+            min_rust_version: None,
+            min_edition: None,
+            ignore: Vec::new(),
+            pinning_mode: None,
+            msrv_mode: MsrvMode::Consistent,
+            dependency_limits: DependencyLimits::default(),
+            allowed_licenses: Vec::new(),
+            options: BTreeMap::new(),
         }
     }
+
+    /// Deserialize the `options` table into a check's typed option struct.
+    pub fn options_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let table = self
+            .options
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        serde_json::from_value(serde_json::Value::Object(table))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -49,6 +214,8 @@ pub struct EffectiveConfig {
     pub scope: Scope,
     pub fail_on: FailOn,
     pub max_findings: usize,
+    /// Severities left out of the verdict; their findings are still reported.
+    pub verdict_ignores: Vec<Severity>,
     /// Optional offline yanked-version index used by deps.yanked_versions.
     pub yanked_index: Option<YankedIndex>,
     pub checks: BTreeMap<String, CheckPolicy>,
@@ -72,6 +239,7 @@ mod tests {
         assert!(enabled.enabled);
         assert_eq!(enabled.severity, Severity::Warning);
         assert!(!enabled.ignore_publish_false);
+        assert!(enabled.deny.is_empty());
 
         let disabled = CheckPolicy::disabled();
         assert!(!disabled.enabled);
@@ -94,6 +262,7 @@ mod tests {
             max_findings: 10,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         assert!(cfg.check_policy("enabled").is_some());
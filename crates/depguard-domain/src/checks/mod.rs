@@ -1,6 +1,16 @@
 use crate::{model::WorkspaceModel, policy::EffectiveConfig};
 use depguard_types::Finding;
 
+pub use depguard_domain_checks::is_workspace_check;
+
 pub fn run_all(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
     depguard_domain_checks::run_all(model, cfg, out)
 }
+
+pub fn run_manifest_checks(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    depguard_domain_checks::run_manifest_checks(model, cfg, out)
+}
+
+pub fn run_workspace_checks(model: &WorkspaceModel, cfg: &EffectiveConfig, out: &mut Vec<Finding>) {
+    depguard_domain_checks::run_workspace_checks(model, cfg, out)
+}
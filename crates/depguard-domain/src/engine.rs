@@ -1,35 +1,108 @@
 use crate::checks;
+use crate::fingerprint::fingerprint_for_finding;
 use crate::model::WorkspaceModel;
 use crate::policy::{EffectiveConfig, FailOn};
-use crate::report::{DomainReport, SeverityCounts};
-use depguard_types::{DepguardData, Finding, Severity, Verdict};
+use crate::report::{DomainReport, FindingsSummary, SeverityCounts};
+use depguard_types::{DepguardData, Finding, Severity, Verdict, ids};
 use std::collections::{BTreeMap, BTreeSet};
 
 pub fn evaluate(model: &WorkspaceModel, cfg: &EffectiveConfig) -> DomainReport {
-    let inline_suppressions = build_inline_suppression_index(model);
     let mut findings: Vec<Finding> = Vec::new();
-
     checks::run_all(model, cfg, &mut findings);
+    report_from_findings(model, cfg, findings)
+}
+
+/// Same report as [`evaluate`], with manifest-scoped checks spread across
+/// manifests on the rayon thread pool.
+///
+/// Per-manifest results are collected in manifest order and workspace-scoped
+/// checks run once afterwards; the final sort makes the output identical to
+/// the sequential path.
+#[cfg(feature = "parallel")]
+pub fn evaluate_parallel(model: &WorkspaceModel, cfg: &EffectiveConfig) -> DomainReport {
+    use rayon::prelude::*;
+
+    let mut findings: Vec<Finding> = (0..model.manifests.len())
+        .into_par_iter()
+        .flat_map_iter(|index| evaluate_manifest(model, index, cfg))
+        .collect();
+    findings.extend(evaluate_workspace(model, cfg));
+    report_from_findings(model, cfg, findings)
+}
+
+/// Findings from the manifest-scoped checks for `model.manifests[index]` alone.
+///
+/// The result depends only on that manifest, `[workspace.dependencies]` and
+/// `cfg`, so callers may cache it per manifest and merge the pieces with
+/// [`evaluate_workspace`] through [`report_from_findings`].
+pub fn evaluate_manifest(
+    model: &WorkspaceModel,
+    index: usize,
+    cfg: &EffectiveConfig,
+) -> Vec<Finding> {
+    let Some(manifest) = model.manifests.get(index) else {
+        return Vec::new();
+    };
+    let single = WorkspaceModel {
+        repo_root: model.repo_root.clone(),
+        workspace_dependencies: model.workspace_dependencies.clone(),
+        resolved_versions: None,
+        manifests: vec![manifest.clone()],
+        source_references: None,
+        unresolved_members: Vec::new(),
+        nested_workspaces: Vec::new(),
+    };
+    let mut findings = Vec::new();
+    checks::run_manifest_checks(&single, cfg, &mut findings);
+    findings
+}
+
+/// Findings from the checks that need the whole workspace.
+pub fn evaluate_workspace(model: &WorkspaceModel, cfg: &EffectiveConfig) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    checks::run_workspace_checks(model, cfg, &mut findings);
+    findings
+}
+
+/// Turn raw check output into a report: fill fallback fingerprints, apply inline
+/// suppressions, sort, truncate and compute the verdict.
+pub fn report_from_findings(
+    model: &WorkspaceModel,
+    cfg: &EffectiveConfig,
+    mut findings: Vec<Finding>,
+) -> DomainReport {
+    let inline_suppressions = build_inline_suppression_index(model);
+    for finding in &mut findings {
+        if finding.fingerprint.is_none() {
+            finding.fingerprint = Some(fallback_fingerprint(finding));
+        }
+    }
+    let before_suppression = findings.len();
     if !inline_suppressions.is_empty() {
         findings.retain(|f| !is_inline_suppressed(f, &inline_suppressions));
     }
+    let suppressed = (before_suppression - findings.len()) as u32;
 
     // Deterministic ordering before truncation.
     findings.sort_by(compare_findings);
 
     let total = findings.len() as u32;
+    let emitted_len = findings.len().min(cfg.max_findings);
+    let summary = FindingsSummary::from_findings(&findings, emitted_len as u32, suppressed);
 
     let mut emitted = findings;
     let mut truncated_reason: Option<String> = None;
+    let mut reasons = Vec::new();
     if emitted.len() > cfg.max_findings {
         emitted.truncate(cfg.max_findings);
         truncated_reason = Some(format!(
             "findings truncated to max_findings={}",
             cfg.max_findings
         ));
+        reasons.push(ids::REASON_FINDINGS_TRUNCATED.to_string());
     }
 
-    let verdict = compute_verdict(&emitted, cfg.fail_on);
+    let verdict = compute_verdict(&emitted, cfg.fail_on, &cfg.verdict_ignores);
     let counts = SeverityCounts::from_findings(&emitted);
 
     let data = DepguardData {
@@ -46,6 +119,7 @@ pub fn evaluate(model: &WorkspaceModel, cfg: &EffectiveConfig) -> DomainReport {
             .sum(),
         findings_total: total,
         findings_emitted: emitted.len() as u32,
+        findings_suppressed: suppressed,
         truncated_reason,
     };
 
@@ -54,9 +128,26 @@ pub fn evaluate(model: &WorkspaceModel, cfg: &EffectiveConfig) -> DomainReport {
         findings: emitted,
         data,
         counts,
+        reasons,
+        summary,
     }
 }
 
+/// Checks fingerprint by dependency identity; anything they leave unset gets a
+/// hash over its check, code, path and normalized message.
+fn fallback_fingerprint(finding: &Finding) -> String {
+    fingerprint_for_finding(
+        finding.check_id.as_str(),
+        finding.code.as_str(),
+        finding
+            .location
+            .as_ref()
+            .map(|loc| loc.path.as_str())
+            .unwrap_or(""),
+        &finding.message,
+    )
+}
+
 type SuppressionIndex = BTreeMap<(String, u32), BTreeSet<String>>;
 
 fn build_inline_suppression_index(model: &WorkspaceModel) -> SuppressionIndex {
@@ -99,13 +190,16 @@ fn is_inline_suppressed(finding: &Finding, suppressions: &SuppressionIndex) -> b
     tokens.contains(&finding.check_id) || tokens.contains(&finding.code)
 }
 
-fn compute_verdict(findings: &[Finding], fail_on: FailOn) -> Verdict {
-    let has_error = findings.iter().any(|f| f.severity == Severity::Error);
-    if has_error {
+/// Findings whose severity is in `ignores` are reported but never move the verdict.
+fn compute_verdict(findings: &[Finding], fail_on: FailOn, ignores: &[Severity]) -> Verdict {
+    let counts = |severity: Severity| {
+        !ignores.contains(&severity) && findings.iter().any(|f| f.severity == severity)
+    };
+    if counts(Severity::Error) {
         return Verdict::Fail;
     }
 
-    let has_warn = findings.iter().any(|f| f.severity == Severity::Warning);
+    let has_warn = counts(Severity::Warning);
     if has_warn {
         return match fail_on {
             FailOn::Warning => Verdict::Fail,
@@ -200,11 +294,13 @@ mod tests {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "root".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -222,7 +318,14 @@ mod tests {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -237,11 +340,14 @@ mod tests {
             max_findings: 200,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
         assert!(report.findings.is_empty());
         assert_eq!(report.verdict, Verdict::Pass);
+        assert_eq!(report.data.findings_total, 0);
+        assert_eq!(report.data.findings_suppressed, 1);
     }
 
     #[test]
@@ -249,11 +355,13 @@ mod tests {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "root".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -271,7 +379,14 @@ mod tests {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -286,6 +401,7 @@ mod tests {
             max_findings: 200,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
@@ -824,11 +940,13 @@ mod tests {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "root".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![
@@ -876,7 +994,14 @@ mod tests {
                         target: None,
                     },
                 ],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -892,6 +1017,7 @@ mod tests {
             max_findings: 200,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         // Evaluate multiple times
@@ -914,16 +1040,91 @@ mod tests {
         assert!(report1.findings[2].message.contains("dep_z"));
     }
 
+    #[test]
+    fn per_manifest_evaluation_merges_to_the_same_report() {
+        let manifest = |path: &str, name: &str, version: &str| ManifestModel {
+            path: RepoPath::new(path),
+            package: Some(PackageMeta {
+                name: name.to_string(),
+                publish: true,
+                ..PackageMeta::default()
+            }),
+            features: BTreeMap::new(),
+            dependencies: vec![DependencyDecl {
+                kind: DepKind::Normal,
+                name: "serde".to_string(),
+                spec: DepSpec {
+                    version: Some(version.to_string()),
+                    ..DepSpec::default()
+                },
+                location: Some(Location {
+                    path: RepoPath::new(path),
+                    line: Some(7),
+                    col: None,
+                }),
+                target: None,
+            }],
+            overrides: Vec::new(),
+            targets: Vec::new(),
+            lints: None,
+            workspace_resolver: None,
+        };
+        let model = WorkspaceModel {
+            repo_root: RepoPath::new("."),
+            workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
+            manifests: vec![
+                manifest("crates/a/Cargo.toml", "a", "*"),
+                manifest("crates/b/Cargo.toml", "b", "1.0"),
+            ],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
+        };
+        let mut checks = BTreeMap::new();
+        for id in [
+            depguard_types::ids::CHECK_DEPS_NO_WILDCARDS,
+            depguard_types::ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS,
+        ] {
+            checks.insert(id.to_string(), CheckPolicy::enabled(Severity::Warning));
+        }
+        let cfg = EffectiveConfig {
+            profile: "test".to_string(),
+            scope: Scope::Repo,
+            fail_on: FailOn::Error,
+            max_findings: 200,
+            yanked_index: None,
+            checks,
+            verdict_ignores: Vec::new(),
+        };
+
+        let whole = evaluate(&model, &cfg);
+
+        let mut pieces: Vec<Finding> = (0..model.manifests.len())
+            .flat_map(|i| evaluate_manifest(&model, i, &cfg))
+            .collect();
+        pieces.extend(evaluate_workspace(&model, &cfg));
+        let merged = report_from_findings(&model, &cfg, pieces);
+
+        let check_ids: BTreeSet<_> = whole.findings.iter().map(|f| f.check_id.as_str()).collect();
+        assert_eq!(check_ids.len(), 2, "both check scopes should report");
+        assert_eq!(whole.findings, merged.findings);
+        assert_eq!(whole.verdict, merged.verdict);
+        assert!(evaluate_manifest(&model, 5, &cfg).is_empty());
+    }
+
     #[test]
     fn verdict_warn_becomes_fail_when_fail_on_warning() {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "root".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -940,7 +1141,14 @@ mod tests {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -956,9 +1164,278 @@ mod tests {
             max_findings: 200,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
         assert_eq!(report.verdict, Verdict::Fail);
     }
+
+    #[test]
+    fn verdict_ignores_excludes_severities_from_verdict() {
+        let model = WorkspaceModel {
+            repo_root: RepoPath::new("."),
+            workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
+            manifests: vec![ManifestModel {
+                path: RepoPath::new("Cargo.toml"),
+                package: Some(PackageMeta {
+                    name: "root".to_string(),
+                    publish: true,
+                    ..PackageMeta::default()
+                }),
+                features: BTreeMap::new(),
+                dependencies: vec![DependencyDecl {
+                    kind: DepKind::Normal,
+                    name: "serde".to_string(),
+                    spec: DepSpec {
+                        version: Some("*".to_string()),
+                        ..DepSpec::default()
+                    },
+                    location: Some(Location {
+                        path: RepoPath::new("Cargo.toml"),
+                        line: Some(1),
+                        col: None,
+                    }),
+                    target: None,
+                }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
+            }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
+        };
+        let cfg = |severity, verdict_ignores| {
+            let mut checks = BTreeMap::new();
+            checks.insert(
+                depguard_types::ids::CHECK_DEPS_NO_WILDCARDS.to_string(),
+                CheckPolicy::enabled(severity),
+            );
+            EffectiveConfig {
+                profile: "warn".to_string(),
+                scope: Scope::Repo,
+                fail_on: FailOn::Warning,
+                max_findings: 200,
+                verdict_ignores,
+                yanked_index: None,
+                checks,
+            }
+        };
+
+        let report = evaluate(&model, &cfg(Severity::Info, vec![Severity::Info]));
+        assert_eq!(report.verdict, Verdict::Pass);
+        assert_eq!(
+            report.findings.len(),
+            1,
+            "ignored findings are still reported"
+        );
+
+        let report = evaluate(&model, &cfg(Severity::Warning, vec![Severity::Info]));
+        assert_eq!(report.verdict, Verdict::Fail);
+
+        let report = evaluate(
+            &model,
+            &cfg(Severity::Warning, vec![Severity::Info, Severity::Warning]),
+        );
+        assert_eq!(report.verdict, Verdict::Pass);
+        assert_eq!(report.counts.warning, 1);
+    }
+
+    #[test]
+    fn truncation_reason_token_only_when_total_exceeds_emitted() {
+        let wildcard = |name: &str, line: u32| DependencyDecl {
+            kind: DepKind::Normal,
+            name: name.to_string(),
+            spec: DepSpec {
+                version: Some("*".to_string()),
+                ..DepSpec::default()
+            },
+            location: Some(Location {
+                path: RepoPath::new("Cargo.toml"),
+                line: Some(line),
+                col: None,
+            }),
+            target: None,
+        };
+        let model = WorkspaceModel {
+            repo_root: RepoPath::new("."),
+            workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
+            manifests: vec![ManifestModel {
+                path: RepoPath::new("Cargo.toml"),
+                package: Some(PackageMeta {
+                    name: "root".to_string(),
+                    publish: true,
+                    ..PackageMeta::default()
+                }),
+                features: BTreeMap::new(),
+                dependencies: vec![wildcard("serde", 1), wildcard("tokio", 2)],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
+            }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
+        };
+
+        let mut checks = BTreeMap::new();
+        checks.insert(
+            depguard_types::ids::CHECK_DEPS_NO_WILDCARDS.to_string(),
+            CheckPolicy::enabled(Severity::Error),
+        );
+        let cfg = |max_findings| EffectiveConfig {
+            profile: "strict".to_string(),
+            scope: Scope::Repo,
+            fail_on: FailOn::Error,
+            max_findings,
+            yanked_index: None,
+            checks: checks.clone(),
+            verdict_ignores: Vec::new(),
+        };
+
+        let truncated = evaluate(&model, &cfg(1));
+        assert_eq!(truncated.data.findings_total, 2);
+        assert_eq!(truncated.data.findings_emitted, 1);
+        assert_eq!(
+            truncated.reasons,
+            vec![depguard_types::ids::REASON_FINDINGS_TRUNCATED.to_string()]
+        );
+
+        let complete = evaluate(&model, &cfg(2));
+        assert_eq!(complete.data.findings_emitted, 2);
+        assert!(complete.reasons.is_empty());
+    }
+
+    #[test]
+    fn fallback_fingerprint_ignores_line_and_volatile_message_details() {
+        let a = make_finding(
+            Severity::Error,
+            Some("Cargo.toml"),
+            Some(3),
+            "deps.custom",
+            "code",
+            "serde pinned to 1.0.100",
+        );
+        let b = make_finding(
+            Severity::Warning,
+            Some("Cargo.toml"),
+            Some(9),
+            "deps.custom",
+            "code",
+            "serde pinned to 1.0.210",
+        );
+        let c = make_finding(
+            Severity::Error,
+            Some("crates/a/Cargo.toml"),
+            Some(3),
+            "deps.custom",
+            "code",
+            "serde pinned to 1.0.100",
+        );
+
+        assert_eq!(fallback_fingerprint(&a), fallback_fingerprint(&b));
+        assert_ne!(fallback_fingerprint(&a), fallback_fingerprint(&c));
+        assert_eq!(fallback_fingerprint(&a).len(), 64);
+    }
+
+    /// A resolver-1 root with a member declaring an unreferenced optional dep.
+    fn resolver_v1_workspace() -> WorkspaceModel {
+        let member = |name: &str, path: &str, optional: bool| ManifestModel {
+            path: RepoPath::new(path),
+            package: Some(PackageMeta {
+                name: name.to_string(),
+                publish: true,
+                ..PackageMeta::default()
+            }),
+            features: BTreeMap::new(),
+            dependencies: if optional {
+                vec![DependencyDecl {
+                    kind: DepKind::Normal,
+                    name: "serde".to_string(),
+                    spec: DepSpec {
+                        version: Some("1".to_string()),
+                        optional: true,
+                        ..DepSpec::default()
+                    },
+                    location: Some(Location {
+                        path: RepoPath::new(path),
+                        line: Some(8),
+                        col: None,
+                    }),
+                    target: None,
+                }]
+            } else {
+                Vec::new()
+            },
+            overrides: Vec::new(),
+            targets: Vec::new(),
+            lints: None,
+            workspace_resolver: None,
+        };
+        let mut root = member("root", "Cargo.toml", false);
+        root.workspace_resolver = Some("1".to_string());
+        WorkspaceModel {
+            repo_root: RepoPath::new("."),
+            workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
+            manifests: vec![root, member("a", "crates/a/Cargo.toml", true)],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
+        }
+    }
+
+    fn optional_unused_config() -> EffectiveConfig {
+        let mut checks = BTreeMap::new();
+        checks.insert(
+            "deps.optional_unused".to_string(),
+            CheckPolicy::enabled(Severity::Warning),
+        );
+        EffectiveConfig {
+            profile: "strict".to_string(),
+            scope: Scope::Repo,
+            fail_on: FailOn::Error,
+            max_findings: 200,
+            yanked_index: None,
+            checks,
+            verdict_ignores: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn per_manifest_evaluation_honours_root_resolver_v1() {
+        let model = resolver_v1_workspace();
+        let cfg = optional_unused_config();
+
+        let sequential = evaluate(&model, &cfg);
+        let mut findings: Vec<Finding> = (0..model.manifests.len())
+            .flat_map(|index| evaluate_manifest(&model, index, &cfg))
+            .collect();
+        findings.extend(evaluate_workspace(&model, &cfg));
+        let per_manifest = report_from_findings(&model, &cfg, findings);
+
+        assert!(sequential.findings.is_empty());
+        assert!(per_manifest.findings.is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_evaluation_honours_root_resolver_v1() {
+        let model = resolver_v1_workspace();
+        let cfg = optional_unused_config();
+
+        let sequential = evaluate(&model, &cfg);
+        let parallel = evaluate_parallel(&model, &cfg);
+
+        assert!(sequential.findings.is_empty());
+        assert_eq!(
+            serde_json::to_vec(&sequential.findings).expect("serialize sequential"),
+            serde_json::to_vec(&parallel.findings).expect("serialize parallel")
+        );
+    }
 }
@@ -28,10 +28,82 @@ pub fn fingerprint_for_dep(
     hex::encode(digest)
 }
 
+/// Compute a fallback fingerprint for a finding whose check did not set one.
+///
+/// Identity fields:
+/// - check_id
+/// - code
+/// - location path (repo-relative; empty when the finding has no location)
+/// - message, normalized so volatile details (versions, counts, spacing) do
+///   not change the identity
+pub fn fingerprint_for_finding(check_id: &str, code: &str, path: &str, message: &str) -> String {
+    let canonical = format!(
+        "{}|{}|{}|{}",
+        check_id,
+        code,
+        path,
+        normalize_message(message)
+    );
+
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Collapse digit runs to `#` and whitespace runs to a single space.
+fn normalize_message(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut in_digits = false;
+    for word in message.split_whitespace() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        for ch in word.chars() {
+            if ch.is_ascii_digit() {
+                if !in_digits {
+                    out.push('#');
+                }
+                in_digits = true;
+            } else {
+                out.push(ch);
+                in_digits = false;
+            }
+        }
+        in_digits = false;
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn finding_fingerprint_ignores_volatile_message_details() {
+        let a = fingerprint_for_finding(
+            "deps.custom",
+            "code",
+            "Cargo.toml",
+            "serde resolved at 1.0.100 and 1.0.200",
+        );
+        let b = fingerprint_for_finding(
+            "deps.custom",
+            "code",
+            "Cargo.toml",
+            "serde  resolved at 1.0.201 and\n1.0.7",
+        );
+        let c = fingerprint_for_finding(
+            "deps.custom",
+            "code",
+            "Cargo.toml",
+            "tokio resolved at 1.0.100 and 1.0.200",
+        );
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(normalize_message(" v1.2.3  x\t42 "), "v#.#.# x #");
+    }
+
     #[test]
     fn fingerprint_is_stable_and_dep_path_sensitive() {
         let a = fingerprint_for_dep("check", "code", "Cargo.toml", "serde", None);
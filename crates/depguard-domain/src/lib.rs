@@ -16,5 +16,8 @@ mod fingerprint;
 #[cfg(test)]
 mod proptest;
 
-pub use engine::evaluate;
+pub use engine::{evaluate, evaluate_manifest, evaluate_workspace, report_from_findings};
 pub use policy::{CheckPolicy, EffectiveConfig, FailOn, Scope};
+
+#[cfg(feature = "parallel")]
+pub use engine::evaluate_parallel;
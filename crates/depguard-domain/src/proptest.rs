@@ -254,6 +254,7 @@ fn config_all_enabled(severity: Severity) -> EffectiveConfig {
         max_findings: 200,
         yanked_index: None,
         checks,
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -284,6 +285,7 @@ fn config_all_disabled() -> EffectiveConfig {
         max_findings: 200,
         yanked_index: None,
         checks,
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -621,7 +623,11 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(severity);
@@ -653,15 +659,20 @@ proptest! {
                         version: Some("1.0.0".to_string()),
                         path: None,
                         workspace: false,
+                        default_features: None,
+                        features: Vec::new(),
+                        location: None,
                     },
                 );
                 m
             },
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![
@@ -712,7 +723,14 @@ proptest! {
                         target: None,
                     },
                 ],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_disabled();
@@ -739,15 +757,20 @@ proptest! {
                         version: Some("1.0.0".to_string()),
                         path: None,
                         workspace: false,
+                        default_features: None,
+                        features: Vec::new(),
+                        location: None,
                     },
                 );
                 m
             },
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![
@@ -780,7 +803,14 @@ proptest! {
                         target: None,
                     },
                 ],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -809,11 +839,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -830,7 +862,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -859,11 +898,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -880,7 +921,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -909,11 +957,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: false, // Not publishable
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![
@@ -946,7 +996,14 @@ proptest! {
                         target: None,
                     },
                 ],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -998,15 +1055,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -1022,6 +1088,7 @@ proptest! {
             max_findings,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
@@ -1075,15 +1142,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut cfg = config_all_enabled(Severity::Warning);
@@ -1147,11 +1223,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![
@@ -1170,7 +1248,14 @@ proptest! {
                         target: None,
                     },
                 ],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(severity);
@@ -1240,15 +1325,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -1271,6 +1365,75 @@ proptest! {
         }
     }
 
+    /// Every finding carries a fingerprint, and the fingerprint of a finding does
+    /// not depend on where its dependency sits in the manifest.
+    #[test]
+    fn engine_fingerprints_are_stable_and_order_independent(
+        num_deps in 1usize..15,
+        seed in any::<u64>(),
+    ) {
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let deps: Vec<DependencyDecl> = (0..num_deps)
+            .map(|i| DependencyDecl {
+                kind: DepKind::Normal,
+                name: format!("dep{}", i),
+                spec: match i % 3 {
+                    0 => DepSpec { version: Some("*".to_string()), ..DepSpec::default() },
+                    1 => DepSpec { path: Some("../escape".to_string()), ..DepSpec::default() },
+                    _ => DepSpec { version: Some("1.0".to_string()), ..DepSpec::default() },
+                },
+                location: Some(Location {
+                    path: RepoPath::new("Cargo.toml"),
+                    line: Some((i + 1) as u32),
+                    col: None,
+                }),
+                target: None,
+            })
+            .collect();
+        let mut shuffled = deps.clone();
+        shuffled.shuffle(&mut rand::rngs::StdRng::seed_from_u64(seed));
+
+        let model_for = |dependencies: Vec<DependencyDecl>| WorkspaceModel {
+            repo_root: RepoPath::new("."),
+            workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
+            manifests: vec![ManifestModel {
+                path: RepoPath::new("Cargo.toml"),
+                package: Some(PackageMeta {
+                    name: "test-pkg".to_string(),
+                    publish: true,
+                    ..PackageMeta::default()
+                }),
+                features: BTreeMap::new(),
+                dependencies,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
+            }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
+        };
+
+        let cfg = config_all_enabled(Severity::Warning);
+        let ordered = evaluate(&model_for(deps.clone()), &cfg);
+        let rerun = evaluate(&model_for(deps), &cfg);
+        let reordered = evaluate(&model_for(shuffled), &cfg);
+
+        let fingerprints = |findings: &[Finding]| {
+            findings
+                .iter()
+                .map(|f| f.fingerprint.clone())
+                .collect::<Vec<_>>()
+        };
+        prop_assert!(ordered.findings.iter().all(|f| f.fingerprint.is_some()));
+        prop_assert_eq!(fingerprints(&ordered.findings), fingerprints(&rerun.findings));
+        prop_assert_eq!(fingerprints(&ordered.findings), fingerprints(&reordered.findings));
+    }
+
     /// JSON serialization of reports must be deterministic.
     #[test]
     fn engine_determinism_json_serialization(
@@ -1279,11 +1442,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -1300,7 +1465,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -1346,15 +1518,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -1370,6 +1551,7 @@ proptest! {
             max_findings,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
@@ -1435,15 +1617,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -1463,6 +1654,7 @@ proptest! {
             max_findings,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
@@ -1497,15 +1689,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -1521,6 +1722,7 @@ proptest! {
             max_findings,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = evaluate(&model, &cfg);
@@ -1548,11 +1750,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -1566,7 +1770,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -1591,7 +1802,11 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new(repo_root.unwrap_or_else(|| ".".to_string()).as_str()),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -1620,11 +1835,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -1641,7 +1858,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -1657,6 +1881,7 @@ proptest! {
             max_findings,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         let report = std::panic::catch_unwind(|| evaluate(&model, &cfg));
@@ -1690,15 +1915,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -1742,15 +1976,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new(&manifest_path),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -1774,11 +2017,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -1795,7 +2040,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -1995,11 +2247,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2016,7 +2270,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2030,11 +2291,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2051,7 +2314,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2065,11 +2335,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2086,7 +2358,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2100,11 +2379,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2121,7 +2402,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2135,11 +2423,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2156,7 +2446,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2170,11 +2467,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2191,7 +2490,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2212,11 +2518,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2233,7 +2541,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2383,11 +2698,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2405,7 +2722,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2419,11 +2743,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2441,7 +2767,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2455,11 +2788,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2476,7 +2811,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -2498,11 +2840,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2519,7 +2863,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -2543,11 +2894,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2564,7 +2917,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Error);
@@ -2585,11 +2945,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2607,7 +2969,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2717,11 +3086,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2739,7 +3110,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2753,11 +3131,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2775,7 +3155,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2789,11 +3176,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2811,7 +3200,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2825,11 +3221,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2847,7 +3245,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2861,11 +3266,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2883,7 +3290,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2897,11 +3311,13 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: vec![DependencyDecl {
@@ -2919,7 +3335,14 @@ proptest! {
                     }),
                     target: None,
                 }],
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -2984,18 +3407,30 @@ proptest! {
                     version: Some("1.0.0".to_string()),
                     path: None,
                     workspace: false,
+                    default_features: None,
+                    features: Vec::new(),
+                    location: None,
                 });
                 m
             },
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -3058,29 +3493,47 @@ proptest! {
         let model1 = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps1,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let model2 = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps2,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -3123,15 +3576,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let mut checks = BTreeMap::new();
@@ -3147,6 +3609,7 @@ proptest! {
             max_findings,
             yanked_index: None,
             checks,
+            verdict_ignores: Vec::new(),
         };
 
         // Run multiple times
@@ -3194,15 +3657,24 @@ proptest! {
         let model = WorkspaceModel {
             repo_root: RepoPath::new("."),
             workspace_dependencies: BTreeMap::new(),
+            resolved_versions: None,
             manifests: vec![ManifestModel {
                 path: RepoPath::new("Cargo.toml"),
                 package: Some(PackageMeta {
                     name: "test-pkg".to_string(),
                     publish: true,
+                    ..PackageMeta::default()
                 }),
                 features: BTreeMap::new(),
                 dependencies: deps,
+                overrides: Vec::new(),
+                targets: Vec::new(),
+                lints: None,
+                workspace_resolver: None,
             }],
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
         };
 
         let cfg = config_all_enabled(Severity::Warning);
@@ -3700,3 +4172,128 @@ mod unit_tests {
         assert!(assert_sorted(&findings).is_err());
     }
 }
+
+// ============================================================================
+// Property tests: Parallel evaluation matches sequential evaluation
+// ============================================================================
+
+#[cfg(feature = "parallel")]
+fn arb_workspace_model() -> impl Strategy<Value = WorkspaceModel> {
+    let dep = (
+        arb_dep_kind(),
+        arb_dep_name(),
+        prop_oneof![
+            arb_dep_spec(),
+            arb_wildcard_version().prop_map(|v| DepSpec {
+                version: Some(v),
+                ..DepSpec::default()
+            }),
+            arb_absolute_path().prop_map(|p| DepSpec {
+                path: Some(p),
+                ..DepSpec::default()
+            }),
+            arb_version().prop_map(|v| DepSpec {
+                version: Some(v),
+                optional: true,
+                ..DepSpec::default()
+            }),
+        ],
+    );
+    let manifest = (any::<bool>(), prop::collection::vec(dep, 0..6));
+    (
+        prop::collection::vec(manifest, 1..8),
+        prop::collection::btree_set(arb_dep_name(), 0..3),
+        prop::option::of(prop_oneof![Just("1"), Just("2")]),
+    )
+        .prop_map(|(manifests, ws_names, root_resolver)| WorkspaceModel {
+            repo_root: RepoPath::new("."),
+            workspace_dependencies: ws_names
+                .into_iter()
+                .map(|name| {
+                    let dep = WorkspaceDependency {
+                        name: name.clone(),
+                        version: Some("1.0".to_string()),
+                        path: None,
+                        workspace: true,
+                        default_features: None,
+                        features: Vec::new(),
+                        location: None,
+                    };
+                    (name, dep)
+                })
+                .collect(),
+            resolved_versions: None,
+            manifests: manifests
+                .into_iter()
+                .enumerate()
+                .map(|(i, (publish, deps))| {
+                    let path = if i == 0 {
+                        "Cargo.toml".to_string()
+                    } else {
+                        format!("crates/m{i}/Cargo.toml")
+                    };
+                    ManifestModel {
+                        path: RepoPath::new(path.as_str()),
+                        package: Some(PackageMeta {
+                            name: format!("m{i}"),
+                            publish,
+                            ..PackageMeta::default()
+                        }),
+                        features: BTreeMap::new(),
+                        dependencies: deps
+                            .into_iter()
+                            .enumerate()
+                            .map(|(line, (kind, name, spec))| DependencyDecl {
+                                kind,
+                                name,
+                                spec,
+                                location: Some(Location {
+                                    path: RepoPath::new(path.as_str()),
+                                    line: Some(line as u32 + 1),
+                                    col: None,
+                                }),
+                                target: None,
+                            })
+                            .collect(),
+                        overrides: Vec::new(),
+                        targets: Vec::new(),
+                        lints: None,
+                        workspace_resolver: root_resolver.filter(|_| i == 0).map(str::to_string),
+                    }
+                })
+                .collect(),
+            source_references: None,
+            unresolved_members: Vec::new(),
+            nested_workspaces: Vec::new(),
+        })
+}
+
+#[cfg(feature = "parallel")]
+proptest! {
+    /// Parallel evaluation must serialize to exactly the same findings as the
+    /// sequential engine, including order, truncation and verdict.
+    #[test]
+    fn parallel_evaluation_is_byte_identical_to_sequential(
+        model in arb_workspace_model(),
+        severity in arb_severity(),
+        max_findings in 1usize..40,
+    ) {
+        let mut cfg = config_all_enabled(severity);
+        cfg.max_findings = max_findings;
+        cfg.checks.insert(
+            ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS.to_string(),
+            CheckPolicy::enabled(severity),
+        );
+
+        let sequential = evaluate(&model, &cfg);
+        let parallel = crate::engine::evaluate_parallel(&model, &cfg);
+
+        prop_assert_eq!(
+            serde_json::to_vec(&sequential.findings).expect("serialize sequential"),
+            serde_json::to_vec(&parallel.findings).expect("serialize parallel")
+        );
+        prop_assert_eq!(sequential.verdict, parallel.verdict);
+        prop_assert_eq!(sequential.reasons, parallel.reasons);
+        prop_assert_eq!(sequential.data.findings_total, parallel.data.findings_total);
+    }
+}
@@ -1,6 +1,8 @@
 use depguard_types::{DepguardData, Finding, Severity, Verdict};
+use serde::Serialize;
+use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct SeverityCounts {
     pub info: u32,
     pub warning: u32,
@@ -21,12 +23,51 @@ impl SeverityCounts {
     }
 }
 
+/// Finding counts for programmatic consumers.
+///
+/// Counts cover every finding left after inline suppressions, including those
+/// cut from the report by `max_findings`; `emitted` is what the report keeps.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct FindingsSummary {
+    pub by_severity: SeverityCounts,
+    /// Findings per check id.
+    pub by_check: BTreeMap<String, u32>,
+    pub total: u32,
+    pub emitted: u32,
+    /// Findings dropped by inline suppressions (not counted in `total`).
+    pub suppressed: u32,
+}
+
+impl FindingsSummary {
+    pub fn from_findings(findings: &[Finding], emitted: u32, suppressed: u32) -> Self {
+        let mut by_check = BTreeMap::new();
+        for f in findings {
+            *by_check.entry(f.check_id.clone()).or_insert(0) += 1;
+        }
+        Self {
+            by_severity: SeverityCounts::from_findings(findings),
+            by_check,
+            total: findings.len() as u32,
+            emitted,
+            suppressed,
+        }
+    }
+
+    /// Findings cut from the report by `max_findings`.
+    pub fn truncated(&self) -> u32 {
+        self.total - self.emitted
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct DomainReport {
     pub verdict: Verdict,
     pub findings: Vec<Finding>,
     pub data: DepguardData,
     pub counts: SeverityCounts,
+    /// Verdict reason tokens, e.g. `findings_truncated` when `max_findings` cut the list.
+    pub reasons: Vec<String>,
+    pub summary: FindingsSummary,
 }
 
 #[cfg(test)]
@@ -155,6 +155,31 @@ serde = "*" # this is not a depguard directive
         assert!(suppressions.is_empty());
     }
 
+    #[test]
+    fn parse_inline_suppression_accepts_marker_without_space() {
+        let manifest = "[dependencies]\nserde = \"*\" # depguard:allow(deps.no_wildcards)\n";
+        let suppressions = parse_inline_suppressions(manifest, 2);
+
+        assert_eq!(suppressions, vec!["deps.no_wildcards".to_string()]);
+    }
+
+    #[test]
+    fn parse_inline_suppression_ignores_malformed_markers() {
+        for comment in [
+            "depguard: allow(deps.no_wildcards",
+            "depguard allow(deps.no_wildcards)",
+            "depguard: allow()",
+            "depguard: allow( , )",
+            "depguard: deny(deps.no_wildcards)",
+            "depguard: allow deps.no_wildcards",
+        ] {
+            let manifest = format!("[dependencies]\nserde = \"*\" # {comment}\n");
+            let suppressions = parse_inline_suppressions(&manifest, 2);
+
+            assert!(suppressions.is_empty(), "{comment:?} should not suppress");
+        }
+    }
+
     #[test]
     fn parse_inline_suppression_handles_zero_line() {
         let manifest = "serde = \"*\" # depguard: allow(no_wildcards)";
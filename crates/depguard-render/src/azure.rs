@@ -0,0 +1,157 @@
+use crate::gha::select_annotations;
+use crate::{AnnotationMode, RenderableFinding, RenderableReport, RenderableSeverity};
+
+/// Render findings as Azure Pipelines `task.logissue` logging commands.
+///
+/// Format:
+/// `##vso[task.logissue type={level};sourcepath={path};linenumber={line};columnnumber={col}]{message}`
+///
+/// Azure only knows `error` and `warning`, so info findings are reported as
+/// warnings. At most `max` commands are returned; `mode` behaves as for
+/// [`render_github_annotations`](crate::render_github_annotations).
+pub fn render_azure_annotations(
+    report: &RenderableReport,
+    max: usize,
+    mode: AnnotationMode,
+) -> Vec<String> {
+    select_annotations(report, max, mode, logissue)
+}
+
+fn logissue(f: &RenderableFinding, message: &str) -> String {
+    let level = match f.severity {
+        RenderableSeverity::Error => "error",
+        RenderableSeverity::Warning | RenderableSeverity::Info => "warning",
+    };
+
+    let mut props = format!("type={level}");
+    if let Some(loc) = &f.location {
+        props.push_str(&format!(";sourcepath={}", escape_property(&loc.path)));
+        if let Some(line) = loc.line {
+            props.push_str(&format!(";linenumber={line}"));
+        }
+        if let Some(col) = loc.col {
+            props.push_str(&format!(";columnnumber={col}"));
+        }
+    }
+
+    format!("##vso[task.logissue {}]{}", props, escape_message(message))
+}
+
+fn escape_message(s: &str) -> String {
+    s.replace('%', "%AZP25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(s: &str) -> String {
+    escape_message(s).replace(';', "%3B").replace(']', "%5D")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableLocation, RenderableVerdictStatus};
+
+    fn finding(
+        severity: RenderableSeverity,
+        message: &str,
+        location: Option<RenderableLocation>,
+    ) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: message.to_string(),
+            location,
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    fn report(findings: Vec<RenderableFinding>) -> RenderableReport {
+        RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            data: RenderableData {
+                findings_emitted: findings.len() as u32,
+                findings_total: findings.len() as u32,
+                truncated_reason: None,
+            },
+            findings,
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn logissue_lines_include_location_and_map_severity() {
+        let report = report(vec![
+            finding(
+                RenderableSeverity::Error,
+                "wildcard",
+                Some(RenderableLocation {
+                    path: "crates/a/Cargo.toml".to_string(),
+                    line: Some(7),
+                    col: Some(3),
+                }),
+            ),
+            finding(
+                RenderableSeverity::Warning,
+                "partial",
+                Some(RenderableLocation {
+                    path: "Cargo.toml".to_string(),
+                    line: Some(2),
+                    col: None,
+                }),
+            ),
+            RenderableFinding {
+                check_id: None,
+                code: "info".to_string(),
+                ..finding(RenderableSeverity::Info, "ok", None)
+            },
+        ]);
+
+        assert_eq!(
+            render_azure_annotations(&report, usize::MAX, AnnotationMode::Individual),
+            vec![
+                "##vso[task.logissue type=error;sourcepath=crates/a/Cargo.toml;linenumber=7;columnnumber=3][deps.no_wildcards:wildcard_version] wildcard",
+                "##vso[task.logissue type=warning;sourcepath=Cargo.toml;linenumber=2][deps.no_wildcards:wildcard_version] partial",
+                "##vso[task.logissue type=warning][depguard:info] ok",
+            ]
+        );
+    }
+
+    #[test]
+    fn logissue_escapes_message_and_properties() {
+        let report = report(vec![finding(
+            RenderableSeverity::Error,
+            "50%\r\nnext",
+            Some(RenderableLocation {
+                path: "odd;dir]/Cargo.toml".to_string(),
+                line: None,
+                col: None,
+            }),
+        )]);
+
+        assert_eq!(
+            render_azure_annotations(&report, usize::MAX, AnnotationMode::Individual),
+            vec![
+                "##vso[task.logissue type=error;sourcepath=odd%3Bdir%5D/Cargo.toml][deps.no_wildcards:wildcard_version] 50%AZP25%0D%0Anext",
+            ]
+        );
+    }
+
+    #[test]
+    fn logissue_respects_max() {
+        let report = report(vec![
+            finding(RenderableSeverity::Error, "one", None),
+            finding(RenderableSeverity::Error, "two", None),
+        ]);
+        assert_eq!(
+            render_azure_annotations(&report, 1, AnnotationMode::Individual).len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity};
+
+/// File element used for findings that carry no location.
+const ROOT_MANIFEST: &str = "Cargo.toml";
+
+/// Render findings as a Checkstyle XML document.
+///
+/// Findings are grouped into one `<file>` element per location path, in path
+/// order; within a file they keep report order. Findings without a location
+/// attach to the root `Cargo.toml`. The check id is emitted as `source`.
+pub fn render_checkstyle(report: &RenderableReport) -> String {
+    let mut files: BTreeMap<&str, Vec<&RenderableFinding>> = BTreeMap::new();
+    for finding in &report.findings {
+        let path = finding
+            .location
+            .as_ref()
+            .map_or(ROOT_MANIFEST, |loc| loc.path.as_str());
+        files.entry(path).or_default().push(finding);
+    }
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<checkstyle version=\"4.3\">\n");
+    for (path, findings) in &files {
+        out.push_str(&format!("  <file name=\"{}\">\n", xml_escape(path)));
+        for finding in findings {
+            out.push_str(&error_element(finding));
+        }
+        out.push_str("  </file>\n");
+    }
+    out.push_str("</checkstyle>\n");
+    out
+}
+
+fn error_element(finding: &RenderableFinding) -> String {
+    let source = finding
+        .check_id
+        .clone()
+        .unwrap_or_else(|| format!("depguard.{}", finding.code));
+    let line = finding.location.as_ref().and_then(|loc| loc.line);
+    let col = finding.location.as_ref().and_then(|loc| loc.col);
+
+    let mut attrs = format!("line=\"{}\"", line.unwrap_or(1));
+    if let Some(col) = col {
+        attrs.push_str(&format!(" column=\"{col}\""));
+    }
+    attrs.push_str(&format!(
+        " severity=\"{}\" message=\"{}\" source=\"{}\"",
+        severity_str(finding.severity),
+        xml_escape(&finding.message),
+        xml_escape(&source)
+    ));
+    format!("    <error {attrs}/>\n")
+}
+
+fn severity_str(severity: RenderableSeverity) -> &'static str {
+    match severity {
+        RenderableSeverity::Info => "info",
+        RenderableSeverity::Warning => "warning",
+        RenderableSeverity::Error => "error",
+    }
+}
+
+fn xml_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableLocation, RenderableVerdictStatus};
+
+    fn finding(
+        severity: RenderableSeverity,
+        path: Option<&str>,
+        line: Option<u32>,
+        message: &str,
+    ) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: message.to_string(),
+            location: path.map(|p| RenderableLocation {
+                path: p.to_string(),
+                line,
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    fn report(findings: Vec<RenderableFinding>) -> RenderableReport {
+        let n = findings.len() as u32;
+        RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            findings,
+            data: RenderableData {
+                findings_emitted: n,
+                findings_total: n,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn groups_findings_in_the_same_file() {
+        let xml = render_checkstyle(&report(vec![
+            finding(
+                RenderableSeverity::Error,
+                Some("crates/a/Cargo.toml"),
+                Some(7),
+                "first",
+            ),
+            finding(
+                RenderableSeverity::Warning,
+                Some("Cargo.toml"),
+                Some(3),
+                "root",
+            ),
+            finding(
+                RenderableSeverity::Error,
+                Some("crates/a/Cargo.toml"),
+                Some(9),
+                "second",
+            ),
+        ]));
+
+        assert_eq!(xml.matches("<file ").count(), 2);
+        assert_eq!(
+            xml.matches("<file name=\"crates/a/Cargo.toml\">").count(),
+            1
+        );
+        assert!(xml.contains(concat!(
+            "  <file name=\"crates/a/Cargo.toml\">\n",
+            "    <error line=\"7\" severity=\"error\" message=\"first\" source=\"deps.no_wildcards\"/>\n",
+            "    <error line=\"9\" severity=\"error\" message=\"second\" source=\"deps.no_wildcards\"/>\n",
+            "  </file>\n"
+        )));
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle"));
+        assert!(xml.trim_end().ends_with("</checkstyle>"));
+    }
+
+    #[test]
+    fn maps_severities_and_attaches_unlocated_findings_to_root_manifest() {
+        let xml = render_checkstyle(&report(vec![
+            finding(RenderableSeverity::Info, None, None, "note"),
+            finding(
+                RenderableSeverity::Warning,
+                Some("Cargo.toml"),
+                Some(2),
+                "warn",
+            ),
+            finding(RenderableSeverity::Error, Some("Cargo.toml"), None, "err"),
+        ]));
+
+        assert_eq!(xml.matches("<file ").count(), 1);
+        assert!(xml.contains("<error line=\"1\" severity=\"info\" message=\"note\""));
+        assert!(xml.contains("<error line=\"2\" severity=\"warning\" message=\"warn\""));
+        assert!(xml.contains("<error line=\"1\" severity=\"error\" message=\"err\""));
+    }
+
+    #[test]
+    fn escapes_attribute_values() {
+        let mut f = finding(
+            RenderableSeverity::Error,
+            Some("odd\"<dir>/Cargo.toml"),
+            Some(4),
+            "bad <value> & \"quote\"",
+        );
+        f.location.as_mut().unwrap().col = Some(5);
+        let xml = render_checkstyle(&report(vec![f]));
+
+        assert!(xml.contains("<file name=\"odd&quot;&lt;dir&gt;/Cargo.toml\">"));
+        assert!(xml.contains(
+            "<error line=\"4\" column=\"5\" severity=\"error\" message=\"bad &lt;value&gt; &amp; &quot;quote&quot;\""
+        ));
+    }
+}
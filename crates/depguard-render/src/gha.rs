@@ -1,44 +1,116 @@
-use crate::{RenderableReport, RenderableSeverity};
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity};
+
+/// How to spend a limited annotation budget.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnnotationMode {
+    /// One annotation per finding, truncated at the cap.
+    #[default]
+    Individual,
+    /// When findings exceed the cap, one annotation per check id carrying the
+    /// finding count and the first location, so a single noisy check cannot
+    /// crowd out the rest.
+    Summarized,
+}
 
 /// Render findings as GitHub Actions workflow command annotations.
 ///
 /// Format:
 /// `::{level} file={path},line={line},col={col}::{message}`
-pub fn render_github_annotations(report: &RenderableReport) -> Vec<String> {
-    let mut out = Vec::new();
+///
+/// At most `max` annotations are returned.
+pub fn render_github_annotations(
+    report: &RenderableReport,
+    max: usize,
+    mode: AnnotationMode,
+) -> Vec<String> {
+    select_annotations(report, max, mode, annotation)
+}
+
+/// Apply the `max` cap and `mode` to a report, formatting each selected
+/// finding (with its display message) through `emit`.
+pub(crate) fn select_annotations(
+    report: &RenderableReport,
+    max: usize,
+    mode: AnnotationMode,
+    emit: fn(&RenderableFinding, &str) -> String,
+) -> Vec<String> {
+    if mode == AnnotationMode::Individual || report.findings.len() <= max {
+        return report
+            .findings
+            .iter()
+            .take(max)
+            .map(|f| emit(f, &finding_message(f)))
+            .collect();
+    }
 
+    // Groups in first-seen order; findings arrive sorted most severe first.
+    let mut groups: Vec<(&str, Vec<&RenderableFinding>)> = Vec::new();
     for f in &report.findings {
-        let level = match f.severity {
-            RenderableSeverity::Error => "error",
-            RenderableSeverity::Warning => "warning",
-            RenderableSeverity::Info => "notice",
-        };
+        let check_id = f.check_id.as_deref().unwrap_or("depguard");
+        match groups.iter_mut().find(|(id, _)| *id == check_id) {
+            Some((_, members)) => members.push(f),
+            None => groups.push((check_id, vec![f])),
+        }
+    }
 
-        let mut meta = String::new();
-        if let Some(loc) = &f.location {
-            meta.push_str(&format!("file={}", loc.path.as_str()));
-            if let Some(line) = loc.line {
-                meta.push_str(&format!(",line={}", line));
-            }
-            if let Some(col) = loc.col {
-                meta.push_str(&format!(",col={}", col));
+    groups
+        .into_iter()
+        .take(max)
+        .map(|(check_id, members)| {
+            let first = members[0];
+            if members.len() == 1 {
+                return emit(first, &finding_message(first));
             }
-        }
+            let message = format!(
+                "[{}] {} findings; first: {}",
+                check_id,
+                members.len(),
+                first.message
+            );
+            let mut lead = first.clone();
+            lead.severity = members
+                .iter()
+                .map(|f| f.severity)
+                .max()
+                .unwrap_or(first.severity);
+            emit(&lead, &message)
+        })
+        .collect()
+}
 
-        let check_id = f.check_id.as_deref().unwrap_or("depguard");
-        let message = format!("[{}:{}] {}", check_id, f.code, f.message)
-            .replace('%', "%25")
-            .replace('\r', "%0D")
-            .replace('\n', "%0A");
-
-        if meta.is_empty() {
-            out.push(format!("::{}::{}", level, message));
-        } else {
-            out.push(format!("::{} {}::{}", level, meta, message));
+fn finding_message(f: &RenderableFinding) -> String {
+    let check_id = f.check_id.as_deref().unwrap_or("depguard");
+    format!("[{}:{}] {}", check_id, f.code, f.message)
+}
+
+fn annotation(f: &RenderableFinding, message: &str) -> String {
+    let level = match f.severity {
+        RenderableSeverity::Error => "error",
+        RenderableSeverity::Warning => "warning",
+        RenderableSeverity::Info => "notice",
+    };
+
+    let mut meta = String::new();
+    if let Some(loc) = &f.location {
+        meta.push_str(&format!("file={}", loc.path.as_str()));
+        if let Some(line) = loc.line {
+            meta.push_str(&format!(",line={}", line));
+        }
+        if let Some(col) = loc.col {
+            meta.push_str(&format!(",col={}", col));
         }
     }
 
-    out
+    let message = message
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+
+    if meta.is_empty() {
+        format!("::{}::{}", level, message)
+    } else {
+        format!("::{} {}::{}", level, meta, message)
+    }
 }
 
 #[cfg(test)]
@@ -66,6 +138,7 @@ mod tests {
                     }),
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Info,
@@ -75,6 +148,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -82,9 +156,14 @@ mod tests {
                 findings_total: 2,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
-        let annotations = render_github_annotations(&report);
+        let annotations =
+            render_github_annotations(&report, usize::MAX, AnnotationMode::Individual);
         assert_eq!(
             annotations[0],
             "::error file=Cargo.toml,line=2,col=3::[deps.no_wildcards:wildcard_version] bad%25line%0D%0Anext"
@@ -108,18 +187,105 @@ mod tests {
                 }),
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
-        let annotations = render_github_annotations(&report);
+        let annotations =
+            render_github_annotations(&report, usize::MAX, AnnotationMode::Individual);
         assert_eq!(
             annotations[0],
             "::warning file=src/lib.rs,line=10::[depguard:warn_code] be careful"
         );
     }
+
+    fn wildcard(path: &str, line: u32, severity: RenderableSeverity) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: format!("wildcard at {line}"),
+            location: Some(RenderableLocation {
+                path: path.to_string(),
+                line: Some(line),
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    fn over_cap_report() -> RenderableReport {
+        let mut findings: Vec<_> = (1..=12)
+            .map(|line| wildcard("Cargo.toml", line, RenderableSeverity::Warning))
+            .collect();
+        findings[3].severity = RenderableSeverity::Error;
+        findings.push(RenderableFinding {
+            check_id: Some("deps.path_safety".to_string()),
+            code: "parent_escape".to_string(),
+            message: "path escapes repo".to_string(),
+            ..wildcard("crates/a/Cargo.toml", 4, RenderableSeverity::Error)
+        });
+        RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            data: RenderableData {
+                findings_emitted: findings.len() as u32,
+                findings_total: findings.len() as u32,
+                truncated_reason: None,
+            },
+            findings,
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn individual_mode_truncates_at_cap() {
+        let annotations =
+            render_github_annotations(&over_cap_report(), 10, AnnotationMode::Individual);
+        assert_eq!(annotations.len(), 10);
+        assert!(
+            annotations
+                .iter()
+                .all(|a| a.contains("[deps.no_wildcards:wildcard_version]"))
+        );
+        assert!(!annotations.iter().any(|a| a.contains("deps.path_safety")));
+    }
+
+    #[test]
+    fn summarized_mode_emits_one_annotation_per_check_over_cap() {
+        let annotations =
+            render_github_annotations(&over_cap_report(), 10, AnnotationMode::Summarized);
+        assert_eq!(
+            annotations,
+            vec![
+                "::error file=Cargo.toml,line=1::[deps.no_wildcards] 12 findings; first: wildcard at 1",
+                "::error file=crates/a/Cargo.toml,line=4::[deps.path_safety:parent_escape] path escapes repo",
+            ]
+        );
+
+        let capped = render_github_annotations(&over_cap_report(), 1, AnnotationMode::Summarized);
+        assert_eq!(capped.len(), 1);
+    }
+
+    #[test]
+    fn summarized_mode_is_individual_within_cap() {
+        let report = over_cap_report();
+        assert_eq!(
+            render_github_annotations(&report, 20, AnnotationMode::Summarized),
+            render_github_annotations(&report, 20, AnnotationMode::Individual)
+        );
+    }
 }
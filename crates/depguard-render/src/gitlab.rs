@@ -0,0 +1,164 @@
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+/// Path used for findings that carry no location (GitLab requires one).
+const ROOT_MANIFEST: &str = "Cargo.toml";
+
+/// Render findings as a GitLab Code Quality report (JSON array of issues).
+///
+/// See <https://docs.gitlab.com/ee/ci/testing/code_quality.html#code-quality-report-format>.
+/// Findings without a location are attributed to the root `Cargo.toml`. The
+/// engine fingerprint is used as the issue fingerprint when present.
+pub fn render_gitlab_codequality(report: &RenderableReport) -> String {
+    let issues: Vec<Value> = report.findings.iter().map(issue_for).collect();
+    serde_json::to_string_pretty(&issues).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn issue_for(finding: &RenderableFinding) -> Value {
+    let check_name = finding
+        .check_id
+        .clone()
+        .unwrap_or_else(|| format!("depguard.{}", finding.code));
+    let (path, line) = match &finding.location {
+        Some(loc) => (loc.path.as_str(), loc.line.unwrap_or(1)),
+        None => (ROOT_MANIFEST, 1),
+    };
+
+    let description = match &finding.help {
+        Some(help) => format!("{} ({})", finding.message, help),
+        None => finding.message.clone(),
+    };
+
+    json!({
+        "description": description,
+        "check_name": check_name,
+        "fingerprint": finding
+            .fingerprint
+            .clone()
+            .unwrap_or_else(|| fingerprint(&check_name, &finding.code, path, line)),
+        "severity": severity_str(finding.severity),
+        "location": {
+            "path": path,
+            "lines": { "begin": line },
+        },
+    })
+}
+
+/// Stable SHA-256 over `check_id|code|path|line`, for findings without an engine fingerprint.
+fn fingerprint(check_name: &str, code: &str, path: &str, line: u32) -> String {
+    let canonical = format!("{}|{}|{}|{}", check_name, code, path, line);
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+fn severity_str(severity: RenderableSeverity) -> &'static str {
+    match severity {
+        RenderableSeverity::Info => "info",
+        RenderableSeverity::Warning => "minor",
+        RenderableSeverity::Error => "critical",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableLocation, RenderableVerdictStatus};
+
+    fn finding(
+        severity: RenderableSeverity,
+        location: Option<RenderableLocation>,
+    ) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: "dependency uses wildcard".to_string(),
+            location,
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    fn report(findings: Vec<RenderableFinding>) -> RenderableReport {
+        RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            data: RenderableData {
+                findings_emitted: findings.len() as u32,
+                findings_total: findings.len() as u32,
+                truncated_reason: None,
+            },
+            findings,
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn renders_issue_fields() {
+        let json = render_gitlab_codequality(&report(vec![finding(
+            RenderableSeverity::Error,
+            Some(RenderableLocation {
+                path: "crates/a/Cargo.toml".to_string(),
+                line: Some(12),
+                col: Some(1),
+            }),
+        )]));
+        let issues: Vec<Value> = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(issues.len(), 1);
+        let issue = &issues[0];
+        assert_eq!(issue["check_name"], "deps.no_wildcards");
+        assert_eq!(issue["description"], "dependency uses wildcard");
+        assert_eq!(issue["severity"], "critical");
+        assert_eq!(issue["location"]["path"], "crates/a/Cargo.toml");
+        assert_eq!(issue["location"]["lines"]["begin"], 12);
+        assert_eq!(issue["fingerprint"].as_str().map(str::len), Some(64));
+    }
+
+    #[test]
+    fn attributes_locationless_findings_to_root_manifest() {
+        let json =
+            render_gitlab_codequality(&report(vec![finding(RenderableSeverity::Warning, None)]));
+        let issues: Vec<Value> = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(issues[0]["location"]["path"], "Cargo.toml");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 1);
+        assert_eq!(issues[0]["severity"], "minor");
+    }
+
+    #[test]
+    fn fingerprint_is_stable_and_line_sensitive() {
+        let at = |line| {
+            finding(
+                RenderableSeverity::Info,
+                Some(RenderableLocation {
+                    path: "Cargo.toml".to_string(),
+                    line: Some(line),
+                    col: None,
+                }),
+            )
+        };
+        let json = render_gitlab_codequality(&report(vec![at(3), at(3), at(4)]));
+        let issues: Vec<Value> = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+        assert_ne!(issues[0]["fingerprint"], issues[2]["fingerprint"]);
+        assert_eq!(issues[0]["severity"], "info");
+    }
+
+    #[test]
+    fn prefers_engine_fingerprint() {
+        let mut f = finding(RenderableSeverity::Error, None);
+        f.fingerprint = Some("abc123".to_string());
+        let json = render_gitlab_codequality(&report(vec![f]));
+        let issues: Vec<Value> = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(issues[0]["fingerprint"], "abc123");
+    }
+
+    #[test]
+    fn empty_report_renders_empty_array() {
+        assert_eq!(render_gitlab_codequality(&report(Vec::new())), "[]");
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity, RenderableVerdictStatus};
+
+const STYLE: &str = "\
+body{font-family:-apple-system,BlinkMacSystemFont,\"Segoe UI\",Helvetica,Arial,sans-serif;\
+margin:2rem auto;max-width:72rem;padding:0 1rem;color:#1f2328;}
+h1{font-size:1.5rem;margin-bottom:1rem;}
+.verdict{padding:.75rem 1rem;border-radius:6px;font-weight:600;color:#fff;}
+.verdict-pass{background:#1a7f37;}
+.verdict-warn{background:#9a6700;}
+.verdict-fail{background:#cf222e;}
+.verdict-skip{background:#57606a;}
+.note{color:#57606a;margin:.5rem 0;}
+table{border-collapse:collapse;width:100%;margin-top:1rem;font-size:.9rem;}
+th,td{border-bottom:1px solid #d0d7de;padding:.4rem .6rem;text-align:left;vertical-align:top;}
+th{background:#f6f8fa;cursor:default;white-space:nowrap;}
+th.sorted::after{content:\" \\25BE\";}
+td.sev{font-weight:600;white-space:nowrap;}
+.sev-error{color:#cf222e;}
+.sev-warning{color:#9a6700;}
+.sev-info{color:#0969da;}
+code{font-family:ui-monospace,SFMono-Regular,Menlo,monospace;font-size:.85em;}
+.help{color:#57606a;font-size:.85em;margin-top:.25rem;}
+footer{margin-top:2rem;color:#57606a;font-size:.8rem;}
+";
+
+/// Render a report as a self-contained HTML page for archiving or attaching to tickets.
+///
+/// Styles are inlined and no scripts or external assets are referenced. Findings
+/// are listed most severe first, then by location.
+pub fn render_html(report: &RenderableReport) -> String {
+    let (verdict, verdict_class) = match report.verdict {
+        RenderableVerdictStatus::Pass => ("PASS", "pass"),
+        RenderableVerdictStatus::Warn => ("WARN", "warn"),
+        RenderableVerdictStatus::Fail => ("FAIL", "fail"),
+        RenderableVerdictStatus::Skip => ("SKIP", "skip"),
+    };
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    out.push_str("<meta charset=\"utf-8\">\n");
+    out.push_str("<title>Depguard report</title>\n");
+    out.push_str("<style>\n");
+    out.push_str(STYLE);
+    out.push_str("</style>\n</head>\n<body>\n");
+    out.push_str("<h1>Depguard report</h1>\n");
+    out.push_str(&format!(
+        "<div class=\"verdict verdict-{}\">Verdict: {}</div>\n",
+        verdict_class, verdict
+    ));
+    out.push_str(&format!(
+        "<p class=\"note\">Findings: {} (emitted) / {} (total)</p>\n",
+        report.data.findings_emitted, report.data.findings_total
+    ));
+    if let Some(reason) = &report.data.truncated_reason {
+        out.push_str(&format!(
+            "<p class=\"note\">Note: {}</p>\n",
+            html_escape(reason)
+        ));
+    }
+    if report.hidden_findings > 0 {
+        out.push_str(&format!(
+            "<p class=\"note\">{} lower-severity finding{} hidden by <code>--min-severity</code>.</p>\n",
+            report.hidden_findings,
+            if report.hidden_findings == 1 { "" } else { "s" }
+        ));
+    }
+
+    if report.findings.is_empty() {
+        out.push_str("<p>No findings.</p>\n");
+    } else {
+        let mut findings: Vec<&RenderableFinding> = report.findings.iter().collect();
+        findings.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| location_label(a).cmp(&location_label(b)))
+                .then_with(|| a.check_id.cmp(&b.check_id))
+        });
+
+        out.push_str("<table>\n<thead>\n<tr>");
+        out.push_str(
+            "<th class=\"sorted\">Severity</th><th>Location</th><th>Check</th><th>Message</th>",
+        );
+        out.push_str("</tr>\n</thead>\n<tbody>\n");
+        for f in findings {
+            render_row(&mut out, f);
+        }
+        out.push_str("</tbody>\n</table>\n");
+    }
+
+    out.push_str("<footer>Generated by depguard");
+    if let Some(version) = &report.tool_version {
+        out.push_str(&format!(" {}", html_escape(version)));
+    }
+    if let Some(started_at) = &report.started_at {
+        out.push_str(&format!(
+            " &middot; run started {}",
+            html_escape(started_at)
+        ));
+    }
+    out.push_str("</footer>\n</body>\n</html>\n");
+    out
+}
+
+fn render_row(out: &mut String, f: &RenderableFinding) {
+    let (label, class) = match f.severity {
+        RenderableSeverity::Error => ("error", "sev-error"),
+        RenderableSeverity::Warning => ("warning", "sev-warning"),
+        RenderableSeverity::Info => ("info", "sev-info"),
+    };
+    out.push_str("<tr class=\"finding\">");
+    out.push_str(&format!("<td class=\"sev {}\">{}</td>", class, label));
+    out.push_str(&format!(
+        "<td><code>{}</code></td>",
+        html_escape(&location_label(f))
+    ));
+    out.push_str(&format!(
+        "<td><code>{}</code><br><code>{}</code></td>",
+        html_escape(f.check_id.as_deref().unwrap_or("-")),
+        html_escape(&f.code)
+    ));
+    out.push_str(&format!("<td>{}", html_escape(&f.message)));
+    if let Some(help) = &f.help {
+        out.push_str(&format!("<div class=\"help\">{}</div>", html_escape(help)));
+    }
+    if let Some(url) = &f.url {
+        out.push_str(&format!(
+            "<div class=\"help\"><a href=\"{}\">{}</a></div>",
+            html_escape(url),
+            html_escape(url)
+        ));
+    }
+    out.push_str("</td></tr>\n");
+}
+
+fn location_label(f: &RenderableFinding) -> String {
+    match &f.location {
+        Some(loc) => match loc.line {
+            Some(line) => format!("{}:{}", loc.path, line),
+            None => loc.path.clone(),
+        },
+        None => "-".to_string(),
+    }
+}
+
+fn html_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableLocation};
+
+    fn finding(severity: RenderableSeverity, message: &str, line: u32) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: message.to_string(),
+            location: Some(RenderableLocation {
+                path: "Cargo.toml".to_string(),
+                line: Some(line),
+                col: None,
+            }),
+            help: Some("Pin a version.".to_string()),
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    #[test]
+    fn render_html_contains_verdict_and_one_row_per_finding() {
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            findings: vec![
+                finding(RenderableSeverity::Warning, "serde uses *", 9),
+                finding(RenderableSeverity::Error, "tokio uses <*> & more", 7),
+            ],
+            data: RenderableData {
+                findings_emitted: 2,
+                findings_total: 2,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: Some("0.1.0".to_string()),
+            started_at: Some("2026-10-15T12:00:00Z".to_string()),
+        };
+
+        let html = render_html(&report);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<div class=\"verdict verdict-fail\">Verdict: FAIL</div>"));
+        assert_eq!(html.matches("<tr class=\"finding\">").count(), 2);
+        assert!(html.contains("tokio uses &lt;*&gt; &amp; more"));
+        assert!(html.contains("<code>Cargo.toml:7</code>"));
+        let error_row = html.find("tokio uses").unwrap();
+        let warning_row = html.find("serde uses").unwrap();
+        assert!(error_row < warning_row, "errors are listed first");
+        assert!(html.contains("Generated by depguard 0.1.0"));
+        assert!(html.contains("run started 2026-10-15T12:00:00Z"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<link"));
+    }
+
+    #[test]
+    fn render_html_without_findings_says_so() {
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Pass,
+            findings: Vec::new(),
+            data: RenderableData {
+                findings_emitted: 0,
+                findings_total: 0,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let html = render_html(&report);
+        assert!(html.contains("Verdict: PASS"));
+        assert!(html.contains("<p>No findings.</p>"));
+        assert!(!html.contains("<table>"));
+        assert!(html.contains("<footer>Generated by depguard</footer>"));
+    }
+}
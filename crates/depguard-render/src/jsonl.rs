@@ -40,6 +40,36 @@ pub fn render_jsonl(report: &RenderableReport) -> String {
     out
 }
 
+/// One self-contained JSON object per finding, in report order, with no summary line.
+///
+/// Meant for consumers that ingest findings incrementally; an empty report renders
+/// as an empty string.
+pub fn render_ndjson(report: &RenderableReport) -> String {
+    let mut out = String::new();
+    for finding in &report.findings {
+        let location = finding.location.as_ref().map(|loc| {
+            json!({
+                "path": loc.path,
+                "line": loc.line,
+                "col": loc.col,
+            })
+        });
+        let obj = json!({
+            "check_id": finding.check_id,
+            "code": finding.code,
+            "severity": severity_str(finding.severity),
+            "location": location,
+            "message": finding.message,
+            "fingerprint": finding.fingerprint,
+            "help": finding.help,
+            "url": finding.url,
+        });
+        out.push_str(&obj.to_string());
+        out.push('\n');
+    }
+    out
+}
+
 fn severity_str(severity: RenderableSeverity) -> &'static str {
     match severity {
         RenderableSeverity::Info => "info",
@@ -81,12 +111,17 @@ mod tests {
                 }),
                 help: Some("pin it".to_string()),
                 url: Some("https://example.invalid/help".to_string()),
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let output = render_jsonl(&report);
@@ -115,6 +150,10 @@ mod tests {
                 findings_total: 0,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let output = render_jsonl(&report);
@@ -125,4 +164,59 @@ mod tests {
         assert_eq!(summary["kind"], "summary");
         assert_eq!(summary["verdict"], "pass");
     }
+
+    #[test]
+    fn render_ndjson_emits_one_object_per_finding() {
+        let finding = |code: &str, fingerprint: Option<&str>| RenderableFinding {
+            severity: RenderableSeverity::Warning,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: code.to_string(),
+            message: "dependency uses wildcard".to_string(),
+            location: Some(RenderableLocation {
+                path: "crates/a/Cargo.toml".to_string(),
+                line: Some(4),
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: fingerprint.map(str::to_string),
+        };
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Warn,
+            findings: vec![
+                finding("wildcard_version", Some("abc")),
+                finding("other", None),
+            ],
+            data: RenderableData {
+                findings_emitted: 2,
+                findings_total: 2,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let output = render_ndjson(&report);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), report.findings.len());
+        for line in &lines {
+            let value: serde_json::Value = serde_json::from_str(line).expect("ndjson line");
+            assert!(value.is_object());
+            assert_eq!(value["check_id"], "deps.no_wildcards");
+            assert_eq!(value["severity"], "warning");
+            assert_eq!(value["location"]["path"], "crates/a/Cargo.toml");
+            assert_eq!(value["location"]["line"], 4);
+        }
+        let first: serde_json::Value = serde_json::from_str(lines[0]).expect("ndjson line");
+        assert_eq!(first["code"], "wildcard_version");
+        assert_eq!(first["fingerprint"], "abc");
+
+        let empty = RenderableReport {
+            findings: Vec::new(),
+            ..report
+        };
+        assert_eq!(render_ndjson(&empty), "");
+    }
 }
@@ -1,22 +1,41 @@
-use crate::{RenderableReport, RenderableSeverity, RenderableVerdictStatus};
+use std::collections::BTreeMap;
 
+use depguard_types::{explain, ids};
+
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity, RenderableVerdictStatus};
+
+const UNKNOWN_CHECK_ID: &str = "depguard.unknown";
+
+/// Render a report as a JUnit XML `<testsuites>` document.
+///
+/// Each known check becomes a `<testsuite>`; checks without findings carry a
+/// single passing testcase so the suite count is stable across runs. Warning
+/// and error findings only render as `<failure>` when the report verdict is
+/// `Fail`; Info findings, and all findings in passing or warning reports, are
+/// passing testcases with `<system-out>` details. Report-level properties are
+/// repeated in every suite, since the JUnit schema only allows `<properties>`
+/// inside `<testsuite>`.
 pub fn render_junit(report: &RenderableReport) -> String {
-    let tests = report.findings.len();
-    let failures = report
-        .findings
+    let mut suites: BTreeMap<&str, Vec<&RenderableFinding>> = explain::all_check_ids()
         .iter()
-        .filter(|f| {
-            matches!(
+        .filter(|id| **id != ids::CHECK_TOOL_RUNTIME)
+        .map(|id| (*id, Vec::new()))
+        .collect();
+    for finding in &report.findings {
+        let check_id = finding.check_id.as_deref().unwrap_or(UNKNOWN_CHECK_ID);
+        suites.entry(check_id).or_default().push(finding);
+    }
+
+    let failing = matches!(report.verdict, RenderableVerdictStatus::Fail);
+    let is_failure = |f: &RenderableFinding| {
+        failing
+            && matches!(
                 f.severity,
                 RenderableSeverity::Warning | RenderableSeverity::Error
             )
-        })
-        .count();
-    let skipped = report
-        .findings
-        .iter()
-        .filter(|f| matches!(f.severity, RenderableSeverity::Info))
-        .count();
+    };
+    let total_tests: usize = suites.values().map(|f| f.len().max(1)).sum();
+    let total_failures = report.findings.iter().filter(|f| is_failure(f)).count();
 
     let verdict = match report.verdict {
         RenderableVerdictStatus::Pass => "pass",
@@ -25,54 +44,69 @@ pub fn render_junit(report: &RenderableReport) -> String {
         RenderableVerdictStatus::Skip => "skip",
     };
 
-    let mut out = String::new();
-    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
-    out.push_str(&format!(
-        "<testsuite name=\"depguard\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
-        tests, failures, skipped
-    ));
-    out.push_str("  <properties>\n");
-    out.push_str(&format!(
-        "    <property name=\"depguard.verdict\" value=\"{}\"/>\n",
+    let mut properties = String::new();
+    properties.push_str("    <properties>\n");
+    properties.push_str(&format!(
+        "      <property name=\"depguard.verdict\" value=\"{}\"/>\n",
         verdict
     ));
-    out.push_str(&format!(
-        "    <property name=\"depguard.findings_emitted\" value=\"{}\"/>\n",
+    properties.push_str(&format!(
+        "      <property name=\"depguard.findings_emitted\" value=\"{}\"/>\n",
         report.data.findings_emitted
     ));
-    out.push_str(&format!(
-        "    <property name=\"depguard.findings_total\" value=\"{}\"/>\n",
+    properties.push_str(&format!(
+        "      <property name=\"depguard.findings_total\" value=\"{}\"/>\n",
         report.data.findings_total
     ));
     if let Some(reason) = &report.data.truncated_reason {
-        out.push_str(&format!(
-            "    <property name=\"depguard.truncated_reason\" value=\"{}\"/>\n",
+        properties.push_str(&format!(
+            "      <property name=\"depguard.truncated_reason\" value=\"{}\"/>\n",
             xml_escape(reason)
         ));
     }
-    out.push_str("  </properties>\n");
+    properties.push_str("    </properties>\n");
 
-    for finding in &report.findings {
-        let class_name = xml_escape(finding.check_id.as_deref().unwrap_or("depguard.unknown"));
-        let test_name = if let Some(loc) = &finding.location {
-            match loc.line {
-                Some(line) => format!("{} [{}:{}]", finding.code, loc.path, line),
-                None => format!("{} [{}]", finding.code, loc.path),
-            }
-        } else {
-            finding.code.clone()
-        };
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuites name=\"depguard\" tests=\"{}\" failures=\"{}\">\n",
+        total_tests, total_failures
+    ));
+
+    for (check_id, findings) in &suites {
+        let class_name = xml_escape(check_id);
+        let failures = findings.iter().filter(|f| is_failure(f)).count();
         out.push_str(&format!(
-            "  <testcase classname=\"{}\" name=\"{}\">",
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
             class_name,
-            xml_escape(&test_name)
+            findings.len().max(1),
+            failures
         ));
+        out.push_str(&properties);
 
-        match finding.severity {
-            RenderableSeverity::Info => {
-                out.push_str("<skipped/>");
-            }
-            RenderableSeverity::Warning | RenderableSeverity::Error => {
+        if findings.is_empty() {
+            out.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\"/>\n",
+                class_name, class_name
+            ));
+        }
+
+        for finding in findings {
+            let test_name = if let Some(loc) = &finding.location {
+                match loc.line {
+                    Some(line) => format!("{} [{}:{}]", finding.code, loc.path, line),
+                    None => format!("{} [{}]", finding.code, loc.path),
+                }
+            } else {
+                finding.code.clone()
+            };
+            out.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\">",
+                class_name,
+                xml_escape(&test_name)
+            ));
+
+            if is_failure(finding) {
                 let failure_type = match finding.severity {
                     RenderableSeverity::Warning => "warning",
                     RenderableSeverity::Error => "error",
@@ -85,17 +119,23 @@ pub fn render_junit(report: &RenderableReport) -> String {
                 ));
                 out.push_str(&xml_escape(&failure_body(finding)));
                 out.push_str("</failure>");
+            } else {
+                out.push_str("<system-out>");
+                out.push_str(&xml_escape(&failure_body(finding)));
+                out.push_str("</system-out>");
             }
+
+            out.push_str("</testcase>\n");
         }
 
-        out.push_str("</testcase>\n");
+        out.push_str("  </testsuite>\n");
     }
 
-    out.push_str("</testsuite>\n");
+    out.push_str("</testsuites>\n");
     out
 }
 
-fn failure_body(finding: &crate::RenderableFinding) -> String {
+fn failure_body(finding: &RenderableFinding) -> String {
     let mut body = String::new();
     body.push_str(&finding.message);
     if let Some(loc) = &finding.location {
@@ -142,7 +182,7 @@ mod tests {
     use crate::{RenderableData, RenderableFinding, RenderableLocation};
 
     #[test]
-    fn render_junit_includes_failure_and_skip() {
+    fn render_junit_renders_info_findings_as_passing_testcases() {
         let report = RenderableReport {
             verdict: RenderableVerdictStatus::Fail,
             findings: vec![
@@ -158,6 +198,7 @@ mod tests {
                     }),
                     help: Some("pin the version".to_string()),
                     url: Some("https://example.invalid/help".to_string()),
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Info,
@@ -167,6 +208,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -174,17 +216,25 @@ mod tests {
                 findings_total: 2,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let xml = render_junit(&report);
-        assert!(
-            xml.contains("<testsuite name=\"depguard\" tests=\"2\" failures=\"1\" skipped=\"1\">")
-        );
+        assert!(xml.contains("<testsuites name=\"depguard\""));
+        assert!(xml.contains("failures=\"1\">"));
+        assert!(xml.contains("<testsuite name=\"deps.no_wildcards\" tests=\"1\" failures=\"1\">"));
         assert!(xml.contains(
             "<testcase classname=\"deps.no_wildcards\" name=\"wildcard_version [Cargo.toml:8]\">"
         ));
+        assert!(xml.contains("<testsuite name=\"deps.info\" tests=\"1\" failures=\"0\">"));
         assert!(xml.contains("<failure type=\"error\""));
-        assert!(xml.contains("<skipped/>"));
+        assert!(xml.contains(
+            "<testcase classname=\"deps.info\" name=\"note\"><system-out>informational</system-out></testcase>"
+        ));
+        assert!(!xml.contains("skipped"));
     }
 
     #[test]
@@ -199,19 +249,103 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: Some("too <many> & more".to_string()),
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let xml = render_junit(&report);
         assert!(xml.contains("classname=\"deps.&lt;bad&gt;&amp;&quot;\""));
         assert!(xml.contains("name=\"code&lt;&apos;&amp;&gt;\""));
-        assert!(xml.contains("message=\"bad &lt;value&gt; &amp; &quot;quote&quot;\""));
+        assert!(xml.contains("<system-out>bad &lt;value&gt; &amp; &quot;quote&quot;</system-out>"));
         assert!(xml.contains("depguard.truncated_reason"));
         assert!(xml.contains("too &lt;many&gt; &amp; more"));
     }
+
+    #[test]
+    fn render_junit_emits_passing_suite_per_known_check() {
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Pass,
+            findings: Vec::new(),
+            data: RenderableData {
+                findings_emitted: 0,
+                findings_total: 0,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let xml = render_junit(&report);
+        let known = explain::all_check_ids()
+            .iter()
+            .filter(|id| **id != ids::CHECK_TOOL_RUNTIME)
+            .count();
+        assert_eq!(xml.matches("<testsuite ").count(), known);
+        assert!(xml.contains(&format!(
+            "<testsuites name=\"depguard\" tests=\"{}\" failures=\"0\">",
+            known
+        )));
+        assert!(
+            xml.contains("<testcase classname=\"deps.no_wildcards\" name=\"deps.no_wildcards\"/>")
+        );
+        assert!(!xml.contains("tool.runtime"));
+        assert_eq!(xml.matches("<properties>").count(), known);
+        assert!(xml.contains(concat!(
+            "  <testsuite name=\"deps.no_wildcards\" tests=\"1\" failures=\"0\">\n",
+            "    <properties>\n",
+            "      <property name=\"depguard.verdict\" value=\"pass\"/>\n",
+        )));
+        let (head, _) = xml.split_once("<testsuite ").expect("suite");
+        assert!(
+            !head.contains("<properties>"),
+            "no properties under <testsuites>"
+        );
+        assert!(xml.trim_end().ends_with("</testsuites>"));
+    }
+
+    #[test]
+    fn render_junit_only_fails_when_verdict_is_fail() {
+        let finding = RenderableFinding {
+            severity: RenderableSeverity::Error,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: "dependency uses wildcard".to_string(),
+            location: None,
+            help: None,
+            url: None,
+            fingerprint: None,
+        };
+        let mut report = RenderableReport {
+            verdict: RenderableVerdictStatus::Warn,
+            findings: vec![finding],
+            data: RenderableData {
+                findings_emitted: 1,
+                findings_total: 1,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let xml = render_junit(&report);
+        assert!(!xml.contains("<failure"));
+        assert!(xml.contains("<system-out>dependency uses wildcard</system-out>"));
+
+        report.verdict = RenderableVerdictStatus::Fail;
+        let xml = render_junit(&report);
+        assert!(xml.contains("<failure type=\"error\" message=\"dependency uses wildcard\">"));
+    }
 }
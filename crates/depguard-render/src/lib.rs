@@ -1,16 +1,28 @@
-//! Rendering utilities for CI surfaces (Markdown, GitHub annotations, etc).
+//! Rendering utilities for CI surfaces (Markdown, GitHub/Azure annotations, TeamCity, GitLab, Checkstyle, etc),
+//! standalone HTML reports, and local terminal output.
 
 #![forbid(unsafe_code)]
 
+mod azure;
+mod checkstyle;
 mod gha;
+mod gitlab;
+mod html;
 mod jsonl;
 mod junit;
 mod markdown;
 mod model;
 mod sarif;
+mod summary;
+mod teamcity;
+mod terminal;
 
-pub use gha::render_github_annotations;
-pub use jsonl::render_jsonl;
+pub use azure::render_azure_annotations;
+pub use checkstyle::render_checkstyle;
+pub use gha::{AnnotationMode, render_github_annotations};
+pub use gitlab::render_gitlab_codequality;
+pub use html::render_html;
+pub use jsonl::{render_jsonl, render_ndjson};
 pub use junit::render_junit;
 pub use markdown::render_markdown;
 pub use model::{
@@ -18,3 +30,6 @@ pub use model::{
     RenderableVerdictStatus,
 };
 pub use sarif::render_sarif;
+pub use summary::render_job_summary;
+pub use teamcity::render_teamcity;
+pub use terminal::render_terminal;
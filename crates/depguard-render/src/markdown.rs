@@ -1,4 +1,5 @@
 use crate::{RenderableReport, RenderableSeverity, RenderableVerdictStatus};
+use depguard_types::lookup_explanation;
 use std::collections::BTreeMap;
 
 pub fn render_markdown(report: &RenderableReport) -> String {
@@ -19,6 +20,20 @@ pub fn render_markdown(report: &RenderableReport) -> String {
     if let Some(r) = &report.data.truncated_reason {
         out.push_str(&format!("> Note: {}\n\n", r));
     }
+    if report.data.findings_total > report.data.findings_emitted {
+        out.push_str(&format!(
+            "> Showing {} of {} findings (increase `max_findings` to see all).\n\n",
+            report.data.findings_emitted, report.data.findings_total
+        ));
+    }
+
+    if report.hidden_findings > 0 {
+        out.push_str(&format!(
+            "> {} lower-severity finding{} hidden by `--min-severity`.\n\n",
+            report.hidden_findings,
+            if report.hidden_findings == 1 { "" } else { "s" }
+        ));
+    }
 
     if report.findings.is_empty() {
         out.push_str("No findings.\n");
@@ -58,18 +73,36 @@ pub fn render_markdown(report: &RenderableReport) -> String {
 
         out.push_str(&format!("### {}\n\n", sev_label));
 
-        // Group by check_id within each severity for even better organization
+        // One collapsible block per check_id; only errors start expanded so long
+        // PR comments stay scannable.
+        let open = if severity == RenderableSeverity::Error {
+            " open"
+        } else {
+            ""
+        };
         let grouped = group_by_check_id(&findings_for_severity);
         for (check_id, findings) in grouped {
-            if let Some(cid) = check_id {
-                out.push_str(&format!("#### `{}`\n\n", cid));
+            let n = findings.len();
+            out.push_str(&format!(
+                "<details{}>\n<summary><code>{}</code> ({} finding{})</summary>\n\n",
+                open,
+                check_id.unwrap_or("other"),
+                n,
+                if n == 1 { "" } else { "s" }
+            ));
+            if report.remediation_hints {
+                render_remediation_hint(&mut out, check_id, &findings);
             }
             for f in findings {
                 render_finding(&mut out, f);
             }
+            out.push_str("\n</details>\n\n");
         }
     }
 
+    let len = out.trim_end().len();
+    out.truncate(len);
+    out.push('\n');
     out
 }
 
@@ -127,6 +160,48 @@ fn group_by_check_id<'a>(
     result
 }
 
+/// Render one compact remediation line for a check group.
+///
+/// The hint is the lead paragraph of the explain entry's remediation text; the
+/// docs link comes from the first finding in the group that carries a URL.
+fn render_remediation_hint(
+    out: &mut String,
+    check_id: Option<&str>,
+    findings: &[&crate::RenderableFinding],
+) {
+    let hint = check_id
+        .and_then(lookup_explanation)
+        .and_then(|e| remediation_lead(e.remediation));
+    let url = findings.iter().find_map(|f| f.url.as_deref());
+
+    let line = match (hint, url) {
+        (Some(hint), Some(url)) => format!("**Fix:** {} See [docs]({}).", hint, url),
+        (Some(hint), None) => format!("**Fix:** {}", hint),
+        (None, Some(url)) => format!("See [docs]({}).", url),
+        (None, None) => return,
+    };
+    out.push_str(&format!("> {}\n\n", line));
+}
+
+/// First paragraph of a remediation text, flattened to a single sentence.
+fn remediation_lead(remediation: &str) -> Option<String> {
+    let lead = remediation
+        .lines()
+        .map(str::trim)
+        .take_while(|l| !l.is_empty() && !l.starts_with("- ") && !l.starts_with("```"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let lead = lead.trim_end_matches(':').trim_end();
+    if lead.is_empty() {
+        return None;
+    }
+    if lead.ends_with(['.', '!', '?']) {
+        Some(lead.to_string())
+    } else {
+        Some(format!("{}.", lead))
+    }
+}
+
 /// Render a single finding to the output buffer
 fn render_finding(out: &mut String, f: &crate::RenderableFinding) {
     if let Some(loc) = &f.location {
@@ -172,6 +247,28 @@ mod tests {
         RenderableVerdictStatus,
     };
 
+    #[test]
+    fn notes_findings_hidden_by_min_severity() {
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Warn,
+            findings: Vec::new(),
+            data: RenderableData {
+                findings_emitted: 2,
+                findings_total: 2,
+                truncated_reason: None,
+            },
+            remediation_hints: true,
+            hidden_findings: 2,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let md = render_markdown(&report);
+        assert!(md.contains("- Verdict: **WARN**\n- Findings: 2 (emitted) / 2 (total)"));
+        assert!(md.contains("> 2 lower-severity findings hidden by `--min-severity`.\n"));
+        assert!(md.ends_with("No findings.\n"));
+    }
+
     #[test]
     fn renders_empty_report() {
         let report = RenderableReport {
@@ -182,6 +279,10 @@ mod tests {
                 findings_total: 0,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
         let md = render_markdown(&report);
         assert!(md.contains("No findings"));
@@ -203,12 +304,17 @@ mod tests {
                 }),
                 help: Some("pin the version".to_string()),
                 url: Some("https://example.com/docs".to_string()),
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 2,
                 truncated_reason: Some("truncated".to_string()),
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -221,6 +327,40 @@ mod tests {
         assert!(md.contains("url: https://example.com/docs"));
     }
 
+    #[test]
+    fn renders_truncation_notice_only_when_findings_were_capped() {
+        let finding = RenderableFinding {
+            severity: RenderableSeverity::Error,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: "bad dependency".to_string(),
+            location: None,
+            help: None,
+            url: None,
+            fingerprint: None,
+        };
+        let mut report = RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            findings: vec![finding.clone(), finding],
+            data: RenderableData {
+                findings_emitted: 2,
+                findings_total: 5,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let md = render_markdown(&report);
+        assert!(md.contains("> Showing 2 of 5 findings (increase `max_findings` to see all)."));
+
+        report.data.findings_total = 2;
+        let md = render_markdown(&report);
+        assert!(!md.contains("Showing"));
+    }
+
     #[test]
     fn renders_skip_with_no_location() {
         let report = RenderableReport {
@@ -233,12 +373,17 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -257,6 +402,10 @@ mod tests {
                 findings_total: 0,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -276,6 +425,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Error,
@@ -285,6 +435,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Warning,
@@ -294,6 +445,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Info,
@@ -303,6 +455,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -310,6 +463,10 @@ mod tests {
                 findings_total: 4,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -329,12 +486,17 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -355,6 +517,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Error,
@@ -364,6 +527,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -371,6 +535,10 @@ mod tests {
                 findings_total: 2,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -393,6 +561,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Error,
@@ -402,6 +571,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Error,
@@ -411,6 +581,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -418,15 +589,19 @@ mod tests {
                 findings_total: 3,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
         // check_a should come before check_b (alphabetically)
         let check_a_pos = md
-            .find("#### `deps.check_a`")
+            .find("<summary><code>deps.check_a</code> (2 findings)</summary>")
             .expect("check_a section not found");
         let check_b_pos = md
-            .find("#### `deps.check_b`")
+            .find("<summary><code>deps.check_b</code> (1 finding)</summary>")
             .expect("check_b section not found");
         assert!(
             check_a_pos < check_b_pos,
@@ -454,12 +629,17 @@ mod tests {
                 }),
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -483,12 +663,17 @@ mod tests {
                 }),
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
@@ -509,6 +694,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
                 RenderableFinding {
                     severity: RenderableSeverity::Error,
@@ -518,6 +704,7 @@ mod tests {
                     location: None,
                     help: None,
                     url: None,
+                    fingerprint: None,
                 },
             ],
             data: RenderableData {
@@ -525,11 +712,16 @@ mod tests {
                 findings_total: 2,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let md = render_markdown(&report);
-        // Findings with check_id should be grouped under a heading
-        assert!(md.contains("#### `deps.check`"));
+        // Findings with check_id should be grouped under their own block
+        assert!(md.contains("<summary><code>deps.check</code> (1 finding)</summary>"));
+        assert!(md.contains("<summary><code>other</code> (1 finding)</summary>"));
         // Finding without check_id should still be rendered
         assert!(md.contains("error without check_id"));
     }
@@ -546,6 +738,7 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             },
             RenderableFinding {
                 severity: RenderableSeverity::Error,
@@ -555,6 +748,7 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             },
             RenderableFinding {
                 severity: RenderableSeverity::Info,
@@ -564,6 +758,7 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             },
         ];
 
@@ -575,6 +770,10 @@ mod tests {
                 findings_total: 3,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         // Render multiple times and ensure output is identical
@@ -585,4 +784,177 @@ mod tests {
         assert_eq!(md1, md2);
         assert_eq!(md2, md3);
     }
+
+    #[test]
+    fn wraps_check_groups_in_details_golden() {
+        let finding = |severity, check_id: &str, code: &str, line| RenderableFinding {
+            severity,
+            check_id: Some(check_id.to_string()),
+            code: code.to_string(),
+            message: format!("{code} message"),
+            location: Some(RenderableLocation {
+                path: "Cargo.toml".to_string(),
+                line: Some(line),
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: None,
+        };
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            findings: vec![
+                finding(
+                    RenderableSeverity::Warning,
+                    "deps.sorted",
+                    "deps_unsorted",
+                    9,
+                ),
+                finding(
+                    RenderableSeverity::Error,
+                    "deps.path_safety",
+                    "parent_escape",
+                    4,
+                ),
+                finding(
+                    RenderableSeverity::Error,
+                    "deps.no_wildcards",
+                    "wildcard_version",
+                    7,
+                ),
+                finding(
+                    RenderableSeverity::Error,
+                    "deps.no_wildcards",
+                    "wildcard_version",
+                    8,
+                ),
+                finding(
+                    RenderableSeverity::Info,
+                    "deps.optional_unused",
+                    "optional_not_in_features",
+                    3,
+                ),
+            ],
+            data: RenderableData {
+                findings_emitted: 5,
+                findings_total: 5,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let expected = "\
+# Depguard report
+
+- Verdict: **FAIL**
+- Findings: 5 (emitted) / 5 (total)
+
+## Summary
+
+3 errors, 1 warning, 1 info
+
+## Findings
+
+### ERROR
+
+<details open>
+<summary><code>deps.no_wildcards</code> (2 findings)</summary>
+
+- `deps.no_wildcards` / `wildcard_version` — wildcard_version message ([`Cargo.toml`:7](Cargo.toml:L7))
+- `deps.no_wildcards` / `wildcard_version` — wildcard_version message ([`Cargo.toml`:8](Cargo.toml:L8))
+
+</details>
+
+<details open>
+<summary><code>deps.path_safety</code> (1 finding)</summary>
+
+- `deps.path_safety` / `parent_escape` — parent_escape message ([`Cargo.toml`:4](Cargo.toml:L4))
+
+</details>
+
+### WARNING
+
+<details>
+<summary><code>deps.sorted</code> (1 finding)</summary>
+
+- `deps.sorted` / `deps_unsorted` — deps_unsorted message ([`Cargo.toml`:9](Cargo.toml:L9))
+
+</details>
+
+### INFO
+
+<details>
+<summary><code>deps.optional_unused</code> (1 finding)</summary>
+
+- `deps.optional_unused` / `optional_not_in_features` — optional_not_in_features message ([`Cargo.toml`:3](Cargo.toml:L3))
+
+</details>
+";
+        assert_eq!(render_markdown(&report), expected);
+    }
+
+    #[test]
+    fn renders_one_remediation_hint_per_check_group() {
+        let finding = |check_id: &str, url: Option<&str>| RenderableFinding {
+            severity: RenderableSeverity::Error,
+            check_id: Some(check_id.to_string()),
+            code: "code".to_string(),
+            message: "msg".to_string(),
+            location: None,
+            help: None,
+            url: url.map(str::to_string),
+            fingerprint: None,
+        };
+        let mut report = RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            findings: vec![
+                finding("deps.no_wildcards", None),
+                finding("deps.no_wildcards", Some("https://example.com/wildcards")),
+                finding("deps.path_safety", None),
+                finding("custom.unknown", Some("https://example.com/custom")),
+                finding("custom.silent", None),
+            ],
+            data: RenderableData {
+                findings_emitted: 5,
+                findings_total: 5,
+                truncated_reason: None,
+            },
+            remediation_hints: true,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let md = render_markdown(&report);
+        assert_eq!(md.matches("> **Fix:**").count(), 2);
+        assert!(md.contains(
+            "> **Fix:** Replace wildcard versions with explicit semver requirements. \
+             See [docs](https://example.com/wildcards).\n"
+        ));
+        assert!(
+            md.contains("> **Fix:** Use repo-relative paths that stay within the repository.\n")
+        );
+        assert!(md.contains("> See [docs](https://example.com/custom).\n"));
+
+        report.remediation_hints = false;
+        let md = render_markdown(&report);
+        assert!(!md.contains("**Fix:**"));
+        assert!(!md.contains("See [docs]"));
+    }
+
+    #[test]
+    fn remediation_lead_flattens_first_paragraph() {
+        assert_eq!(
+            remediation_lead("Find the culprit, then\nupgrade it:\n- step one\n").as_deref(),
+            Some("Find the culprit, then upgrade it.")
+        );
+        assert_eq!(
+            remediation_lead("Done already.\n\nMore text").as_deref(),
+            Some("Done already.")
+        );
+        assert_eq!(remediation_lead("- only a list"), None);
+    }
 }
@@ -29,6 +29,8 @@ pub struct RenderableFinding {
     pub location: Option<RenderableLocation>,
     pub help: Option<String>,
     pub url: Option<String>,
+    /// Stable identity assigned by the engine; used for cross-run dedup.
+    pub fingerprint: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -43,4 +45,12 @@ pub struct RenderableReport {
     pub verdict: RenderableVerdictStatus,
     pub findings: Vec<RenderableFinding>,
     pub data: RenderableData,
+    /// Append a one-line remediation hint per check group in Markdown output.
+    pub remediation_hints: bool,
+    /// Findings dropped by a render-time severity filter; noted in Markdown output.
+    pub hidden_findings: u32,
+    /// Version of the tool that produced the report; shown in standalone HTML output.
+    pub tool_version: Option<String>,
+    /// Run start time (RFC 3339); shown in standalone HTML output.
+    pub started_at: Option<String>,
 }
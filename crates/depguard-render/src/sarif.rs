@@ -52,6 +52,12 @@ pub fn render_sarif(report: &RenderableReport) -> String {
             }),
         );
         result.insert("message".to_string(), json!({ "text": finding.message }));
+        if let Some(fingerprint) = &finding.fingerprint {
+            result.insert(
+                "partialFingerprints".to_string(),
+                json!({ "depguardFingerprint/v1": fingerprint }),
+            );
+        }
         result.insert("properties".to_string(), Value::Object(result_props));
 
         if let Some(loc) = &finding.location {
@@ -138,12 +144,17 @@ mod tests {
                 }),
                 help: Some("pin the version".to_string()),
                 url: Some("https://example.invalid/help".to_string()),
+                fingerprint: Some("fp-1".to_string()),
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let sarif = render_sarif(&report);
@@ -161,6 +172,10 @@ mod tests {
             doc["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"]["startLine"],
             8
         );
+        assert_eq!(
+            doc["runs"][0]["results"][0]["partialFingerprints"]["depguardFingerprint/v1"],
+            "fp-1"
+        );
     }
 
     #[test]
@@ -175,12 +190,17 @@ mod tests {
                 location: None,
                 help: None,
                 url: None,
+                fingerprint: None,
             }],
             data: RenderableData {
                 findings_emitted: 1,
                 findings_total: 1,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let sarif = render_sarif(&report);
@@ -191,6 +211,11 @@ mod tests {
             "depguard.tool_warning"
         );
         assert!(doc["runs"][0]["results"][0].get("locations").is_none());
+        assert!(
+            doc["runs"][0]["results"][0]
+                .get("partialFingerprints")
+                .is_none()
+        );
         assert_eq!(doc["runs"][0]["properties"]["depguard_verdict"], "warn");
     }
 
@@ -204,6 +229,10 @@ mod tests {
                 findings_total: 0,
                 truncated_reason: None,
             },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
         };
 
         let sarif = render_sarif(&report);
@@ -0,0 +1,183 @@
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity, RenderableVerdictStatus};
+
+/// Render a GitHub Actions job summary (`$GITHUB_STEP_SUMMARY`).
+///
+/// Unlike the PR-comment Markdown this lists every emitted finding in a single
+/// table, since step summaries render tables well and have no length pressure.
+pub fn render_job_summary(report: &RenderableReport) -> String {
+    let mut out = String::new();
+
+    let badge = match report.verdict {
+        RenderableVerdictStatus::Pass => "✅ PASS",
+        RenderableVerdictStatus::Warn => "⚠️ WARN",
+        RenderableVerdictStatus::Fail => "❌ FAIL",
+        RenderableVerdictStatus::Skip => "⏭️ SKIP",
+    };
+    out.push_str(&format!("## depguard: {}\n\n", badge));
+
+    if report.findings.is_empty() {
+        out.push_str("No findings.\n\n");
+    } else {
+        out.push_str("| Severity | Location | Check | Message |\n");
+        out.push_str("| --- | --- | --- | --- |\n");
+        for f in &report.findings {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                severity_label(f.severity),
+                location_cell(f),
+                f.check_id
+                    .as_deref()
+                    .map(|id| format!("`{}`", id))
+                    .unwrap_or_default(),
+                escape_cell(&f.message)
+            ));
+        }
+        out.push('\n');
+    }
+
+    let count = |severity| {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .count()
+    };
+    let errors = count(RenderableSeverity::Error);
+    let warnings = count(RenderableSeverity::Warning);
+    let infos = count(RenderableSeverity::Info);
+    out.push_str(&format!(
+        "**{} error{}, {} warning{}, {} info** · {} of {} findings shown\n",
+        errors,
+        if errors == 1 { "" } else { "s" },
+        warnings,
+        if warnings == 1 { "" } else { "s" },
+        infos,
+        report.data.findings_emitted,
+        report.data.findings_total
+    ));
+    if let Some(reason) = &report.data.truncated_reason {
+        out.push_str(&format!("\n> Note: {}\n", reason));
+    }
+
+    out
+}
+
+fn severity_label(severity: RenderableSeverity) -> &'static str {
+    match severity {
+        RenderableSeverity::Error => "error",
+        RenderableSeverity::Warning => "warning",
+        RenderableSeverity::Info => "info",
+    }
+}
+
+fn location_cell(f: &RenderableFinding) -> String {
+    match &f.location {
+        Some(loc) => match loc.line {
+            Some(line) => format!("`{}:{}`", escape_cell(&loc.path), line),
+            None => format!("`{}`", escape_cell(&loc.path)),
+        },
+        None => String::new(),
+    }
+}
+
+/// Keep cell text on one row: pipes would split the column and newlines end the row.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableLocation};
+
+    fn finding(
+        severity: RenderableSeverity,
+        check_id: &str,
+        message: &str,
+        path: &str,
+        line: Option<u32>,
+    ) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some(check_id.to_string()),
+            code: "code".to_string(),
+            message: message.to_string(),
+            location: Some(RenderableLocation {
+                path: path.to_string(),
+                line,
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    #[test]
+    fn renders_badge_table_and_footer_golden() {
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            findings: vec![
+                finding(
+                    RenderableSeverity::Error,
+                    "deps.no_wildcards",
+                    "dependency 'serde' uses a wildcard version: *",
+                    "Cargo.toml",
+                    Some(9),
+                ),
+                finding(
+                    RenderableSeverity::Warning,
+                    "deps.sorted",
+                    "entries a | b are\nunsorted",
+                    "crates/a/Cargo.toml",
+                    None,
+                ),
+            ],
+            data: RenderableData {
+                findings_emitted: 2,
+                findings_total: 3,
+                truncated_reason: Some("max_findings reached".to_string()),
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let expected = "\
+## depguard: ❌ FAIL
+
+| Severity | Location | Check | Message |
+| --- | --- | --- | --- |
+| error | `Cargo.toml:9` | `deps.no_wildcards` | dependency 'serde' uses a wildcard version: * |
+| warning | `crates/a/Cargo.toml` | `deps.sorted` | entries a \\| b are unsorted |
+
+**1 error, 1 warning, 0 info** · 2 of 3 findings shown
+
+> Note: max_findings reached
+";
+        assert_eq!(render_job_summary(&report), expected);
+    }
+
+    #[test]
+    fn renders_pass_without_table() {
+        let report = RenderableReport {
+            verdict: RenderableVerdictStatus::Pass,
+            findings: Vec::new(),
+            data: RenderableData {
+                findings_emitted: 0,
+                findings_total: 0,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        };
+
+        let out = render_job_summary(&report);
+        assert!(out.starts_with("## depguard: ✅ PASS\n\nNo findings.\n"));
+        assert!(!out.contains("| Severity |"));
+        assert!(out.ends_with("**0 errors, 0 warnings, 0 info** · 0 of 0 findings shown\n"));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{RenderableReport, RenderableSeverity};
+use depguard_types::lookup_explanation;
+
+/// Render findings as TeamCity `inspectionType` / `inspection` service messages.
+///
+/// Each check id becomes an inspection type, declared once before its first
+/// finding with the title and description from the explain registry. Unlike the
+/// GitHub and Azure renderers there is no cap: TeamCity aggregates inspections
+/// into its own report tab.
+pub fn render_teamcity(report: &RenderableReport) -> Vec<String> {
+    let mut declared: Vec<&str> = Vec::new();
+    let mut out = Vec::new();
+
+    for f in &report.findings {
+        let type_id = f.check_id.as_deref().unwrap_or("depguard");
+        if !declared.contains(&type_id) {
+            declared.push(type_id);
+            let (name, description) = match lookup_explanation(type_id) {
+                Some(exp) => (exp.title.to_string(), exp.description.to_string()),
+                None => (type_id.to_string(), type_id.to_string()),
+            };
+            out.push(format!(
+                "##teamcity[inspectionType id='{}' name='{}' category='depguard' description='{}']",
+                escape(type_id),
+                escape(&name),
+                escape(&description)
+            ));
+        }
+
+        let severity = match f.severity {
+            RenderableSeverity::Error => "ERROR",
+            RenderableSeverity::Warning => "WARNING",
+            RenderableSeverity::Info => "INFO",
+        };
+        let mut attrs = format!(
+            "typeId='{}' message='{}'",
+            escape(type_id),
+            escape(&format!("[{}] {}", f.code, f.message))
+        );
+        if let Some(loc) = &f.location {
+            attrs.push_str(&format!(" file='{}'", escape(&loc.path)));
+            if let Some(line) = loc.line {
+                attrs.push_str(&format!(" line='{line}'"));
+            }
+        }
+        attrs.push_str(&format!(" SEVERITY='{severity}'"));
+        out.push(format!("##teamcity[inspection {attrs}]"));
+    }
+
+    out
+}
+
+/// Escape an attribute value per TeamCity service-message rules.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '|' => out.push_str("||"),
+            '\'' => out.push_str("|'"),
+            '\n' => out.push_str("|n"),
+            '\r' => out.push_str("|r"),
+            '[' => out.push_str("|["),
+            ']' => out.push_str("|]"),
+            '\u{0085}' => out.push_str("|x"),
+            '\u{2028}' => out.push_str("|l"),
+            '\u{2029}' => out.push_str("|p"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableFinding, RenderableLocation, RenderableVerdictStatus};
+
+    fn finding(check_id: Option<&str>, message: &str, line: Option<u32>) -> RenderableFinding {
+        RenderableFinding {
+            severity: RenderableSeverity::Error,
+            check_id: check_id.map(str::to_string),
+            code: "wildcard_version".to_string(),
+            message: message.to_string(),
+            location: Some(RenderableLocation {
+                path: "crates/a/Cargo.toml".to_string(),
+                line,
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    fn report(findings: Vec<RenderableFinding>) -> RenderableReport {
+        RenderableReport {
+            verdict: RenderableVerdictStatus::Fail,
+            data: RenderableData {
+                findings_emitted: findings.len() as u32,
+                findings_total: findings.len() as u32,
+                truncated_reason: None,
+            },
+            findings,
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn declares_each_inspection_type_once_before_its_findings() {
+        let lines = render_teamcity(&report(vec![
+            finding(Some("deps.no_wildcards"), "first", Some(7)),
+            finding(Some("deps.no_wildcards"), "second", None),
+            RenderableFinding {
+                severity: RenderableSeverity::Info,
+                location: None,
+                ..finding(None, "tool", None)
+            },
+        ]));
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with(
+            "##teamcity[inspectionType id='deps.no_wildcards' name='No Wildcard Versions' category='depguard' description='"
+        ));
+        assert_eq!(
+            lines[1],
+            "##teamcity[inspection typeId='deps.no_wildcards' message='|[wildcard_version|] first' file='crates/a/Cargo.toml' line='7' SEVERITY='ERROR']"
+        );
+        assert_eq!(
+            lines[2],
+            "##teamcity[inspection typeId='deps.no_wildcards' message='|[wildcard_version|] second' file='crates/a/Cargo.toml' SEVERITY='ERROR']"
+        );
+        assert_eq!(
+            lines[3],
+            "##teamcity[inspectionType id='depguard' name='depguard' category='depguard' description='depguard']"
+        );
+        assert_eq!(
+            lines[4],
+            "##teamcity[inspection typeId='depguard' message='|[wildcard_version|] tool' SEVERITY='INFO']"
+        );
+    }
+
+    #[test]
+    fn escapes_pipes_brackets_quotes_and_newlines() {
+        let lines = render_teamcity(&report(vec![finding(
+            Some("deps.no_wildcards"),
+            "bad [dep] a|b 'x'\nnext\r",
+            Some(1),
+        )]));
+
+        assert!(lines[1].contains("message='|[wildcard_version|] bad |[dep|] a||b |'x|'|nnext|r'"));
+        assert_eq!(escape("\u{0085}\u{2028}\u{2029}"), "|x|l|p");
+        // The registry description spans lines; it must stay on one service message.
+        assert!(!lines[0].contains('\n'));
+        assert!(lines[0].contains("|n"));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{RenderableFinding, RenderableReport, RenderableSeverity, RenderableVerdictStatus};
+use std::collections::BTreeMap;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const BLUE: &str = "\x1b[34m";
+const CYAN: &str = "\x1b[36m";
+
+/// Render a compact, human-oriented summary for local terminal runs.
+///
+/// Findings are grouped by manifest path. With `no_color` set the output is
+/// plain text with no ANSI escape sequences.
+pub fn render_terminal(report: &RenderableReport, no_color: bool) -> String {
+    let paint = |text: &str, style: &str| {
+        if no_color {
+            text.to_string()
+        } else {
+            format!("{style}{text}{RESET}")
+        }
+    };
+    let mut out = String::new();
+
+    let (label, style) = match report.verdict {
+        RenderableVerdictStatus::Pass => ("PASS", GREEN),
+        RenderableVerdictStatus::Warn => ("WARN", YELLOW),
+        RenderableVerdictStatus::Fail => ("FAIL", RED),
+        RenderableVerdictStatus::Skip => ("SKIP", DIM),
+    };
+    let count = |severity| {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .count()
+    };
+    let errors = count(RenderableSeverity::Error);
+    let warnings = count(RenderableSeverity::Warning);
+    out.push_str(&format!(
+        "depguard: {} ({} error{}, {} warning{}, {} info)\n",
+        paint(label, &format!("{BOLD}{style}")),
+        errors,
+        if errors == 1 { "" } else { "s" },
+        warnings,
+        if warnings == 1 { "" } else { "s" },
+        count(RenderableSeverity::Info)
+    ));
+
+    // Group by path; the `true` key sorts findings without a location last.
+    let mut groups: BTreeMap<(bool, &str), Vec<&RenderableFinding>> = BTreeMap::new();
+    for f in &report.findings {
+        let key = match &f.location {
+            Some(loc) => (false, loc.path.as_str()),
+            None => (true, "(no location)"),
+        };
+        groups.entry(key).or_default().push(f);
+    }
+
+    for ((_, heading), findings) in groups {
+        out.push('\n');
+        out.push_str(&paint(heading, BOLD));
+        out.push('\n');
+        for f in findings {
+            let location = match &f.location {
+                Some(loc) => match loc.line {
+                    Some(line) => format!("{}:{}", loc.path, line),
+                    None => loc.path.clone(),
+                },
+                None => String::new(),
+            };
+            let (severity, style) = match f.severity {
+                RenderableSeverity::Error => ("error", RED),
+                RenderableSeverity::Warning => ("warning", YELLOW),
+                RenderableSeverity::Info => ("info", BLUE),
+            };
+            out.push_str("  ");
+            if !location.is_empty() {
+                out.push_str(&paint(&location, CYAN));
+                out.push(' ');
+            }
+            out.push_str(&paint(severity, style));
+            if let Some(check_id) = &f.check_id {
+                out.push_str(&format!(" [{}]", check_id));
+            }
+            out.push_str(&format!(" {}\n", f.message));
+            if let Some(help) = &f.help {
+                out.push_str(&format!("    {}\n", paint(&format!("help: {help}"), DIM)));
+            }
+        }
+    }
+
+    if let Some(reason) = &report.data.truncated_reason {
+        out.push_str(&format!(
+            "\n{} of {} findings shown ({})\n",
+            report.data.findings_emitted, report.data.findings_total, reason
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RenderableData, RenderableLocation};
+
+    fn finding(
+        severity: RenderableSeverity,
+        path: Option<&str>,
+        line: Option<u32>,
+        message: &str,
+    ) -> RenderableFinding {
+        RenderableFinding {
+            severity,
+            check_id: Some("deps.no_wildcards".to_string()),
+            code: "wildcard_version".to_string(),
+            message: message.to_string(),
+            location: path.map(|path| RenderableLocation {
+                path: path.to_string(),
+                line,
+                col: None,
+            }),
+            help: None,
+            url: None,
+            fingerprint: None,
+        }
+    }
+
+    fn report(
+        verdict: RenderableVerdictStatus,
+        findings: Vec<RenderableFinding>,
+    ) -> RenderableReport {
+        let n = findings.len() as u32;
+        RenderableReport {
+            verdict,
+            findings,
+            data: RenderableData {
+                findings_emitted: n,
+                findings_total: n,
+                truncated_reason: None,
+            },
+            remediation_hints: false,
+            hidden_findings: 0,
+            tool_version: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn groups_findings_by_path_without_color() {
+        let mut with_help = finding(
+            RenderableSeverity::Error,
+            Some("crates/b/Cargo.toml"),
+            Some(9),
+            "dependency 'serde' uses a wildcard version: *",
+        );
+        with_help.help = Some("Pin a version.".to_string());
+        let report = report(
+            RenderableVerdictStatus::Fail,
+            vec![
+                with_help,
+                finding(RenderableSeverity::Info, None, None, "workspace note"),
+                finding(
+                    RenderableSeverity::Warning,
+                    Some("Cargo.toml"),
+                    None,
+                    "manifest-level finding",
+                ),
+            ],
+        );
+
+        let expected = "\
+depguard: FAIL (1 error, 1 warning, 1 info)
+
+Cargo.toml
+  Cargo.toml warning [deps.no_wildcards] manifest-level finding
+
+crates/b/Cargo.toml
+  crates/b/Cargo.toml:9 error [deps.no_wildcards] dependency 'serde' uses a wildcard version: *
+    help: Pin a version.
+
+(no location)
+  info [deps.no_wildcards] workspace note
+";
+        assert_eq!(render_terminal(&report, true), expected);
+    }
+
+    #[test]
+    fn pass_renders_header_only_and_truncation_note() {
+        let out = render_terminal(&report(RenderableVerdictStatus::Pass, Vec::new()), true);
+        assert_eq!(out, "depguard: PASS (0 errors, 0 warnings, 0 info)\n");
+
+        let mut truncated = report(
+            RenderableVerdictStatus::Warn,
+            vec![finding(
+                RenderableSeverity::Warning,
+                Some("Cargo.toml"),
+                Some(3),
+                "w",
+            )],
+        );
+        truncated.data.findings_total = 5;
+        truncated.data.truncated_reason = Some("max_findings reached".to_string());
+        assert!(
+            render_terminal(&truncated, true)
+                .ends_with("\n1 of 5 findings shown (max_findings reached)\n")
+        );
+    }
+
+    #[test]
+    fn color_wraps_verdict_location_and_severity() {
+        let report = report(
+            RenderableVerdictStatus::Fail,
+            vec![finding(
+                RenderableSeverity::Error,
+                Some("Cargo.toml"),
+                Some(1),
+                "m",
+            )],
+        );
+        let out = render_terminal(&report, false);
+        assert!(out.contains("\x1b[1m\x1b[31mFAIL\x1b[0m"));
+        assert!(out.contains("\x1b[36mCargo.toml:1\x1b[0m"));
+        assert!(out.contains("\x1b[31merror\x1b[0m"));
+        assert!(!render_terminal(&report, true).contains('\x1b'));
+    }
+}
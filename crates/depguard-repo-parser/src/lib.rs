@@ -5,13 +5,16 @@
 
 #![forbid(unsafe_code)]
 
+mod target_spec;
+
 use anyhow::Context;
 use depguard_domain_core::model::{
-    DepKind, DepSpec, DependencyDecl, ManifestModel, PackageMeta, WorkspaceDependency,
+    BuildSetting, DepKind, DepSpec, DependencyDecl, LintsDecl, ManifestModel, OverrideKind,
+    OverrideSection, PackageMeta, TargetSpec, WorkspaceDependency, WorkspacePackage,
 };
 use depguard_inline_suppressions::parse_inline_suppressions;
 use depguard_types::{Location, RepoPath};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use toml_edit::{Document, Item, Value};
 
 /// Calculate the 1-based line number from a byte offset in the source text.
@@ -25,16 +28,22 @@ fn byte_offset_to_line(source: &str, offset: usize) -> u32 {
     (line_count + 1) as u32
 }
 
-pub fn parse_root_manifest(
-    manifest_path: &RepoPath,
-    text: &str,
-) -> anyhow::Result<(BTreeMap<String, WorkspaceDependency>, ManifestModel)> {
+/// Parsed root manifest: `[workspace.dependencies]`, `[workspace.package]`, and the
+/// root's own manifest model.
+pub type RootManifest = (
+    BTreeMap<String, WorkspaceDependency>,
+    WorkspacePackage,
+    ManifestModel,
+);
+
+pub fn parse_root_manifest(manifest_path: &RepoPath, text: &str) -> anyhow::Result<RootManifest> {
     let doc: Document<&str> = Document::parse(text).context("parse Cargo.toml")?;
     let ws_deps = parse_workspace_dependencies(&doc, manifest_path, text);
+    let ws_package = parse_workspace_package(&doc);
 
     let model = parse_manifest_doc(&doc, manifest_path, text);
 
-    Ok((ws_deps, model))
+    Ok((ws_deps, ws_package, model))
 }
 
 pub fn parse_member_manifest(
@@ -45,12 +54,41 @@ pub fn parse_member_manifest(
     Ok(parse_manifest_doc(&doc, manifest_path, text))
 }
 
+/// Extract depguard config embedded in a root manifest.
+///
+/// `[workspace.metadata.depguard]` takes precedence over
+/// `[package.metadata.depguard]`. The table is returned as a standalone TOML
+/// document suitable for `depguard_settings::parse_config_toml`; `Ok(None)`
+/// means neither table is present.
+pub fn parse_metadata_config(text: &str) -> anyhow::Result<Option<String>> {
+    let doc: toml_edit::DocumentMut = text.parse().context("parse Cargo.toml")?;
+    for section in ["workspace", "package"] {
+        let Some(item) = doc
+            .get(section)
+            .and_then(|s| s.get("metadata"))
+            .and_then(|m| m.get("depguard"))
+        else {
+            continue;
+        };
+        let mut table = match item {
+            Item::Table(t) => t.clone(),
+            Item::Value(Value::InlineTable(t)) => t.clone().into_table(),
+            _ => continue,
+        };
+        table.decor_mut().clear();
+        let mut out = toml_edit::DocumentMut::new();
+        *out.as_table_mut() = table;
+        return Ok(Some(out.to_string()));
+    }
+    Ok(None)
+}
+
 fn parse_manifest_doc(
     doc: &Document<&str>,
     manifest_path: &RepoPath,
     source: &str,
 ) -> ManifestModel {
-    let package = parse_package(doc);
+    let package = parse_package(doc, manifest_path, source);
 
     let mut deps: Vec<DependencyDecl> = Vec::new();
     deps.extend(parse_dep_table(
@@ -81,15 +119,32 @@ fn parse_manifest_doc(
     // Parse features table
     let features = parse_features(doc);
 
+    let overrides = parse_overrides(doc, manifest_path, source);
+    let targets = parse_targets(doc, manifest_path, source);
+    let lints = parse_lints(doc, manifest_path, source);
+    let workspace_resolver = doc
+        .get("workspace")
+        .and_then(|ws| ws.get("resolver"))
+        .and_then(Item::as_str)
+        .map(str::to_string);
+
     ManifestModel {
         path: manifest_path.clone(),
         package,
         dependencies: deps,
         features,
+        overrides,
+        targets,
+        lints,
+        workspace_resolver,
     }
 }
 
-fn parse_package(doc: &Document<&str>) -> Option<PackageMeta> {
+fn parse_package(
+    doc: &Document<&str>,
+    manifest_path: &RepoPath,
+    source: &str,
+) -> Option<PackageMeta> {
     let pkg = doc.get("package")?.as_table()?;
     let name = pkg.get("name")?.as_str()?.to_string();
 
@@ -100,7 +155,155 @@ fn parse_package(doc: &Document<&str>) -> Option<PackageMeta> {
         _ => true,
     };
 
-    Some(PackageMeta { name, publish })
+    let mut inherited = BTreeSet::new();
+    let mut field = |key: &str| match pkg.get(key) {
+        Some(item) if is_workspace_inherited(item) => {
+            inherited.insert(key.to_string());
+            None
+        }
+        Some(item) => item.as_str().map(str::to_string),
+        None => None,
+    };
+    let version = field("version");
+    let edition = field("edition");
+    let rust_version = field("rust-version");
+    let license = field("license");
+    let license_file = field("license-file");
+    let description = field("description");
+    let repository = field("repository");
+    let homepage = field("homepage");
+    let documentation = field("documentation");
+    let mut list = |key: &str| match pkg.get(key) {
+        Some(item) if is_workspace_inherited(item) => {
+            inherited.insert(key.to_string());
+            Vec::new()
+        }
+        Some(item) => string_array(item),
+        None => Vec::new(),
+    };
+    let keywords = list("keywords");
+    let categories = list("categories");
+    let build = match pkg.get("build") {
+        Some(Item::Value(Value::Boolean(b))) => Some(BuildSetting::Enabled(*b.value())),
+        Some(Item::Value(Value::String(s))) => Some(BuildSetting::Script(s.value().clone())),
+        _ => None,
+    };
+    let resolver = pkg
+        .get("resolver")
+        .and_then(Item::as_str)
+        .map(str::to_string);
+
+    Some(PackageMeta {
+        name,
+        publish,
+        version,
+        edition,
+        rust_version,
+        license,
+        license_file,
+        description,
+        repository,
+        homepage,
+        documentation,
+        keywords,
+        categories,
+        inherited,
+        build,
+        resolver,
+        location: Some(Location {
+            path: manifest_path.clone(),
+            line: pkg
+                .span()
+                .map(|span| byte_offset_to_line(source, span.start)),
+            col: None,
+        }),
+    })
+}
+
+fn string_array(item: &Item) -> Vec<String> {
+    item.as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Matches both `key = { workspace = true }` and dotted `key.workspace = true`.
+fn is_workspace_inherited(item: &Item) -> bool {
+    item.get("workspace")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn parse_workspace_package(doc: &Document<&str>) -> WorkspacePackage {
+    let Some(pkg) = doc
+        .get("workspace")
+        .and_then(|w| w.get("package"))
+        .filter(|p| p.is_table_like())
+    else {
+        return WorkspacePackage::default();
+    };
+    let field = |key: &str| pkg.get(key).and_then(|v| v.as_str()).map(str::to_string);
+
+    WorkspacePackage {
+        version: field("version"),
+        edition: field("edition"),
+        rust_version: field("rust-version"),
+        license: field("license"),
+        license_file: field("license-file"),
+        description: field("description"),
+        repository: field("repository"),
+        homepage: field("homepage"),
+        documentation: field("documentation"),
+        keywords: pkg.get("keywords").map(string_array).unwrap_or_default(),
+        categories: pkg.get("categories").map(string_array).unwrap_or_default(),
+    }
+}
+
+/// Fill `{ workspace = true }` package fields from the root `[workspace.package]`.
+///
+/// Inherited keys stay recorded in `PackageMeta::inherited`; a key the workspace
+/// does not define resolves to `None`.
+pub fn resolve_workspace_package(manifest: &mut ManifestModel, ws_package: &WorkspacePackage) {
+    let Some(pkg) = manifest.package.as_mut() else {
+        return;
+    };
+    if pkg.is_inherited("version") {
+        pkg.version = ws_package.version.clone();
+    }
+    if pkg.is_inherited("edition") {
+        pkg.edition = ws_package.edition.clone();
+    }
+    if pkg.is_inherited("rust-version") {
+        pkg.rust_version = ws_package.rust_version.clone();
+    }
+    if pkg.is_inherited("license") {
+        pkg.license = ws_package.license.clone();
+    }
+    if pkg.is_inherited("license-file") {
+        pkg.license_file = ws_package.license_file.clone();
+    }
+    if pkg.is_inherited("description") {
+        pkg.description = ws_package.description.clone();
+    }
+    if pkg.is_inherited("repository") {
+        pkg.repository = ws_package.repository.clone();
+    }
+    if pkg.is_inherited("homepage") {
+        pkg.homepage = ws_package.homepage.clone();
+    }
+    if pkg.is_inherited("documentation") {
+        pkg.documentation = ws_package.documentation.clone();
+    }
+    if pkg.is_inherited("keywords") {
+        pkg.keywords = ws_package.keywords.clone();
+    }
+    if pkg.is_inherited("categories") {
+        pkg.categories = ws_package.categories.clone();
+    }
 }
 
 fn parse_workspace_dependencies(
@@ -118,6 +321,9 @@ fn parse_workspace_dependencies(
 
     for (name, item) in deps.iter() {
         let spec = parse_spec(item);
+        let line = item
+            .span()
+            .map(|span| byte_offset_to_line(source, span.start));
         out.insert(
             name.to_string(),
             WorkspaceDependency {
@@ -125,13 +331,17 @@ fn parse_workspace_dependencies(
                 version: spec.version,
                 path: spec.path,
                 workspace: spec.workspace,
+                default_features: spec.default_features,
+                features: spec.features.unwrap_or_default(),
+                location: Some(Location {
+                    path: manifest_path.clone(),
+                    line,
+                    col: None,
+                }),
             },
         );
     }
 
-    // Note: locations for workspace deps are not captured in this scaffold.
-    let _ = manifest_path;
-    let _ = source;
     out
 }
 
@@ -221,6 +431,104 @@ fn parse_target_dependencies(
     out
 }
 
+/// Collect `[target.<spec>]` tables, recording whether each spec is valid.
+///
+/// The `target.<spec>` table itself is usually implicit (only its
+/// `dependencies` subtables are written), so the location falls back to the
+/// first subtable header.
+fn parse_targets(doc: &Document<&str>, manifest_path: &RepoPath, source: &str) -> Vec<TargetSpec> {
+    let Some(target_table) = doc.get("target").and_then(|i| i.as_table()) else {
+        return Vec::new();
+    };
+
+    target_table
+        .iter()
+        .map(|(spec, item)| {
+            let span = item.span().or_else(|| {
+                item.as_table()
+                    .and_then(|t| t.iter().find_map(|(_, sub)| sub.span()))
+            });
+            TargetSpec {
+                raw: spec.to_string(),
+                valid: target_spec::is_valid_target_spec(spec),
+                location: Some(Location {
+                    path: manifest_path.clone(),
+                    line: span.map(|span| byte_offset_to_line(source, span.start)),
+                    col: None,
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Parse the `[lints]` table: `workspace = true` inheritance and the tool
+/// tables declared directly.
+///
+/// Like `target.<spec>`, `[lints]` is often implicit (only `[lints.clippy]`
+/// is written), so the location falls back to the first subtable header.
+fn parse_lints(doc: &Document<&str>, manifest_path: &RepoPath, source: &str) -> Option<LintsDecl> {
+    let item = doc.get("lints")?;
+    let table = item.as_table_like()?;
+
+    let workspace = table
+        .get("workspace")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let tools = table
+        .iter()
+        .filter(|(key, _)| *key != "workspace")
+        .map(|(key, _)| key.to_string())
+        .collect();
+    let span = item
+        .span()
+        .or_else(|| table.iter().find_map(|(_, sub)| sub.span()));
+
+    Some(LintsDecl {
+        workspace,
+        tools,
+        location: Some(Location {
+            path: manifest_path.clone(),
+            line: span.map(|span| byte_offset_to_line(source, span.start)),
+            col: None,
+        }),
+    })
+}
+
+/// Collect `[patch.<source>]` and `[replace]` tables with their header lines.
+fn parse_overrides(
+    doc: &Document<&str>,
+    manifest_path: &RepoPath,
+    source: &str,
+) -> Vec<OverrideSection> {
+    let section = |kind, source_key: Option<&str>, item: &Item| {
+        let table = item.as_table_like()?;
+        let line = item
+            .span()
+            .map(|span| byte_offset_to_line(source, span.start));
+        Some(OverrideSection {
+            kind,
+            source: source_key.map(str::to_string),
+            entries: table.iter().map(|(k, _)| k.to_string()).collect(),
+            location: Some(Location {
+                path: manifest_path.clone(),
+                line,
+                col: None,
+            }),
+        })
+    };
+
+    let mut out = Vec::new();
+    if let Some(patch) = doc.get("patch").and_then(|i| i.as_table()) {
+        for (source_key, item) in patch.iter() {
+            out.extend(section(OverrideKind::Patch, Some(source_key), item));
+        }
+    }
+    if let Some(item) = doc.get("replace") {
+        out.extend(section(OverrideKind::Replace, None, item));
+    }
+    out
+}
+
 /// Parse the [features] table.
 fn parse_features(doc: &Document<&str>) -> BTreeMap<String, Vec<String>> {
     let mut out = BTreeMap::new();
@@ -284,12 +592,23 @@ fn parse_inline_table(t: &toml_edit::InlineTable) -> DepSpec {
     if let Some(df) = t.get("default-features").and_then(|v| v.as_bool()) {
         spec.default_features = Some(df);
     }
+    if let Some(features) = t.get("features").and_then(|v| v.as_array()) {
+        spec.features = Some(
+            features
+                .iter()
+                .filter_map(|f| f.as_str().map(str::to_string))
+                .collect(),
+        );
+    }
     if let Some(o) = t.get("optional").and_then(|v| v.as_bool()) {
         spec.optional = o;
     }
     // Package rename: `alias = { package = "real-crate", ... }`
     if let Some(pkg) = t.get("package").and_then(|v| v.as_str()) {
-        spec.package = Some(pkg.to_string());
+        spec.rename_of = Some(pkg.to_string());
+    }
+    if let Some(r) = t.get("registry").and_then(|v| v.as_str()) {
+        spec.registry = Some(r.to_string());
     }
     spec
 }
@@ -322,12 +641,23 @@ fn parse_table(t: &toml_edit::Table) -> DepSpec {
     if let Some(df) = t.get("default-features").and_then(|v| v.as_bool()) {
         spec.default_features = Some(df);
     }
+    if let Some(features) = t.get("features").and_then(|v| v.as_array()) {
+        spec.features = Some(
+            features
+                .iter()
+                .filter_map(|f| f.as_str().map(str::to_string))
+                .collect(),
+        );
+    }
     if let Some(o) = t.get("optional").and_then(|v| v.as_bool()) {
         spec.optional = o;
     }
     // Package rename: `[dependencies.alias] package = "real-crate"`
     if let Some(pkg) = t.get("package").and_then(|v| v.as_str()) {
-        spec.package = Some(pkg.to_string());
+        spec.rename_of = Some(pkg.to_string());
+    }
+    if let Some(r) = t.get("registry").and_then(|v| v.as_str()) {
+        spec.registry = Some(r.to_string());
     }
     spec
 }
@@ -912,7 +1242,7 @@ inherited.workspace = true
 "#;
 
         let manifest_path = RepoPath::new("Cargo.toml");
-        let (ws_deps, _model) =
+        let (ws_deps, _ws_package, _model) =
             parse_root_manifest(&manifest_path, manifest).expect("parse root manifest");
 
         let serde = ws_deps.get("serde").expect("serde dep");
@@ -928,6 +1258,131 @@ inherited.workspace = true
         assert!(inherited.workspace);
     }
 
+    #[test]
+    fn parse_root_manifest_records_workspace_dependency_lines() {
+        let manifest = r#"[workspace]
+members = ["crates/a"]
+
+[workspace.dependencies]
+serde = "1.0"
+local = { path = "crates/local", version = "0.2.0" }
+
+[workspace.dependencies.tokio]
+version = "1"
+default-features = false
+features = ["rt"]
+"#;
+
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let (ws_deps, _ws_package, _model) =
+            parse_root_manifest(&manifest_path, manifest).expect("parse root manifest");
+
+        let line = |name: &str| {
+            let location = ws_deps[name].location.as_ref().expect("location");
+            assert_eq!(location.path, manifest_path);
+            location.line
+        };
+        assert_eq!(line("serde"), Some(5));
+        assert_eq!(line("local"), Some(6));
+        assert_eq!(line("tokio"), Some(8));
+        assert_eq!(ws_deps["tokio"].default_features, Some(false));
+        assert_eq!(ws_deps["serde"].default_features, None);
+        assert_eq!(ws_deps["tokio"].features, vec!["rt"]);
+        assert!(ws_deps["serde"].features.is_empty());
+    }
+
+    #[test]
+    fn parse_manifest_collects_patch_and_replace_sections() {
+        let manifest = r#"[package]
+name = "pkg"
+version = "0.1.0"
+
+[patch.crates-io]
+serde = { path = "../serde" }
+toml = { git = "https://github.com/toml-rs/toml" }
+
+[patch."https://github.com/org/repo"]
+my-dep = { path = "../my-dep" }
+
+[replace]
+"foo:0.1.0" = { path = "../foo" }
+"#;
+
+        let model =
+            parse_member_manifest(&RepoPath::new("Cargo.toml"), manifest).expect("parse manifest");
+        let summary: Vec<(String, Vec<String>, Option<u32>)> = model
+            .overrides
+            .iter()
+            .map(|o| {
+                (
+                    o.table_name(),
+                    o.entries.clone(),
+                    o.location.as_ref().and_then(|l| l.line),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "patch.crates-io".to_string(),
+                    vec!["serde".to_string(), "toml".to_string()],
+                    Some(5)
+                ),
+                (
+                    "patch.https://github.com/org/repo".to_string(),
+                    vec!["my-dep".to_string()],
+                    Some(9)
+                ),
+                (
+                    "replace".to_string(),
+                    vec!["foo:0.1.0".to_string()],
+                    Some(12)
+                ),
+            ]
+        );
+        assert_eq!(model.overrides[2].kind, OverrideKind::Replace);
+    }
+
+    #[test]
+    fn parse_manifest_records_target_specs_and_validity() {
+        let manifest = r#"[package]
+name = "pkg"
+version = "0.1.0"
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[target.'cfg(feature = )'.dependencies]
+winapi = "0.3"
+
+[target.x86_64-pc-windows-msvc.dev-dependencies]
+helper = "1"
+"#;
+
+        let model =
+            parse_member_manifest(&RepoPath::new("Cargo.toml"), manifest).expect("parse manifest");
+        let summary: Vec<(&str, bool, Option<u32>)> = model
+            .targets
+            .iter()
+            .map(|t| {
+                (
+                    t.raw.as_str(),
+                    t.valid,
+                    t.location.as_ref().and_then(|l| l.line),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("cfg(unix)", true, Some(5)),
+                ("cfg(feature = )", false, Some(8)),
+                ("x86_64-pc-windows-msvc", true, Some(11)),
+            ]
+        );
+    }
+
     #[test]
     fn parse_package_publish_array_controls_publishable() {
         let manifest = r#"
@@ -956,6 +1411,73 @@ publish = ["crates-io"]
         assert!(pkg.publish, "non-empty publish array should be publishable");
     }
 
+    #[test]
+    fn parse_package_build_setting() {
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let build_of = |line: &str| {
+            let manifest = format!("[package]\nname = \"pkg\"\n{line}\n");
+            parse_member_manifest(&manifest_path, &manifest)
+                .expect("parse manifest")
+                .package
+                .expect("package meta")
+                .build
+        };
+
+        assert_eq!(
+            build_of("build = \"build.rs\""),
+            Some(BuildSetting::Script("build.rs".to_string()))
+        );
+        assert_eq!(
+            build_of("build = false"),
+            Some(BuildSetting::Enabled(false))
+        );
+        assert_eq!(build_of("build = true"), Some(BuildSetting::Enabled(true)));
+        assert_eq!(build_of(""), None);
+        assert!(!BuildSetting::Enabled(false).runs_script());
+        assert!(BuildSetting::Script("gen.rs".to_string()).runs_script());
+    }
+
+    #[test]
+    fn parse_package_and_workspace_resolver() {
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let parse = |text: &str| parse_member_manifest(&manifest_path, text).expect("parse");
+
+        let model = parse("[package]\nname = \"pkg\"\nresolver = \"1\"\n");
+        assert_eq!(model.package.and_then(|p| p.resolver).as_deref(), Some("1"));
+        assert_eq!(model.workspace_resolver, None);
+
+        let model = parse("[workspace]\nmembers = [\"a\"]\nresolver = \"2\"\n");
+        assert_eq!(model.workspace_resolver.as_deref(), Some("2"));
+
+        let model = parse("[package]\nname = \"pkg\"\n");
+        assert_eq!(model.package.and_then(|p| p.resolver), None);
+    }
+
+    #[test]
+    fn parse_manifest_lints_table() {
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let lints_of = |body: &str| {
+            let manifest = format!("[package]\nname = \"pkg\"\n\n{body}");
+            parse_member_manifest(&manifest_path, &manifest)
+                .expect("parse manifest")
+                .lints
+        };
+
+        let inherited = lints_of("[lints]\nworkspace = true\n").expect("lints");
+        assert!(inherited.workspace);
+        assert!(inherited.tools.is_empty());
+        assert_eq!(inherited.location.and_then(|l| l.line), Some(4));
+
+        let own =
+            lints_of("[lints.rust]\nunsafe_code = \"forbid\"\n\n[lints.clippy]\nall = \"warn\"\n")
+                .expect("lints");
+        assert!(!own.workspace);
+        assert_eq!(own.tools, vec!["rust", "clippy"]);
+        assert_eq!(own.location.and_then(|l| l.line), Some(4));
+
+        assert!(lints_of("[dependencies]\nserde = \"1\"\n").is_none());
+    }
+
     #[test]
     fn parse_features_ignores_non_string_entries() {
         let manifest = r#"
@@ -1009,7 +1531,7 @@ name = "pkg"
 version = "0.1.0"
 
 [dependencies]
-git_dep = { git = "https://example.com/repo.git", branch = "main", tag = "v1.2.3", rev = "deadbeef", default-features = false, optional = true }
+git_dep = { git = "https://example.com/repo.git", branch = "main", tag = "v1.2.3", rev = "deadbeef", default-features = false, features = ["std"], optional = true }
 "#;
 
         let manifest_path = RepoPath::new("Cargo.toml");
@@ -1028,9 +1550,335 @@ git_dep = { git = "https://example.com/repo.git", branch = "main", tag = "v1.2.3
         assert_eq!(dep.spec.tag.as_deref(), Some("v1.2.3"));
         assert_eq!(dep.spec.rev.as_deref(), Some("deadbeef"));
         assert_eq!(dep.spec.default_features, Some(false));
+        assert_eq!(dep.spec.features, Some(vec!["std".to_string()]));
         assert!(dep.spec.optional);
     }
 
+    #[test]
+    fn parse_inline_table_features_and_default_features() {
+        let manifest = r#"
+[package]
+name = "pkg"
+version = "0.1.0"
+
+[dependencies]
+foo = { version = "1", features = ["a", "b"], default-features = false }
+bar = "1"
+bad = { version = "1", features = { a = true } }
+
+[dependencies.baz]
+version = "1"
+features = []
+"#;
+
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        let spec = |name: &str| {
+            &model
+                .dependencies
+                .iter()
+                .find(|d| d.name == name)
+                .expect("dependency")
+                .spec
+        };
+
+        let foo = spec("foo");
+        assert_eq!(foo.features, Some(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(foo.default_features, Some(false));
+
+        // The string shorthand carries no feature information.
+        let bar = spec("bar");
+        assert_eq!(bar.features, None);
+        assert_eq!(bar.default_features, None);
+
+        // A malformed `features` value is ignored rather than rejected.
+        assert_eq!(spec("bad").features, None);
+        assert_eq!(spec("baz").features, Some(Vec::new()));
+    }
+
+    #[test]
+    fn parse_metadata_config_prefers_workspace_table() {
+        let manifest = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.depguard]
+profile = "warn"
+
+[workspace.metadata.depguard.checks."deps.no_wildcards"]
+enabled = false
+
+[package]
+name = "pkg"
+version = "0.1.0"
+
+[package.metadata.depguard]
+profile = "strict"
+"#;
+
+        let config = parse_metadata_config(manifest)
+            .expect("parse manifest")
+            .expect("metadata config");
+        let reparsed: toml_edit::DocumentMut = config.parse().expect("valid toml");
+        assert_eq!(reparsed["profile"].as_str(), Some("warn"));
+        assert_eq!(
+            reparsed["checks"]["deps.no_wildcards"]["enabled"].as_bool(),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn parse_metadata_config_reads_package_table_and_inline_form() {
+        let manifest = r#"
+[package]
+name = "pkg"
+version = "0.1.0"
+metadata.depguard = { profile = "compat", max_findings = 10 }
+"#;
+
+        let config = parse_metadata_config(manifest)
+            .expect("parse manifest")
+            .expect("metadata config");
+        let reparsed: toml_edit::DocumentMut = config.parse().expect("valid toml");
+        assert_eq!(reparsed["profile"].as_str(), Some("compat"));
+        assert_eq!(reparsed["max_findings"].as_integer(), Some(10));
+
+        let plain = "[package]\nname = \"pkg\"\nversion = \"0.1.0\"\n";
+        assert!(parse_metadata_config(plain).expect("parse").is_none());
+    }
+
+    #[test]
+    fn parse_package_rename_into_rename_of() {
+        let manifest = r#"
+[package]
+name = "pkg"
+version = "0.1.0"
+
+[dependencies]
+foo = { package = "bar", version = "1" }
+
+[dependencies.baz]
+package = "qux"
+version = "2"
+"#;
+
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        let foo = model
+            .dependencies
+            .iter()
+            .find(|d| d.name == "foo")
+            .expect("foo");
+        assert_eq!(foo.spec.rename_of.as_deref(), Some("bar"));
+        assert_eq!(foo.spec.version.as_deref(), Some("1"));
+
+        let baz = model
+            .dependencies
+            .iter()
+            .find(|d| d.name == "baz")
+            .expect("baz");
+        assert_eq!(baz.spec.rename_of.as_deref(), Some("qux"));
+    }
+
+    #[test]
+    fn parse_ignores_non_string_package_and_registry() {
+        let manifest = r#"
+[package]
+name = "pkg"
+version = "0.1.0"
+
+[dependencies]
+odd = { version = "1", package = 42, registry = ["internal"] }
+"#;
+
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        let dep = &model.dependencies[0];
+        assert_eq!(dep.spec.rename_of, None);
+        assert_eq!(dep.spec.registry, None);
+    }
+
+    #[test]
+    fn parse_package_version_edition_and_rust_version() {
+        let manifest = r#"
+[package]
+name = "pkg"
+version = "0.1.0"
+edition = "2021"
+rust-version = "1.74"
+license = "MIT OR Apache-2.0"
+license-file = "LICENSE"
+"#;
+
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        let pkg = model.package.expect("package meta");
+        assert_eq!(pkg.version.as_deref(), Some("0.1.0"));
+        assert_eq!(pkg.edition.as_deref(), Some("2021"));
+        assert_eq!(pkg.rust_version.as_deref(), Some("1.74"));
+        assert_eq!(pkg.license.as_deref(), Some("MIT OR Apache-2.0"));
+        assert_eq!(pkg.license_file.as_deref(), Some("LICENSE"));
+        assert!(!pkg.is_inherited("version"));
+
+        let manifest = r#"
+[package]
+name = "pkg"
+"#;
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        let pkg = model.package.expect("package meta");
+        assert_eq!(pkg.version, None);
+        assert_eq!(pkg.edition, None);
+        assert_eq!(pkg.rust_version, None);
+        assert_eq!(pkg.license, None);
+        assert_eq!(pkg.license_file, None);
+
+        // A non-string version is ignored rather than failing the parse.
+        let manifest = r#"
+[package]
+name = "pkg"
+version = 1
+"#;
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        assert_eq!(model.package.expect("package meta").version, None);
+    }
+
+    #[test]
+    fn parse_and_resolve_workspace_inherited_package_fields() {
+        let root = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+version = "1.2.3"
+edition = "2024"
+rust-version = "1.85"
+license = "Apache-2.0"
+"#;
+        let root_path = RepoPath::new("Cargo.toml");
+        let (_ws_deps, ws_package, root_model) =
+            parse_root_manifest(&root_path, root).expect("parse root manifest");
+        assert!(root_model.package.is_none());
+        assert_eq!(ws_package.version.as_deref(), Some("1.2.3"));
+        assert_eq!(ws_package.edition.as_deref(), Some("2024"));
+        assert_eq!(ws_package.rust_version.as_deref(), Some("1.85"));
+        assert_eq!(ws_package.license.as_deref(), Some("Apache-2.0"));
+
+        let member = r#"
+[package]
+name = "member"
+version = { workspace = true }
+edition.workspace = true
+rust-version = "1.80"
+license.workspace = true
+"#;
+        let member_path = RepoPath::new("crates/member/Cargo.toml");
+        let mut model = parse_member_manifest(&member_path, member).expect("parse manifest");
+        {
+            let pkg = model.package.as_ref().expect("package meta");
+            assert_eq!(pkg.version, None);
+            assert_eq!(pkg.edition, None);
+            assert!(pkg.is_inherited("version"));
+            assert!(pkg.is_inherited("edition"));
+            assert!(!pkg.is_inherited("rust-version"));
+        }
+
+        resolve_workspace_package(&mut model, &ws_package);
+        let pkg = model.package.expect("package meta");
+        assert_eq!(pkg.version.as_deref(), Some("1.2.3"));
+        assert_eq!(pkg.edition.as_deref(), Some("2024"));
+        assert_eq!(pkg.rust_version.as_deref(), Some("1.80"));
+        assert_eq!(pkg.license.as_deref(), Some("Apache-2.0"));
+        assert!(pkg.is_inherited("edition"));
+    }
+
+    #[test]
+    fn parse_package_publish_metadata_and_table_line() {
+        let root = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+repository = "https://github.com/org/repo"
+keywords = ["cli", "deps"]
+"#;
+        let root_path = RepoPath::new("Cargo.toml");
+        let (_ws_deps, ws_package, _root_model) =
+            parse_root_manifest(&root_path, root).expect("parse root manifest");
+        assert_eq!(ws_package.keywords, vec!["cli", "deps"]);
+
+        let member = r#"# a comment
+
+[package]
+name = "member"
+description = "Parses widgets"
+repository.workspace = true
+keywords = { workspace = true }
+categories = ["parsing"]
+"#;
+        let member_path = RepoPath::new("crates/member/Cargo.toml");
+        let mut model = parse_member_manifest(&member_path, member).expect("parse manifest");
+        resolve_workspace_package(&mut model, &ws_package);
+        let pkg = model.package.expect("package meta");
+        assert_eq!(pkg.description.as_deref(), Some("Parses widgets"));
+        assert_eq!(
+            pkg.repository.as_deref(),
+            Some("https://github.com/org/repo")
+        );
+        assert_eq!(pkg.keywords, vec!["cli", "deps"]);
+        assert_eq!(pkg.categories, vec!["parsing"]);
+        assert!(pkg.is_inherited("keywords"));
+        assert!(pkg.has_metadata("repository"));
+        assert!(!pkg.has_metadata("homepage"));
+        let location = pkg.location.expect("package location");
+        assert_eq!(location.path, member_path);
+        assert_eq!(location.line, Some(3));
+    }
+
+    #[test]
+    fn resolve_workspace_package_leaves_missing_workspace_keys_unset() {
+        let member = r#"
+[package]
+name = "member"
+rust-version.workspace = true
+"#;
+        let member_path = RepoPath::new("crates/member/Cargo.toml");
+        let mut model = parse_member_manifest(&member_path, member).expect("parse manifest");
+        resolve_workspace_package(&mut model, &WorkspacePackage::default());
+
+        let pkg = model.package.expect("package meta");
+        assert_eq!(pkg.rust_version, None);
+        assert!(pkg.is_inherited("rust-version"));
+    }
+
+    #[test]
+    fn parse_registry_in_inline_and_table_forms() {
+        let manifest = r#"
+[package]
+name = "pkg"
+version = "0.1.0"
+
+[dependencies]
+inline_dep = { version = "1", registry = "internal" }
+
+[dependencies.table_dep]
+version = "2"
+registry = "my-mirror"
+"#;
+
+        let manifest_path = RepoPath::new("Cargo.toml");
+        let model = parse_member_manifest(&manifest_path, manifest).expect("parse manifest");
+        let registry_of = |name: &str| {
+            model
+                .dependencies
+                .iter()
+                .find(|d| d.name == name)
+                .and_then(|d| d.spec.registry.clone())
+        };
+
+        assert_eq!(registry_of("inline_dep").as_deref(), Some("internal"));
+        assert_eq!(registry_of("table_dep").as_deref(), Some("my-mirror"));
+    }
+
     #[test]
     fn parse_table_git_fields_and_flags() {
         let manifest = r#"
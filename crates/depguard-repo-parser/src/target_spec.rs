@@ -0,0 +1,142 @@
+//! Validation of `[target.<spec>]` table keys.
+//!
+//! Mirrors the grammar Cargo accepts: either `cfg(<predicate>)` or a bare
+//! target triple. Cargo silently ignores tables whose spec it cannot evaluate,
+//! so the parser records validity for `deps.valid_target_cfg` to report.
+
+/// Whether `spec` is a well-formed `cfg(...)` expression or target triple.
+pub(crate) fn is_valid_target_spec(spec: &str) -> bool {
+    let spec = spec.trim();
+    if let Some(inner) = spec
+        .strip_prefix("cfg(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let mut parser = CfgParser { rest: inner };
+        return parser.predicate() && parser.at_end();
+    }
+    !spec.is_empty()
+        && spec
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Recursive-descent parser over the text inside `cfg(...)`.
+struct CfgParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> CfgParser<'a> {
+    /// `ident`, `ident = "value"`, or `all(..)`/`any(..)`/`not(..)`.
+    fn predicate(&mut self) -> bool {
+        let Some(ident) = self.ident() else {
+            return false;
+        };
+        match ident {
+            "all" | "any" if self.eat('(') => self.list(),
+            "not" if self.eat('(') => self.predicate() && self.eat(')'),
+            _ if self.eat('=') => self.string(),
+            _ => true,
+        }
+    }
+
+    /// Comma-separated predicates up to the closing `)`; a trailing comma is allowed.
+    fn list(&mut self) -> bool {
+        loop {
+            if self.eat(')') {
+                return true;
+            }
+            if !self.predicate() {
+                return false;
+            }
+            if !self.eat(',') {
+                return self.eat(')');
+            }
+        }
+    }
+
+    fn ident(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest;
+        let first = rest.chars().next()?;
+        if !(first.is_ascii_alphabetic() || first == '_') {
+            return None;
+        }
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        self.rest = &rest[len..];
+        Some(&rest[..len])
+    }
+
+    fn string(&mut self) -> bool {
+        if !self.eat('"') {
+            return false;
+        }
+        match self.rest.find('"') {
+            Some(end) => {
+                self.rest = &self.rest[end + 1..];
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest.is_empty()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_target_spec;
+
+    #[test]
+    fn accepts_cfg_expressions_and_triples() {
+        for spec in [
+            "cfg(unix)",
+            "cfg(target_os = \"linux\")",
+            "cfg(all(unix, not(target_os = \"macos\")))",
+            "cfg(any(windows, target_arch = \"wasm32\",))",
+            "cfg( any() )",
+            "x86_64-unknown-linux-gnu",
+            "thumbv7em-none-eabihf",
+        ] {
+            assert!(is_valid_target_spec(spec), "{spec} should be valid");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_specs() {
+        for spec in [
+            "cfg(feature = )",
+            "cfg()",
+            "cfg(all(unix, windows)",
+            "cfg(not(unix, windows))",
+            "cfg(unix windows)",
+            "cfg(target_os = linux)",
+            "cfg(target_os = \"linux)",
+            "cfg(1unix)",
+            "cfg(unix",
+            "linux gnu",
+            "",
+        ] {
+            assert!(!is_valid_target_spec(spec), "{spec} should be invalid");
+        }
+    }
+}
@@ -1,13 +1,14 @@
 use anyhow::Context;
 use camino::{Utf8Path, Utf8PathBuf};
-use depguard_domain_core::model::{ManifestModel, WorkspaceDependency};
+use depguard_domain_core::model::{ManifestModel, WorkspaceDependency, WorkspacePackage};
+use depguard_repo_parser::RootManifest;
 use depguard_types::RepoPath;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::time::SystemTime;
 
 pub const MANIFEST_CACHE_FILENAME: &str = "manifests.v1.json";
-const MANIFEST_CACHE_VERSION: u32 = 1;
+const MANIFEST_CACHE_VERSION: u32 = 12;
 
 #[derive(Clone, Debug, Default)]
 pub struct ManifestCache {
@@ -36,6 +37,7 @@ struct ManifestCacheEntry {
     stamp: ManifestStamp,
     manifest: ManifestModel,
     workspace_dependencies: Option<BTreeMap<String, WorkspaceDependency>>,
+    workspace_package: Option<WorkspacePackage>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -87,17 +89,18 @@ impl ManifestCache {
         })
     }
 
-    pub fn root_if_fresh(
-        &self,
-        manifest: &RepoPath,
-        stamp: ManifestStamp,
-    ) -> Option<(BTreeMap<String, WorkspaceDependency>, ManifestModel)> {
+    pub fn root_if_fresh(&self, manifest: &RepoPath, stamp: ManifestStamp) -> Option<RootManifest> {
         let entry = self.file.entries.get(manifest.as_str())?;
         if entry.stamp != stamp {
             return None;
         }
         let workspace_dependencies = entry.workspace_dependencies.clone()?;
-        Some((workspace_dependencies, entry.manifest.clone()))
+        let workspace_package = entry.workspace_package.clone()?;
+        Some((
+            workspace_dependencies,
+            workspace_package,
+            entry.manifest.clone(),
+        ))
     }
 
     pub fn member_if_fresh(
@@ -117,6 +120,7 @@ impl ManifestCache {
         manifest: &RepoPath,
         stamp: ManifestStamp,
         workspace_dependencies: &BTreeMap<String, WorkspaceDependency>,
+        workspace_package: &WorkspacePackage,
         model: &ManifestModel,
     ) {
         self.file.entries.insert(
@@ -125,6 +129,7 @@ impl ManifestCache {
                 stamp,
                 manifest: model.clone(),
                 workspace_dependencies: Some(workspace_dependencies.clone()),
+                workspace_package: Some(workspace_package.clone()),
             },
         );
         self.dirty = true;
@@ -142,6 +147,7 @@ impl ManifestCache {
                 stamp,
                 manifest: model.clone(),
                 workspace_dependencies: None,
+                workspace_package: None,
             },
         );
         self.dirty = true;
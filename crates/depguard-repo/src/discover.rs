@@ -1,9 +1,10 @@
 use anyhow::Context;
 use camino::{Utf8Path, Utf8PathBuf};
-use depguard_types::RepoPath;
-use globset::{Glob, GlobSetBuilder};
+use depguard_domain_core::model::UnresolvedMember;
+use depguard_types::{Location, RepoPath};
+use globset::{Glob, GlobMatcher, GlobSetBuilder};
 use std::path::PathBuf;
-use toml_edit::DocumentMut;
+use toml_edit::Document;
 use walkdir::WalkDir;
 
 /// Represents a parsed member pattern with its exclusion flag.
@@ -13,6 +14,20 @@ struct MemberPattern {
     pattern: String,
     /// Whether this pattern excludes previously matched paths
     is_exclusion: bool,
+    /// Line of the entry in the root manifest
+    line: Option<u32>,
+}
+
+/// Result of workspace discovery.
+#[derive(Debug, Default)]
+pub(crate) struct Discovery {
+    /// Root manifest plus every member manifest, sorted.
+    pub manifests: Vec<RepoPath>,
+    /// Inclusion entries of `members` that matched no manifest on disk.
+    pub unresolved_members: Vec<UnresolvedMember>,
+    /// `[workspace]` tables in manifests below the root, sorted by path. Manifests
+    /// matched by the root's `exclude` list are intentionally separate and omitted.
+    pub nested_workspaces: Vec<Location>,
 }
 
 /// Discover Cargo manifests for the workspace rooted at `repo_root`.
@@ -36,7 +51,8 @@ struct MemberPattern {
 /// - **Empty member lists**: When `[workspace]` is present with no members,
 ///   all `Cargo.toml` files in the repository are included.
 ///
-/// - **Non-existent paths**: Patterns that match no files are silently ignored.
+/// - **Non-existent paths**: Patterns that match no files add no members; they are
+///   recorded as unresolved so `deps.members_exist` can report them.
 ///
 /// - **Relative path normalization**: Both `./path` and `path` forms are handled.
 ///
@@ -48,15 +64,24 @@ struct MemberPattern {
 /// - **Default members**: The `default-members` field is not honored during discovery.
 ///   All matched members are included regardless of `default-members`.
 pub fn discover_manifests(repo_root: &Utf8Path) -> anyhow::Result<Vec<RepoPath>> {
+    discover_workspace(repo_root).map(|d| d.manifests)
+}
+
+/// Like [`discover_manifests`], also reporting `members` entries that matched nothing.
+///
+/// An entry counts as resolved when it matches any `Cargo.toml` on disk, even one that
+/// is later dropped as excluded or as a nested virtual workspace.
+pub(crate) fn discover_workspace(repo_root: &Utf8Path) -> anyhow::Result<Discovery> {
     let root = repo_root.join("Cargo.toml");
     let text = std::fs::read_to_string(&root).with_context(|| format!("read {}", root))?;
-    let doc = text
-        .parse::<DocumentMut>()
-        .context("parse root Cargo.toml")?;
+    let doc: Document<&str> = Document::parse(text.as_str()).context("parse root Cargo.toml")?;
 
     let workspace = doc.get("workspace");
     if workspace.is_none() {
-        return Ok(vec![RepoPath::new("Cargo.toml")]);
+        return Ok(Discovery {
+            manifests: vec![RepoPath::new("Cargo.toml")],
+            ..Discovery::default()
+        });
     }
 
     let member_patterns: Vec<MemberPattern> = doc
@@ -65,7 +90,11 @@ pub fn discover_manifests(repo_root: &Utf8Path) -> anyhow::Result<Vec<RepoPath>>
         .and_then(|i| i.as_array())
         .map(|a| {
             a.iter()
-                .filter_map(|v| v.as_str().and_then(parse_member_pattern))
+                .filter_map(|v| {
+                    let mut pattern = v.as_str().and_then(parse_member_pattern)?;
+                    pattern.line = v.span().map(|span| byte_offset_to_line(&text, span.start));
+                    Some(pattern)
+                })
                 .collect()
         })
         .unwrap_or_default();
@@ -90,15 +119,23 @@ pub fn discover_manifests(repo_root: &Utf8Path) -> anyhow::Result<Vec<RepoPath>>
     let (include_patterns, exclude_from_members): (Vec<_>, Vec<_>) =
         member_patterns.iter().partition(|p| !p.is_exclusion);
 
-    let include_patterns: Vec<String> = include_patterns
-        .into_iter()
-        .map(|p| p.pattern.clone())
+    // One matcher per inclusion entry, to tell which entries matched nothing.
+    let mut include_matchers: Vec<(&MemberPattern, GlobMatcher, bool)> = include_patterns
+        .iter()
+        .filter_map(|p| {
+            let matcher = Glob::new(&p.pattern).ok()?.compile_matcher();
+            Some((*p, matcher, false))
+        })
         .collect();
+    let include_patterns: Vec<String> =
+        include_patterns.iter().map(|p| p.pattern.clone()).collect();
     let exclude_from_members: Vec<String> = exclude_from_members
         .into_iter()
         .map(|p| p.pattern.clone())
         .collect();
 
+    let explicit_exclude_set = build_globset(&excludes).context("compile exclude globset")?;
+
     // Combine explicit excludes with exclusion patterns from members
     let all_excludes: Vec<String> = excludes.into_iter().chain(exclude_from_members).collect();
 
@@ -107,6 +144,7 @@ pub fn discover_manifests(repo_root: &Utf8Path) -> anyhow::Result<Vec<RepoPath>>
 
     let mut out: Vec<RepoPath> = Vec::new();
     out.push(RepoPath::new("Cargo.toml"));
+    let mut nested_workspaces: Vec<Location> = Vec::new();
 
     for abs in WalkDir::new(repo_root)
         .into_iter()
@@ -119,23 +157,40 @@ pub fn discover_manifests(repo_root: &Utf8Path) -> anyhow::Result<Vec<RepoPath>>
             .unwrap_or(&abs)
             .as_str()
             .replace('\\', "/");
+
+        // Match both the file path and its parent directory against globs.
+        let dir_rel = Utf8Path::new(&rel)
+            .parent()
+            .map(|p| p.as_str())
+            .unwrap_or("");
+
+        for (_, matcher, matched) in include_matchers.iter_mut() {
+            *matched |= matcher.is_match(&rel) || matcher.is_match(dir_rel);
+        }
+
         if rel == "Cargo.toml" {
             continue;
         }
 
+        let nested = read_workspace_table(&abs);
+        if let Some(table) = &nested
+            && !explicit_exclude_set.is_match(&rel)
+            && !explicit_exclude_set.is_match(dir_rel)
+        {
+            nested_workspaces.push(Location {
+                path: RepoPath::new(&rel),
+                line: table.line,
+                col: None,
+            });
+        }
+
         // Skip virtual workspaces (Cargo behavior)
         // Virtual workspaces have [workspace] but no [package] and represent workspace boundaries
         // Package workspaces (with both [package] and [workspace]) are included as members
-        if is_nested_workspace(&abs) {
+        if nested.is_some_and(|table| !table.has_package) {
             continue;
         }
 
-        // Match both the file path and its parent directory against globs.
-        let dir_rel = Utf8Path::new(&rel)
-            .parent()
-            .map(|p| p.as_str())
-            .unwrap_or("");
-
         // Empty member list means include all (Cargo behavior)
         let is_member = include_patterns.is_empty()
             || member_set.is_match(&rel)
@@ -152,7 +207,27 @@ pub fn discover_manifests(repo_root: &Utf8Path) -> anyhow::Result<Vec<RepoPath>>
     out.sort();
     out.dedup();
 
-    Ok(out)
+    let unresolved_members = include_matchers
+        .into_iter()
+        // `.` names the root package, which the walk above sees as directory "".
+        .filter(|(p, _, matched)| !matched && p.pattern != ".")
+        .map(|(p, _, _)| UnresolvedMember {
+            pattern: p.pattern.clone(),
+            location: Some(Location {
+                path: RepoPath::new("Cargo.toml"),
+                line: p.line,
+                col: None,
+            }),
+        })
+        .collect();
+
+    nested_workspaces.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(Discovery {
+        manifests: out,
+        unresolved_members,
+        nested_workspaces,
+    })
 }
 
 /// Parse a member pattern, detecting exclusion prefix.
@@ -211,6 +286,7 @@ fn parse_member_pattern(s: &str) -> Option<MemberPattern> {
     Some(MemberPattern {
         pattern: normalize_path(pattern_str),
         is_exclusion,
+        line: None,
     })
 }
 
@@ -228,7 +304,7 @@ fn normalize_path(s: &str) -> String {
     }
 }
 
-fn build_globset(patterns: &[String]) -> anyhow::Result<globset::GlobSet> {
+pub(crate) fn build_globset(patterns: &[String]) -> anyhow::Result<globset::GlobSet> {
     let mut b = GlobSetBuilder::new();
     for p in patterns {
         // Skip empty patterns
@@ -246,33 +322,52 @@ fn build_globset(patterns: &[String]) -> anyhow::Result<globset::GlobSet> {
     Ok(b.build()?)
 }
 
+/// Calculate the 1-based line number from a byte offset in the source text.
+fn byte_offset_to_line(source: &str, offset: usize) -> u32 {
+    let line_count = source[..offset.min(source.len())]
+        .bytes()
+        .filter(|&b| b == b'\n')
+        .count();
+    (line_count + 1) as u32
+}
+
+/// Whether `set` matches a manifest path or the directory containing it.
+pub(crate) fn matches_manifest(set: &globset::GlobSet, manifest: &RepoPath) -> bool {
+    let dir = Utf8Path::new(manifest.as_str())
+        .parent()
+        .map(|p| p.as_str())
+        .unwrap_or("");
+    set.is_match(manifest.as_str()) || set.is_match(dir)
+}
+
 fn pathbuf_to_utf8(path: PathBuf) -> Option<Utf8PathBuf> {
     Utf8PathBuf::from_path_buf(path).ok()
 }
 
-/// Check if a Cargo.toml file defines a nested virtual workspace.
+/// A `[workspace]` table found in a manifest below the root.
+struct WorkspaceTable {
+    /// Whether the manifest is also a package ("package workspace").
+    has_package: bool,
+    /// Line of the `[workspace]` header.
+    line: Option<u32>,
+}
+
+/// Read the `[workspace]` table of a Cargo.toml file, if it defines one.
 ///
 /// Cargo only includes members from the root workspace, not from nested workspaces.
 /// However, a "package workspace" (a manifest with both [package] and [workspace])
 /// is still included as a member. Only virtual workspaces (with [workspace] but no [package])
 /// are excluded.
-///
-/// Returns true if this is a virtual workspace (has [workspace] but no [package]).
-fn is_nested_workspace(path: &Utf8Path) -> bool {
-    match std::fs::read_to_string(path) {
-        Ok(content) => {
-            // Parse the TOML and check for workspace section
-            if let Ok(doc) = content.parse::<DocumentMut>() {
-                let has_workspace = doc.get("workspace").is_some();
-                let has_package = doc.get("package").is_some();
-                // Virtual workspace: has [workspace] but no [package]
-                has_workspace && !has_package
-            } else {
-                false
-            }
-        }
-        Err(_) => false,
-    }
+fn read_workspace_table(path: &Utf8Path) -> Option<WorkspaceTable> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let doc = Document::parse(content.as_str()).ok()?;
+    let workspace = doc.get("workspace")?;
+    Some(WorkspaceTable {
+        has_package: doc.get("package").is_some(),
+        line: workspace
+            .span()
+            .map(|span| byte_offset_to_line(&content, span.start)),
+    })
 }
 
 #[cfg(test)]
@@ -365,6 +460,94 @@ version = "0.1.0"
         );
     }
 
+    #[test]
+    fn discover_workspace_reports_members_matching_no_manifest() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = utf8_root(&tmp);
+
+        write_file(
+            &root.join("Cargo.toml"),
+            r#"[package]
+name = "root"
+version = "0.1.0"
+
+[workspace]
+members = [
+    ".",
+    "crates/a",
+    "./crates/typo",
+    "tools/*",
+    "crates/excluded",
+]
+exclude = ["crates/excluded"]
+"#,
+        );
+        for member in ["crates/a", "crates/excluded"] {
+            write_file(
+                &root.join(member).join("Cargo.toml"),
+                "[package]\nname = \"m\"\nversion = \"0.1.0\"\n",
+            );
+        }
+
+        let discovery = discover_workspace(&root).expect("discover");
+        let unresolved: Vec<(&str, Option<u32>)> = discovery
+            .unresolved_members
+            .iter()
+            .map(|m| (m.pattern.as_str(), m.location.as_ref().and_then(|l| l.line)))
+            .collect();
+        // Excluded members exist on disk, so only the typo and the empty glob remain.
+        assert_eq!(
+            unresolved,
+            vec![("crates/typo", Some(9)), ("tools/*", Some(10))]
+        );
+        assert!(
+            discovery.unresolved_members[0]
+                .location
+                .as_ref()
+                .is_some_and(|l| l.path.as_str() == "Cargo.toml")
+        );
+    }
+
+    #[test]
+    fn discover_workspace_records_nested_workspace_tables() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = utf8_root(&tmp);
+
+        write_file(
+            &root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"examples/standalone\"]\n",
+        );
+        write_file(
+            &root.join("crates/app/Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[workspace]\n",
+        );
+        write_file(
+            &root.join("tools/Cargo.toml"),
+            "# helper tools\n[workspace]\nmembers = [\"xtask\"]\n",
+        );
+        write_file(
+            &root.join("examples/standalone/Cargo.toml"),
+            "[workspace]\n",
+        );
+
+        let discovery = discover_workspace(&root).expect("discover");
+        let nested: Vec<(&str, Option<u32>)> = discovery
+            .nested_workspaces
+            .iter()
+            .map(|l| (l.path.as_str(), l.line))
+            .collect();
+        assert_eq!(
+            nested,
+            vec![
+                ("crates/app/Cargo.toml", Some(5)),
+                ("tools/Cargo.toml", Some(2))
+            ]
+        );
+        // The package workspace stays a member; the virtual one does not.
+        let manifests: Vec<&str> = discovery.manifests.iter().map(|p| p.as_str()).collect();
+        assert_eq!(manifests, vec!["Cargo.toml", "crates/app/Cargo.toml"]);
+    }
+
     #[test]
     fn pathbuf_to_utf8_rejects_invalid() {
         #[cfg(windows)]
@@ -1,7 +1,9 @@
 //! Repository adapters: discover workspaces, read manifest files, and assemble parsed models.
 //!
 //! Parsing is delegated to `depguard-repo-parser`; this crate is responsible for
-//! filesystem IO, manifest discovery, and model caching.
+//! filesystem IO, manifest discovery, and model caching. A root `Cargo.lock` is read
+//! when present to populate resolved versions; its absence is not an error. Package
+//! sources are only read by the opt-in [`scan_source_references`].
 //! It should not spawn external processes; diff scoping should be supplied as a list
 //! of changed paths by the caller (typically the CLI).
 
@@ -9,19 +11,23 @@
 
 mod cache;
 mod discover;
+mod lockfile;
+mod sources;
 
 use anyhow::Context;
 use cache::{ManifestCache, ManifestStamp};
 use camino::{Utf8Path, Utf8PathBuf};
 use depguard_domain_core::model::WorkspaceModel;
 use depguard_repo_parser::{
-    parse_member_manifest as parse_member_manifest_impl,
-    parse_root_manifest as parse_root_manifest_impl,
+    parse_member_manifest as parse_member_manifest_impl, parse_metadata_config,
+    parse_root_manifest as parse_root_manifest_impl, resolve_workspace_package,
 };
 use depguard_types::RepoPath;
 use rayon::prelude::*;
 
 pub use discover::discover_manifests;
+pub use lockfile::{ResolvedVersions, read_lockfile};
+pub use sources::{SourceReferences, scan_source_references};
 
 /// Fuzz-friendly API for testing parsing robustness without filesystem access.
 /// These functions are designed to never panic on any input.
@@ -48,6 +54,13 @@ pub mod fuzz {
         Ok(())
     }
 
+    /// Parse arbitrary text as a `Cargo.lock`.
+    ///
+    /// Returns `None` for anything that is not a lockfile. **Never panics** on any input.
+    pub fn parse_lockfile(text: &str) -> Option<crate::ResolvedVersions> {
+        crate::lockfile::parse_lockfile(text)
+    }
+
     /// Expand workspace member glob patterns against a list of candidate paths.
     ///
     /// This tests the glob compilation and matching logic without filesystem access.
@@ -115,6 +128,20 @@ pub enum ScopeInput {
     Diff { changed_files: Vec<RepoPath> },
 }
 
+/// Read depguard config embedded in the root `Cargo.toml` metadata.
+///
+/// Returns the `[workspace.metadata.depguard]` (or `[package.metadata.depguard]`)
+/// table as TOML text. A missing root manifest or metadata table yields `Ok(None)`.
+pub fn read_metadata_config(repo_root: &Utf8Path) -> anyhow::Result<Option<String>> {
+    let root_abs = repo_root.join("Cargo.toml");
+    let text = match std::fs::read_to_string(&root_abs) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).with_context(|| format!("read {}", root_abs)),
+    };
+    parse_metadata_config(&text).with_context(|| format!("parse {}", root_abs))
+}
+
 /// Build the in-memory workspace model used by the policy engine.
 ///
 /// `repo_root` should be the git/workspace root (directory containing the root `Cargo.toml`).
@@ -122,21 +149,29 @@ pub fn build_workspace_model(
     repo_root: &Utf8Path,
     scope: ScopeInput,
 ) -> anyhow::Result<WorkspaceModel> {
-    build_workspace_model_with_cache(repo_root, scope, None)
+    build_workspace_model_with_cache(repo_root, scope, None, &[])
 }
 
 /// Build the in-memory workspace model and optionally cache parsed manifests.
 ///
 /// When `cache_dir` is set, parsed manifests are persisted and reused when file
-/// metadata `(size, modified timestamp)` is unchanged.
+/// metadata `(size, modified timestamp)` is unchanged. Manifests matching an
+/// `exclude` glob (by file path or directory) are left out in every scope; the
+/// root manifest is always kept.
 pub fn build_workspace_model_with_cache(
     repo_root: &Utf8Path,
     scope: ScopeInput,
     cache_dir: Option<&Utf8Path>,
+    exclude: &[String],
 ) -> anyhow::Result<WorkspaceModel> {
-    let manifests = discover::discover_manifests(repo_root).context("discover manifests")?;
+    let discovery = discover::discover_workspace(repo_root).context("discover manifests")?;
+    let manifests = discovery.manifests;
     let root_manifest = RepoPath::new("Cargo.toml");
-    let in_scope = manifests_in_scope(&manifests, &root_manifest, scope);
+    let exclude_set = discover::build_globset(exclude).context("compile exclude globset")?;
+    let in_scope: Vec<RepoPath> = manifests_in_scope(&manifests, &root_manifest, scope)
+        .into_iter()
+        .filter(|p| *p == root_manifest || !discover::matches_manifest(&exclude_set, p))
+        .collect();
 
     let mut cache = cache_dir
         .map(|dir| ManifestCache::load(repo_root, dir))
@@ -146,16 +181,16 @@ pub fn build_workspace_model_with_cache(
     let root_abs = repo_root.join(root_manifest.as_str());
     let root_stamp = cache_stamp_for(&root_abs)?;
 
-    let (root_ws_deps, root_model) = if let Some(store) = cache.as_mut() {
+    let (root_ws_deps, root_ws_package, root_model) = if let Some(store) = cache.as_mut() {
         if let Some(cached) = store.root_if_fresh(&root_manifest, root_stamp) {
             cached
         } else {
             let root_text =
                 std::fs::read_to_string(&root_abs).with_context(|| format!("read {}", root_abs))?;
-            let (deps, model) = parse_root_manifest_impl(&root_manifest, &root_text)
+            let (deps, ws_package, model) = parse_root_manifest_impl(&root_manifest, &root_text)
                 .context("parse root manifest")?;
-            store.store_root(&root_manifest, root_stamp, &deps, &model);
-            (deps, model)
+            store.store_root(&root_manifest, root_stamp, &deps, &ws_package, &model);
+            (deps, ws_package, model)
         }
     } else {
         let root_text =
@@ -166,7 +201,15 @@ pub fn build_workspace_model_with_cache(
     let mut model = WorkspaceModel {
         repo_root: RepoPath::from(repo_root),
         workspace_dependencies: root_ws_deps,
+        resolved_versions: lockfile::read_lockfile(repo_root),
         manifests: Vec::new(),
+        source_references: None,
+        unresolved_members: discovery.unresolved_members,
+        nested_workspaces: discovery
+            .nested_workspaces
+            .into_iter()
+            .filter(|loc| !discover::matches_manifest(&exclude_set, &loc.path))
+            .collect(),
     };
 
     // Add the parsed root manifest (it may or may not be a package).
@@ -214,6 +257,55 @@ pub fn build_workspace_model_with_cache(
         }
     }
 
+    // Resolve after caching so cached entries stay independent of `[workspace.package]`.
+    for manifest in &mut model.manifests {
+        resolve_workspace_package(manifest, &root_ws_package);
+    }
+
+    Ok(model)
+}
+
+/// Build the workspace model from manifest texts supplied by the caller, without any IO.
+///
+/// `root_text` is the root `Cargo.toml`; `members` pairs each member manifest's
+/// repo-relative path with its contents. Members are parsed in path order and
+/// `[workspace.package]` inheritance is resolved as in [`build_workspace_model`].
+/// Nothing is discovered from `members = [...]` globs, no `Cargo.lock` is read,
+/// and `repo_root` is `"."`, so the result is suitable for embedding the engine
+/// where manifests arrive as strings.
+pub fn build_workspace_model_from_sources(
+    root_text: &str,
+    members: Vec<(RepoPath, String)>,
+) -> anyhow::Result<WorkspaceModel> {
+    let root_manifest = RepoPath::new("Cargo.toml");
+    let (root_ws_deps, root_ws_package, root_model) =
+        parse_root_manifest_impl(&root_manifest, root_text).context("parse root manifest")?;
+
+    let mut members: Vec<(RepoPath, String)> = members
+        .into_iter()
+        .filter(|(path, _)| *path != root_manifest)
+        .collect();
+    members.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut model = WorkspaceModel {
+        repo_root: RepoPath::new("."),
+        workspace_dependencies: root_ws_deps,
+        resolved_versions: None,
+        manifests: vec![root_model],
+        source_references: None,
+        unresolved_members: Vec::new(),
+        nested_workspaces: Vec::new(),
+    };
+    for (manifest_path, text) in &members {
+        let parsed = parse_member_manifest_impl(manifest_path, text)
+            .with_context(|| format!("parse {}", manifest_path.as_str()))?;
+        model.manifests.push(parsed);
+    }
+
+    for manifest in &mut model.manifests {
+        resolve_workspace_package(manifest, &root_ws_package);
+    }
+
     Ok(model)
 }
 
@@ -382,7 +474,7 @@ version = "0.1.0"
         );
 
         let cache_dir = Utf8Path::new(".depguard-cache");
-        let _ = build_workspace_model_with_cache(&root, ScopeInput::Repo, Some(cache_dir))
+        let _ = build_workspace_model_with_cache(&root, ScopeInput::Repo, Some(cache_dir), &[])
             .expect("build model with cache");
 
         let cache_file = root
@@ -411,7 +503,7 @@ version = "0.1.0"
         );
 
         let cache_dir = Utf8Path::new(".depguard-cache");
-        let _ = build_workspace_model_with_cache(&root, ScopeInput::Repo, Some(cache_dir))
+        let _ = build_workspace_model_with_cache(&root, ScopeInput::Repo, Some(cache_dir), &[])
             .expect("first build");
 
         // Break the manifest to ensure stale cache is not reused.
@@ -422,11 +514,96 @@ name = "a"
 version = "#,
         );
 
-        let err = build_workspace_model_with_cache(&root, ScopeInput::Repo, Some(cache_dir))
+        let err = build_workspace_model_with_cache(&root, ScopeInput::Repo, Some(cache_dir), &[])
             .expect_err("expected parse error");
         assert!(err.to_string().contains("parse"));
     }
 
+    #[test]
+    fn build_workspace_model_from_sources_runs_checks_without_disk() {
+        use depguard_domain::{CheckPolicy, EffectiveConfig, FailOn, Scope};
+        use depguard_types::{Severity, ids};
+        use std::collections::BTreeMap;
+
+        let root = r#"[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+edition = "2021"
+
+[workspace.dependencies]
+serde = "1.0"
+"#;
+        let members = vec![
+            (
+                RepoPath::new("crates/b/Cargo.toml"),
+                r#"[package]
+name = "b"
+version = "0.1.0"
+
+[dependencies]
+serde = { workspace = true }
+"#
+                .to_string(),
+            ),
+            (
+                RepoPath::new("crates/a/Cargo.toml"),
+                r#"[package]
+name = "a"
+version = "0.1.0"
+edition.workspace = true
+
+[dependencies]
+rand = "*"
+"#
+                .to_string(),
+            ),
+        ];
+
+        let model = build_workspace_model_from_sources(root, members).expect("build model");
+        let paths: Vec<&str> = model.manifests.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["Cargo.toml", "crates/a/Cargo.toml", "crates/b/Cargo.toml"]
+        );
+        assert!(model.workspace_dependencies.contains_key("serde"));
+        assert!(model.resolved_versions.is_none());
+        let a = model.manifests[1].package.as_ref().expect("package a");
+        assert_eq!(a.edition.as_deref(), Some("2021"));
+
+        let cfg = EffectiveConfig {
+            profile: "strict".to_string(),
+            scope: Scope::Repo,
+            fail_on: FailOn::Error,
+            max_findings: 200,
+            yanked_index: None,
+            checks: BTreeMap::from([(
+                ids::CHECK_DEPS_NO_WILDCARDS.to_string(),
+                CheckPolicy::enabled(Severity::Error),
+            )]),
+            verdict_ignores: Vec::new(),
+        };
+        let report = depguard_domain::evaluate(&model, &cfg);
+        assert_eq!(report.findings.len(), 1);
+        let finding = &report.findings[0];
+        assert_eq!(finding.code, ids::CODE_WILDCARD_VERSION);
+        assert_eq!(
+            finding.location.as_ref().expect("location").path.as_str(),
+            "crates/a/Cargo.toml"
+        );
+    }
+
+    #[test]
+    fn build_workspace_model_from_sources_reports_member_parse_errors() {
+        let members = vec![(
+            RepoPath::new("crates/a/Cargo.toml"),
+            "[package\nname = ".to_string(),
+        )];
+        let err = build_workspace_model_from_sources("[workspace]\n", members)
+            .expect_err("invalid member manifest");
+        assert!(format!("{err:#}").contains("crates/a/Cargo.toml"));
+    }
+
     proptest! {
         #[test]
         fn fuzz_parsers_never_panic(input in ".*") {
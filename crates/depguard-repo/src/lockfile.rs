@@ -0,0 +1,87 @@
+//! Best-effort `Cargo.lock` reading.
+//!
+//! The lockfile is optional input: a missing or malformed lock never fails the run,
+//! it only leaves `WorkspaceModel::resolved_versions` unset.
+
+use camino::Utf8Path;
+use std::collections::{BTreeMap, BTreeSet};
+use toml_edit::DocumentMut;
+
+/// Resolved versions per crate name.
+pub type ResolvedVersions = BTreeMap<String, BTreeSet<String>>;
+
+/// Read `Cargo.lock` next to the root manifest.
+///
+/// Returns `None` when the file is missing, unreadable, or unparseable.
+pub fn read_lockfile(repo_root: &Utf8Path) -> Option<ResolvedVersions> {
+    let text = std::fs::read_to_string(repo_root.join("Cargo.lock")).ok()?;
+    parse_lockfile(&text)
+}
+
+/// Collect `[[package]]` name/version pairs from lockfile text.
+///
+/// Returns `None` if the text is not TOML or `package` is not an array of tables.
+/// Entries without a string `name` and `version` are skipped. **Never panics.**
+pub fn parse_lockfile(text: &str) -> Option<ResolvedVersions> {
+    let doc: DocumentMut = text.parse().ok()?;
+    let mut resolved = ResolvedVersions::new();
+
+    let Some(packages) = doc.get("package") else {
+        return Some(resolved);
+    };
+    for pkg in packages.as_array_of_tables()? {
+        let name = pkg.get("name").and_then(|v| v.as_str());
+        let version = pkg.get("version").and_then(|v| v.as_str());
+        if let (Some(name), Some(version)) = (name, version) {
+            resolved
+                .entry(name.to_string())
+                .or_default()
+                .insert(version.to_string());
+        }
+    }
+
+    Some(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lockfile_groups_versions_by_name() {
+        let text = r#"
+version = 3
+
+[[package]]
+name = "serde"
+version = "1.0.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "serde"
+version = "1.0.200"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "app"
+version = "0.1.0"
+
+[[package]]
+name = "broken"
+"#;
+        let resolved = parse_lockfile(text).expect("lockfile");
+        assert_eq!(resolved.len(), 2);
+        assert_eq!(
+            resolved["serde"].iter().collect::<Vec<_>>(),
+            vec!["1.0.1", "1.0.200"]
+        );
+        assert_eq!(resolved["app"].len(), 1);
+    }
+
+    #[test]
+    fn parse_lockfile_rejects_malformed_input() {
+        assert!(parse_lockfile("not = [toml").is_none());
+        assert!(parse_lockfile("package = 1").is_none());
+        assert_eq!(parse_lockfile("version = 3"), Some(ResolvedVersions::new()));
+    }
+}
@@ -0,0 +1,178 @@
+//! Opt-in scan of package sources for crate references.
+//!
+//! This is a line-based heuristic, not a Rust parser: it records the first path
+//! segment of `use` and `extern crate` items in `src/**/*.rs`. Test code is
+//! skipped by convention — files named `tests.rs`, directories named `tests`,
+//! and everything after the first `#[cfg(test)]` in a file. Unreadable files are
+//! ignored rather than failing the run.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use depguard_domain_core::model::ManifestModel;
+use depguard_types::{Location, RepoPath};
+use std::collections::BTreeMap;
+use walkdir::WalkDir;
+
+/// Crate identifier -> first reference, per manifest path.
+pub type SourceReferences = BTreeMap<String, BTreeMap<String, Location>>;
+
+/// Scan `src/` next to every package manifest for crate references.
+///
+/// Manifests without a `[package]` table are skipped. Files are visited in
+/// sorted order so the reported location is stable across runs.
+pub fn scan_source_references(
+    repo_root: &Utf8Path,
+    manifests: &[ManifestModel],
+) -> SourceReferences {
+    let mut out = SourceReferences::new();
+    for manifest in manifests {
+        if manifest.package.is_none() {
+            continue;
+        }
+        let package_dir = Utf8Path::new(manifest.path.as_str())
+            .parent()
+            .map(Utf8Path::to_path_buf)
+            .unwrap_or_default();
+        out.insert(
+            manifest.path.as_str().to_string(),
+            scan_package(repo_root, &package_dir.join("src")),
+        );
+    }
+    out
+}
+
+fn scan_package(repo_root: &Utf8Path, src_dir: &Utf8Path) -> BTreeMap<String, Location> {
+    let mut refs = BTreeMap::new();
+    let walker = WalkDir::new(repo_root.join(src_dir))
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| !(e.file_type().is_dir() && e.file_name() == "tests"));
+    for entry in walker.filter_map(Result::ok) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(abs) = Utf8PathBuf::from_path_buf(entry.into_path()) else {
+            continue;
+        };
+        if abs.extension() != Some("rs") || abs.file_name() == Some("tests.rs") {
+            continue;
+        }
+        let Ok(text) = std::fs::read_to_string(&abs) else {
+            continue;
+        };
+        let rel = abs.strip_prefix(repo_root).unwrap_or(&abs);
+        for (ident, line) in crate_references(&text) {
+            refs.entry(ident).or_insert_with(|| Location {
+                path: RepoPath::new(rel.as_str()),
+                line: Some(line),
+                col: None,
+            });
+        }
+    }
+    refs
+}
+
+/// Crate identifiers named by `use`/`extern crate` items, with 1-based lines.
+fn crate_references(text: &str) -> Vec<(String, u32)> {
+    let mut out = Vec::new();
+    for (idx, raw) in text.lines().enumerate() {
+        let line = raw.trim_start();
+        if line.starts_with("#[cfg(test)]") {
+            break;
+        }
+        if let Some(ident) = referenced_crate(line) {
+            out.push((ident.to_string(), idx as u32 + 1));
+        }
+    }
+    out
+}
+
+fn referenced_crate(line: &str) -> Option<&str> {
+    let line = strip_visibility(line);
+    let rest = if let Some(rest) = line.strip_prefix("use ") {
+        rest.trim_start().trim_start_matches("::")
+    } else {
+        line.strip_prefix("extern crate ")?
+    };
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    let ident = &rest[..end];
+    match ident {
+        "" | "crate" | "self" | "super" => None,
+        _ => Some(ident),
+    }
+}
+
+/// Drop a leading `pub`, `pub(crate)`, `pub(super)`, or `pub(in ...)`.
+fn strip_visibility(line: &str) -> &str {
+    let Some(rest) = line.strip_prefix("pub") else {
+        return line;
+    };
+    let rest = match rest.strip_prefix('(') {
+        Some(inner) => match inner.find(')') {
+            Some(close) => &inner[close + 1..],
+            None => return line,
+        },
+        None => rest,
+    };
+    if rest.starts_with(char::is_whitespace) {
+        rest.trim_start()
+    } else {
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn collects_use_and_extern_crate_items() {
+        let text = "\
+use serde::Deserialize;
+pub use ::anyhow::Result;
+pub(crate) use tokio;
+extern crate rand;
+use crate::model;
+use super::x;
+// use commented_out::Thing;
+";
+        let refs: Vec<_> = crate_references(text)
+            .into_iter()
+            .map(|(ident, _)| ident)
+            .collect();
+        assert_eq!(refs, vec!["serde", "anyhow", "tokio", "rand"]);
+    }
+
+    #[test]
+    fn stops_at_first_cfg_test() {
+        let text = "use serde::Serialize;\n\n#[cfg(test)]\nmod tests {\n    use proptest::prelude::*;\n}\n";
+        assert_eq!(crate_references(text), vec![("serde".to_string(), 1)]);
+    }
+
+    #[test]
+    fn scan_skips_test_files_and_records_first_location() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = Utf8Path::from_path(tmp.path()).expect("utf8 path");
+        let src = root.join("crates/a/src");
+        std::fs::create_dir_all(src.join("tests")).expect("mkdir");
+        std::fs::write(src.join("lib.rs"), "mod util;\nuse serde_json::Value;\n").expect("write");
+        std::fs::write(src.join("util.rs"), "use serde_json::json;\n").expect("write");
+        std::fs::write(src.join("tests.rs"), "use proptest::prelude::*;\n").expect("write");
+        std::fs::write(src.join("tests/helpers.rs"), "use tempfile::TempDir;\n").expect("write");
+
+        let manifest = ManifestModel {
+            path: RepoPath::new("crates/a/Cargo.toml"),
+            package: Some(Default::default()),
+            ..Default::default()
+        };
+        let refs = scan_source_references(root, &[manifest]);
+        let pkg = refs.get("crates/a/Cargo.toml").expect("package scanned");
+
+        assert_eq!(pkg.keys().collect::<Vec<_>>(), vec!["serde_json"]);
+        let loc = &pkg["serde_json"];
+        assert_eq!(loc.path.as_str(), "crates/a/src/lib.rs");
+        assert_eq!(loc.line, Some(2));
+    }
+}
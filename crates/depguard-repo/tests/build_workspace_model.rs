@@ -1,5 +1,7 @@
 use camino::{Utf8Path, Utf8PathBuf};
-use depguard_repo::{ScopeInput, build_workspace_model};
+use depguard_repo::{
+    ScopeInput, build_workspace_model, build_workspace_model_with_cache, read_metadata_config,
+};
 use depguard_types::RepoPath;
 use tempfile::TempDir;
 
@@ -87,6 +89,59 @@ fn build_workspace_model_diff_scope_ignores_unknown_paths() {
     assert_eq!(paths, vec!["Cargo.toml"]);
 }
 
+#[test]
+fn build_workspace_model_exclude_drops_vendored_manifests_in_both_scopes() {
+    let (_temp, root) = setup_workspace();
+    write_file(
+        &root.join("Cargo.toml"),
+        r#"[workspace]
+members = ["crates/a", "crates/b", "vendor/*"]
+"#,
+    );
+    write_file(
+        &root.join("vendor").join("gen").join("Cargo.toml"),
+        r#"[package]
+name = "gen"
+version = "0.1.0"
+"#,
+    );
+    let exclude = vec!["vendor/**/Cargo.toml".to_string()];
+
+    let model = build_workspace_model_with_cache(root.as_path(), ScopeInput::Repo, None, &exclude)
+        .expect("build model");
+    let paths: Vec<&str> = model.manifests.iter().map(|m| m.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec!["Cargo.toml", "crates/a/Cargo.toml", "crates/b/Cargo.toml"]
+    );
+
+    let model = build_workspace_model_with_cache(
+        root.as_path(),
+        ScopeInput::Diff {
+            changed_files: vec![
+                RepoPath::new("crates/a/Cargo.toml"),
+                RepoPath::new("vendor/gen/Cargo.toml"),
+            ],
+        },
+        None,
+        &exclude,
+    )
+    .expect("build model");
+    let paths: Vec<&str> = model.manifests.iter().map(|m| m.path.as_str()).collect();
+    assert_eq!(paths, vec!["Cargo.toml", "crates/a/Cargo.toml"]);
+
+    // The root manifest is never excluded, even by a catch-all pattern.
+    let model = build_workspace_model_with_cache(
+        root.as_path(),
+        ScopeInput::Repo,
+        None,
+        &["**".to_string()],
+    )
+    .expect("build model");
+    let paths: Vec<&str> = model.manifests.iter().map(|m| m.path.as_str()).collect();
+    assert_eq!(paths, vec!["Cargo.toml"]);
+}
+
 #[test]
 fn fuzz_helpers_parse_and_expand_globs() {
     let ok_manifest = r#"[package]
@@ -102,3 +157,131 @@ version = "0.1.0"
     assert_eq!(matched, vec!["crates/a/Cargo.toml".to_string()]);
     assert!(depguard_repo::fuzz::expand_globs(&["[".to_string()], &candidates).is_err());
 }
+
+#[test]
+fn read_metadata_config_returns_workspace_metadata_table() {
+    let (_temp, root) = setup_workspace();
+    assert!(
+        read_metadata_config(root.as_path())
+            .expect("read metadata")
+            .is_none()
+    );
+
+    let manifest = r#"[workspace]
+members = ["crates/a", "crates/b"]
+
+[workspace.metadata.depguard]
+profile = "warn"
+"#;
+    write_file(&root.join("Cargo.toml"), manifest);
+    let config = read_metadata_config(root.as_path())
+        .expect("read metadata")
+        .expect("metadata config");
+    assert!(config.contains("profile = \"warn\""));
+}
+
+#[test]
+fn read_metadata_config_tolerates_missing_manifest() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let root = Utf8PathBuf::from_path_buf(temp.path().to_path_buf()).expect("utf8 path");
+    assert!(
+        read_metadata_config(root.as_path())
+            .expect("read")
+            .is_none()
+    );
+}
+
+#[test]
+fn build_workspace_model_resolves_workspace_package_inheritance() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let root = Utf8PathBuf::from_path_buf(temp.path().to_path_buf()).expect("utf8 path");
+
+    write_file(
+        &root.join("Cargo.toml"),
+        r#"[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+version = "0.4.0"
+edition = "2021"
+rust-version = "1.74"
+"#,
+    );
+    write_file(
+        &root.join("crates").join("inherits").join("Cargo.toml"),
+        r#"[package]
+name = "inherits"
+version.workspace = true
+edition.workspace = true
+rust-version.workspace = true
+"#,
+    );
+    write_file(
+        &root.join("crates").join("overrides").join("Cargo.toml"),
+        r#"[package]
+name = "overrides"
+version = "2.0.0"
+edition = "2024"
+rust-version = { workspace = true }
+"#,
+    );
+
+    let cache_dir = root.join(".depguard-cache");
+    for _ in 0..2 {
+        // The second pass restores manifests from the cache and must resolve identically.
+        let model = build_workspace_model_with_cache(
+            root.as_path(),
+            ScopeInput::Repo,
+            Some(cache_dir.as_path()),
+            &[],
+        )
+        .expect("build model");
+        let package = |name: &str| {
+            model
+                .manifests
+                .iter()
+                .filter_map(|m| m.package.as_ref())
+                .find(|p| p.name == name)
+                .cloned()
+                .expect("package")
+        };
+
+        let inherits = package("inherits");
+        assert_eq!(inherits.version.as_deref(), Some("0.4.0"));
+        assert_eq!(inherits.edition.as_deref(), Some("2021"));
+        assert_eq!(inherits.rust_version.as_deref(), Some("1.74"));
+        assert!(inherits.is_inherited("version"));
+        assert!(inherits.is_inherited("rust-version"));
+
+        let overrides = package("overrides");
+        assert_eq!(overrides.version.as_deref(), Some("2.0.0"));
+        assert_eq!(overrides.edition.as_deref(), Some("2024"));
+        assert_eq!(overrides.rust_version.as_deref(), Some("1.74"));
+        assert!(!overrides.is_inherited("edition"));
+        assert!(overrides.is_inherited("rust-version"));
+    }
+}
+
+#[test]
+fn build_workspace_model_reads_resolved_versions_from_lockfile() {
+    let (_temp, root) = setup_workspace();
+
+    let model = build_workspace_model(&root, ScopeInput::Repo).expect("build model");
+    assert!(model.resolved_versions.is_none());
+
+    let lockfile = r#"version = 4
+
+[[package]]
+name = "serde"
+version = "1.0.100"
+
+[[package]]
+name = "serde"
+version = "1.0.200"
+"#;
+    write_file(&root.join("Cargo.lock"), lockfile);
+
+    let model = build_workspace_model(&root, ScopeInput::Repo).expect("build model");
+    let resolved = model.resolved_versions.expect("resolved versions");
+    assert_eq!(resolved["serde"].len(), 2);
+}
@@ -0,0 +1,102 @@
+use crate::resolve::ResolvedConfig;
+use depguard_domain_core::policy::{CheckPolicy, FailOn, MsrvMode, PinningMode, Scope};
+use depguard_types::Severity;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Serializable snapshot of a [`ResolvedConfig`], for showing users what a run will use.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ConfigDump {
+    pub profile: String,
+    pub scope: &'static str,
+    pub fail_on: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub verdict_ignores: Vec<Severity>,
+    pub max_findings: usize,
+    pub baseline: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    pub checks: BTreeMap<String, CheckDump>,
+}
+
+/// One check's resolved policy; check-specific options are omitted when unset.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CheckDump {
+    pub enabled: bool,
+    pub severity: Severity,
+    pub allow: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_publish_false: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_rust_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_edition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_dev: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_build: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_licenses: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub options: BTreeMap<String, serde_json::Value>,
+}
+
+impl From<&ResolvedConfig> for ConfigDump {
+    fn from(resolved: &ResolvedConfig) -> Self {
+        let cfg = &resolved.effective;
+        Self {
+            profile: cfg.profile.clone(),
+            scope: match cfg.scope {
+                Scope::Repo => "repo",
+                Scope::Diff => "diff",
+            },
+            fail_on: match cfg.fail_on {
+                FailOn::Error => "error",
+                FailOn::Warning => "warning",
+            },
+            verdict_ignores: cfg.verdict_ignores.clone(),
+            max_findings: cfg.max_findings,
+            baseline: resolved.baseline_path.clone(),
+            exclude: resolved.exclude.clone(),
+            checks: cfg
+                .checks
+                .iter()
+                .map(|(id, policy)| (id.clone(), CheckDump::from(policy)))
+                .collect(),
+        }
+    }
+}
+
+impl From<&CheckPolicy> for CheckDump {
+    fn from(policy: &CheckPolicy) -> Self {
+        Self {
+            enabled: policy.enabled,
+            severity: policy.severity,
+            allow: policy.allow.clone(),
+            deny: policy.deny.clone(),
+            ignore: policy.ignore.clone(),
+            ignore_publish_false: policy.ignore_publish_false,
+            min_rust_version: policy.min_rust_version.clone(),
+            min_edition: policy.min_edition.clone(),
+            mode: match (policy.pinning_mode, policy.msrv_mode) {
+                (Some(PinningMode::ForbidExact), _) => Some("forbid_exact"),
+                (Some(PinningMode::RequireExact), _) => Some("require_exact"),
+                (None, MsrvMode::RequireInherit) => Some("require_inherit"),
+                (None, MsrvMode::Consistent) => None,
+            },
+            max: policy.dependency_limits.normal,
+            max_dev: policy.dependency_limits.dev,
+            max_build: policy.dependency_limits.build,
+            allowed_licenses: policy.allowed_licenses.clone(),
+            options: policy.options.clone(),
+        }
+    }
+}
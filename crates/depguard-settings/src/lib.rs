@@ -4,13 +4,17 @@
 
 #![forbid(unsafe_code)]
 
+mod dump;
+mod merge;
 mod model;
 mod presets;
 mod resolve;
 mod validation_error;
 
-pub use model::{CheckConfig, DepguardConfigV1};
-pub use resolve::{Overrides, ResolvedConfig};
+pub use dump::{CheckDump, ConfigDump};
+pub use merge::merge_configs;
+pub use model::{CheckConfig, DepguardConfigV1, ProfileConfig};
+pub use resolve::{ConfigValidation, Overrides, ResolvedConfig};
 pub use validation_error::{ValidationError, ValidationErrors};
 
 /// Parse `depguard.toml` (or equivalent) into a typed model.
@@ -19,6 +23,11 @@ pub fn parse_config_toml(input: &str) -> anyhow::Result<DepguardConfigV1> {
     Ok(cfg)
 }
 
+/// Serialize a config back to `depguard.toml` text; unset keys are omitted.
+pub fn render_config_toml(cfg: &DepguardConfigV1) -> anyhow::Result<String> {
+    Ok(toml::to_string(cfg)?)
+}
+
 /// Resolve the effective config used by the engine (profiles + overrides + per-check config).
 pub fn resolve_config(
     cfg: DepguardConfigV1,
@@ -27,11 +36,20 @@ pub fn resolve_config(
     resolve::resolve_config(cfg, overrides)
 }
 
+/// Validate a config without resolving it for a run.
+///
+/// Unlike [`resolve_config`], which stops at the first problem, this collects every
+/// validation error and reports configured check ids that are not registered.
+pub fn validate_config(cfg: DepguardConfigV1) -> ConfigValidation {
+    resolve::validate_config(cfg)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use depguard_domain_core::policy::{FailOn, Scope};
+    use depguard_domain_core::policy::{DependencyLimits, FailOn, MsrvMode, PinningMode, Scope};
     use depguard_types::Severity;
+    use std::collections::BTreeMap;
 
     #[test]
     fn parse_empty_config() {
@@ -115,6 +133,30 @@ mod tests {
         assert_eq!(check.severity, Severity::Warning);
     }
 
+    #[test]
+    fn secure_git_url_is_on_in_strict_and_off_in_compat() {
+        let resolve = |profile: &str| {
+            let cfg = DepguardConfigV1 {
+                profile: Some(profile.to_string()),
+                ..Default::default()
+            };
+            resolve_config(cfg, Overrides::default()).unwrap()
+        };
+
+        let strict = resolve("strict");
+        let check = strict.effective.checks.get("deps.secure_git_url").unwrap();
+        assert!(check.enabled);
+        assert_eq!(check.severity, Severity::Error);
+
+        let compat = resolve("compat");
+        assert!(
+            compat
+                .effective
+                .check_policy("deps.secure_git_url")
+                .is_none()
+        );
+    }
+
     #[test]
     fn cli_overrides_take_precedence() {
         let cfg = DepguardConfigV1 {
@@ -240,6 +282,577 @@ mod tests {
         );
     }
 
+    #[test]
+    fn denylist_is_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.banned_crates"]
+            enabled = true
+            deny = ["openssl*", "native-tls"]
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.banned_crates")
+            .expect("banned_crates enabled");
+        assert_eq!(policy.deny, vec!["openssl*", "native-tls"]);
+
+        let toml = r#"
+            [checks."deps.banned_crates"]
+            deny = ["["]
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let err_msg = resolve_config(cfg, Overrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err_msg.contains("checks.deps.banned_crates.deny"),
+            "error message should contain key path: {err_msg}"
+        );
+    }
+
+    #[test]
+    fn msrv_floor_options_are_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.msrv_floor"]
+            enabled = true
+            min_rust_version = "1.74"
+            min_edition = "2021"
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.msrv_floor")
+            .expect("msrv_floor enabled");
+        assert_eq!(policy.min_rust_version.as_deref(), Some("1.74"));
+        assert_eq!(policy.min_edition.as_deref(), Some("2021"));
+
+        for (toml, key) in [
+            (
+                r#"[checks."deps.msrv_floor"]
+                min_rust_version = "latest""#,
+                "checks.deps.msrv_floor.min_rust_version",
+            ),
+            (
+                r#"[checks."deps.msrv_floor"]
+                min_edition = "2020""#,
+                "checks.deps.msrv_floor.min_edition",
+            ),
+            (
+                r#"[checks."deps.no_wildcards"]
+                min_edition = "2021""#,
+                "checks.deps.no_wildcards.min_edition",
+            ),
+        ] {
+            let cfg = parse_config_toml(toml).unwrap();
+            let err_msg = resolve_config(cfg, Overrides::default())
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err_msg.contains(key),
+                "error message should contain key path {key}: {err_msg}"
+            );
+        }
+    }
+
+    #[test]
+    fn consistent_msrv_mode_is_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.consistent_msrv"]
+            enabled = true
+            mode = "require_inherit"
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.consistent_msrv")
+            .expect("consistent_msrv enabled");
+        assert_eq!(policy.msrv_mode, MsrvMode::RequireInherit);
+        assert_eq!(
+            ConfigDump::from(&resolved).checks["deps.consistent_msrv"].mode,
+            Some("require_inherit")
+        );
+
+        for (toml, needle) in [
+            (
+                r#"[checks."deps.consistent_msrv"]
+            mode = "require_exact""#,
+                "valid modes: consistent, require_inherit",
+            ),
+            (
+                r#"[checks."deps.version_pinning"]
+            mode = "require_inherit""#,
+                "valid modes: forbid_exact, require_exact",
+            ),
+        ] {
+            let cfg = parse_config_toml(toml).unwrap();
+            let err_msg = format!(
+                "{:#}",
+                resolve_config(cfg, Overrides::default()).unwrap_err()
+            );
+            assert!(err_msg.contains(".mode"), "{err_msg}");
+            assert!(err_msg.contains(needle), "{err_msg}");
+        }
+    }
+
+    #[test]
+    fn version_pinning_mode_is_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.version_pinning"]
+            enabled = true
+            mode = "require_exact"
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.version_pinning")
+            .expect("version_pinning enabled");
+        assert_eq!(policy.pinning_mode, Some(PinningMode::RequireExact));
+
+        for toml in [
+            r#"[checks."deps.version_pinning"]
+            mode = "loose""#,
+            r#"[checks."deps.no_wildcards"]
+            mode = "forbid_exact""#,
+        ] {
+            let cfg = parse_config_toml(toml).unwrap();
+            let err_msg = resolve_config(cfg, Overrides::default())
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err_msg.contains(".mode"),
+                "error message should name the mode key: {err_msg}"
+            );
+        }
+    }
+
+    #[test]
+    fn verdict_ignores_is_resolved_and_validated() {
+        let cfg = parse_config_toml(
+            r#"
+            fail_on = "warning"
+            verdict_ignores = ["info", "warn", "info"]
+            "#,
+        )
+        .unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        assert_eq!(
+            resolved.effective.verdict_ignores,
+            vec![Severity::Info, Severity::Warning]
+        );
+
+        let cfg = parse_config_toml(r#"verdict_ignores = ["error"]"#).unwrap();
+        let err_msg = resolve_config(cfg, Overrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err_msg.contains("verdict_ignores"),
+            "error message should name the verdict_ignores key: {err_msg}"
+        );
+    }
+
+    #[test]
+    fn exclude_globs_are_resolved_and_validated() {
+        let cfg = parse_config_toml(
+            r#"exclude = ["vendor/**/Cargo.toml", "gen/*", "vendor/**/Cargo.toml"]"#,
+        )
+        .unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        assert_eq!(resolved.exclude, vec!["vendor/**/Cargo.toml", "gen/*"]);
+
+        let cfg = parse_config_toml(r#"exclude = ["vendor/[bad"]"#).unwrap();
+        let err_msg = resolve_config(cfg, Overrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err_msg.contains("exclude") && err_msg.contains("invalid glob pattern"),
+            "error message should name the exclude key: {err_msg}"
+        );
+    }
+
+    #[test]
+    fn max_direct_deps_limits_are_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.max_direct_deps"]
+            enabled = true
+            max = 40
+            max_dev = 20
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.max_direct_deps")
+            .expect("max_direct_deps enabled");
+        assert_eq!(
+            policy.dependency_limits,
+            DependencyLimits {
+                normal: Some(40),
+                dev: Some(20),
+                build: None,
+            }
+        );
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.no_wildcards"]
+            max_build = 3"#,
+        )
+        .unwrap();
+        let err_msg = resolve_config(cfg, Overrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err_msg.contains("checks.deps.no_wildcards.max_build"),
+            "error message should name the max_build key: {err_msg}"
+        );
+    }
+
+    #[test]
+    fn license_present_allowed_licenses_are_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.license_present"]
+            enabled = true
+            allowed_licenses = ["MIT", "Apache-2.0"]
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.license_present")
+            .expect("license_present enabled");
+        assert_eq!(policy.allowed_licenses, vec!["MIT", "Apache-2.0"]);
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.no_wildcards"]
+            allowed_licenses = ["MIT"]"#,
+        )
+        .unwrap();
+        let err_msg = resolve_config(cfg, Overrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err_msg.contains("checks.deps.no_wildcards.allowed_licenses"),
+            "error message should name the allowed_licenses key: {err_msg}"
+        );
+    }
+
+    #[test]
+    fn deprecated_crates_option_is_resolved_and_validated() {
+        use depguard_domain_core::policy::DeprecatedCratesOptions;
+
+        let toml = r#"
+            [checks."deps.deprecated_crates"]
+            enabled = true
+
+            [checks."deps.deprecated_crates".options.deprecated]
+            serde_yaml = "serde_yml"
+            structopt = "clap"
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let options: DeprecatedCratesOptions = resolved
+            .effective
+            .check_policy("deps.deprecated_crates")
+            .expect("deprecated_crates enabled")
+            .options_as()
+            .expect("typed options");
+        assert_eq!(options.deprecated.len(), 2);
+        assert_eq!(options.deprecated["serde_yaml"], "serde_yml");
+        assert_eq!(options.deprecated["structopt"], "clap");
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.deprecated_crates".options]
+            deprecated = ["serde_yaml"]"#,
+        )
+        .unwrap();
+        let err_msg = resolve_config(cfg, Overrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err_msg.contains("checks.deps.deprecated_crates.options"),
+            "error message should name the options table: {err_msg}"
+        );
+    }
+
+    #[test]
+    fn publish_metadata_require_option_is_resolved_and_validated() {
+        use depguard_domain_core::policy::PublishMetadataOptions;
+
+        let toml = r#"
+            [checks."deps.publish_metadata"]
+            enabled = true
+            options = { require = ["description", "repository", "keywords"] }
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.publish_metadata")
+            .expect("publish_metadata enabled");
+        assert_eq!(
+            policy
+                .options_as::<PublishMetadataOptions>()
+                .unwrap()
+                .require,
+            vec!["description", "repository", "keywords"]
+        );
+
+        let error_for = |toml: &str| {
+            let cfg = parse_config_toml(toml).unwrap();
+            let err = resolve_config(cfg, Overrides::default()).unwrap_err();
+            err.downcast_ref::<ValidationError>()
+                .expect("validation error")
+                .clone()
+        };
+
+        let err = error_for(
+            r#"[checks."deps.publish_metadata"]
+            options = { require = ["readme"] }"#,
+        );
+        assert_eq!(
+            err.key_path(),
+            "checks.deps.publish_metadata.options.require"
+        );
+        assert!(err.message().contains("'readme'"));
+
+        let err = error_for(
+            r#"[checks."deps.publish_metadata"]
+            options = { require = "description" }"#,
+        );
+        assert_eq!(err.key_path(), "checks.deps.publish_metadata.options");
+        assert!(err.message().contains("invalid type"), "{}", err.message());
+
+        let err = error_for(
+            r#"[checks."deps.publish_metadata"]
+            options = { requires = ["description"] }"#,
+        );
+        assert_eq!(err.key_path(), "checks.deps.publish_metadata.options");
+        assert!(err.message().contains("unknown field"), "{}", err.message());
+    }
+
+    #[test]
+    fn banned_features_option_accepts_list_and_reason_forms() {
+        use depguard_domain_core::policy::BannedFeaturesOptions;
+
+        let toml = r#"
+            [checks."deps.banned_features"]
+            enabled = true
+
+            [checks."deps.banned_features".options.features]
+            tokio = ["process"]
+            openssl = { features = ["vendored"], reason = "link the system library" }
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.banned_features")
+            .expect("banned_features enabled");
+        let banned = policy
+            .options_as::<BannedFeaturesOptions>()
+            .unwrap()
+            .features;
+        assert_eq!(banned["tokio"].features, vec!["process"]);
+        assert_eq!(banned["tokio"].reason, None);
+        assert_eq!(banned["openssl"].features, vec!["vendored"]);
+        assert_eq!(
+            banned["openssl"].reason.as_deref(),
+            Some("link the system library")
+        );
+
+        let dump = ConfigDump::from(&resolved);
+        assert_eq!(
+            dump.checks["deps.banned_features"].options["features"]["tokio"],
+            serde_json::json!(["process"])
+        );
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.banned_features".options.features]
+            tokio = "process""#,
+        )
+        .unwrap();
+        let err = resolve_config(cfg, Overrides::default()).unwrap_err();
+        let err = err
+            .downcast_ref::<ValidationError>()
+            .expect("validation error");
+        assert_eq!(err.key_path(), "checks.deps.banned_features.options");
+    }
+
+    #[test]
+    fn lints_configured_require_workspace_must_be_boolean() {
+        use depguard_domain_core::policy::LintsConfiguredOptions;
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.lints_configured"]
+            enabled = true
+            options = { require_workspace = true }"#,
+        )
+        .unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.lints_configured")
+            .expect("lints_configured enabled");
+        assert!(
+            policy
+                .options_as::<LintsConfiguredOptions>()
+                .unwrap()
+                .require_workspace
+        );
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.lints_configured"]
+            enabled = true
+            options = { require_workspace = "yes" }"#,
+        )
+        .unwrap();
+        let err = resolve_config(cfg, Overrides::default()).unwrap_err();
+        let err = err
+            .downcast_ref::<ValidationError>()
+            .expect("validation error");
+        assert_eq!(err.key_path(), "checks.deps.lints_configured.options");
+        assert!(
+            err.message().contains("expected a boolean"),
+            "{}",
+            err.message()
+        );
+    }
+
+    #[test]
+    fn no_std_default_features_requires_a_crate_list() {
+        let error_for = |options: &str| {
+            let cfg = parse_config_toml(&format!(
+                "[checks.\"deps.no_std_default_features\"]\nenabled = true\n{options}"
+            ))
+            .unwrap();
+            let err = resolve_config(cfg, Overrides::default()).unwrap_err();
+            err.downcast_ref::<ValidationError>()
+                .expect("validation error")
+                .key_path()
+                .to_string()
+        };
+
+        assert_eq!(
+            error_for(""),
+            "checks.deps.no_std_default_features.options.no_std_crates"
+        );
+        assert_eq!(
+            error_for("options = { no_std_crates = \"my-crate\" }"),
+            "checks.deps.no_std_default_features.options"
+        );
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.no_std_default_features"]
+            enabled = true
+            options = { no_std_crates = ["my-embedded"] }"#,
+        )
+        .unwrap();
+        assert!(resolve_config(cfg, Overrides::default()).is_ok());
+    }
+
+    #[test]
+    fn check_options_table_is_resolved_and_deserialized() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct MaxOptions {
+            max: u32,
+            #[serde(default)]
+            label: Option<String>,
+        }
+
+        let toml = r#"
+            [checks."deps.no_wildcards"]
+            options = { max = 10 }
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        assert_eq!(
+            cfg.checks["deps.no_wildcards"].options["max"],
+            serde_json::json!(10)
+        );
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.no_wildcards")
+            .expect("no_wildcards enabled in strict");
+        assert_eq!(
+            policy.options_as::<MaxOptions>().unwrap(),
+            MaxOptions {
+                max: 10,
+                label: None
+            }
+        );
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.no_wildcards"]
+            options = { max = "ten" }"#,
+        )
+        .unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.no_wildcards")
+            .expect("no_wildcards enabled in strict");
+        assert!(policy.options_as::<MaxOptions>().is_err());
+    }
+
+    #[test]
+    fn required_check_options_must_be_present() {
+        let options = BTreeMap::from([("max".to_string(), serde_json::json!(10))]);
+        assert!(resolve::validate_required_options("deps.example", &["max"], &options).is_ok());
+
+        let err = resolve::validate_required_options("deps.example", &["max", "mode"], &options)
+            .unwrap_err();
+        assert_eq!(err.key_path(), "checks.deps.example.options.mode");
+        assert!(err.message().contains("requires option 'mode'"));
+    }
+
+    #[test]
+    fn no_multiple_versions_ignore_is_resolved_and_validated() {
+        let toml = r#"
+            [checks."deps.no_multiple_versions"]
+            enabled = true
+            ignore = ["windows-sys", "windows-*"]
+        "#;
+        let cfg = parse_config_toml(toml).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.no_multiple_versions")
+            .expect("no_multiple_versions enabled");
+        assert_eq!(policy.ignore, vec!["windows-sys", "windows-*"]);
+
+        let cfg = parse_config_toml(
+            r#"[checks."deps.consistent_versions"]
+            enabled = true
+            ignore = ["rand"]"#,
+        )
+        .unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let policy = resolved
+            .effective
+            .check_policy("deps.consistent_versions")
+            .expect("consistent_versions enabled");
+        assert_eq!(policy.ignore, vec!["rand"]);
+
+        for toml in [
+            r#"[checks."deps.no_multiple_versions"]
+            ignore = ["["]"#,
+            r#"[checks."deps.no_wildcards"]
+            ignore = ["serde"]"#,
+        ] {
+            let cfg = parse_config_toml(toml).unwrap();
+            let err_msg = resolve_config(cfg, Overrides::default())
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err_msg.contains(".ignore"),
+                "error message should contain ignore key path: {err_msg}"
+            );
+        }
+    }
+
     #[test]
     fn fail_on_config_overrides_profile() {
         let cfg = DepguardConfigV1 {
@@ -495,4 +1108,266 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn validate_config_collects_every_error_and_unknown_check_ids() {
+        let cfg = parse_config_toml(
+            r#"
+            profile = "nope"
+            fail_on = "never"
+
+            [checks."deps.no_wildcard"]
+            severity = "loud"
+
+            [checks."deps.banned_crates"]
+            deny = ["a[b"]
+            "#,
+        )
+        .expect("parse config");
+
+        let validation = validate_config(cfg);
+        let keys: Vec<&str> = validation.errors.iter().map(|e| e.key_path()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "profile",
+                "checks.deps.banned_crates.deny",
+                "checks.deps.no_wildcard.severity",
+                "fail_on",
+            ]
+        );
+        assert_eq!(validation.unknown_check_ids, vec!["deps.no_wildcard"]);
+    }
+
+    #[test]
+    fn validate_config_accepts_valid_config() {
+        let cfg = parse_config_toml(
+            r#"
+            profile = "warn"
+            [checks."deps.no_wildcards"]
+            allow = ["vendor-*"]
+            "#,
+        )
+        .expect("parse config");
+
+        let validation = validate_config(cfg);
+        assert!(validation.errors.is_empty());
+        assert!(validation.unknown_check_ids.is_empty());
+    }
+
+    #[test]
+    fn unknown_check_ids_are_reported_but_not_fatal_by_default() {
+        let cfg = parse_config_toml(
+            r#"
+            [checks."deps.no_wildcard"]
+            enabled = true
+
+            [checks."deps.no_wildcards"]
+            enabled = true
+            "#,
+        )
+        .expect("parse config");
+
+        let resolved = resolve_config(cfg, Overrides::default()).expect("resolve");
+        assert_eq!(resolved.unknown_checks, vec!["deps.no_wildcard"]);
+    }
+
+    #[test]
+    fn strict_unknown_checks_turns_unknown_ids_into_errors() {
+        let cfg = parse_config_toml(
+            r#"
+            strict_unknown_checks = true
+
+            [checks."deps.no_wildcard"]
+            enabled = true
+            "#,
+        )
+        .expect("parse config");
+
+        let err = resolve_config(cfg.clone(), Overrides::default()).expect_err("unknown id");
+        let validation_err = err.downcast_ref::<ValidationError>().expect("validation");
+        assert_eq!(validation_err.key_path(), "checks.deps.no_wildcard");
+        assert!(
+            err.to_string()
+                .contains("unknown check ID: 'deps.no_wildcard'")
+        );
+
+        let validation = validate_config(cfg);
+        assert_eq!(validation.errors.len(), 1);
+        assert!(validation.unknown_check_ids.is_empty());
+    }
+
+    #[test]
+    fn config_dump_reflects_overrides_and_check_options() {
+        let cfg = parse_config_toml(
+            r#"
+            profile = "warn"
+
+            [checks."deps.version_pinning"]
+            enabled = true
+            mode = "require_exact"
+            allow = ["internal-*"]
+            "#,
+        )
+        .expect("parse config");
+        let overrides = Overrides {
+            profile: Some("strict".to_string()),
+            ..Overrides::default()
+        };
+        let resolved = resolve_config(cfg, overrides).expect("resolve");
+
+        let dump = ConfigDump::from(&resolved);
+        assert_eq!(dump.profile, "strict");
+        assert_eq!(dump.scope, "repo");
+        let pinning = &dump.checks["deps.version_pinning"];
+        assert!(pinning.enabled);
+        assert_eq!(pinning.allow, vec!["internal-*"]);
+        assert_eq!(pinning.mode, Some("require_exact"));
+        assert_eq!(dump.checks.len(), resolved.effective.checks.len());
+    }
+
+    const CI_BLOCKING: &str = r#"
+        profile = "ci-blocking"
+
+        [profiles.ci-blocking]
+        extends = "warn"
+        scope = "diff"
+        fail_on = "error"
+
+        [profiles.ci-blocking.checks."deps.no_wildcards"]
+        severity = "error"
+
+        [profiles.ci-blocking.checks."deps.path_safety"]
+        enabled = false
+    "#;
+
+    #[test]
+    fn resolve_custom_profile() {
+        let cfg = parse_config_toml(CI_BLOCKING).unwrap();
+        let resolved = resolve_config(cfg, Overrides::default()).unwrap();
+        let effective = &resolved.effective;
+
+        assert_eq!(effective.profile, "ci-blocking");
+        assert_eq!(effective.scope, Scope::Diff);
+        assert_eq!(effective.fail_on, FailOn::Error);
+        assert_eq!(
+            effective.checks["deps.no_wildcards"].severity,
+            Severity::Error
+        );
+        assert!(!effective.checks["deps.path_safety"].enabled);
+        // Untouched checks keep the base preset's policy.
+        let warn = presets::preset("warn");
+        let (got, base) = (
+            &effective.checks["deps.git_requires_version"],
+            &warn.checks["deps.git_requires_version"],
+        );
+        assert_eq!((got.enabled, got.severity), (base.enabled, base.severity));
+    }
+
+    #[test]
+    fn custom_profile_selected_by_override_and_under_top_level_keys() {
+        let cfg = parse_config_toml(&format!(
+            "{}
+[checks.\"deps.no_wildcards\"]\nseverity = \"info\"\n",
+            CI_BLOCKING.replace("profile = \"ci-blocking\"", "profile = \"strict\"")
+        ))
+        .unwrap();
+        let overrides = Overrides {
+            profile: Some("ci-blocking".to_string()),
+            ..Default::default()
+        };
+        let resolved = resolve_config(cfg, overrides).unwrap();
+
+        assert_eq!(resolved.effective.profile, "ci-blocking");
+        assert_eq!(
+            resolved.effective.checks["deps.no_wildcards"].severity,
+            Severity::Info
+        );
+    }
+
+    #[test]
+    fn undefined_profile_lists_custom_profiles() {
+        let cfg = parse_config_toml(
+            &CI_BLOCKING.replace("profile = \"ci-blocking\"", "profile = \"ci-blockng\""),
+        )
+        .unwrap();
+        let err = resolve_config(cfg, Overrides::default()).unwrap_err();
+        let ve = err.downcast_ref::<ValidationError>().unwrap();
+
+        assert_eq!(ve.key_path(), "profile");
+        assert_eq!(ve.message(), "unknown profile: 'ci-blockng'");
+        assert_eq!(
+            ve.suggestion(),
+            Some("expected 'strict', 'warn', 'compat', or a custom profile: 'ci-blocking'")
+        );
+    }
+
+    #[test]
+    fn custom_profile_errors_are_keyed_under_the_profile() {
+        let cfg = parse_config_toml(
+            r#"
+            [profiles.broken]
+            extends = "lenient"
+            fail_on = "sometimes"
+        "#,
+        )
+        .unwrap();
+        let validation = validate_config(cfg);
+        let keys: Vec<&str> = validation.errors.iter().map(|e| e.key_path()).collect();
+
+        assert_eq!(
+            keys,
+            vec!["profiles.broken.extends", "profiles.broken.fail_on"]
+        );
+    }
+
+    #[test]
+    fn merge_configs_child_wins_and_lists_union() {
+        let base = parse_config_toml(
+            r#"
+            extends = "../org.toml"
+            profile = "warn"
+            fail_on = "warning"
+
+            [checks."deps.no_wildcards"]
+            severity = "warning"
+            allow = ["vendor-*", "legacy"]
+
+            [checks."deps.deprecated_crates".options.deprecated]
+            serde_yaml = "serde_yml"
+            failure = "anyhow"
+        "#,
+        )
+        .unwrap();
+        let child = parse_config_toml(
+            r#"
+            extends = "../base.toml"
+            profile = "strict"
+
+            [checks."deps.no_wildcards"]
+            severity = "error"
+            allow = ["legacy", "local-*"]
+
+            [checks."deps.path_safety"]
+            enabled = false
+
+            [checks."deps.deprecated_crates".options.deprecated]
+            failure = "thiserror"
+        "#,
+        )
+        .unwrap();
+
+        let merged = merge_configs(base, child);
+
+        assert_eq!(merged.extends.as_deref(), Some("../org.toml"));
+        assert_eq!(merged.profile.as_deref(), Some("strict"));
+        assert_eq!(merged.fail_on.as_deref(), Some("warning"));
+        let wildcards = &merged.checks["deps.no_wildcards"];
+        assert_eq!(wildcards.severity.as_deref(), Some("error"));
+        assert_eq!(wildcards.allow, vec!["vendor-*", "legacy", "local-*"]);
+        assert_eq!(merged.checks["deps.path_safety"].enabled, Some(false));
+        let deprecated = &merged.checks["deps.deprecated_crates"].options["deprecated"];
+        assert_eq!(deprecated["serde_yaml"], "serde_yml");
+        assert_eq!(deprecated["failure"], "thiserror");
+    }
 }
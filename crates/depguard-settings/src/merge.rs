@@ -0,0 +1,105 @@
+use crate::model::{CheckConfig, DepguardConfigV1, ProfileConfig};
+use std::collections::BTreeMap;
+
+/// Layer `child` over `base`, as for a config that `extends` another.
+///
+/// Scalar keys set in `child` win. Per-check and per-profile tables are merged
+/// key by key, with `allow`/`deny`/`ignore` lists (and `verdict_ignores`,
+/// `exclude`) taking the union (base entries first) and `options` tables merged
+/// key by key, recursing into nested tables, with child entries winning. The
+/// result's `extends` is the base's, so chains can be folded one level at a time.
+pub fn merge_configs(base: DepguardConfigV1, child: DepguardConfigV1) -> DepguardConfigV1 {
+    DepguardConfigV1 {
+        schema: child.schema.or(base.schema),
+        extends: base.extends,
+        profile: child.profile.or(base.profile),
+        scope: child.scope.or(base.scope),
+        fail_on: child.fail_on.or(base.fail_on),
+        verdict_ignores: union(base.verdict_ignores, child.verdict_ignores),
+        exclude: union(base.exclude, child.exclude),
+        max_findings: child.max_findings.or(base.max_findings),
+        baseline: child.baseline.or(base.baseline),
+        strict_unknown_checks: child.strict_unknown_checks.or(base.strict_unknown_checks),
+        checks: merge_checks(base.checks, child.checks),
+        profiles: merge_tables(base.profiles, child.profiles, merge_profile),
+    }
+}
+
+fn merge_checks(
+    base: BTreeMap<String, CheckConfig>,
+    child: BTreeMap<String, CheckConfig>,
+) -> BTreeMap<String, CheckConfig> {
+    merge_tables(base, child, merge_check)
+}
+
+fn merge_tables<T>(
+    mut base: BTreeMap<String, T>,
+    child: BTreeMap<String, T>,
+    merge: fn(T, T) -> T,
+) -> BTreeMap<String, T> {
+    for (key, value) in child {
+        let merged = match base.remove(&key) {
+            Some(existing) => merge(existing, value),
+            None => value,
+        };
+        base.insert(key, merged);
+    }
+    base
+}
+
+fn merge_profile(base: ProfileConfig, child: ProfileConfig) -> ProfileConfig {
+    ProfileConfig {
+        extends: child.extends.or(base.extends),
+        scope: child.scope.or(base.scope),
+        fail_on: child.fail_on.or(base.fail_on),
+        checks: merge_checks(base.checks, child.checks),
+    }
+}
+
+fn merge_check(base: CheckConfig, child: CheckConfig) -> CheckConfig {
+    CheckConfig {
+        enabled: child.enabled.or(base.enabled),
+        severity: child.severity.or(base.severity),
+        allow: union(base.allow, child.allow),
+        deny: union(base.deny, child.deny),
+        ignore_publish_false: child.ignore_publish_false.or(base.ignore_publish_false),
+        min_rust_version: child.min_rust_version.or(base.min_rust_version),
+        min_edition: child.min_edition.or(base.min_edition),
+        ignore: union(base.ignore, child.ignore),
+        mode: child.mode.or(base.mode),
+        max: child.max.or(base.max),
+        max_dev: child.max_dev.or(base.max_dev),
+        max_build: child.max_build.or(base.max_build),
+        allowed_licenses: union(base.allowed_licenses, child.allowed_licenses),
+        options: merge_options(base.options, child.options),
+    }
+}
+
+fn merge_options(
+    mut base: BTreeMap<String, serde_json::Value>,
+    child: BTreeMap<String, serde_json::Value>,
+) -> BTreeMap<String, serde_json::Value> {
+    for (key, value) in child {
+        let merged = match (base.remove(&key), value) {
+            (Some(serde_json::Value::Object(base)), serde_json::Value::Object(child)) => {
+                serde_json::Value::Object(
+                    merge_options(base.into_iter().collect(), child.into_iter().collect())
+                        .into_iter()
+                        .collect(),
+                )
+            }
+            (_, value) => value,
+        };
+        base.insert(key, merged);
+    }
+    base
+}
+
+fn union(mut base: Vec<String>, child: Vec<String>) -> Vec<String> {
+    for item in child {
+        if !base.contains(&item) {
+            base.push(item);
+        }
+    }
+    base
+}
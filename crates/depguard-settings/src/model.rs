@@ -11,6 +11,11 @@ pub struct DepguardConfigV1 {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
 
+    /// Path of a base config to inherit from, relative to this file. Values set
+    /// here win; per-check `allow`/`deny`/`ignore` lists are merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<String>,
 
@@ -21,6 +26,17 @@ pub struct DepguardConfigV1 {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fail_on: Option<String>,
 
+    /// Severities that never affect the verdict, e.g. `["info"]`. Their findings
+    /// are still reported; `error` cannot be ignored.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub verdict_ignores: Vec<String>,
+
+    /// Manifest globs left out of evaluation in every scope, e.g. `["vendor/**"]`.
+    /// Patterns match the manifest path or its directory; the root manifest is
+    /// always kept.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
     /// How many findings to emit before truncating the list.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_findings: Option<u32>,
@@ -29,9 +45,39 @@ pub struct DepguardConfigV1 {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub baseline: Option<String>,
 
+    /// Treat `[checks."<id>"]` entries with unknown check ids as errors instead of warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_unknown_checks: Option<bool>,
+
     /// Map of check_id -> config.
     #[serde(default)]
     pub checks: BTreeMap<String, CheckConfig>,
+
+    /// Custom profiles, selectable via `profile = "<name>"` or `--profile <name>`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+}
+
+/// A `[profiles.<name>]` table: a named policy bundle layered on a built-in preset.
+///
+/// Top-level keys in the same config still override the profile's values.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileConfig {
+    /// Built-in preset to start from: `strict` (default), `warn`, or `compat`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
+    /// Default scope for this profile: `repo` or `diff`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// When to fail the check under this profile: `error` or `warning`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fail_on: Option<String>,
+
+    /// Per-check overrides applied on top of the base preset.
+    #[serde(default)]
+    pub checks: BTreeMap<String, CheckConfig>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -48,7 +94,54 @@ pub struct CheckConfig {
     #[serde(default)]
     pub allow: Vec<String>,
 
+    /// Generic denylist patterns (semantics are check-specific).
+    #[serde(default)]
+    pub deny: Vec<String>,
+
     /// deps.path_requires_version: ignore publish = false and still enforce.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ignore_publish_false: Option<bool>,
+
+    /// deps.msrv_floor: minimum `rust-version` every package must declare (e.g. `"1.74"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_rust_version: Option<String>,
+
+    /// deps.msrv_floor: minimum `edition` (e.g. `"2021"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_edition: Option<String>,
+
+    /// deps.no_multiple_versions: crates allowed to resolve at several versions in
+    /// `Cargo.lock`; deps.consistent_versions: crates whose requirements may diverge
+    /// across manifests (glob patterns, e.g. `"windows-sys"`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+
+    /// deps.version_pinning: `forbid_exact` or `require_exact`. Unset leaves the
+    /// check inert even when enabled. deps.consistent_msrv: `consistent` (default)
+    /// or `require_inherit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+
+    /// deps.max_direct_deps: maximum `[dependencies]` entries per package. Unset
+    /// leaves the check inert even when enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+
+    /// deps.max_direct_deps: maximum `[dev-dependencies]` entries; unset excludes them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_dev: Option<u32>,
+
+    /// deps.max_direct_deps: maximum `[build-dependencies]` entries; unset excludes them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_build: Option<u32>,
+
+    /// deps.license_present: SPDX license identifiers a package's `license`
+    /// expression must be satisfiable with. Empty only requires license metadata.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_licenses: Vec<String>,
+
+    /// Free-form check-specific options, e.g. `options = { max = 10 }`. Checks
+    /// deserialize their own typed options from this table.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub options: BTreeMap<String, serde_json::Value>,
 }
@@ -22,6 +22,7 @@ fn strict_profile() -> EffectiveConfig {
         max_findings: 200,
         yanked_index: None,
         checks: default_checks("strict"),
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -33,6 +34,7 @@ fn warn_profile() -> EffectiveConfig {
         max_findings: 200,
         yanked_index: None,
         checks: default_checks("warn"),
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -45,6 +47,7 @@ fn compat_profile() -> EffectiveConfig {
         max_findings: 200,
         yanked_index: None,
         checks: default_checks("compat"),
+        verdict_ignores: Vec::new(),
     }
 }
 
@@ -1,7 +1,14 @@
-use crate::{ValidationError, model::DepguardConfigV1, presets};
-use depguard_domain_core::policy::{CheckPolicy, EffectiveConfig, FailOn, Scope};
+use crate::model::{CheckConfig, DepguardConfigV1, ProfileConfig};
+use crate::{ValidationError, ValidationErrors, presets};
+use depguard_check_catalog as check_catalog;
+use depguard_domain_core::model::PUBLISH_METADATA_KEYS;
+use depguard_domain_core::policy::{
+    BannedFeaturesOptions, CheckPolicy, DeprecatedCratesOptions, EffectiveConfig, FailOn,
+    LintsConfiguredOptions, MsrvMode, NoStdOptions, PinningMode, PublishMetadataOptions, Scope,
+};
 use depguard_types::Severity;
 use globset::Glob;
+use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Default)]
 pub struct Overrides {
@@ -15,40 +22,211 @@ pub struct Overrides {
 pub struct ResolvedConfig {
     pub effective: EffectiveConfig,
     pub baseline_path: Option<String>,
+    /// Validated `exclude` globs for manifests to leave out of the workspace model.
+    pub exclude: Vec<String>,
+    /// Configured check ids that match no registered check (likely typos), sorted.
+    pub unknown_checks: Vec<String>,
 }
 
 pub fn resolve_config(
     cfg: DepguardConfigV1,
     overrides: Overrides,
 ) -> anyhow::Result<ResolvedConfig> {
+    let (resolved, errors) = resolve_collecting(cfg, overrides);
+    match errors.into_inner().into_iter().next() {
+        Some(first) => Err(anyhow::Error::new(first)),
+        None => Ok(resolved),
+    }
+}
+
+/// Outcome of validating a config without running checks.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigValidation {
+    /// Every validation error, in config order.
+    pub errors: ValidationErrors,
+    /// Configured check ids that no registered check answers to (likely typos).
+    pub unknown_check_ids: Vec<String>,
+}
+
+pub fn validate_config(cfg: DepguardConfigV1) -> ConfigValidation {
+    let strict_unknown_checks = cfg.strict_unknown_checks.unwrap_or(false);
+    let (resolved, errors) = resolve_collecting(cfg, Overrides::default());
+    ConfigValidation {
+        errors,
+        // Under `strict_unknown_checks` these are already reported as errors.
+        unknown_check_ids: if strict_unknown_checks {
+            Vec::new()
+        } else {
+            resolved.unknown_checks
+        },
+    }
+}
+
+/// Resolve as much of the config as possible, collecting every validation error.
+fn resolve_collecting(
+    cfg: DepguardConfigV1,
+    overrides: Overrides,
+) -> (ResolvedConfig, ValidationErrors) {
+    let mut errors = ValidationErrors::new();
+    let mut check = |result: Result<(), ValidationError>| {
+        if let Err(err) = result {
+            errors.push(err);
+        }
+    };
+
     let profile = overrides
         .profile
         .clone()
         .or(cfg.profile.clone())
         .unwrap_or_else(|| "strict".to_string());
 
-    // Validate profile
-    validate_profile(&profile)?;
+    let strict_unknown_checks = cfg.strict_unknown_checks.unwrap_or(false);
+
+    // Custom profiles are all validated, but only the selected one is used.
+    let mut selected = None;
+    for (name, profile_cfg) in &cfg.profiles {
+        let prefix = format!("profiles.{name}");
+        let mut nested = |result: Result<(), ValidationError>| {
+            check(result.map_err(|err| err.nested_under(&prefix)));
+        };
+        let built = custom_profile(name, profile_cfg, strict_unknown_checks, &mut nested);
+        if *name == profile {
+            selected = Some(built);
+        }
+    }
 
-    let mut effective = presets::preset(&profile);
+    let mut effective = match selected {
+        Some(effective) => effective,
+        None => {
+            check(validate_profile(&profile, &cfg.profiles));
+            presets::preset(&profile)
+        }
+    };
 
     // Scope
     if let Some(scope_s) = overrides.scope.clone().or(cfg.scope.clone()) {
-        effective.scope = parse_scope(&scope_s)?;
+        check(parse_scope(&scope_s).map(|scope| effective.scope = scope));
     }
 
     // max findings
     if let Some(mf) = overrides.max_findings.or(cfg.max_findings) {
         if mf == 0 {
-            return Err(anyhow::Error::new(ValidationError::invalid_max_findings(
-                mf,
-            )));
+            check(Err(ValidationError::invalid_max_findings(mf)));
+        } else {
+            effective.max_findings = mf as usize;
         }
-        effective.max_findings = mf as usize;
     }
 
+    // Unknown check ids are warnings unless `strict_unknown_checks` makes
+    // `apply_check_configs` report them as errors.
+    let mut unknown_checks: Vec<String> = cfg
+        .checks
+        .keys()
+        .chain(cfg.profiles.values().flat_map(|p| p.checks.keys()))
+        .filter(|id| !check_catalog::is_known_check_id(id))
+        .cloned()
+        .collect();
+    unknown_checks.sort();
+    unknown_checks.dedup();
+
     // per-check overrides
-    for (check_id, cc) in cfg.checks.iter() {
+    apply_check_configs(
+        &mut effective,
+        &cfg.checks,
+        strict_unknown_checks,
+        &mut check,
+    );
+
+    for (check_id, policy) in effective.checks.iter().filter(|(_, p)| p.enabled) {
+        check(validate_required_options(
+            check_id,
+            check_catalog::required_options(check_id),
+            &policy.options,
+        ));
+    }
+    for (check_id, policy) in &effective.checks {
+        check(validate_check_options(check_id, policy));
+    }
+
+    // fail_on override from config
+    if let Some(fail_on_s) = cfg.fail_on.as_deref() {
+        check(parse_fail_on(fail_on_s).map(|fail_on| effective.fail_on = fail_on));
+    }
+
+    for value in &cfg.verdict_ignores {
+        check(parse_verdict_ignore(value).map(|severity| {
+            if !effective.verdict_ignores.contains(&severity) {
+                effective.verdict_ignores.push(severity);
+            }
+        }));
+    }
+
+    let mut exclude: Vec<String> = Vec::new();
+    for pattern in &cfg.exclude {
+        check(
+            Glob::new(pattern)
+                .map(|_| {
+                    if !exclude.contains(pattern) {
+                        exclude.push(pattern.clone());
+                    }
+                })
+                .map_err(|e| ValidationError::invalid_exclude_glob(pattern, &e.to_string())),
+        );
+    }
+
+    let baseline_path = overrides.baseline.or(cfg.baseline);
+
+    (
+        ResolvedConfig {
+            effective,
+            baseline_path,
+            exclude,
+            unknown_checks,
+        },
+        errors,
+    )
+}
+
+/// Build a `[profiles.<name>]` table on top of its base preset.
+fn custom_profile(
+    name: &str,
+    profile_cfg: &ProfileConfig,
+    strict_unknown_checks: bool,
+    check: &mut impl FnMut(Result<(), ValidationError>),
+) -> EffectiveConfig {
+    let base = profile_cfg.extends.as_deref().unwrap_or("strict");
+    if !BUILTIN_PROFILES.contains(&base) {
+        check(Err(ValidationError::unknown_base_profile(base)));
+    }
+
+    let mut effective = presets::preset(base);
+    effective.profile = name.to_string();
+    if let Some(scope_s) = profile_cfg.scope.as_deref() {
+        check(parse_scope(scope_s).map(|scope| effective.scope = scope));
+    }
+    if let Some(fail_on_s) = profile_cfg.fail_on.as_deref() {
+        check(parse_fail_on(fail_on_s).map(|fail_on| effective.fail_on = fail_on));
+    }
+    apply_check_configs(
+        &mut effective,
+        &profile_cfg.checks,
+        strict_unknown_checks,
+        check,
+    );
+    effective
+}
+
+/// Layer `[checks."<id>"]` tables onto the effective config.
+fn apply_check_configs(
+    effective: &mut EffectiveConfig,
+    checks: &BTreeMap<String, CheckConfig>,
+    strict_unknown_checks: bool,
+    check: &mut impl FnMut(Result<(), ValidationError>),
+) {
+    for (check_id, cc) in checks {
+        if strict_unknown_checks && !check_catalog::is_known_check_id(check_id) {
+            check(Err(ValidationError::unknown_check_id(check_id)));
+        }
         let entry = effective
             .checks
             .entry(check_id.clone())
@@ -58,79 +236,265 @@ pub fn resolve_config(
             entry.enabled = enabled;
         }
         if let Some(sev) = cc.severity.as_deref() {
-            entry.severity = parse_severity(check_id, sev)?;
+            check(parse_severity(check_id, sev).map(|sev| entry.severity = sev));
         }
         if !cc.allow.is_empty() {
-            validate_allowlist(check_id, &cc.allow)?;
+            check(validate_allowlist(check_id, &cc.allow));
             entry.allow = cc.allow.clone();
         }
+        if !cc.deny.is_empty() {
+            check(validate_denylist(check_id, &cc.deny));
+            entry.deny = cc.deny.clone();
+        }
         if let Some(ignore_publish_false) = cc.ignore_publish_false {
             // ignore_publish_false is only valid for deps.path_requires_version
             if check_id != "deps.path_requires_version" {
-                return Err(anyhow::Error::new(
-                    ValidationError::ignore_publish_false_not_supported(check_id),
-                ));
+                check(Err(ValidationError::ignore_publish_false_not_supported(
+                    check_id,
+                )));
             }
             entry.ignore_publish_false = ignore_publish_false;
         }
+        if let Some(min_rust_version) = cc.min_rust_version.as_deref() {
+            if check_id != "deps.msrv_floor" {
+                check(Err(ValidationError::msrv_option_not_supported(
+                    check_id,
+                    "min_rust_version",
+                )));
+            } else if !is_valid_rust_version(min_rust_version) {
+                check(Err(ValidationError::invalid_rust_version(
+                    check_id,
+                    min_rust_version,
+                )));
+            }
+            entry.min_rust_version = Some(min_rust_version.to_string());
+        }
+        if let Some(min_edition) = cc.min_edition.as_deref() {
+            if check_id != "deps.msrv_floor" {
+                check(Err(ValidationError::msrv_option_not_supported(
+                    check_id,
+                    "min_edition",
+                )));
+            } else if !KNOWN_EDITIONS.contains(&min_edition) {
+                check(Err(ValidationError::invalid_edition(check_id, min_edition)));
+            }
+            entry.min_edition = Some(min_edition.to_string());
+        }
+        if !cc.ignore.is_empty() {
+            if !matches!(
+                check_id.as_str(),
+                "deps.no_multiple_versions" | "deps.consistent_versions"
+            ) {
+                check(Err(ValidationError::ignore_not_supported(check_id)));
+            } else {
+                check(validate_ignorelist(check_id, &cc.ignore));
+            }
+            entry.ignore = cc.ignore.clone();
+        }
+        if let Some(mode) = cc.mode.as_deref() {
+            match check_id.as_str() {
+                "deps.version_pinning" => check(
+                    parse_pinning_mode(check_id, mode).map(|mode| entry.pinning_mode = Some(mode)),
+                ),
+                "deps.consistent_msrv" => {
+                    check(parse_msrv_mode(check_id, mode).map(|mode| entry.msrv_mode = mode))
+                }
+                _ => check(Err(ValidationError::mode_not_supported(check_id))),
+            }
+        }
+        for (option, limit, slot) in [
+            ("max", cc.max, &mut entry.dependency_limits.normal),
+            ("max_dev", cc.max_dev, &mut entry.dependency_limits.dev),
+            (
+                "max_build",
+                cc.max_build,
+                &mut entry.dependency_limits.build,
+            ),
+        ] {
+            let Some(limit) = limit else {
+                continue;
+            };
+            if check_id != "deps.max_direct_deps" {
+                check(Err(ValidationError::limit_option_not_supported(
+                    check_id, option,
+                )));
+            }
+            *slot = Some(limit);
+        }
+        if !cc.allowed_licenses.is_empty() {
+            if check_id != "deps.license_present" {
+                check(Err(ValidationError::allowed_licenses_not_supported(
+                    check_id,
+                )));
+            }
+            entry.allowed_licenses = cc.allowed_licenses.clone();
+        }
+        entry
+            .options
+            .extend(cc.options.iter().map(|(k, v)| (k.clone(), v.clone())));
     }
+}
 
-    // fail_on override from config
-    if let Some(fail_on_s) = cfg.fail_on.as_deref() {
-        effective.fail_on = parse_fail_on(fail_on_s)?;
+/// Every key a check declares in its catalog `required_options` must be set.
+pub(crate) fn validate_required_options(
+    check_id: &str,
+    required: &[&str],
+    options: &BTreeMap<String, serde_json::Value>,
+) -> Result<(), ValidationError> {
+    match required.iter().find(|key| !options.contains_key(**key)) {
+        Some(key) => Err(ValidationError::missing_check_option(check_id, key)),
+        None => Ok(()),
     }
+}
 
-    let baseline_path = overrides.baseline.or(cfg.baseline);
+/// Deserialize the `options` of checks that read typed options, so a wrong type
+/// or unknown key fails resolution instead of silently changing the check.
+pub(crate) fn validate_check_options(
+    check_id: &str,
+    policy: &CheckPolicy,
+) -> Result<(), ValidationError> {
+    match check_id {
+        "deps.publish_metadata" => {
+            let options: PublishMetadataOptions = typed_options(check_id, policy)?;
+            match options
+                .require
+                .iter()
+                .find(|key| !PUBLISH_METADATA_KEYS.contains(&key.as_str()))
+            {
+                Some(key) => Err(ValidationError::invalid_publish_metadata_key(
+                    check_id,
+                    key,
+                    PUBLISH_METADATA_KEYS,
+                )),
+                None => Ok(()),
+            }
+        }
+        "deps.deprecated_crates" => {
+            typed_options::<DeprecatedCratesOptions>(check_id, policy).map(|_| ())
+        }
+        "deps.banned_features" => {
+            typed_options::<BannedFeaturesOptions>(check_id, policy).map(|_| ())
+        }
+        "deps.lints_configured" => {
+            typed_options::<LintsConfiguredOptions>(check_id, policy).map(|_| ())
+        }
+        "deps.no_std_default_features" => {
+            typed_options::<NoStdOptions>(check_id, policy).map(|_| ())
+        }
+        _ => Ok(()),
+    }
+}
 
-    Ok(ResolvedConfig {
-        effective,
-        baseline_path,
-    })
+fn typed_options<T: serde::de::DeserializeOwned>(
+    check_id: &str,
+    policy: &CheckPolicy,
+) -> Result<T, ValidationError> {
+    policy
+        .options_as()
+        .map_err(|e| ValidationError::invalid_check_options(check_id, &e.to_string()))
 }
 
-fn validate_allowlist(check_id: &str, patterns: &[String]) -> anyhow::Result<()> {
+fn validate_allowlist(check_id: &str, patterns: &[String]) -> Result<(), ValidationError> {
     for pattern in patterns {
-        Glob::new(pattern).map_err(|e| {
-            anyhow::Error::new(ValidationError::invalid_allow_glob(
-                check_id,
-                pattern,
-                &e.to_string(),
-            ))
-        })?;
+        Glob::new(pattern)
+            .map_err(|e| ValidationError::invalid_allow_glob(check_id, pattern, &e.to_string()))?;
     }
     Ok(())
 }
 
-fn validate_profile(profile: &str) -> anyhow::Result<()> {
-    match profile {
-        "strict" | "warn" | "team" | "compat" | "oss" => Ok(()),
-        other => Err(anyhow::Error::new(ValidationError::unknown_profile(other))),
+fn validate_denylist(check_id: &str, patterns: &[String]) -> Result<(), ValidationError> {
+    for pattern in patterns {
+        Glob::new(pattern)
+            .map_err(|e| ValidationError::invalid_deny_glob(check_id, pattern, &e.to_string()))?;
     }
+    Ok(())
 }
 
-fn parse_scope(v: &str) -> anyhow::Result<Scope> {
+fn validate_ignorelist(check_id: &str, patterns: &[String]) -> Result<(), ValidationError> {
+    for pattern in patterns {
+        Glob::new(pattern)
+            .map_err(|e| ValidationError::invalid_ignore_glob(check_id, pattern, &e.to_string()))?;
+    }
+    Ok(())
+}
+
+fn parse_pinning_mode(check_id: &str, value: &str) -> Result<PinningMode, ValidationError> {
+    match value {
+        "forbid_exact" => Ok(PinningMode::ForbidExact),
+        "require_exact" => Ok(PinningMode::RequireExact),
+        other => Err(ValidationError::invalid_pinning_mode(check_id, other)),
+    }
+}
+
+fn parse_msrv_mode(check_id: &str, value: &str) -> Result<MsrvMode, ValidationError> {
+    match value {
+        "consistent" => Ok(MsrvMode::Consistent),
+        "require_inherit" => Ok(MsrvMode::RequireInherit),
+        other => Err(ValidationError::invalid_msrv_mode(check_id, other)),
+    }
+}
+
+const KNOWN_EDITIONS: &[&str] = &["2015", "2018", "2021", "2024"];
+
+/// `MAJOR.MINOR` or `MAJOR.MINOR.PATCH`, as accepted by Cargo's `rust-version`.
+fn is_valid_rust_version(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('.').collect();
+    (2..=3).contains(&parts.len())
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Built-in preset names, including the `team` and `oss` aliases.
+const BUILTIN_PROFILES: &[&str] = &["strict", "warn", "team", "compat", "oss"];
+
+fn validate_profile(
+    profile: &str,
+    custom: &BTreeMap<String, ProfileConfig>,
+) -> Result<(), ValidationError> {
+    if BUILTIN_PROFILES.contains(&profile) {
+        return Ok(());
+    }
+    let err = ValidationError::unknown_profile(profile);
+    if custom.is_empty() {
+        return Err(err);
+    }
+    let names: Vec<String> = custom.keys().map(|n| format!("'{n}'")).collect();
+    Err(err.with_suggestion(format!(
+        "expected 'strict', 'warn', 'compat', or a custom profile: {}",
+        names.join(", ")
+    )))
+}
+
+fn parse_scope(v: &str) -> Result<Scope, ValidationError> {
     match v {
         "repo" => Ok(Scope::Repo),
         "diff" => Ok(Scope::Diff),
-        other => Err(anyhow::Error::new(ValidationError::unknown_scope(other))),
+        other => Err(ValidationError::unknown_scope(other)),
     }
 }
 
-fn parse_severity(check_id: &str, v: &str) -> anyhow::Result<Severity> {
+fn parse_severity(check_id: &str, v: &str) -> Result<Severity, ValidationError> {
     match v {
         "info" => Ok(Severity::Info),
         "warning" | "warn" => Ok(Severity::Warning),
         "error" => Ok(Severity::Error),
-        other => Err(anyhow::Error::new(ValidationError::unknown_severity(
-            check_id, other,
-        ))),
+        other => Err(ValidationError::unknown_severity(check_id, other)),
+    }
+}
+
+fn parse_verdict_ignore(v: &str) -> Result<Severity, ValidationError> {
+    match v {
+        "info" => Ok(Severity::Info),
+        "warning" | "warn" => Ok(Severity::Warning),
+        other => Err(ValidationError::invalid_verdict_ignore(other)),
     }
 }
 
-fn parse_fail_on(v: &str) -> anyhow::Result<FailOn> {
+fn parse_fail_on(v: &str) -> Result<FailOn, ValidationError> {
     match v {
         "error" => Ok(FailOn::Error),
         "warning" | "warn" => Ok(FailOn::Warning),
-        other => Err(anyhow::Error::new(ValidationError::unknown_fail_on(other))),
+        other => Err(ValidationError::unknown_fail_on(other)),
     }
 }
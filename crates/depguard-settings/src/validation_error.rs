@@ -52,6 +52,13 @@ impl ValidationError {
         self
     }
 
+    /// Prefix the key path with the table the error was found in
+    /// (e.g. `profiles.ci-blocking`).
+    pub fn nested_under(mut self, prefix: &str) -> Self {
+        self.key_path = format!("{prefix}.{}", self.key_path);
+        self
+    }
+
     /// Get the config key path.
     pub fn key_path(&self) -> &str {
         &self.key_path
@@ -98,12 +105,29 @@ impl ValidationError {
             .with_suggestion("expected 'error' or 'warning'")
     }
 
+    /// Create a validation error for a `verdict_ignores` entry that is not `info` or `warning`.
+    pub fn invalid_verdict_ignore(value: &str) -> Self {
+        Self::new(
+            "verdict_ignores",
+            format!("invalid verdict_ignores entry: '{value}'"),
+        )
+        .with_suggestion(
+            "expected 'info' or 'warning'; error findings always count toward the verdict",
+        )
+    }
+
     /// Create a validation error for an unknown profile value.
     pub fn unknown_profile(value: &str) -> Self {
         Self::new("profile", format!("unknown profile: '{value}'"))
             .with_suggestion("expected 'strict', 'warn', or 'compat'")
     }
 
+    /// Create a validation error for a custom profile extending an unknown preset.
+    pub fn unknown_base_profile(value: &str) -> Self {
+        Self::new("extends", format!("unknown base profile: '{value}'"))
+            .with_suggestion("custom profiles extend 'strict', 'warn', or 'compat'")
+    }
+
     /// Create a validation error for an invalid glob pattern in an allowlist.
     pub fn invalid_allow_glob(check_id: &str, pattern: &str, error: &str) -> Self {
         Self::new(
@@ -112,13 +136,37 @@ impl ValidationError {
         )
     }
 
+    /// Create a validation error for an invalid glob pattern in an ignore list.
+    pub fn invalid_ignore_glob(check_id: &str, pattern: &str, error: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.ignore"),
+            format!("invalid glob pattern '{pattern}': {error}"),
+        )
+    }
+
+    /// Create a validation error for an invalid glob pattern in a denylist.
+    pub fn invalid_deny_glob(check_id: &str, pattern: &str, error: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.deny"),
+            format!("invalid glob pattern '{pattern}': {error}"),
+        )
+    }
+
+    /// Create a validation error for an invalid glob pattern in the top-level `exclude` list.
+    pub fn invalid_exclude_glob(pattern: &str, error: &str) -> Self {
+        Self::new(
+            "exclude",
+            format!("invalid glob pattern '{pattern}': {error}"),
+        )
+    }
+
     /// Create a validation error for an unknown check ID.
     pub fn unknown_check_id(check_id: &str) -> Self {
         Self::new(
             format!("checks.{check_id}"),
             format!("unknown check ID: '{check_id}'"),
         )
-        .with_suggestion("run 'depguard explain' to see available checks")
+        .with_suggestion("run 'depguard list-checks' to see available checks")
     }
 
     /// Create a validation error for an invalid max_findings value.
@@ -139,6 +187,120 @@ impl ValidationError {
         .with_suggestion("this option is only valid for 'deps.path_requires_version' check")
     }
 
+    /// Create a validation error for `ignore` on an unsupported check.
+    pub fn ignore_not_supported(check_id: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.ignore"),
+            format!("ignore is not supported for check '{check_id}'"),
+        )
+        .with_suggestion(
+            "this option is only valid for 'deps.no_multiple_versions' and 'deps.consistent_versions' checks",
+        )
+    }
+
+    /// Create a validation error for a deps.msrv_floor option used on another check.
+    pub fn msrv_option_not_supported(check_id: &str, option: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.{option}"),
+            format!("{option} is not supported for check '{check_id}'"),
+        )
+        .with_suggestion("this option is only valid for 'deps.msrv_floor' check")
+    }
+
+    /// Create a validation error for a malformed minimum rust-version.
+    pub fn invalid_rust_version(check_id: &str, value: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.min_rust_version"),
+            format!("invalid rust-version: '{value}'"),
+        )
+        .with_suggestion("expected MAJOR.MINOR or MAJOR.MINOR.PATCH (e.g. \"1.74\")")
+    }
+
+    /// Create a validation error for an unknown minimum edition.
+    pub fn invalid_edition(check_id: &str, value: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.min_edition"),
+            format!("invalid edition: '{value}'"),
+        )
+        .with_suggestion("valid editions: 2015, 2018, 2021, 2024")
+    }
+
+    /// Create a validation error for `mode` on an unsupported check.
+    pub fn mode_not_supported(check_id: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.mode"),
+            format!("mode is not supported for check '{check_id}'"),
+        )
+        .with_suggestion(
+            "this option is only valid for 'deps.version_pinning' and 'deps.consistent_msrv' checks",
+        )
+    }
+
+    /// Create a validation error for an unknown deps.version_pinning mode.
+    pub fn invalid_pinning_mode(check_id: &str, value: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.mode"),
+            format!("invalid mode: '{value}'"),
+        )
+        .with_suggestion("valid modes: forbid_exact, require_exact")
+    }
+
+    /// Create a validation error for an unknown deps.consistent_msrv mode.
+    pub fn invalid_msrv_mode(check_id: &str, value: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.mode"),
+            format!("invalid mode: '{value}'"),
+        )
+        .with_suggestion("valid modes: consistent, require_inherit")
+    }
+
+    /// Create a validation error for a deps.max_direct_deps limit used on another check.
+    pub fn limit_option_not_supported(check_id: &str, option: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.{option}"),
+            format!("{option} is not supported for check '{check_id}'"),
+        )
+        .with_suggestion("this option is only valid for 'deps.max_direct_deps' check")
+    }
+
+    /// Create a validation error for `allowed_licenses` on an unsupported check.
+    pub fn allowed_licenses_not_supported(check_id: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.allowed_licenses"),
+            format!("allowed_licenses is not supported for check '{check_id}'"),
+        )
+        .with_suggestion("this option is only valid for 'deps.license_present' check")
+    }
+
+    /// Create a validation error for an unknown `[package]` key in `options.require`.
+    pub fn invalid_publish_metadata_key(check_id: &str, value: &str, expected: &[&str]) -> Self {
+        Self::invalid_enum_value(
+            &format!("checks.{check_id}.options.require"),
+            value,
+            expected,
+        )
+    }
+
+    /// Create a validation error for an `options` table that does not match the
+    /// check's typed options.
+    pub fn invalid_check_options(check_id: &str, error: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.options"),
+            format!("invalid options for check '{check_id}': {error}"),
+        )
+    }
+
+    /// Create a validation error for a required check option that is not set.
+    pub fn missing_check_option(check_id: &str, option: &str) -> Self {
+        Self::new(
+            format!("checks.{check_id}.options.{option}"),
+            format!("check '{check_id}' requires option '{option}'"),
+        )
+        .with_suggestion(format!(
+            "set it under [checks.\"{check_id}\".options] or disable the check"
+        ))
+    }
+
     /// Create a validation error for an invalid boolean value.
     pub fn invalid_boolean(key_path: &str, value: &str) -> Self {
         Self::new(key_path, format!("invalid boolean value: '{value}'"))
@@ -349,6 +511,61 @@ mod tests {
         assert!(err.message().contains("unclosed bracket"));
     }
 
+    #[test]
+    fn invalid_deny_glob_factory() {
+        let err = ValidationError::invalid_deny_glob("deps.banned_crates", "[", "unclosed bracket");
+        assert_eq!(err.key_path(), "checks.deps.banned_crates.deny");
+        assert!(err.message().contains("unclosed bracket"));
+    }
+
+    #[test]
+    fn ignore_option_factories() {
+        let err = ValidationError::ignore_not_supported("deps.no_wildcards");
+        assert_eq!(err.key_path(), "checks.deps.no_wildcards.ignore");
+        assert!(
+            err.suggestion()
+                .unwrap()
+                .contains("deps.no_multiple_versions")
+        );
+
+        let err =
+            ValidationError::invalid_ignore_glob("deps.no_multiple_versions", "[", "unclosed");
+        assert_eq!(err.key_path(), "checks.deps.no_multiple_versions.ignore");
+    }
+
+    #[test]
+    fn msrv_floor_option_factories() {
+        let err = ValidationError::msrv_option_not_supported("deps.no_wildcards", "min_edition");
+        assert_eq!(err.key_path(), "checks.deps.no_wildcards.min_edition");
+        assert!(err.suggestion().unwrap().contains("deps.msrv_floor"));
+
+        let err = ValidationError::invalid_rust_version("deps.msrv_floor", "1.x");
+        assert_eq!(err.key_path(), "checks.deps.msrv_floor.min_rust_version");
+
+        let err = ValidationError::invalid_edition("deps.msrv_floor", "2020");
+        assert_eq!(err.key_path(), "checks.deps.msrv_floor.min_edition");
+        assert!(err.message().contains("2020"));
+    }
+
+    #[test]
+    fn version_pinning_mode_factories() {
+        let err = ValidationError::mode_not_supported("deps.no_wildcards");
+        assert_eq!(err.key_path(), "checks.deps.no_wildcards.mode");
+        assert!(err.suggestion().unwrap().contains("deps.version_pinning"));
+
+        let err = ValidationError::invalid_pinning_mode("deps.version_pinning", "loose");
+        assert_eq!(err.key_path(), "checks.deps.version_pinning.mode");
+        assert!(err.message().contains("loose"));
+    }
+
+    #[test]
+    fn max_direct_deps_limit_factories() {
+        let err = ValidationError::limit_option_not_supported("deps.no_wildcards", "max_dev");
+        assert_eq!(err.key_path(), "checks.deps.no_wildcards.max_dev");
+        assert!(err.message().contains("max_dev"));
+        assert!(err.suggestion().unwrap().contains("deps.max_direct_deps"));
+    }
+
     #[test]
     fn validation_errors_collection() {
         let mut errors = ValidationErrors::new();
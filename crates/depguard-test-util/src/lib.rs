@@ -18,6 +18,9 @@ pub use uselesskey;
 ///    `schema`, `tool`, `run`, `verdict`, `findings`).  This prevents
 ///    false normalization of nested objects that happen to share the same
 ///    shape (e.g. a finding `data` payload containing envelope-like keys).
+///    The same envelope check gates removal of `run.git`, `run.ci` and
+///    `run.capabilities.provenance`, which describe the checkout and CI
+///    runner rather than the analysis.
 ///
 /// 2. **Recursive** — timestamp keys (`started_at`, `finished_at`,
 ///    `ended_at`) and `duration_ms` are normalized at any depth because
@@ -41,6 +44,13 @@ pub fn normalize_nondeterministic(mut value: Value) -> Value {
                 Value::String("__VERSION__".to_string()),
             );
         }
+        if is_envelope && let Some(run) = obj.get_mut("run").and_then(Value::as_object_mut) {
+            run.remove("git");
+            run.remove("ci");
+            if let Some(caps) = run.get_mut("capabilities").and_then(Value::as_object_mut) {
+                caps.remove("provenance");
+            }
+        }
     }
     // Recursive: timestamps and duration at any depth
     normalize_timestamps_recursive(&mut value);
@@ -223,6 +233,34 @@ mod tests {
         assert_eq!(normalized, non_object);
     }
 
+    #[test]
+    fn normalize_drops_run_provenance_from_envelope() {
+        let input = json!({
+            "schema": "urn:effortless:sensor.report.v1",
+            "tool": { "name": "depguard", "version": "0.1.0" },
+            "run": {
+                "started_at": "2025-01-01T00:00:00Z",
+                "git": { "head_sha": "0123abcd" },
+                "ci": { "provider": "github-actions" },
+                "capabilities": {
+                    "config": { "status": "available" },
+                    "provenance": { "status": "available" }
+                }
+            },
+            "verdict": { "pass": true },
+            "findings": []
+        });
+
+        let result = normalize_nondeterministic(input);
+        assert!(result["run"].get("git").is_none());
+        assert!(result["run"].get("ci").is_none());
+        assert!(result["run"]["capabilities"].get("provenance").is_none());
+        assert_eq!(
+            result["run"]["capabilities"]["config"]["status"],
+            "available"
+        );
+    }
+
     #[cfg(feature = "crypto-fixtures")]
     #[test]
     fn crypto_fixture_factory_scopes_are_stable() {
@@ -3,9 +3,13 @@
 //! Maps check IDs and codes to human-readable explanations with remediation guidance.
 
 use crate::ids;
+use serde::Serialize;
+
+/// Schema identifier for `depguard explain --json` output.
+pub const SCHEMA_EXPLAIN_V1: &str = "depguard.explain.v1";
 
 /// Explanation entry for a check or code.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Explanation {
     /// Short description of the check/code.
     pub title: &'static str,
@@ -18,7 +22,7 @@ pub struct Explanation {
 }
 
 /// Before and after code examples.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExamplePair {
     /// Code that would trigger a finding.
     pub before: &'static str,
@@ -43,6 +47,47 @@ pub fn lookup_explanation(identifier: &str) -> Option<Explanation> {
         ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS => Some(explain_no_multiple_versions()),
         ids::CHECK_DEPS_OPTIONAL_UNUSED => Some(explain_optional_unused()),
         ids::CHECK_DEPS_YANKED_VERSIONS => Some(explain_yanked_versions()),
+        ids::CHECK_DEPS_BANNED_CRATES => Some(explain_banned_crates()),
+        ids::CHECK_DEPS_ALLOWED_REGISTRIES => Some(explain_allowed_registries()),
+        ids::CHECK_DEPS_GIT_PINNED => Some(explain_git_pinned()),
+        ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS => Some(explain_duplicate_across_kinds()),
+        ids::CHECK_DEPS_SORTED => Some(explain_sorted()),
+        ids::CHECK_DEPS_MSRV_FLOOR => Some(explain_msrv_floor()),
+        ids::CHECK_DEPS_NO_PATCH_OVERRIDE => Some(explain_no_patch_override()),
+        ids::CHECK_DEPS_NO_DEFAULT_FEATURES => Some(explain_no_default_features()),
+        ids::CHECK_DEPS_NO_PRERELEASE => Some(explain_no_prerelease()),
+        ids::CHECK_DEPS_VERSION_PINNING => Some(explain_version_pinning()),
+        ids::CHECK_DEPS_DEV_DEP_LEAKAGE => Some(explain_dev_dep_leakage()),
+        ids::CHECK_DEPS_CRATE_DIR_NAME => Some(explain_crate_dir_name()),
+        ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE => Some(explain_workspace_version_override()),
+        ids::CHECK_DEPS_RESTRICT_BUILD_DEPS => Some(explain_restrict_build_deps()),
+        ids::CHECK_DEPS_VERSION_REQUIRED => Some(explain_version_required()),
+        ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES => Some(explain_misleading_empty_features()),
+        ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE => Some(explain_explicit_dep_feature()),
+        ids::CHECK_DEPS_MAX_DIRECT_DEPS => Some(explain_max_direct_deps()),
+        ids::CHECK_DEPS_NO_OPEN_RANGES => Some(explain_no_open_ranges()),
+        ids::CHECK_DEPS_PUBLISH_REACHABILITY => Some(explain_publish_reachability()),
+        ids::CHECK_DEPS_SECURE_GIT_URL => Some(explain_secure_git_url()),
+        ids::CHECK_DEPS_VALID_TARGET_CFG => Some(explain_valid_target_cfg()),
+        ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE => Some(explain_no_zerover_in_stable()),
+        ids::CHECK_DEPS_LICENSE_PRESENT => Some(explain_license_present()),
+        ids::CHECK_DEPS_NO_RENAME => Some(explain_no_rename()),
+        ids::CHECK_DEPS_CONSISTENT_VERSIONS => Some(explain_consistent_versions()),
+        ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS => Some(explain_no_optional_dev_deps()),
+        ids::CHECK_DEPS_GIT_VERSION_CONFLICT => Some(explain_git_version_conflict()),
+        ids::CHECK_DEPS_CONSISTENT_MSRV => Some(explain_consistent_msrv()),
+        ids::CHECK_DEPS_LOCK_IN_SYNC => Some(explain_lock_in_sync()),
+        ids::CHECK_DEPS_MEMBERS_EXIST => Some(explain_members_exist()),
+        ids::CHECK_DEPS_NO_NESTED_WORKSPACE => Some(explain_no_nested_workspace()),
+        ids::CHECK_DEPS_DEPRECATED_CRATES => Some(explain_deprecated_crates()),
+        ids::CHECK_DEPS_PUBLISH_METADATA => Some(explain_publish_metadata()),
+        ids::CHECK_DEPS_BANNED_FEATURES => Some(explain_banned_features()),
+        ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF => Some(explain_features_after_default_off()),
+        ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE => Some(explain_path_within_workspace()),
+        ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY => Some(explain_build_script_consistency()),
+        ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES => Some(explain_no_dependency_cycles()),
+        ids::CHECK_DEPS_LINTS_CONFIGURED => Some(explain_lints_configured()),
+        ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES => Some(explain_no_std_default_features()),
         ids::CHECK_TOOL_RUNTIME => Some(explain_tool_runtime()),
 
         // Codes
@@ -55,8 +100,64 @@ pub fn lookup_explanation(identifier: &str) -> Option<Explanation> {
         ids::CODE_DEV_DEP_IN_NORMAL => Some(explain_dev_dep_in_normal()),
         ids::CODE_DEFAULT_FEATURES_IMPLICIT => Some(explain_default_features_implicit()),
         ids::CODE_DUPLICATE_DIFFERENT_VERSIONS => Some(explain_duplicate_different_versions()),
+        ids::CODE_MULTIPLE_VERSIONS => Some(explain_multiple_versions()),
         ids::CODE_OPTIONAL_NOT_IN_FEATURES => Some(explain_optional_not_in_features()),
+        ids::CODE_OPTIONAL_NEVER_ENABLED => Some(explain_optional_never_enabled()),
         ids::CODE_VERSION_YANKED => Some(explain_version_yanked()),
+        ids::CODE_BANNED_CRATE => Some(explain_banned_crate()),
+        ids::CODE_DISALLOWED_REGISTRY => Some(explain_disallowed_registry()),
+        ids::CODE_GIT_BRANCH_UNPINNED => Some(explain_git_branch_unpinned()),
+        ids::CODE_DUPLICATE_DEPENDENCY_KINDS => Some(explain_duplicate_dependency_kinds()),
+        ids::CODE_DEPS_UNSORTED => Some(explain_deps_unsorted()),
+        ids::CODE_MSRV_BELOW_FLOOR => Some(explain_msrv_below_floor()),
+        ids::CODE_EDITION_BELOW_FLOOR => Some(explain_edition_below_floor()),
+        ids::CODE_MISSING_RUST_VERSION => Some(explain_missing_rust_version()),
+        ids::CODE_PATCH_SECTION_PRESENT => Some(explain_patch_section_present()),
+        ids::CODE_REPLACE_SECTION_PRESENT => Some(explain_replace_section_present()),
+        ids::CODE_DEFAULT_FEATURES_ENABLED => Some(explain_default_features_enabled()),
+        ids::CODE_PRERELEASE_VERSION => Some(explain_prerelease_version()),
+        ids::CODE_EXACT_PIN_FORBIDDEN => Some(explain_exact_pin_forbidden()),
+        ids::CODE_EXACT_PIN_REQUIRED => Some(explain_exact_pin_required()),
+        ids::CODE_DEV_DEP_USED_IN_SRC => Some(explain_dev_dep_used_in_src()),
+        ids::CODE_CRATE_NAME_DIR_MISMATCH => Some(explain_crate_name_dir_mismatch()),
+        ids::CODE_SHOULD_INHERIT_WORKSPACE_DEP => Some(explain_should_inherit_workspace_dep()),
+        ids::CODE_BUILD_DEPENDENCY_PRESENT => Some(explain_build_dependency_present()),
+        ids::CODE_DEPENDENCY_WITHOUT_VERSION => Some(explain_dependency_without_version()),
+        ids::CODE_EMPTY_FEATURES_WITH_DEFAULTS => Some(explain_empty_features_with_defaults()),
+        ids::CODE_IMPLICIT_OPTIONAL_FEATURE => Some(explain_implicit_optional_feature()),
+        ids::CODE_TOO_MANY_DEPENDENCIES => Some(explain_too_many_dependencies()),
+        ids::CODE_OPEN_ENDED_RANGE => Some(explain_open_ended_range()),
+        ids::CODE_DEPENDS_ON_UNPUBLISHABLE => Some(explain_depends_on_unpublishable()),
+        ids::CODE_INSECURE_GIT_URL => Some(explain_insecure_git_url()),
+        ids::CODE_INVALID_TARGET_CFG => Some(explain_invalid_target_cfg()),
+        ids::CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE => {
+            Some(explain_zerover_dependency_in_stable_crate())
+        }
+        ids::CODE_MISSING_LICENSE => Some(explain_missing_license()),
+        ids::CODE_DISALLOWED_LICENSE => Some(explain_disallowed_license()),
+        ids::CODE_DEPENDENCY_RENAMED => Some(explain_dependency_renamed()),
+        ids::CODE_DIVERGENT_VERSION_REQUIREMENTS => Some(explain_divergent_version_requirements()),
+        ids::CODE_OPTIONAL_DEV_DEPENDENCY => Some(explain_optional_dev_dependency()),
+        ids::CODE_GIT_WITH_VERSION => Some(explain_git_with_version()),
+        ids::CODE_INCONSISTENT_MSRV => Some(explain_inconsistent_msrv()),
+        ids::CODE_MSRV_NOT_INHERITED => Some(explain_msrv_not_inherited()),
+        ids::CODE_DEPENDENCY_MISSING_FROM_LOCK => Some(explain_dependency_missing_from_lock()),
+        ids::CODE_WORKSPACE_MEMBER_NOT_FOUND => Some(explain_workspace_member_not_found()),
+        ids::CODE_NESTED_WORKSPACE_DETECTED => Some(explain_nested_workspace_detected()),
+        ids::CODE_DEPRECATED_CRATE => Some(explain_deprecated_crate()),
+        ids::CODE_MISSING_PUBLISH_METADATA => Some(explain_missing_publish_metadata()),
+        ids::CODE_BANNED_FEATURE_ENABLED => Some(explain_banned_feature_enabled()),
+        ids::CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED => {
+            Some(explain_no_features_after_default_disabled())
+        }
+        ids::CODE_PATH_OUTSIDE_WORKSPACE => Some(explain_path_outside_workspace()),
+        ids::CODE_BUILD_SCRIPT_WITHOUT_DEPS => Some(explain_build_script_without_deps()),
+        ids::CODE_BUILD_DEPS_WITHOUT_SCRIPT => Some(explain_build_deps_without_script()),
+        ids::CODE_DEPENDENCY_CYCLE => Some(explain_dependency_cycle()),
+        ids::CODE_SELF_DEPENDENCY => Some(explain_self_dependency()),
+        ids::CODE_MISSING_LINTS_TABLE => Some(explain_missing_lints_table()),
+        ids::CODE_LINTS_NOT_INHERITED => Some(explain_lints_not_inherited()),
+        ids::CODE_DEFAULT_FEATURES_IN_NO_STD => Some(explain_default_features_in_no_std()),
         ids::CODE_RUNTIME_ERROR => Some(explain_runtime_error()),
 
         _ => None,
@@ -76,6 +177,47 @@ pub fn all_check_ids() -> &'static [&'static str] {
         ids::CHECK_DEPS_NO_MULTIPLE_VERSIONS,
         ids::CHECK_DEPS_OPTIONAL_UNUSED,
         ids::CHECK_DEPS_YANKED_VERSIONS,
+        ids::CHECK_DEPS_BANNED_CRATES,
+        ids::CHECK_DEPS_ALLOWED_REGISTRIES,
+        ids::CHECK_DEPS_GIT_PINNED,
+        ids::CHECK_DEPS_DUPLICATE_ACROSS_KINDS,
+        ids::CHECK_DEPS_SORTED,
+        ids::CHECK_DEPS_MSRV_FLOOR,
+        ids::CHECK_DEPS_NO_PATCH_OVERRIDE,
+        ids::CHECK_DEPS_NO_DEFAULT_FEATURES,
+        ids::CHECK_DEPS_NO_PRERELEASE,
+        ids::CHECK_DEPS_VERSION_PINNING,
+        ids::CHECK_DEPS_DEV_DEP_LEAKAGE,
+        ids::CHECK_DEPS_CRATE_DIR_NAME,
+        ids::CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+        ids::CHECK_DEPS_RESTRICT_BUILD_DEPS,
+        ids::CHECK_DEPS_VERSION_REQUIRED,
+        ids::CHECK_DEPS_MISLEADING_EMPTY_FEATURES,
+        ids::CHECK_DEPS_EXPLICIT_DEP_FEATURE,
+        ids::CHECK_DEPS_MAX_DIRECT_DEPS,
+        ids::CHECK_DEPS_NO_OPEN_RANGES,
+        ids::CHECK_DEPS_PUBLISH_REACHABILITY,
+        ids::CHECK_DEPS_SECURE_GIT_URL,
+        ids::CHECK_DEPS_VALID_TARGET_CFG,
+        ids::CHECK_DEPS_NO_ZEROVER_IN_STABLE,
+        ids::CHECK_DEPS_LICENSE_PRESENT,
+        ids::CHECK_DEPS_NO_RENAME,
+        ids::CHECK_DEPS_CONSISTENT_VERSIONS,
+        ids::CHECK_DEPS_NO_OPTIONAL_DEV_DEPS,
+        ids::CHECK_DEPS_GIT_VERSION_CONFLICT,
+        ids::CHECK_DEPS_CONSISTENT_MSRV,
+        ids::CHECK_DEPS_LOCK_IN_SYNC,
+        ids::CHECK_DEPS_MEMBERS_EXIST,
+        ids::CHECK_DEPS_NO_NESTED_WORKSPACE,
+        ids::CHECK_DEPS_DEPRECATED_CRATES,
+        ids::CHECK_DEPS_PUBLISH_METADATA,
+        ids::CHECK_DEPS_BANNED_FEATURES,
+        ids::CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+        ids::CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+        ids::CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY,
+        ids::CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+        ids::CHECK_DEPS_LINTS_CONFIGURED,
+        ids::CHECK_DEPS_NO_STD_DEFAULT_FEATURES,
         ids::CHECK_TOOL_RUNTIME,
     ]
 }
@@ -92,8 +234,60 @@ pub fn all_codes() -> &'static [&'static str] {
         ids::CODE_DEV_DEP_IN_NORMAL,
         ids::CODE_DEFAULT_FEATURES_IMPLICIT,
         ids::CODE_DUPLICATE_DIFFERENT_VERSIONS,
+        ids::CODE_MULTIPLE_VERSIONS,
         ids::CODE_OPTIONAL_NOT_IN_FEATURES,
+        ids::CODE_OPTIONAL_NEVER_ENABLED,
         ids::CODE_VERSION_YANKED,
+        ids::CODE_BANNED_CRATE,
+        ids::CODE_DISALLOWED_REGISTRY,
+        ids::CODE_GIT_BRANCH_UNPINNED,
+        ids::CODE_DUPLICATE_DEPENDENCY_KINDS,
+        ids::CODE_DEPS_UNSORTED,
+        ids::CODE_MSRV_BELOW_FLOOR,
+        ids::CODE_EDITION_BELOW_FLOOR,
+        ids::CODE_MISSING_RUST_VERSION,
+        ids::CODE_PATCH_SECTION_PRESENT,
+        ids::CODE_REPLACE_SECTION_PRESENT,
+        ids::CODE_DEFAULT_FEATURES_ENABLED,
+        ids::CODE_PRERELEASE_VERSION,
+        ids::CODE_EXACT_PIN_FORBIDDEN,
+        ids::CODE_EXACT_PIN_REQUIRED,
+        ids::CODE_DEV_DEP_USED_IN_SRC,
+        ids::CODE_CRATE_NAME_DIR_MISMATCH,
+        ids::CODE_SHOULD_INHERIT_WORKSPACE_DEP,
+        ids::CODE_BUILD_DEPENDENCY_PRESENT,
+        ids::CODE_DEPENDENCY_WITHOUT_VERSION,
+        ids::CODE_EMPTY_FEATURES_WITH_DEFAULTS,
+        ids::CODE_IMPLICIT_OPTIONAL_FEATURE,
+        ids::CODE_TOO_MANY_DEPENDENCIES,
+        ids::CODE_OPEN_ENDED_RANGE,
+        ids::CODE_DEPENDS_ON_UNPUBLISHABLE,
+        ids::CODE_INSECURE_GIT_URL,
+        ids::CODE_INVALID_TARGET_CFG,
+        ids::CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE,
+        ids::CODE_MISSING_LICENSE,
+        ids::CODE_DISALLOWED_LICENSE,
+        ids::CODE_DEPENDENCY_RENAMED,
+        ids::CODE_DIVERGENT_VERSION_REQUIREMENTS,
+        ids::CODE_OPTIONAL_DEV_DEPENDENCY,
+        ids::CODE_GIT_WITH_VERSION,
+        ids::CODE_INCONSISTENT_MSRV,
+        ids::CODE_MSRV_NOT_INHERITED,
+        ids::CODE_DEPENDENCY_MISSING_FROM_LOCK,
+        ids::CODE_WORKSPACE_MEMBER_NOT_FOUND,
+        ids::CODE_NESTED_WORKSPACE_DETECTED,
+        ids::CODE_DEPRECATED_CRATE,
+        ids::CODE_MISSING_PUBLISH_METADATA,
+        ids::CODE_BANNED_FEATURE_ENABLED,
+        ids::CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED,
+        ids::CODE_PATH_OUTSIDE_WORKSPACE,
+        ids::CODE_BUILD_SCRIPT_WITHOUT_DEPS,
+        ids::CODE_BUILD_DEPS_WITHOUT_SCRIPT,
+        ids::CODE_DEPENDENCY_CYCLE,
+        ids::CODE_SELF_DEPENDENCY,
+        ids::CODE_MISSING_LINTS_TABLE,
+        ids::CODE_LINTS_NOT_INHERITED,
+        ids::CODE_DEFAULT_FEATURES_IN_NO_STD,
         ids::CODE_RUNTIME_ERROR,
     ]
 }
@@ -510,6 +704,8 @@ fn explain_no_multiple_versions() -> Explanation {
         title: "No Multiple Versions",
         description: "\
 Detects the same crate with different versions across workspace members.
+When a `Cargo.lock` is present, also reports crates that resolve at more
+than one version (`multiple_versions`).
 
 Having multiple versions of the same dependency in a workspace:
 - Increases binary size (both versions are compiled)
@@ -558,6 +754,45 @@ fn explain_duplicate_different_versions() -> Explanation {
     exp
 }
 
+fn explain_multiple_versions() -> Explanation {
+    Explanation {
+        title: "Multiple Resolved Versions",
+        description: "\
+Detects crates that `Cargo.lock` resolves at more than one version.
+
+Manifests can agree on a requirement and still end up with two copies of a
+crate when transitive dependencies need incompatible releases (for example
+`syn 1.x` and `syn 2.x`). Each copy is compiled separately, which costs
+build time and binary size, and types from one copy don't interoperate with
+the other.
+
+This code is only emitted when a `Cargo.lock` is present at the repo root.",
+        remediation: "\
+Find which dependents pull in each version with `cargo tree -d`, then
+upgrade or replace the ones holding the older release.
+
+Duplicates that can't be avoided (for example `windows-sys`) can be
+tolerated explicitly:
+
+    [checks.\"deps.no_multiple_versions\"]
+    ignore = [\"windows-sys\"]",
+        examples: ExamplePair {
+            before: r#"# Cargo.lock
+[[package]]
+name = "syn"
+version = "1.0.109"
+
+[[package]]
+name = "syn"
+version = "2.0.48""#,
+            after: r#"# Cargo.lock
+[[package]]
+name = "syn"
+version = "2.0.48""#,
+        },
+    }
+}
+
 fn explain_optional_unused() -> Explanation {
     Explanation {
         title: "Unused Optional Dependency",
@@ -569,7 +804,11 @@ at least one feature in the [features] table. An optional dependency that
 isn't referenced in any feature:
 - Cannot be enabled by users
 - Suggests incomplete feature configuration
-- May indicate dead code or misconfiguration",
+- May indicate dead code or misconfiguration
+
+Workspaces resolving features with resolver 1 (`resolver = \"1\"` on the root,
+or a root package on edition 2015/2018 without `resolver`) are skipped, since
+that resolver unifies features in ways the manifest alone does not show.",
         remediation: "\
 Either reference the optional dependency in a feature:
 
@@ -601,6 +840,37 @@ fn explain_optional_not_in_features() -> Explanation {
     exp
 }
 
+fn explain_optional_never_enabled() -> Explanation {
+    Explanation {
+        title: "Optional Never Enabled",
+        description: "\
+Detects optional dependencies that features only reference weakly.
+
+A weak reference such as `\"serde?/derive\"` enables a feature of the
+dependency *if something else already enabled it*; it never activates the
+dependency itself. When every reference is weak, no feature can turn the
+dependency on.",
+        remediation: "\
+Enable the dependency from at least one feature with `dep:` or a strong
+`name/feature` reference:
+
+    [features]
+    serde = [\"dep:serde\", \"serde?/derive\"]",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde = { version = "1.0", optional = true }
+
+[features]
+derive = ["serde?/derive"]"#,
+            after: r#"[dependencies]
+serde = { version = "1.0", optional = true }
+
+[features]
+derive = ["dep:serde", "serde/derive"]"#,
+        },
+    }
+}
+
 fn explain_yanked_versions() -> Explanation {
     Explanation {
         title: "No Yanked Versions",
@@ -635,6 +905,1831 @@ fn explain_version_yanked() -> Explanation {
     exp
 }
 
+fn explain_banned_crates() -> Explanation {
+    Explanation {
+        title: "Banned Crates",
+        description: "\
+Detects dependencies that match an organization-wide deny list.
+
+Some crates are disallowed by policy, for example:
+- `openssl` when the organization standardizes on `rustls`
+- crates with incompatible licenses
+- crates superseded by an internal replacement
+
+The deny list is configured per check and accepts exact names or globs
+(`openssl*`). Every dependency table (normal, dev, build, and target-specific)
+is inspected. A renamed dependency (`tls = { package = \"openssl\" }`) is matched
+by its real crate name as well as its key.",
+        remediation: "\
+Remove the dependency or replace it with the approved alternative:
+
+    [dependencies]
+    rustls = \"0.23\"
+
+If a specific crate must stay temporarily, add it to the check allowlist:
+
+    [checks.\"deps.banned_crates\"]
+    deny = [\"openssl*\"]
+    allow = [\"openssl-probe\"]",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+openssl = "0.10""#,
+            after: r#"[dependencies]
+rustls = "0.23""#,
+        },
+    }
+}
+
+fn explain_banned_crate() -> Explanation {
+    let mut exp = explain_banned_crates();
+    exp.title = "Dependency Is Banned";
+    exp
+}
+
+fn explain_allowed_registries() -> Explanation {
+    Explanation {
+        title: "Allowed Registries",
+        description: "\
+Detects dependencies sourced from a registry that is not on the allowlist.
+
+Locked-down environments often permit crates.io plus a single internal
+registry. A stray `registry = \"...\"` entry can pull code from an
+unvetted mirror or a personal registry.
+
+The check's `allow` list holds registry names (globs are supported).
+Registry dependencies without a `registry` key resolve from crates.io, which
+is always allowed unless `crates-io` is listed in `deny`. Path, git, and
+workspace-inherited dependencies are not evaluated.",
+        remediation: "\
+Switch the dependency to an approved registry:
+
+    [dependencies]
+    internal-utils = { version = \"1.2\", registry = \"internal\" }
+
+Or add the registry to the allowlist:
+
+    [checks.\"deps.allowed_registries\"]
+    allow = [\"internal\"]",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+internal-utils = { version = "1.2", registry = "my-mirror" }"#,
+            after: r#"[dependencies]
+internal-utils = { version = "1.2", registry = "internal" }"#,
+        },
+    }
+}
+
+fn explain_disallowed_registry() -> Explanation {
+    let mut exp = explain_allowed_registries();
+    exp.title = "Registry Not Allowed";
+    exp
+}
+
+fn explain_git_pinned() -> Explanation {
+    Explanation {
+        title: "Git Dependencies Must Be Pinned",
+        description: "\
+Detects git dependencies that do not pin an immutable ref.
+
+A git dependency that names a `branch`, or specifies only `git = \"...\"`
+(which tracks the default branch), resolves to whatever commit the branch
+points at when the lockfile is next updated. Builds are only reproducible
+when the dependency pins a `rev` or a `tag`.
+
+Workspace-inherited (`workspace = true`) declarations are skipped.",
+        remediation: "\
+Pin the dependency to a commit or a release tag:
+
+    my-crate = { git = \"https://github.com/org/repo\", rev = \"4f2c1e9\" }
+    my-crate = { git = \"https://github.com/org/repo\", tag = \"v0.3.1\" }",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+my-lib = { git = "https://github.com/org/my-lib", branch = "main" }"#,
+            after: r#"[dependencies]
+my-lib = { git = "https://github.com/org/my-lib", rev = "4f2c1e9" }"#,
+        },
+    }
+}
+
+fn explain_git_branch_unpinned() -> Explanation {
+    let mut exp = explain_git_pinned();
+    exp.title = "Git Branch Unpinned";
+    exp
+}
+
+fn explain_duplicate_across_kinds() -> Explanation {
+    Explanation {
+        title: "Duplicate Across Dependency Kinds",
+        description: "\
+Detects crates declared in more than one dependency table of the same manifest,
+e.g. both `[dependencies]` and `[dev-dependencies]`.
+
+A normal dependency is already available to tests, examples, and benches, so a
+matching `[dev-dependencies]` entry is usually redundant. Keeping both invites
+drift: the two entries can request different versions or feature sets, and
+Cargo unifies features across them in ways that are hard to follow.
+
+Entries are compared within the same target table, so a platform-specific
+`[target.'cfg(unix)'.dependencies]` entry does not collide with an
+unconditional `[dev-dependencies]` entry. The finding points at the dev or
+build entry.",
+        remediation: "\
+Remove the redundant entry. If tests need extra features, add them to the
+single declaration or enable them through a dev-only feature:
+
+    [dependencies]
+    serde = { version = \"1\", features = [\"derive\"] }",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde = "1"
+
+[dev-dependencies]
+serde = { version = "1", features = ["derive"] }"#,
+            after: r#"[dependencies]
+serde = { version = "1", features = ["derive"] }"#,
+        },
+    }
+}
+
+fn explain_duplicate_dependency_kinds() -> Explanation {
+    let mut exp = explain_duplicate_across_kinds();
+    exp.title = "Duplicate Dependency Kinds";
+    exp
+}
+
+fn explain_sorted() -> Explanation {
+    Explanation {
+        title: "Sorted Dependency Tables",
+        description: "\
+Detects dependency tables whose entries are not in alphabetical order.
+
+Keeping `[dependencies]`, `[dev-dependencies]`, and `[build-dependencies]`
+sorted makes additions land in predictable places, which keeps diffs small and
+avoids merge conflicts when several branches add dependencies at once.
+
+Each table is checked independently, and target-specific tables such as
+`[target.'cfg(unix)'.dependencies]` are sorted on their own. Only the first
+out-of-order entry in each table is reported.",
+        remediation: "\
+Reorder the table alphabetically. The finding's `expected_previous` names the
+entry the flagged dependency should follow (absent when it belongs first).
+
+Tools such as `cargo sort` can reorder tables automatically.",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde = "1"
+anyhow = "1"
+toml = "0.8""#,
+            after: r#"[dependencies]
+anyhow = "1"
+serde = "1"
+toml = "0.8""#,
+        },
+    }
+}
+
+fn explain_deps_unsorted() -> Explanation {
+    let mut exp = explain_sorted();
+    exp.title = "Dependencies Unsorted";
+    exp
+}
+
+fn explain_msrv_floor() -> Explanation {
+    Explanation {
+        title: "MSRV and Edition Floor",
+        description: "\
+Ensures every package declares a `rust-version` and that its `rust-version`
+and `edition` meet the minimums configured for the workspace.
+
+Platform teams use a floor to guarantee that all crates can rely on a known
+baseline of language and standard-library features, and that toolchain
+upgrades are coordinated rather than discovered one crate at a time.
+
+Configure the floor on the check:
+
+    [checks.\"deps.msrv_floor\"]
+    enabled = true
+    min_rust_version = \"1.74\"
+    min_edition = \"2021\"
+
+A package without `edition` is treated as edition 2015, matching Cargo.",
+        remediation: "\
+Declare (or raise) the values in the package manifest:
+
+    [package]
+    edition = \"2021\"
+    rust-version = \"1.74\"",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+edition = "2018""#,
+            after: r#"[package]
+name = "my-crate"
+edition = "2021"
+rust-version = "1.74""#,
+        },
+    }
+}
+
+fn explain_msrv_below_floor() -> Explanation {
+    let mut exp = explain_msrv_floor();
+    exp.title = "MSRV Below Floor";
+    exp
+}
+
+fn explain_edition_below_floor() -> Explanation {
+    let mut exp = explain_msrv_floor();
+    exp.title = "Edition Below Floor";
+    exp
+}
+
+fn explain_missing_rust_version() -> Explanation {
+    let mut exp = explain_msrv_floor();
+    exp.title = "Missing rust-version";
+    exp
+}
+
+fn explain_no_patch_override() -> Explanation {
+    Explanation {
+        title: "No Patch or Replace Overrides",
+        description: "\
+Detects `[patch.<source>]` and `[replace]` tables in publishable package
+manifests.
+
+Overrides redirect part of the dependency graph to a fork, a local path, or a
+git commit. Cargo ignores them when a crate is used as a dependency or
+published, so a crate that only builds because of a patch works in this
+repository and breaks for everyone downstream.
+
+Workspace roots and packages with `publish = false` are skipped: a root
+`[patch]` is often a deliberate, repository-local fix. The check's `allow`
+list accepts the overridden crate names (globs are supported).",
+        remediation: "\
+Upstream the fix and depend on a released version, or move the override
+to the workspace root manifest where it only affects local builds:
+
+    # Cargo.toml (workspace root)
+    [patch.crates-io]
+    my-dep = { git = \"https://github.com/org/my-dep\", rev = \"4f2c1e9\" }",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+
+[dependencies]
+my-dep = "1.2"
+
+[patch.crates-io]
+my-dep = { path = "../my-dep" }"#,
+            after: r#"[package]
+name = "my-crate"
+
+[dependencies]
+my-dep = "1.3""#,
+        },
+    }
+}
+
+fn explain_patch_section_present() -> Explanation {
+    let mut exp = explain_no_patch_override();
+    exp.title = "Patch Section Present";
+    exp
+}
+
+fn explain_replace_section_present() -> Explanation {
+    let mut exp = explain_no_patch_override();
+    exp.title = "Replace Section Present";
+    exp.remediation = "\
+`[replace]` is deprecated in favor of `[patch]`. Remove it and depend on a
+released version, or express the override as a `[patch]` in the workspace
+root manifest.";
+    exp
+}
+
+fn explain_no_default_features() -> Explanation {
+    Explanation {
+        title: "No Default Features",
+        description: "\
+Requires `default-features = false` for a configured set of crates.
+
+Crates such as `tokio` or `reqwest` pull in large default feature sets (TLS
+backends, runtimes, compression) that most consumers only partly need.
+Listing them in the check's `deny` list forces every declaration to opt out
+of defaults and name the features it actually uses.
+
+A declaration is flagged when `default-features` is omitted or set to `true`.
+For `workspace = true` dependencies the `[workspace.dependencies]` entry
+decides, because Cargo ignores `default-features = false` on the member side
+when the workspace entry enables defaults. Renamed dependencies are matched
+by their package name.",
+        remediation: "\
+Disable default features and enable only what the crate needs:
+
+    [dependencies]
+    tokio = { version = \"1\", default-features = false, features = [\"rt\", \"macros\"] }
+
+For inherited dependencies, set it on the workspace entry:
+
+    [workspace.dependencies]
+    tokio = { version = \"1\", default-features = false }",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+reqwest = "0.12""#,
+            after: r#"[dependencies]
+reqwest = { version = "0.12", default-features = false, features = ["rustls-tls"] }"#,
+        },
+    }
+}
+
+fn explain_default_features_enabled() -> Explanation {
+    let mut exp = explain_no_default_features();
+    exp.title = "Default Features Enabled";
+    exp
+}
+
+fn explain_no_prerelease() -> Explanation {
+    Explanation {
+        title: "No Prerelease Requirements",
+        description: "\
+Detects dependency version requirements that name a prerelease, such as
+`1.0.0-beta.1` or `>=2.0.0-rc.1, <3`, in publishable packages.
+
+A prerelease requirement ties a released crate to an API that its authors have
+not committed to. Prereleases routinely change or disappear before the final
+release, and Cargo only matches them when the requirement names one explicitly,
+so downstream users inherit a version constraint that is hard to satisfy.
+
+Requirements are parsed with semver rules, so every comparator in a range is
+inspected. For `workspace = true` dependencies the `[workspace.dependencies]`
+entry supplies the requirement. Packages with `publish = false` are skipped.",
+        remediation: "\
+Depend on a stable release once one is available:
+
+    [dependencies]
+    tokio = \"1\"
+
+If the prerelease is genuinely required, keep the crate unpublished until the
+dependency ships a stable version, or list it in the check's `allow` list.",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+tokio = "1.0.0-beta.1""#,
+            after: r#"[dependencies]
+tokio = "1.0""#,
+        },
+    }
+}
+
+fn explain_prerelease_version() -> Explanation {
+    let mut exp = explain_no_prerelease();
+    exp.title = "Prerelease Version Requirement";
+    exp
+}
+
+fn explain_version_pinning() -> Explanation {
+    Explanation {
+        title: "Version Pinning",
+        description: "\
+Enforces a team policy on exact version pins (`=x.y.z`).
+
+Libraries usually should not pin exactly: an `=1.2.3` requirement stops Cargo
+from unifying the dependency with other crates in a downstream graph and
+forces duplicate versions or resolution failures. Binaries sometimes want the
+opposite, pinning every dependency for reproducible builds without relying on
+`Cargo.lock`.
+
+The check's `mode` selects the policy:
+
+- `forbid_exact` flags requirements that are a single `=MAJOR.MINOR.PATCH`
+  comparator (`exact_pin_forbidden`).
+- `require_exact` flags every other requirement (`exact_pin_required`).
+
+There is no default mode: with `mode` unset the check emits nothing, so it
+must be opted into explicitly. `workspace = true` and `path` dependencies are
+exempt, as are declarations without a parseable `version`.",
+        remediation: "\
+With `forbid_exact`, relax the pin to a caret requirement:
+
+    [dependencies]
+    serde = \"1.0.200\"
+
+With `require_exact`, pin the version you build against:
+
+    [dependencies]
+    serde = \"=1.0.200\"",
+        examples: ExamplePair {
+            before: r#"[checks."deps.version_pinning"]
+enabled = true
+mode = "forbid_exact"
+
+# Cargo.toml
+[dependencies]
+serde = "=1.0.200""#,
+            after: r#"[dependencies]
+serde = "1.0.200""#,
+        },
+    }
+}
+
+fn explain_exact_pin_forbidden() -> Explanation {
+    let mut exp = explain_version_pinning();
+    exp.title = "Exact Pin Forbidden";
+    exp
+}
+
+fn explain_exact_pin_required() -> Explanation {
+    let mut exp = explain_version_pinning();
+    exp.title = "Exact Pin Required";
+    exp.examples = ExamplePair {
+        before: r#"[checks."deps.version_pinning"]
+enabled = true
+mode = "require_exact"
+
+# Cargo.toml
+[dependencies]
+serde = "1.0""#,
+        after: r#"[dependencies]
+serde = "=1.0.200""#,
+    };
+    exp
+}
+
+fn explain_dev_dep_leakage() -> Explanation {
+    Explanation {
+        title: "Dev-Dependency Leakage",
+        description: "\
+Detects crates declared only under `[dev-dependencies]` that are referenced from
+a package's library or binary sources under `src/`.
+
+Dev-dependencies are only available when building tests, examples and benches.
+A `use` of one from non-test code compiles under `cargo test` but breaks
+`cargo build`, `cargo publish` and every downstream user, often long after the
+change merged.
+
+This check needs a scan of package sources, which the repository adapter only
+performs when the check is enabled. `use <crate>` and `extern crate` items are
+collected from `src/**/*.rs`; files named `tests.rs`, directories named `tests`,
+and everything after the first `#[cfg(test)]` in a file are treated as test
+code and skipped. A crate also declared under `[dependencies]` is not reported.",
+        remediation: "\
+Move the crate to `[dependencies]` if non-test code needs it:
+
+    [dependencies]
+    serde_json = \"1\"
+
+Otherwise gate the code that uses it behind `#[cfg(test)]`, or list the crate
+in the check's `allow` list.",
+        examples: ExamplePair {
+            before: r#"[dev-dependencies]
+serde_json = "1"
+
+# src/lib.rs: use serde_json::Value;"#,
+            after: r#"[dependencies]
+serde_json = "1"
+
+# src/lib.rs: use serde_json::Value;"#,
+        },
+    }
+}
+
+fn explain_dev_dep_used_in_src() -> Explanation {
+    let mut exp = explain_dev_dep_leakage();
+    exp.title = "Dev-Dependency Used In Source";
+    exp
+}
+
+fn explain_crate_dir_name() -> Explanation {
+    Explanation {
+        title: "Crate Directory Name",
+        description: "\
+Detects member crates whose `package.name` does not match the directory that
+holds their `Cargo.toml`, e.g. a crate named `gadgets` living in `crates/widgets/`.
+
+Monorepos usually rely on the directory name to find a crate: `cargo -p`, CODEOWNERS
+entries and CI path filters all assume `crates/foo/` contains `foo`. A mismatch makes
+those lookups silently target the wrong place.
+
+Names are compared after mapping `_` to `-`, so `crates/foo-bar/` may hold `foo_bar`.
+Manifests without a `[package]` table and a package at the repository root are
+skipped. The check's `allow` globs are matched against both the package name and
+the manifest's directory (e.g. `vendor/*`).",
+        remediation: "\
+Rename the package or its directory so they agree:
+
+    # crates/widgets/Cargo.toml
+    [package]
+    name = \"widgets\"
+
+If the mismatch is intentional, add the package name or directory to the check's
+`allow` list.",
+        examples: ExamplePair {
+            before: r#"# crates/widgets/Cargo.toml
+[package]
+name = "gadgets""#,
+            after: r#"# crates/widgets/Cargo.toml
+[package]
+name = "widgets""#,
+        },
+    }
+}
+
+fn explain_crate_name_dir_mismatch() -> Explanation {
+    let mut exp = explain_crate_dir_name();
+    exp.title = "Crate Name / Directory Mismatch";
+    exp
+}
+
+fn explain_workspace_version_override() -> Explanation {
+    Explanation {
+        title: "Workspace Version Override",
+        description: "\
+Detects member crates that declare a dependency with an explicit `version` even
+though the same dependency is defined in the root [workspace.dependencies] table.
+
+This is a stricter companion to `deps.workspace_inheritance`: it only fires when
+the member pins its own version, which is the case that silently drifts from the
+workspace. Path-only and git-only declarations are left to `deps.workspace_inheritance`.
+
+Each finding records the member's declared version and the workspace version so
+reviewers can see how far the override has drifted.",
+        remediation: "\
+Replace the explicit version with workspace inheritance:
+
+    [dependencies]
+    serde = { workspace = true }
+
+Local features can still be added alongside `workspace = true`. If a member
+intentionally needs a different version, add the dependency name to the check's
+`allow` list in depguard.toml.",
+        examples: ExamplePair {
+            before: r#"# In Cargo.toml (workspace root)
+[workspace.dependencies]
+serde = "1.0.200"
+
+# In crates/my-crate/Cargo.toml
+[dependencies]
+serde = "1.0.150""#,
+            after: r#"# In Cargo.toml (workspace root)
+[workspace.dependencies]
+serde = "1.0.200"
+
+# In crates/my-crate/Cargo.toml
+[dependencies]
+serde = { workspace = true }"#,
+        },
+    }
+}
+
+fn explain_should_inherit_workspace_dep() -> Explanation {
+    let mut exp = explain_workspace_version_override();
+    exp.title = "Should Inherit Workspace Dependency";
+    exp
+}
+
+fn explain_restrict_build_deps() -> Explanation {
+    Explanation {
+        title: "Restrict Build Dependencies",
+        description: "\
+Detects `[build-dependencies]` entries that are not on the check's allowlist.
+
+Build dependencies run arbitrary code on the build machine through `build.rs`,
+before any of the crate's own code is compiled. Every one of them widens the
+supply-chain attack surface of CI runners and developer machines, so many teams
+prefer to keep the set small and reviewed.
+
+With an empty `allow` list every build dependency is flagged. Target-specific
+tables such as `[target.'cfg(unix)'.build-dependencies]` are included.",
+        remediation: "\
+Remove the build dependency if it is not needed, or move the generated code into
+a checked-in file. If the build dependency is expected, add its crate name to the
+check's `allow` list:
+
+    [checks.\"deps.restrict_build_deps\"]
+    enabled = true
+    allow = [\"cc\", \"prost-build\"]",
+        examples: ExamplePair {
+            before: r#"[build-dependencies]
+cc = "1"
+openssl-src = "300"  # not on the allowlist"#,
+            after: r#"[build-dependencies]
+cc = "1""#,
+        },
+    }
+}
+
+fn explain_build_dependency_present() -> Explanation {
+    let mut exp = explain_restrict_build_deps();
+    exp.title = "Build Dependency Present";
+    exp
+}
+
+fn explain_version_required() -> Explanation {
+    Explanation {
+        title: "Version Required",
+        description: "\
+Detects normal dependencies of publishable packages that declare no `version` and
+do not inherit one with `workspace = true`.
+
+A dependency such as `foo = { git = \"...\" }` or `foo = { registry = \"internal\" }`
+gives reviewers nothing to audit: the crate that gets built depends on whatever the
+source serves at resolution time, and the manifest cannot be published as-is.
+
+This generalizes `deps.git_requires_version` to every source. Pure path
+dependencies are left to `deps.path_requires_version`, and packages with
+`publish = false` are skipped. Each finding lists the fields that were present.",
+        remediation: "\
+Add an explicit version requirement, or inherit one from the workspace:
+
+    [dependencies]
+    foo = { git = \"https://github.com/org/foo\", version = \"1.2\" }
+    bar = { workspace = true }",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+foo = { git = "https://github.com/org/foo", branch = "main" }"#,
+            after: r#"[dependencies]
+foo = { git = "https://github.com/org/foo", branch = "main", version = "1.2" }"#,
+        },
+    }
+}
+
+fn explain_dependency_without_version() -> Explanation {
+    let mut exp = explain_version_required();
+    exp.title = "Dependency Without Version";
+    exp
+}
+
+fn explain_misleading_empty_features() -> Explanation {
+    Explanation {
+        title: "Misleading Empty Features",
+        description: "\
+Detects dependencies declared with an empty `features = []` list while default
+features are still enabled.
+
+An empty list reads like \"no features\", but Cargo only adds to the default set:
+without `default-features = false` the dependency still builds with all of its
+default features. The declaration usually means the author intended to opt out.
+
+Inherited dependencies (`workspace = true`) take `default-features` from the
+`[workspace.dependencies]` entry, so that entry is consulted instead.",
+        remediation: "\
+Add `default-features = false` if the defaults are not wanted, or drop the empty
+`features` list if they are:
+
+    [dependencies]
+    serde = { version = \"1\", default-features = false, features = [] }",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde = { version = "1", features = [] }"#,
+            after: r#"[dependencies]
+serde = { version = "1", default-features = false }"#,
+        },
+    }
+}
+
+fn explain_empty_features_with_defaults() -> Explanation {
+    let mut exp = explain_misleading_empty_features();
+    exp.title = "Empty Features With Defaults";
+    exp
+}
+
+fn explain_explicit_dep_feature() -> Explanation {
+    Explanation {
+        title: "Explicit dep: Feature",
+        description: "\
+Detects optional dependencies that features enable through the dependency's
+implicit feature (a bare `foo` or `foo/feat`) instead of `dep:foo`.
+
+Unless some feature refers to `dep:foo`, Cargo creates an implicit public feature
+named after every optional dependency. Downstream crates can then turn on `foo`
+directly, which makes the dependency part of the crate's feature API whether or not
+that was intended. Referring to the dependency with `dep:foo` suppresses the implicit
+feature and keeps the public feature set to what `[features]` declares.
+
+This check is opinionated and disabled by default in every profile.",
+        remediation: "\
+Enable the dependency with `dep:` in the features that need it:
+
+    [features]
+    tls = [\"dep:rustls\"]",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+rustls = { version = "0.23", optional = true }
+
+[features]
+tls = ["rustls"]"#,
+            after: r#"[dependencies]
+rustls = { version = "0.23", optional = true }
+
+[features]
+tls = ["dep:rustls"]"#,
+        },
+    }
+}
+
+fn explain_implicit_optional_feature() -> Explanation {
+    let mut exp = explain_explicit_dep_feature();
+    exp.title = "Implicit Optional Feature";
+    exp
+}
+
+fn explain_max_direct_deps() -> Explanation {
+    Explanation {
+        title: "Max Direct Dependencies",
+        description: "\
+Caps the number of direct dependencies a package declares.
+
+Every direct dependency adds build time and attack surface, and the count tends to
+creep up one reasonable-looking addition at a time. This check sets a soft ceiling
+so growth past it becomes a deliberate, reviewed decision.
+
+`max` limits `[dependencies]`. Dev- and build-dependencies are excluded unless
+`max_dev` or `max_build` is set, in which case they are counted against their own
+limit. Target-specific tables count toward the same section. With no limit set the
+check is inert even when enabled.",
+        remediation: "\
+Remove dependencies that are no longer needed, fold small helpers into the crate, or
+split the package. If the extra dependencies are justified, raise the limit:
+
+    [checks.\"deps.max_direct_deps\"]
+    enabled = true
+    max = 40",
+        examples: ExamplePair {
+            before: r#"[checks."deps.max_direct_deps"]
+enabled = true
+max = 2
+
+[dependencies]
+anyhow = "1"
+serde = "1"
+serde_json = "1""#,
+            after: r#"[checks."deps.max_direct_deps"]
+enabled = true
+max = 2
+
+[dependencies]
+anyhow = "1"
+serde = "1""#,
+        },
+    }
+}
+
+fn explain_too_many_dependencies() -> Explanation {
+    let mut exp = explain_max_direct_deps();
+    exp.title = "Too Many Dependencies";
+    exp
+}
+
+fn explain_no_open_ranges() -> Explanation {
+    Explanation {
+        title: "No Open Ranges",
+        description: "\
+Detects version requirements on publishable packages that set a lower bound but no
+upper bound, such as `>=1.0` or `>1.2, >=1.3`.
+
+An open-ended range accepts every future major version. A downstream build that
+resolves a new major release picks up breaking changes the package was never tested
+against, the same risk as a `*` wildcard with a floor attached.
+
+Requirements containing `*` are left to `deps.no_wildcards`, so one declaration is
+never reported by both checks. Packages with `publish = false` are skipped.",
+        remediation: "\
+Cap the range, usually by using a caret requirement:
+
+    [dependencies]
+    serde = \"1.0\"          # same as ^1.0, i.e. >=1.0, <2.0
+    tokio = \">=1.20, <2\"",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde = ">=1.0""#,
+            after: r#"[dependencies]
+serde = "1.0""#,
+        },
+    }
+}
+
+fn explain_open_ended_range() -> Explanation {
+    let mut exp = explain_no_open_ranges();
+    exp.title = "Open-Ended Range";
+    exp
+}
+
+fn explain_publish_reachability() -> Explanation {
+    Explanation {
+        title: "Publish Reachability",
+        description: "\
+Detects publishable packages whose normal or build dependencies point, by path, at a
+workspace member marked `publish = false`.
+
+`cargo publish` needs every non-dev dependency to be available from a registry. A
+path dependency on an unpublishable member can never satisfy that, so the publish
+fails, usually late in a release. Path dependencies inherited with
+`workspace = true` are resolved through `[workspace.dependencies]`.
+
+Dev-dependencies are ignored because Cargo strips them from published manifests.",
+        remediation: "\
+Make the dependency publishable, move it to `[dev-dependencies]` if it is only used
+in tests, or mark the depending package `publish = false` as well:
+
+    [package]
+    name = \"internal-helpers\"
+    publish = true",
+        examples: ExamplePair {
+            before: r#"# crates/app/Cargo.toml (publishable)
+[dependencies]
+helpers = { path = "../helpers", version = "0.1" }
+
+# crates/helpers/Cargo.toml
+[package]
+name = "helpers"
+publish = false"#,
+            after: r#"# crates/app/Cargo.toml (publishable)
+[dependencies]
+helpers = { path = "../helpers", version = "0.1" }
+
+# crates/helpers/Cargo.toml
+[package]
+name = "helpers""#,
+        },
+    }
+}
+
+fn explain_depends_on_unpublishable() -> Explanation {
+    let mut exp = explain_publish_reachability();
+    exp.title = "Depends On Unpublishable";
+    exp
+}
+
+fn explain_secure_git_url() -> Explanation {
+    Explanation {
+        title: "Secure Git URL",
+        description: "\
+Detects git dependencies fetched over an unauthenticated, unencrypted transport:
+`http://` or `git://` URLs.
+
+Neither scheme verifies the server or protects the fetched objects in transit, so
+anyone on the network path can substitute the source Cargo builds. Unlike registry
+crates, git dependencies carry no checksum in the index to catch tampering.
+
+Enabled by default in the strict profile; off in the warn and compat profiles.",
+        remediation: "\
+Switch the URL to `https://` (or `ssh://` for private repositories) and pin a `rev`
+or `tag`:
+
+    [dependencies]
+    my-crate = { git = \"https://github.com/org/my-crate\", rev = \"abc123\" }",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+my-crate = { git = "http://github.com/org/my-crate", rev = "abc123" }
+other = { git = "git://example.org/other.git", tag = "v1.0.0" }"#,
+            after: r#"[dependencies]
+my-crate = { git = "https://github.com/org/my-crate", rev = "abc123" }
+other = { git = "https://example.org/other.git", tag = "v1.0.0" }"#,
+        },
+    }
+}
+
+fn explain_insecure_git_url() -> Explanation {
+    let mut exp = explain_secure_git_url();
+    exp.title = "Insecure Git URL";
+    exp
+}
+
+fn explain_valid_target_cfg() -> Explanation {
+    Explanation {
+        title: "Valid Target Cfg",
+        description: "\
+Detects `[target.<spec>]` tables whose spec is neither a well-formed `cfg(...)`
+expression nor a bare target triple.
+
+Cargo does not reject these tables: a predicate it cannot evaluate simply never
+matches, so the dependencies declared under it are silently left out of every build.
+A typo such as `cfg(feature = )` or `cfg(all(unix, )` therefore hides a dependency
+instead of failing loudly.
+
+A valid spec is either `cfg(<predicate>)`, where a predicate is an identifier, a
+`key = \"value\"` pair, or `all(...)`, `any(...)` and `not(...)` over predicates,
+or a target triple made of letters, digits, `_`, `-` and `.`.
+
+Enabled by default in all profiles.",
+        remediation: "\
+Fix the predicate so Cargo can evaluate it. Note that Cargo features cannot be used
+in `[target]` specs; gate optional dependencies with `[features]` instead:
+
+    [target.'cfg(unix)'.dependencies]
+    libc = \"0.2\"",
+        examples: ExamplePair {
+            before: r#"[target.'cfg(feature = )'.dependencies]
+libc = "0.2"
+
+[target.'cfg(all(unix, windows'.dependencies]
+nix = "0.29""#,
+            after: r#"[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[target.'cfg(all(unix, not(target_os = "macos")))'.dependencies]
+nix = "0.29""#,
+        },
+    }
+}
+
+fn explain_invalid_target_cfg() -> Explanation {
+    let mut exp = explain_valid_target_cfg();
+    exp.title = "Invalid Target Cfg";
+    exp
+}
+
+fn explain_no_zerover_in_stable() -> Explanation {
+    Explanation {
+        title: "No Zerover In Stable",
+        description: "\
+Detects normal dependencies pinned to a pre-1.0 (`0.x`) release in a publishable
+package whose own version is `1.0.0` or later.
+
+A 1.0 crate promises that its public API only breaks on a major version bump. Any
+`0.x` dependency whose types appear in that API can break on every minor release,
+dragging the stable crate into a semver-major change it did not choose.
+
+Only `[dependencies]` are inspected; dev- and build-dependencies never reach the
+public API. Requirements that can match a 1.x release (e.g. `>=0.9`) are not
+flagged. Some `0.x` crates are stable in practice; list them in `allow`.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Upgrade to a 1.0+ release of the dependency, keep its types out of your public API,
+or allowlist crates you consider stable:
+
+    [checks.\"deps.no_zerover_in_stable\"]
+    enabled = true
+    allow = [\"libc\"]",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+version = "1.2.0"
+
+[dependencies]
+bytes = "0.4""#,
+            after: r#"[package]
+name = "my-crate"
+version = "1.2.0"
+
+[dependencies]
+bytes = "1.5""#,
+        },
+    }
+}
+
+fn explain_zerover_dependency_in_stable_crate() -> Explanation {
+    let mut exp = explain_no_zerover_in_stable();
+    exp.title = "Zerover Dependency In Stable Crate";
+    exp
+}
+
+fn explain_license_present() -> Explanation {
+    Explanation {
+        title: "License Present",
+        description: "\
+Detects publishable packages that declare neither `license` nor `license-file`, and,
+when `allowed_licenses` is configured, packages whose `license` expression falls
+outside that list.
+
+crates.io rejects uploads without license metadata, and downstream license audits
+treat a missing field as \"all rights reserved\". Catching it in CI avoids a failed
+`cargo publish` and an awkward follow-up release.
+
+`allowed_licenses` holds SPDX identifiers. An expression is allowed when it can be
+satisfied with allowed licenses: every operand of `AND`, at least one of `OR`.
+`WITH` exceptions must be listed as written (e.g. `Apache-2.0 WITH LLVM-exception`)
+unless the bare license is allowed. Packages using `license-file` are not matched
+against `allowed_licenses`. Packages with `publish = false` are skipped.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Add an SPDX license expression to `[package]` (or inherit it from
+`[workspace.package]`), or point `license-file` at the license text:
+
+    [package]
+    license = \"MIT OR Apache-2.0\"
+
+Restrict accepted licenses with:
+
+    [checks.\"deps.license_present\"]
+    enabled = true
+    allowed_licenses = [\"MIT\", \"Apache-2.0\"]",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+version = "0.1.0""#,
+            after: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+license = "MIT OR Apache-2.0""#,
+        },
+    }
+}
+
+fn explain_missing_license() -> Explanation {
+    let mut exp = explain_license_present();
+    exp.title = "Missing License";
+    exp
+}
+
+fn explain_disallowed_license() -> Explanation {
+    let mut exp = explain_license_present();
+    exp.title = "Disallowed License";
+    exp
+}
+
+fn explain_no_rename() -> Explanation {
+    Explanation {
+        title: "No Rename",
+        description: "\
+Detects dependencies renamed with `package = \"...\"`, where the table key differs
+from the crate actually fetched.
+
+`foo = { package = \"bar\" }` makes `bar` available as `foo`. Renames are
+legitimate (e.g. depending on two major versions at once), but they also let a
+crate hide behind an innocuous name in review. Reporting every rename keeps the
+real crate visible; `deps.banned_crates` matches the real name as well as the key.
+
+The allowlist matches either the key or the real crate name.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Depend on the crate under its own name, or allowlist renames you rely on:
+
+    [checks.\"deps.no_rename\"]
+    enabled = true
+    allow = [\"rand_old\"]",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+http-client = { package = "reqwest", version = "0.12" }"#,
+            after: r#"[dependencies]
+reqwest = "0.12""#,
+        },
+    }
+}
+
+fn explain_dependency_renamed() -> Explanation {
+    let mut exp = explain_no_rename();
+    exp.title = "Dependency Renamed";
+    exp
+}
+
+fn explain_consistent_versions() -> Explanation {
+    Explanation {
+        title: "Consistent Versions",
+        description: "\
+Detects a crate that manifests in the workspace require at semver-incompatible
+versions, e.g. `serde = \"1.0\"` in the root package and `serde = \"2\"` in a member.
+
+Workspaces that do not route every dependency through `[workspace.dependencies]`
+can drift: each manifest pins its own requirement and the workspace ends up
+building two copies of the same crate, or types from one copy fail to match the
+other. Requirements are compared by their semver-compatible range (`1.0` and
+`1.4` agree; `0.3` and `0.4` do not), using the real crate name behind
+`package = \"...\"` renames. `{ workspace = true }` entries take their
+requirement from `[workspace.dependencies]`.
+
+The finding points at the first declaration that diverges from the first one
+seen. List crates that may diverge on purpose in `ignore`.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Align the requirements, ideally by moving the crate to `[workspace.dependencies]`:
+
+    [workspace.dependencies]
+    serde = \"1.0\"
+
+    # member Cargo.toml
+    [dependencies]
+    serde = { workspace = true }
+
+Crates that are expected to diverge can be ignored:
+
+    [checks.\"deps.consistent_versions\"]
+    enabled = true
+    ignore = [\"windows-sys\"]",
+        examples: ExamplePair {
+            before: r#"# Cargo.toml
+[dependencies]
+rand = "0.8"
+
+# crates/sim/Cargo.toml
+[dependencies]
+rand = "0.9""#,
+            after: r#"# Cargo.toml
+[dependencies]
+rand = "0.9"
+
+# crates/sim/Cargo.toml
+[dependencies]
+rand = "0.9""#,
+        },
+    }
+}
+
+fn explain_divergent_version_requirements() -> Explanation {
+    let mut exp = explain_consistent_versions();
+    exp.title = "Divergent Version Requirements";
+    exp
+}
+
+fn explain_no_optional_dev_deps() -> Explanation {
+    Explanation {
+        title: "No Optional Dev Deps",
+        description: "\
+Detects `[dev-dependencies]` entries marked `optional = true`.
+
+Dev-dependencies are only built for tests, examples and benchmarks and cannot be
+enabled through features, so `optional` has no meaning there. Cargo rejects the
+manifest outright (\"dev-dependencies are not allowed to be optional\"); the flag
+is almost always copied from a `[dependencies]` entry. Target-specific
+dev-dependency tables are inspected too.
+
+Enabled by default in the strict profile; disabled in warn and compat.",
+        remediation: "\
+Drop `optional = true` from the dev-dependency. If the crate should be gated by a
+feature, move it to `[dependencies]` instead:
+
+    [dev-dependencies]
+    proptest = \"1\"",
+        examples: ExamplePair {
+            before: r#"[dev-dependencies]
+proptest = { version = "1", optional = true }"#,
+            after: r#"[dev-dependencies]
+proptest = "1""#,
+        },
+    }
+}
+
+fn explain_optional_dev_dependency() -> Explanation {
+    let mut exp = explain_no_optional_dev_deps();
+    exp.title = "Optional Dev Dependency";
+    exp
+}
+
+fn explain_git_version_conflict() -> Explanation {
+    Explanation {
+        title: "Git Version Conflict",
+        description: "\
+Detects dependencies that declare both `git` and `version`.
+
+Cargo uses the git source locally and only consults `version` when publishing,
+so the two can silently disagree: the checked-out commit may not be the release
+the requirement names, and nothing verifies it. Many teams prefer git
+dependencies to carry `rev`/`tag` alone and leave versioning to the registry.
+
+This is the opposite stance to `deps.git_requires_version`; enable at most one
+of the two. Disabled by default in all profiles.",
+        remediation: "\
+Remove `version` from the git dependency and pin the source with `rev` or `tag`,
+or drop `git` and depend on the published release instead. Crates that must keep
+both can be listed in `allow`:
+
+    [checks.\"deps.git_version_conflict\"]
+    enabled = true
+    allow = [\"my-fork\"]",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+foo = { git = "https://github.com/org/foo", tag = "v2.0.0", version = "2.0" }"#,
+            after: r#"[dependencies]
+foo = { git = "https://github.com/org/foo", tag = "v2.0.0" }"#,
+        },
+    }
+}
+
+fn explain_git_with_version() -> Explanation {
+    let mut exp = explain_git_version_conflict();
+    exp.title = "Git With Version";
+    exp
+}
+
+fn explain_consistent_msrv() -> Explanation {
+    Explanation {
+        title: "Consistent MSRV",
+        description: "\
+Detects workspace members whose `rust-version` differs from the rest of the
+workspace.
+
+Mixed MSRVs make builds fail in confusing ways: a toolchain that satisfies one
+member rejects another, and CI pinned to the lowest version breaks only for some
+crates. The reference MSRV is the value members inherit from
+`[workspace.package]`; when no member inherits, it is the most common declared
+value. Only members that diverge from it are flagged, and members without a
+`rust-version` are left to `deps.msrv_floor`.
+
+With `mode = \"require_inherit\"`, every member of a multi-manifest workspace
+must instead use `rust-version.workspace = true`.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Declare the MSRV once in `[workspace.package]` and inherit it in every member:
+
+    [workspace.package]
+    rust-version = \"1.74\"
+
+    # member Cargo.toml
+    [package]
+    rust-version.workspace = true
+
+To require inheritance rather than matching values:
+
+    [checks.\"deps.consistent_msrv\"]
+    enabled = true
+    mode = \"require_inherit\"",
+        examples: ExamplePair {
+            before: r#"# crates/a/Cargo.toml
+[package]
+name = "a"
+rust-version = "1.70"
+
+# crates/b/Cargo.toml
+[package]
+name = "b"
+rust-version = "1.74""#,
+            after: r#"# Cargo.toml
+[workspace.package]
+rust-version = "1.74"
+
+# crates/a/Cargo.toml and crates/b/Cargo.toml
+[package]
+rust-version.workspace = true"#,
+        },
+    }
+}
+
+fn explain_inconsistent_msrv() -> Explanation {
+    let mut exp = explain_consistent_msrv();
+    exp.title = "Inconsistent MSRV";
+    exp
+}
+
+fn explain_msrv_not_inherited() -> Explanation {
+    let mut exp = explain_consistent_msrv();
+    exp.title = "MSRV Not Inherited";
+    exp
+}
+
+fn explain_lock_in_sync() -> Explanation {
+    Explanation {
+        title: "Lock In Sync",
+        description: "\
+Detects declared dependencies that have no entry in the root `Cargo.lock`.
+
+A dependency missing from the lockfile means the lock was not regenerated after a
+manifest edit. Locally Cargo silently rewrites it, but CI running with
+`--locked` fails, and without `--locked` CI resolves versions nobody reviewed.
+Renamed dependencies are looked up by their real package name.
+
+A stale lock is a whole-workspace concern, so all missing names are reported in
+a single finding on the root manifest. Without a readable `Cargo.lock` the check
+is skipped and the lockfile capability is reported as missing.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Regenerate the lockfile and commit it alongside the manifest change:
+
+    cargo update --workspace
+
+In CI, build with `--locked` so a stale lock fails fast.",
+        examples: ExamplePair {
+            before: r#"# Cargo.toml
+[dependencies]
+serde = "1"
+anyhow = "1"    # added, but Cargo.lock has no `anyhow` package"#,
+            after: r#"# Cargo.toml
+[dependencies]
+serde = "1"
+anyhow = "1"
+
+# Cargo.lock (after `cargo update --workspace`)
+[[package]]
+name = "anyhow"
+version = "1.0.86""#,
+        },
+    }
+}
+
+fn explain_dependency_missing_from_lock() -> Explanation {
+    let mut exp = explain_lock_in_sync();
+    exp.title = "Dependency Missing From Lock";
+    exp
+}
+
+fn explain_members_exist() -> Explanation {
+    Explanation {
+        title: "Members Exist",
+        description: "\
+Detects `[workspace] members` entries in the root manifest that match no
+`Cargo.toml` on disk.
+
+A typo or a crate that was moved or deleted leaves a dangling entry. Cargo refuses
+to load such a workspace, but depguard's own discovery silently skips the entry,
+so every check would otherwise pass while the build is broken. Globs count as
+resolved when they match at least one manifest, even one that `exclude` drops.
+
+Each unmatched entry is reported on its line in the root manifest.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Fix the path of the entry or remove it from `members`. For globs, make sure at
+least one crate directory under the pattern contains a `Cargo.toml`.",
+        examples: ExamplePair {
+            before: r#"[workspace]
+members = ["crates/core", "crates/clie"]   # crates/clie does not exist"#,
+            after: r#"[workspace]
+members = ["crates/core", "crates/cli"]"#,
+        },
+    }
+}
+
+fn explain_workspace_member_not_found() -> Explanation {
+    let mut exp = explain_members_exist();
+    exp.title = "Workspace Member Not Found";
+    exp
+}
+
+fn explain_no_nested_workspace() -> Explanation {
+    Explanation {
+        title: "No Nested Workspace",
+        description: "\
+Detects manifests below the repository root that declare their own `[workspace]`
+table.
+
+A stray `[workspace]` (often copied in with a crate, or left behind by
+`cargo new` in a subdirectory) silently splits the repository: the nested crates
+resolve against their own lockfile and `[workspace.dependencies]`, are skipped by
+`cargo build --workspace` at the root, and a package workspace stops inheriting
+from the root entirely. Virtual nested workspaces are also invisible to depguard's
+member discovery.
+
+Directories listed in the root's `[workspace] exclude` are treated as
+intentionally separate and are not reported.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Remove the nested `[workspace]` table and list the crate in the root's `members`.
+If the nested workspace is intentional, add its directory to the root's
+`[workspace] exclude` list (or to this check's `allow` list).",
+        examples: ExamplePair {
+            before: r#"# tools/Cargo.toml
+[workspace]
+members = ["xtask"]"#,
+            after: r#"# Cargo.toml (root)
+[workspace]
+members = ["crates/*", "tools/xtask"]"#,
+        },
+    }
+}
+
+fn explain_nested_workspace_detected() -> Explanation {
+    let mut exp = explain_no_nested_workspace();
+    exp.title = "Nested Workspace Detected";
+    exp
+}
+
+fn explain_deprecated_crates() -> Explanation {
+    Explanation {
+        title: "Deprecated Crates",
+        description: "\
+Flags dependencies on crates that the organization has marked as deprecated and
+points at the suggested replacement.
+
+The migration map is configured per repository with the check's
+`options.deprecated` table (`old = \"replacement\"`). Renamed dependencies are matched by their real
+crate name. Unlike `deps.banned_crates`, the goal is a guided migration: each
+finding carries the replacement in its help text and `data.replacement`.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Replace the deprecated crate with the suggested one and update call sites.
+If a dependency cannot be migrated yet, add it to this check's `allow` list.",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde_yaml = "0.9""#,
+            after: r#"[dependencies]
+serde_yml = "0.0.12""#,
+        },
+    }
+}
+
+fn explain_deprecated_crate() -> Explanation {
+    let mut exp = explain_deprecated_crates();
+    exp.title = "Deprecated Crate";
+    exp
+}
+
+fn explain_publish_metadata() -> Explanation {
+    Explanation {
+        title: "Publish Metadata",
+        description: "\
+Detects publishable packages that are missing `[package]` metadata crates.io
+expects, by default `description` and `repository`.
+
+crates.io warns when a crate is published without a description or a link to its
+source, and the crate page, search results, and `cargo search` output are far less
+useful without them. Fields inherited from `[workspace.package]` count as present.
+
+The required keys are configurable with the `require` option; supported keys are
+`description`, `repository`, `homepage`, `documentation`, `keywords`, and
+`categories`. Packages with `publish = false` are skipped.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Add the missing keys to `[package]` (or inherit them from `[workspace.package]`):
+
+    [package]
+    description = \"Short summary of what the crate does\"
+    repository = \"https://github.com/org/project\"
+
+Change the required set with:
+
+    [checks.\"deps.publish_metadata\"]
+    enabled = true
+    options = { require = [\"description\", \"repository\", \"keywords\"] }",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+version = "0.1.0""#,
+            after: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+description = "Parses widgets"
+repository = "https://github.com/org/my-crate""#,
+        },
+    }
+}
+
+fn explain_missing_publish_metadata() -> Explanation {
+    let mut exp = explain_publish_metadata();
+    exp.title = "Missing Publish Metadata";
+    exp
+}
+
+fn explain_banned_features() -> Explanation {
+    Explanation {
+        title: "Banned Features",
+        description: "\
+Flags dependencies that enable a feature the organization has banned for that
+crate, such as `tokio`'s `process` feature or `openssl`'s `vendored`.
+
+Banning a whole crate is often too blunt: the crate is fine, but one feature pulls
+in unwanted code, build steps, or capabilities. The banned features are configured
+per crate in the check's `options.features` table, optionally with a reason that is
+included in each finding.
+
+Features listed in `features = [...]` are matched, including those inherited from
+a `[workspace.dependencies]` entry. Banning `default` also flags dependencies that
+do not set `default-features = false`, since Cargo enables the default feature
+implicitly. Renamed dependencies are matched by their real crate name.
+
+Disabled by default in all profiles.",
+        remediation: "\
+Remove the banned feature from the dependency's `features` list (for `default`, set
+`default-features = false` and enable only the features you need). If a package
+must keep the feature, add the crate to this check's `allow` list.",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+tokio = { version = "1", features = ["rt", "process"] }"#,
+            after: r#"[dependencies]
+tokio = { version = "1", features = ["rt"] }"#,
+        },
+    }
+}
+
+fn explain_banned_feature_enabled() -> Explanation {
+    let mut exp = explain_banned_features();
+    exp.title = "Banned Feature Enabled";
+    exp
+}
+
+fn explain_features_after_default_off() -> Explanation {
+    Explanation {
+        title: "Features After Default Off",
+        description: "\
+Detects dependencies declared with `default-features = false` that do not enable
+any feature in its place.
+
+Turning off default features without listing replacements builds the dependency
+in its most minimal configuration. Occasionally that is the intent, but more
+often the needed features were forgotten, and the crate then fails to build or
+silently loses functionality (for example `std` or a TLS backend).
+
+Inherited dependencies (`workspace = true`) are judged on the combined
+`default-features` and `features` of the member and `[workspace.dependencies]`
+entries. Because it is advisory, the check is disabled by default.",
+        remediation: "\
+List the features you need alongside `default-features = false`:
+
+    [dependencies]
+    serde = { version = \"1\", default-features = false, features = [\"derive\"] }
+
+If the minimal build is intentional, allowlist the dependency.",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+serde = { version = "1", default-features = false }"#,
+            after: r#"[dependencies]
+serde = { version = "1", default-features = false, features = ["derive"] }"#,
+        },
+    }
+}
+
+fn explain_no_features_after_default_disabled() -> Explanation {
+    let mut exp = explain_features_after_default_off();
+    exp.title = "No Features After Default Disabled";
+    exp
+}
+
+fn explain_path_within_workspace() -> Explanation {
+    Explanation {
+        title: "Path Within Workspace",
+        description: "\
+Detects path dependencies that resolve to a directory outside the repository or
+to a crate that is not a member of the workspace.
+
+Each `path` is resolved against the directory of the manifest that declares it
+(inherited `workspace = true` paths against the root). A path such as
+`../../other-repo/crate` only builds on machines with the same sibling checkout,
+which breaks reproducible builds and CI. A path into the repository that is not a
+workspace member is built outside the workspace's lockfile and settings.
+
+Membership is judged against the manifests depguard scanned, so the member test is
+skipped in diff scope; paths that leave the repository are flagged in every scope.",
+        remediation: "\
+Move the crate into the workspace and list it under `members`, or depend on a
+published or git version instead:
+
+    [dependencies]
+    shared = { path = \"../shared\" }   # crates/shared is a workspace member
+
+Allowlist dependency names or paths (as written) that are intentionally external.",
+        examples: ExamplePair {
+            before: r#"[dependencies]
+shared = { path = "../../other-repo/shared" }"#,
+            after: r#"[dependencies]
+shared = { git = "https://github.com/org/other-repo", rev = "4f2c1ab" }"#,
+        },
+    }
+}
+
+fn explain_path_outside_workspace() -> Explanation {
+    let mut exp = explain_path_within_workspace();
+    exp.title = "Path Outside Workspace";
+    exp
+}
+
+fn explain_build_script_consistency() -> Explanation {
+    Explanation {
+        title: "Build Script Consistency",
+        description: "\
+Detects packages whose `package.build` setting disagrees with their
+`[build-dependencies]`.
+
+A package that names a build script (`build = \"build.rs\"` or `build = true`)
+but declares no build dependencies often carries a stray setting left behind
+after the script was removed or emptied. Conversely, `[build-dependencies]` in a
+package with `build = false` can never be used, because Cargo will not run a
+build script at all.
+
+When `build` is not set, Cargo auto-detects `build.rs` on disk, so packages
+without the key are not judged. The check is opinionated and disabled by default.",
+        remediation: "\
+Remove the `build` key when the script is gone, or delete the unused
+`[build-dependencies]` when the build script is disabled:
+
+    [package]
+    name = \"my-crate\"
+    # build = \"build.rs\"   # removed: no build script any more",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+build = "build.rs"
+
+[dependencies]
+serde = "1""#,
+            after: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+
+[dependencies]
+serde = "1""#,
+        },
+    }
+}
+
+fn explain_build_script_without_deps() -> Explanation {
+    let mut exp = explain_build_script_consistency();
+    exp.title = "Build Script Without Build Dependencies";
+    exp
+}
+
+fn explain_build_deps_without_script() -> Explanation {
+    let mut exp = explain_build_script_consistency();
+    exp.title = "Build Dependencies Without Build Script";
+    exp.examples = ExamplePair {
+        before: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+build = false
+
+[build-dependencies]
+cc = "1""#,
+        after: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+build = false"#,
+    };
+    exp
+}
+
+fn explain_no_dependency_cycles() -> Explanation {
+    Explanation {
+        title: "No Dependency Cycles",
+        description: "\
+Detects workspace members that depend on themselves, or groups of members whose
+path dependencies form a cycle.
+
+Each `path` is resolved to a workspace member (inherited `workspace = true` paths
+against the root) and the members are linked into a graph. A package that reaches
+itself through normal or build dependencies makes Cargo fail with a \"cyclic package
+dependency\" error that does not say where the cycle was introduced.
+
+Dev-dependencies are ignored because Cargo permits cycles through them (for example
+a helper crate whose tests use the crate it supports).",
+        remediation: "\
+Remove the self-dependency, or break the cycle by moving the shared code into a
+new crate both members depend on:
+
+    [dependencies]
+    core = { path = \"../core\" }   # app -> core, and core no longer -> app
+
+Allowlist package names that should be ignored when building the graph.",
+        examples: ExamplePair {
+            before: r#"# crates/a/Cargo.toml
+[dependencies]
+b = { path = "../b" }
+
+# crates/b/Cargo.toml
+[dependencies]
+a = { path = "../a" }"#,
+            after: r#"# crates/a/Cargo.toml
+[dependencies]
+b = { path = "../b" }
+
+# crates/b/Cargo.toml
+[dependencies]
+shared = { path = "../shared" }"#,
+        },
+    }
+}
+
+fn explain_dependency_cycle() -> Explanation {
+    let mut exp = explain_no_dependency_cycles();
+    exp.title = "Dependency Cycle";
+    exp
+}
+
+fn explain_self_dependency() -> Explanation {
+    let mut exp = explain_no_dependency_cycles();
+    exp.title = "Self Dependency";
+    exp.examples = ExamplePair {
+        before: r#"[package]
+name = "app"
+
+[dependencies]
+app = { path = "." }"#,
+        after: r#"[package]
+name = "app"
+
+[dependencies]"#,
+    };
+    exp
+}
+
+fn explain_lints_configured() -> Explanation {
+    Explanation {
+        title: "Lints Configured",
+        description: "\
+Detects packages whose manifest has no `[lints]` table.
+
+Since Rust 1.74, lint levels for `rustc`, `clippy`, and `rustdoc` can live in the
+manifest instead of `#![deny(...)]` attributes scattered across crate roots. A
+package without `[lints]` silently opts out of the workspace's `[workspace.lints]`
+policy, because Cargo only applies it to members that declare
+`[lints] workspace = true`.
+
+Set `require_workspace = true` in the check's `options` table to also flag
+packages that configure their own lint tables instead of inheriting the
+workspace ones. The check is opinionated and disabled by default.",
+        remediation: "\
+Inherit the workspace lint policy in each member:
+
+    [lints]
+    workspace = true
+
+or declare the lints the package needs directly:
+
+    [lints.clippy]
+    unwrap_used = \"deny\"
+
+Allowlist package names that are exempt.",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+
+[dependencies]
+serde = "1""#,
+            after: r#"[package]
+name = "my-crate"
+version = "0.1.0"
+
+[lints]
+workspace = true
+
+[dependencies]
+serde = "1""#,
+        },
+    }
+}
+
+fn explain_missing_lints_table() -> Explanation {
+    let mut exp = explain_lints_configured();
+    exp.title = "Missing Lints Table";
+    exp
+}
+
+fn explain_lints_not_inherited() -> Explanation {
+    let mut exp = explain_lints_configured();
+    exp.title = "Lints Not Inherited";
+    exp.examples = ExamplePair {
+        before: r#"[package]
+name = "my-crate"
+
+[lints.clippy]
+unwrap_used = "deny""#,
+        after: r#"[package]
+name = "my-crate"
+
+[lints]
+workspace = true"#,
+    };
+    exp
+}
+
+fn explain_no_std_default_features() -> Explanation {
+    Explanation {
+        title: "No-std Default Features",
+        description: "\
+Requires `default-features = false` on the dependencies of `no_std` crates.
+
+Most crates enable `std` through their default features, so a `#![no_std]`
+package that keeps a dependency's defaults usually fails to build for its
+embedded or kernel target, or silently links `std` on hosts where it happens
+to be available. depguard cannot see `#![no_std]` attributes, so the packages
+to check are listed in the check's `options` table as `no_std_crates` (glob
+patterns are accepted); the option is required when the check is enabled.
+
+Only `[dependencies]` of those packages are checked: dev- and
+build-dependencies run on the host with `std`. For `workspace = true`
+dependencies the `[workspace.dependencies]` entry decides, because Cargo
+ignores `default-features = false` on the member side when the workspace entry
+enables defaults. The check is disabled by default.",
+        remediation: "\
+Disable default features and enable only what the crate needs without `std`:
+
+    [dependencies]
+    serde = { version = \"1\", default-features = false, features = [\"derive\"] }
+
+Configure the check with the no_std packages:
+
+    [checks.\"deps.no_std_default_features\"]
+    enabled = true
+    options = { no_std_crates = [\"my-embedded\"] }
+
+Allowlist dependencies that have no `std` feature.",
+        examples: ExamplePair {
+            before: r#"[package]
+name = "my-embedded"
+
+[dependencies]
+heapless = "0.8"
+serde = "1""#,
+            after: r#"[package]
+name = "my-embedded"
+
+[dependencies]
+heapless = { version = "0.8", default-features = false }
+serde = { version = "1", default-features = false }"#,
+        },
+    }
+}
+
+fn explain_default_features_in_no_std() -> Explanation {
+    let mut exp = explain_no_std_default_features();
+    exp.title = "Default Features in No-std Crate";
+    exp
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -13,6 +13,47 @@ pub const CHECK_DEPS_DEFAULT_FEATURES_EXPLICIT: &str = "deps.default_features_ex
 pub const CHECK_DEPS_NO_MULTIPLE_VERSIONS: &str = "deps.no_multiple_versions";
 pub const CHECK_DEPS_OPTIONAL_UNUSED: &str = "deps.optional_unused";
 pub const CHECK_DEPS_YANKED_VERSIONS: &str = "deps.yanked_versions";
+pub const CHECK_DEPS_BANNED_CRATES: &str = "deps.banned_crates";
+pub const CHECK_DEPS_ALLOWED_REGISTRIES: &str = "deps.allowed_registries";
+pub const CHECK_DEPS_GIT_PINNED: &str = "deps.git_pinned";
+pub const CHECK_DEPS_DUPLICATE_ACROSS_KINDS: &str = "deps.duplicate_across_kinds";
+pub const CHECK_DEPS_SORTED: &str = "deps.sorted";
+pub const CHECK_DEPS_MSRV_FLOOR: &str = "deps.msrv_floor";
+pub const CHECK_DEPS_NO_PATCH_OVERRIDE: &str = "deps.no_patch_override";
+pub const CHECK_DEPS_NO_DEFAULT_FEATURES: &str = "deps.no_default_features";
+pub const CHECK_DEPS_NO_PRERELEASE: &str = "deps.no_prerelease";
+pub const CHECK_DEPS_VERSION_PINNING: &str = "deps.version_pinning";
+pub const CHECK_DEPS_DEV_DEP_LEAKAGE: &str = "deps.dev_dep_leakage";
+pub const CHECK_DEPS_CRATE_DIR_NAME: &str = "deps.crate_dir_name";
+pub const CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE: &str = "deps.workspace_version_override";
+pub const CHECK_DEPS_RESTRICT_BUILD_DEPS: &str = "deps.restrict_build_deps";
+pub const CHECK_DEPS_VERSION_REQUIRED: &str = "deps.version_required";
+pub const CHECK_DEPS_MISLEADING_EMPTY_FEATURES: &str = "deps.misleading_empty_features";
+pub const CHECK_DEPS_EXPLICIT_DEP_FEATURE: &str = "deps.explicit_dep_feature";
+pub const CHECK_DEPS_MAX_DIRECT_DEPS: &str = "deps.max_direct_deps";
+pub const CHECK_DEPS_NO_OPEN_RANGES: &str = "deps.no_open_ranges";
+pub const CHECK_DEPS_PUBLISH_REACHABILITY: &str = "deps.publish_reachability";
+pub const CHECK_DEPS_SECURE_GIT_URL: &str = "deps.secure_git_url";
+pub const CHECK_DEPS_VALID_TARGET_CFG: &str = "deps.valid_target_cfg";
+pub const CHECK_DEPS_NO_ZEROVER_IN_STABLE: &str = "deps.no_zerover_in_stable";
+pub const CHECK_DEPS_LICENSE_PRESENT: &str = "deps.license_present";
+pub const CHECK_DEPS_NO_RENAME: &str = "deps.no_rename";
+pub const CHECK_DEPS_CONSISTENT_VERSIONS: &str = "deps.consistent_versions";
+pub const CHECK_DEPS_NO_OPTIONAL_DEV_DEPS: &str = "deps.no_optional_dev_deps";
+pub const CHECK_DEPS_GIT_VERSION_CONFLICT: &str = "deps.git_version_conflict";
+pub const CHECK_DEPS_CONSISTENT_MSRV: &str = "deps.consistent_msrv";
+pub const CHECK_DEPS_LOCK_IN_SYNC: &str = "deps.lock_in_sync";
+pub const CHECK_DEPS_MEMBERS_EXIST: &str = "deps.members_exist";
+pub const CHECK_DEPS_NO_NESTED_WORKSPACE: &str = "deps.no_nested_workspace";
+pub const CHECK_DEPS_DEPRECATED_CRATES: &str = "deps.deprecated_crates";
+pub const CHECK_DEPS_PUBLISH_METADATA: &str = "deps.publish_metadata";
+pub const CHECK_DEPS_BANNED_FEATURES: &str = "deps.banned_features";
+pub const CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF: &str = "deps.features_after_default_off";
+pub const CHECK_DEPS_PATH_WITHIN_WORKSPACE: &str = "deps.path_within_workspace";
+pub const CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY: &str = "deps.build_script_consistency";
+pub const CHECK_DEPS_NO_DEPENDENCY_CYCLES: &str = "deps.no_dependency_cycles";
+pub const CHECK_DEPS_LINTS_CONFIGURED: &str = "deps.lints_configured";
+pub const CHECK_DEPS_NO_STD_DEFAULT_FEATURES: &str = "deps.no_std_default_features";
 
 // Codes: deps.no_wildcards
 pub const CODE_WILDCARD_VERSION: &str = "wildcard_version";
@@ -38,13 +79,147 @@ pub const CODE_DEFAULT_FEATURES_IMPLICIT: &str = "default_features_implicit";
 
 // Codes: deps.no_multiple_versions
 pub const CODE_DUPLICATE_DIFFERENT_VERSIONS: &str = "duplicate_different_versions";
+pub const CODE_MULTIPLE_VERSIONS: &str = "multiple_versions";
 
 // Codes: deps.optional_unused
 pub const CODE_OPTIONAL_NOT_IN_FEATURES: &str = "optional_not_in_features";
+pub const CODE_OPTIONAL_NEVER_ENABLED: &str = "optional_never_enabled";
 
 // Codes: deps.yanked_versions
 pub const CODE_VERSION_YANKED: &str = "version_yanked";
 
+// Codes: deps.banned_crates
+pub const CODE_BANNED_CRATE: &str = "banned_crate";
+
+// Codes: deps.allowed_registries
+pub const CODE_DISALLOWED_REGISTRY: &str = "disallowed_registry";
+
+// Codes: deps.git_pinned
+pub const CODE_GIT_BRANCH_UNPINNED: &str = "git_branch_unpinned";
+
+// Codes: deps.duplicate_across_kinds
+pub const CODE_DUPLICATE_DEPENDENCY_KINDS: &str = "duplicate_dependency_kinds";
+
+// Codes: deps.sorted
+pub const CODE_DEPS_UNSORTED: &str = "deps_unsorted";
+
+// Codes: deps.msrv_floor
+pub const CODE_MSRV_BELOW_FLOOR: &str = "msrv_below_floor";
+pub const CODE_EDITION_BELOW_FLOOR: &str = "edition_below_floor";
+pub const CODE_MISSING_RUST_VERSION: &str = "missing_rust_version";
+
+// Codes: deps.no_patch_override
+pub const CODE_PATCH_SECTION_PRESENT: &str = "patch_section_present";
+pub const CODE_REPLACE_SECTION_PRESENT: &str = "replace_section_present";
+
+// Codes: deps.no_default_features
+pub const CODE_DEFAULT_FEATURES_ENABLED: &str = "default_features_enabled";
+
+// Codes: deps.no_prerelease
+pub const CODE_PRERELEASE_VERSION: &str = "prerelease_version";
+
+// Codes: deps.version_pinning
+pub const CODE_EXACT_PIN_FORBIDDEN: &str = "exact_pin_forbidden";
+pub const CODE_EXACT_PIN_REQUIRED: &str = "exact_pin_required";
+
+// Codes: deps.dev_dep_leakage
+pub const CODE_DEV_DEP_USED_IN_SRC: &str = "dev_dep_used_in_src";
+
+// Codes: deps.crate_dir_name
+pub const CODE_CRATE_NAME_DIR_MISMATCH: &str = "crate_name_dir_mismatch";
+
+// Codes: deps.workspace_version_override
+pub const CODE_SHOULD_INHERIT_WORKSPACE_DEP: &str = "should_inherit_workspace_dep";
+
+// Codes: deps.restrict_build_deps
+pub const CODE_BUILD_DEPENDENCY_PRESENT: &str = "build_dependency_present";
+
+// Codes: deps.version_required
+pub const CODE_DEPENDENCY_WITHOUT_VERSION: &str = "dependency_without_version";
+
+// Codes: deps.misleading_empty_features
+pub const CODE_EMPTY_FEATURES_WITH_DEFAULTS: &str = "empty_features_with_defaults";
+
+// Codes: deps.explicit_dep_feature
+pub const CODE_IMPLICIT_OPTIONAL_FEATURE: &str = "implicit_optional_feature";
+
+// Codes: deps.max_direct_deps
+pub const CODE_TOO_MANY_DEPENDENCIES: &str = "too_many_dependencies";
+
+// Codes: deps.no_open_ranges
+pub const CODE_OPEN_ENDED_RANGE: &str = "open_ended_range";
+
+// Codes: deps.publish_reachability
+pub const CODE_DEPENDS_ON_UNPUBLISHABLE: &str = "depends_on_unpublishable";
+
+// Codes: deps.secure_git_url
+pub const CODE_INSECURE_GIT_URL: &str = "insecure_git_url";
+
+// Codes: deps.valid_target_cfg
+pub const CODE_INVALID_TARGET_CFG: &str = "invalid_target_cfg";
+
+// Codes: deps.no_zerover_in_stable
+pub const CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE: &str = "zerover_dependency_in_stable_crate";
+
+// Codes: deps.license_present
+pub const CODE_MISSING_LICENSE: &str = "missing_license";
+pub const CODE_DISALLOWED_LICENSE: &str = "disallowed_license";
+
+// Codes: deps.no_rename
+pub const CODE_DEPENDENCY_RENAMED: &str = "dependency_renamed";
+
+// Codes: deps.consistent_versions
+pub const CODE_DIVERGENT_VERSION_REQUIREMENTS: &str = "divergent_version_requirements";
+
+// Codes: deps.no_optional_dev_deps
+pub const CODE_OPTIONAL_DEV_DEPENDENCY: &str = "optional_dev_dependency";
+
+// Codes: deps.git_version_conflict
+pub const CODE_GIT_WITH_VERSION: &str = "git_with_version";
+
+// Codes: deps.consistent_msrv
+pub const CODE_INCONSISTENT_MSRV: &str = "inconsistent_msrv";
+pub const CODE_MSRV_NOT_INHERITED: &str = "msrv_not_inherited";
+
+// Codes: deps.lock_in_sync
+pub const CODE_DEPENDENCY_MISSING_FROM_LOCK: &str = "dependency_missing_from_lock";
+
+// Codes: deps.members_exist
+pub const CODE_WORKSPACE_MEMBER_NOT_FOUND: &str = "workspace_member_not_found";
+
+// Codes: deps.no_nested_workspace
+pub const CODE_NESTED_WORKSPACE_DETECTED: &str = "nested_workspace_detected";
+
+// Codes: deps.deprecated_crates
+pub const CODE_DEPRECATED_CRATE: &str = "deprecated_crate";
+
+// Codes: deps.publish_metadata
+pub const CODE_MISSING_PUBLISH_METADATA: &str = "missing_publish_metadata";
+
+// Codes: deps.banned_features
+pub const CODE_BANNED_FEATURE_ENABLED: &str = "banned_feature_enabled";
+
+// Codes: deps.features_after_default_off
+pub const CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED: &str = "no_features_after_default_disabled";
+
+// Codes: deps.path_within_workspace
+pub const CODE_PATH_OUTSIDE_WORKSPACE: &str = "path_outside_workspace";
+
+// Codes: deps.build_script_consistency
+pub const CODE_BUILD_SCRIPT_WITHOUT_DEPS: &str = "build_script_without_deps";
+pub const CODE_BUILD_DEPS_WITHOUT_SCRIPT: &str = "build_deps_without_script";
+
+// Codes: deps.no_dependency_cycles
+pub const CODE_DEPENDENCY_CYCLE: &str = "dependency_cycle";
+pub const CODE_SELF_DEPENDENCY: &str = "self_dependency";
+
+// Codes: deps.lints_configured
+pub const CODE_MISSING_LINTS_TABLE: &str = "missing_lints_table";
+pub const CODE_LINTS_NOT_INHERITED: &str = "lints_not_inherited";
+
+// Codes: deps.no_std_default_features
+pub const CODE_DEFAULT_FEATURES_IN_NO_STD: &str = "default_features_in_no_std";
+
 // Tool-level
 pub const CHECK_TOOL_RUNTIME: &str = "tool.runtime";
 pub const CODE_RUNTIME_ERROR: &str = "runtime_error";
@@ -54,6 +229,12 @@ pub const REASON_DIFF_SCOPE_DISABLED: &str = "diff_scope_disabled";
 pub const REASON_CONFIG_MISSING_DEFAULTED: &str = "config_missing_defaulted";
 pub const REASON_RUNTIME_ERROR: &str = "runtime_error";
 pub const REASON_NO_MANIFEST_FOUND: &str = "no_manifest_found";
+pub const REASON_LOCKFILE_MISSING: &str = "lockfile_missing";
+pub const REASON_LOCKFILE_UNPARSEABLE: &str = "lockfile_unparseable";
+pub const REASON_SOURCE_SCAN_DISABLED: &str = "source_scan_disabled";
+pub const REASON_FINDINGS_TRUNCATED: &str = "findings_truncated";
+pub const REASON_GIT_METADATA_UNAVAILABLE: &str = "git_metadata_unavailable";
+pub const REASON_NO_MANIFESTS_IN_SCOPE: &str = "no_manifests_in_scope";
 
 // Fix action tokens (stable machine-readable routing for actuators)
 pub const FIX_ACTION_PIN_VERSION: &str = "pin_version";
@@ -67,6 +248,49 @@ pub const FIX_ACTION_ADD_DEFAULT_FEATURES: &str = "add_default_features";
 pub const FIX_ACTION_ALIGN_WORKSPACE_VERSIONS: &str = "align_workspace_versions";
 pub const FIX_ACTION_RESOLVE_OPTIONAL_FEATURE: &str = "resolve_optional_feature";
 pub const FIX_ACTION_UPGRADE_YANKED_VERSION: &str = "upgrade_yanked_version";
+pub const FIX_ACTION_REPLACE_BANNED_CRATE: &str = "replace_banned_crate";
+pub const FIX_ACTION_USE_ALLOWED_REGISTRY: &str = "use_allowed_registry";
+pub const FIX_ACTION_PIN_GIT_REV: &str = "pin_git_rev";
+pub const FIX_ACTION_REMOVE_DUPLICATE_DEP: &str = "remove_duplicate_dep";
+pub const FIX_ACTION_SORT_DEPENDENCIES: &str = "sort_dependencies";
+pub const FIX_ACTION_RAISE_RUST_VERSION: &str = "raise_rust_version";
+pub const FIX_ACTION_RAISE_EDITION: &str = "raise_edition";
+pub const FIX_ACTION_ADD_RUST_VERSION: &str = "add_rust_version";
+pub const FIX_ACTION_DEDUPE_RESOLVED_VERSIONS: &str = "dedupe_resolved_versions";
+pub const FIX_ACTION_REMOVE_DEPENDENCY_OVERRIDE: &str = "remove_dependency_override";
+pub const FIX_ACTION_DISABLE_DEFAULT_FEATURES: &str = "disable_default_features";
+pub const FIX_ACTION_USE_STABLE_VERSION: &str = "use_stable_version";
+pub const FIX_ACTION_RELAX_EXACT_PIN: &str = "relax_exact_pin";
+pub const FIX_ACTION_PIN_EXACT_VERSION: &str = "pin_exact_version";
+pub const FIX_ACTION_MOVE_TO_NORMAL_DEPS: &str = "move_to_normal_deps";
+pub const FIX_ACTION_RENAME_CRATE_OR_DIRECTORY: &str = "rename_crate_or_directory";
+pub const FIX_ACTION_REMOVE_BUILD_DEPENDENCY: &str = "remove_build_dependency";
+pub const FIX_ACTION_USE_DEP_PREFIX: &str = "use_dep_prefix";
+pub const FIX_ACTION_REDUCE_DEPENDENCIES: &str = "reduce_dependencies";
+pub const FIX_ACTION_PUBLISH_PATH_DEPENDENCY: &str = "publish_path_dependency";
+pub const FIX_ACTION_USE_HTTPS_GIT_URL: &str = "use_https_git_url";
+pub const FIX_ACTION_FIX_TARGET_CFG: &str = "fix_target_cfg";
+pub const FIX_ACTION_UPGRADE_ZEROVER_DEPENDENCY: &str = "upgrade_zerover_dependency";
+pub const FIX_ACTION_ADD_LICENSE: &str = "add_license";
+pub const FIX_ACTION_CHANGE_LICENSE: &str = "change_license";
+pub const FIX_ACTION_REMOVE_DEPENDENCY_RENAME: &str = "remove_dependency_rename";
+pub const FIX_ACTION_ALIGN_VERSION_REQUIREMENTS: &str = "align_version_requirements";
+pub const FIX_ACTION_REMOVE_OPTIONAL_FLAG: &str = "remove_optional_flag";
+pub const FIX_ACTION_REMOVE_GIT_VERSION: &str = "remove_git_version";
+pub const FIX_ACTION_ALIGN_RUST_VERSION: &str = "align_rust_version";
+pub const FIX_ACTION_INHERIT_RUST_VERSION: &str = "inherit_rust_version";
+pub const FIX_ACTION_REGENERATE_LOCKFILE: &str = "regenerate_lockfile";
+pub const FIX_ACTION_FIX_WORKSPACE_MEMBER: &str = "fix_workspace_member";
+pub const FIX_ACTION_MERGE_NESTED_WORKSPACE: &str = "merge_nested_workspace";
+pub const FIX_ACTION_REPLACE_DEPRECATED_CRATE: &str = "replace_deprecated_crate";
+pub const FIX_ACTION_ADD_PUBLISH_METADATA: &str = "add_publish_metadata";
+pub const FIX_ACTION_REMOVE_BANNED_FEATURE: &str = "remove_banned_feature";
+pub const FIX_ACTION_ENABLE_NEEDED_FEATURES: &str = "enable_needed_features";
+pub const FIX_ACTION_USE_WORKSPACE_MEMBER_PATH: &str = "use_workspace_member_path";
+pub const FIX_ACTION_ALIGN_BUILD_SCRIPT: &str = "align_build_script";
+pub const FIX_ACTION_BREAK_DEPENDENCY_CYCLE: &str = "break_dependency_cycle";
+pub const FIX_ACTION_ADD_LINTS_TABLE: &str = "add_lints_table";
+pub const FIX_ACTION_INHERIT_WORKSPACE_LINTS: &str = "inherit_workspace_lints";
 
 #[cfg(test)]
 mod tests {
@@ -86,6 +310,47 @@ mod tests {
             CHECK_DEPS_NO_MULTIPLE_VERSIONS,
             CHECK_DEPS_OPTIONAL_UNUSED,
             CHECK_DEPS_YANKED_VERSIONS,
+            CHECK_DEPS_BANNED_CRATES,
+            CHECK_DEPS_ALLOWED_REGISTRIES,
+            CHECK_DEPS_GIT_PINNED,
+            CHECK_DEPS_DUPLICATE_ACROSS_KINDS,
+            CHECK_DEPS_SORTED,
+            CHECK_DEPS_MSRV_FLOOR,
+            CHECK_DEPS_NO_PATCH_OVERRIDE,
+            CHECK_DEPS_NO_DEFAULT_FEATURES,
+            CHECK_DEPS_NO_PRERELEASE,
+            CHECK_DEPS_VERSION_PINNING,
+            CHECK_DEPS_DEV_DEP_LEAKAGE,
+            CHECK_DEPS_CRATE_DIR_NAME,
+            CHECK_DEPS_WORKSPACE_VERSION_OVERRIDE,
+            CHECK_DEPS_RESTRICT_BUILD_DEPS,
+            CHECK_DEPS_VERSION_REQUIRED,
+            CHECK_DEPS_MISLEADING_EMPTY_FEATURES,
+            CHECK_DEPS_EXPLICIT_DEP_FEATURE,
+            CHECK_DEPS_MAX_DIRECT_DEPS,
+            CHECK_DEPS_NO_OPEN_RANGES,
+            CHECK_DEPS_PUBLISH_REACHABILITY,
+            CHECK_DEPS_SECURE_GIT_URL,
+            CHECK_DEPS_VALID_TARGET_CFG,
+            CHECK_DEPS_NO_ZEROVER_IN_STABLE,
+            CHECK_DEPS_LICENSE_PRESENT,
+            CHECK_DEPS_NO_RENAME,
+            CHECK_DEPS_CONSISTENT_VERSIONS,
+            CHECK_DEPS_NO_OPTIONAL_DEV_DEPS,
+            CHECK_DEPS_GIT_VERSION_CONFLICT,
+            CHECK_DEPS_CONSISTENT_MSRV,
+            CHECK_DEPS_LOCK_IN_SYNC,
+            CHECK_DEPS_MEMBERS_EXIST,
+            CHECK_DEPS_NO_NESTED_WORKSPACE,
+            CHECK_DEPS_DEPRECATED_CRATES,
+            CHECK_DEPS_PUBLISH_METADATA,
+            CHECK_DEPS_BANNED_FEATURES,
+            CHECK_DEPS_FEATURES_AFTER_DEFAULT_OFF,
+            CHECK_DEPS_PATH_WITHIN_WORKSPACE,
+            CHECK_DEPS_BUILD_SCRIPT_CONSISTENCY,
+            CHECK_DEPS_NO_DEPENDENCY_CYCLES,
+            CHECK_DEPS_LINTS_CONFIGURED,
+            CHECK_DEPS_NO_STD_DEFAULT_FEATURES,
             CHECK_TOOL_RUNTIME,
         ];
         let codes = vec![
@@ -98,8 +363,60 @@ mod tests {
             CODE_DEV_DEP_IN_NORMAL,
             CODE_DEFAULT_FEATURES_IMPLICIT,
             CODE_DUPLICATE_DIFFERENT_VERSIONS,
+            CODE_MULTIPLE_VERSIONS,
             CODE_OPTIONAL_NOT_IN_FEATURES,
+            CODE_OPTIONAL_NEVER_ENABLED,
             CODE_VERSION_YANKED,
+            CODE_BANNED_CRATE,
+            CODE_DISALLOWED_REGISTRY,
+            CODE_GIT_BRANCH_UNPINNED,
+            CODE_DUPLICATE_DEPENDENCY_KINDS,
+            CODE_DEPS_UNSORTED,
+            CODE_MSRV_BELOW_FLOOR,
+            CODE_EDITION_BELOW_FLOOR,
+            CODE_MISSING_RUST_VERSION,
+            CODE_PATCH_SECTION_PRESENT,
+            CODE_REPLACE_SECTION_PRESENT,
+            CODE_DEFAULT_FEATURES_ENABLED,
+            CODE_PRERELEASE_VERSION,
+            CODE_EXACT_PIN_FORBIDDEN,
+            CODE_EXACT_PIN_REQUIRED,
+            CODE_DEV_DEP_USED_IN_SRC,
+            CODE_CRATE_NAME_DIR_MISMATCH,
+            CODE_SHOULD_INHERIT_WORKSPACE_DEP,
+            CODE_BUILD_DEPENDENCY_PRESENT,
+            CODE_DEPENDENCY_WITHOUT_VERSION,
+            CODE_EMPTY_FEATURES_WITH_DEFAULTS,
+            CODE_IMPLICIT_OPTIONAL_FEATURE,
+            CODE_TOO_MANY_DEPENDENCIES,
+            CODE_OPEN_ENDED_RANGE,
+            CODE_DEPENDS_ON_UNPUBLISHABLE,
+            CODE_INSECURE_GIT_URL,
+            CODE_INVALID_TARGET_CFG,
+            CODE_ZEROVER_DEPENDENCY_IN_STABLE_CRATE,
+            CODE_MISSING_LICENSE,
+            CODE_DISALLOWED_LICENSE,
+            CODE_DEPENDENCY_RENAMED,
+            CODE_DIVERGENT_VERSION_REQUIREMENTS,
+            CODE_OPTIONAL_DEV_DEPENDENCY,
+            CODE_GIT_WITH_VERSION,
+            CODE_INCONSISTENT_MSRV,
+            CODE_MSRV_NOT_INHERITED,
+            CODE_DEPENDENCY_MISSING_FROM_LOCK,
+            CODE_WORKSPACE_MEMBER_NOT_FOUND,
+            CODE_NESTED_WORKSPACE_DETECTED,
+            CODE_DEPRECATED_CRATE,
+            CODE_MISSING_PUBLISH_METADATA,
+            CODE_BANNED_FEATURE_ENABLED,
+            CODE_NO_FEATURES_AFTER_DEFAULT_DISABLED,
+            CODE_PATH_OUTSIDE_WORKSPACE,
+            CODE_BUILD_SCRIPT_WITHOUT_DEPS,
+            CODE_BUILD_DEPS_WITHOUT_SCRIPT,
+            CODE_DEPENDENCY_CYCLE,
+            CODE_SELF_DEPENDENCY,
+            CODE_MISSING_LINTS_TABLE,
+            CODE_LINTS_NOT_INHERITED,
+            CODE_DEFAULT_FEATURES_IN_NO_STD,
             CODE_RUNTIME_ERROR,
         ];
         let reasons = vec![
@@ -107,6 +424,12 @@ mod tests {
             REASON_CONFIG_MISSING_DEFAULTED,
             REASON_RUNTIME_ERROR,
             REASON_NO_MANIFEST_FOUND,
+            REASON_LOCKFILE_MISSING,
+            REASON_LOCKFILE_UNPARSEABLE,
+            REASON_SOURCE_SCAN_DISABLED,
+            REASON_FINDINGS_TRUNCATED,
+            REASON_GIT_METADATA_UNAVAILABLE,
+            REASON_NO_MANIFESTS_IN_SCOPE,
         ];
         let fix_actions = vec![
             FIX_ACTION_PIN_VERSION,
@@ -120,6 +443,49 @@ mod tests {
             FIX_ACTION_ALIGN_WORKSPACE_VERSIONS,
             FIX_ACTION_RESOLVE_OPTIONAL_FEATURE,
             FIX_ACTION_UPGRADE_YANKED_VERSION,
+            FIX_ACTION_REPLACE_BANNED_CRATE,
+            FIX_ACTION_USE_ALLOWED_REGISTRY,
+            FIX_ACTION_PIN_GIT_REV,
+            FIX_ACTION_REMOVE_DUPLICATE_DEP,
+            FIX_ACTION_SORT_DEPENDENCIES,
+            FIX_ACTION_RAISE_RUST_VERSION,
+            FIX_ACTION_RAISE_EDITION,
+            FIX_ACTION_ADD_RUST_VERSION,
+            FIX_ACTION_DEDUPE_RESOLVED_VERSIONS,
+            FIX_ACTION_REMOVE_DEPENDENCY_OVERRIDE,
+            FIX_ACTION_DISABLE_DEFAULT_FEATURES,
+            FIX_ACTION_USE_STABLE_VERSION,
+            FIX_ACTION_RELAX_EXACT_PIN,
+            FIX_ACTION_PIN_EXACT_VERSION,
+            FIX_ACTION_MOVE_TO_NORMAL_DEPS,
+            FIX_ACTION_RENAME_CRATE_OR_DIRECTORY,
+            FIX_ACTION_REMOVE_BUILD_DEPENDENCY,
+            FIX_ACTION_USE_DEP_PREFIX,
+            FIX_ACTION_REDUCE_DEPENDENCIES,
+            FIX_ACTION_PUBLISH_PATH_DEPENDENCY,
+            FIX_ACTION_USE_HTTPS_GIT_URL,
+            FIX_ACTION_FIX_TARGET_CFG,
+            FIX_ACTION_UPGRADE_ZEROVER_DEPENDENCY,
+            FIX_ACTION_ADD_LICENSE,
+            FIX_ACTION_CHANGE_LICENSE,
+            FIX_ACTION_REMOVE_DEPENDENCY_RENAME,
+            FIX_ACTION_ALIGN_VERSION_REQUIREMENTS,
+            FIX_ACTION_REMOVE_OPTIONAL_FLAG,
+            FIX_ACTION_REMOVE_GIT_VERSION,
+            FIX_ACTION_ALIGN_RUST_VERSION,
+            FIX_ACTION_INHERIT_RUST_VERSION,
+            FIX_ACTION_REGENERATE_LOCKFILE,
+            FIX_ACTION_FIX_WORKSPACE_MEMBER,
+            FIX_ACTION_MERGE_NESTED_WORKSPACE,
+            FIX_ACTION_REPLACE_DEPRECATED_CRATE,
+            FIX_ACTION_ADD_PUBLISH_METADATA,
+            FIX_ACTION_REMOVE_BANNED_FEATURE,
+            FIX_ACTION_ENABLE_NEEDED_FEATURES,
+            FIX_ACTION_USE_WORKSPACE_MEMBER_PATH,
+            FIX_ACTION_ALIGN_BUILD_SCRIPT,
+            FIX_ACTION_BREAK_DEPENDENCY_CYCLE,
+            FIX_ACTION_ADD_LINTS_TABLE,
+            FIX_ACTION_INHERIT_WORKSPACE_LINTS,
         ];
 
         for id in check_ids
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Normalization rules are intentionally simple and deterministic:
 /// - always forward slashes (`/`)
 /// - no leading `./`
+/// - no repeated separators (`a//b` becomes `a/b`)
 /// - never absolute (best-effort: absolute inputs are preserved but flagged by checks)
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
@@ -23,6 +24,10 @@ impl Default for RepoPath {
 impl RepoPath {
     pub fn new<S: AsRef<str>>(s: S) -> Self {
         let mut v = s.as_ref().replace('\\', "/");
+        // Collapse before stripping so `.//Cargo.toml` cannot turn absolute.
+        while v.contains("//") {
+            v = v.replace("//", "/");
+        }
         while v.starts_with("./") {
             v = v.trim_start_matches("./").to_string();
         }
@@ -72,6 +77,23 @@ mod tests {
         assert_eq!(path.as_str(), "Cargo.toml");
     }
 
+    #[test]
+    fn repo_path_canonicalizes_windows_and_redundant_forms() {
+        for (input, expected) in [
+            (r"crates\a\Cargo.toml", "crates/a/Cargo.toml"),
+            ("./Cargo.toml", "Cargo.toml"),
+            ("crates//a/Cargo.toml", "crates/a/Cargo.toml"),
+            (r".\\crates\\a//Cargo.toml", "crates/a/Cargo.toml"),
+        ] {
+            assert_eq!(RepoPath::new(input).as_str(), expected, "input: {input}");
+            assert_eq!(
+                RepoPath::from(Utf8Path::new(input)).as_str(),
+                expected,
+                "input: {input}"
+            );
+        }
+    }
+
     #[test]
     fn repo_path_empty_defaults_to_dot() {
         let path = RepoPath::new("");
@@ -58,6 +58,8 @@ pub enum Verdict {
     Pass,
     Warn,
     Fail,
+    /// Nothing in scope was evaluated, e.g. a diff that touches no manifest.
+    Skip,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -101,6 +103,10 @@ pub struct VerdictCounts {
     /// Count of findings suppressed by baseline filtering.
     #[serde(default, skip_serializing_if = "is_zero")]
     pub suppressed: u32,
+    /// Count of findings left out of the report by `max_findings`; the severity
+    /// counts above cover emitted findings only.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub truncated: u32,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -187,6 +193,15 @@ pub struct Capabilities {
     /// Configuration file status.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config: Option<CapabilityStatus>,
+    /// `Cargo.lock` status (resolved versions for lockfile-aware checks).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lockfile: Option<CapabilityStatus>,
+    /// Package source scan status (for checks that inspect `src/`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_scan: Option<CapabilityStatus>,
+    /// Run provenance status (`run.git` head commit detection).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<CapabilityStatus>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -274,6 +289,10 @@ pub struct DepguardData {
 
     pub findings_total: u32,
     pub findings_emitted: u32,
+    /// Findings dropped by inline `# depguard: allow(...)` comments; not part of
+    /// `findings_total`.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub findings_suppressed: u32,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub truncated_reason: Option<String>,
@@ -302,6 +321,8 @@ pub struct ReportEnvelope<TData = DepguardData> {
 pub type DepguardReportV1 = ReportEnvelope<DepguardData>;
 
 /// V2 report (cockpit-aligned envelope).
+///
+/// Field order is the serialized key order; reordering fields changes report bytes.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ReportEnvelopeV2<TData = DepguardData> {
     pub schema: String,
@@ -326,24 +347,28 @@ mod tests {
     use time::OffsetDateTime;
 
     #[test]
-    fn verdict_counts_skip_zero_suppressed() {
+    fn verdict_counts_skip_zero_suppressed_and_truncated() {
         let counts = VerdictCounts {
             info: 0,
             warn: 0,
             error: 0,
             suppressed: 0,
+            truncated: 0,
         };
         let value = serde_json::to_value(&counts).unwrap();
         assert!(value.get("suppressed").is_none());
+        assert!(value.get("truncated").is_none());
 
         let counts = VerdictCounts {
             info: 0,
             warn: 1,
             error: 0,
             suppressed: 2,
+            truncated: 3,
         };
         let value = serde_json::to_value(&counts).unwrap();
         assert_eq!(value["suppressed"], 2);
+        assert_eq!(value["truncated"], 3);
     }
 
     #[test]
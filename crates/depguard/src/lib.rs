@@ -19,11 +19,14 @@ pub mod report {
 }
 
 pub use checks::run_all;
-pub use depguard_domain::evaluate;
+pub use depguard_domain::{evaluate, evaluate_manifest, evaluate_workspace, report_from_findings};
 pub use model::*;
 pub use policy::*;
 pub use report::{DomainReport, SeverityCounts};
 
+#[cfg(feature = "parallel")]
+pub use depguard_domain::evaluate_parallel;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,6 +40,7 @@ mod tests {
             max_findings: 100,
             yanked_index: None,
             checks: BTreeMap::new(),
+            verdict_ignores: Vec::new(),
         }
     }
 
@@ -57,6 +61,7 @@ mod tests {
             max_findings: 100,
             yanked_index: None,
             checks: BTreeMap::new(),
+            verdict_ignores: Vec::new(),
         };
         let mut findings = Vec::new();
         checks::run_all(&model, &cfg, &mut findings);
@@ -34,10 +34,16 @@ struct DependencySpecInput {
     workspace: bool,
     /// Default features flag
     default_features: Option<bool>,
+    /// Feature list
+    features: Option<Vec<String>>,
+    /// Emit `features` as a table instead of an array (malformed)
+    features_as_table: bool,
     /// Optional flag
     optional: bool,
     /// Package rename
     package: Option<String>,
+    /// Alternate registry name
+    registry: Option<String>,
 }
 
 /// Input type for generating manifests with various dependency formats.
@@ -62,6 +68,11 @@ fuzz_target!(|input: DependencySpecInput| {
         || input.tag.as_ref().map_or(false, |t| t.len() > 256)
         || input.rev.as_ref().map_or(false, |r| r.len() > 128)
         || input.package.as_ref().map_or(false, |p| p.len() > 256)
+        || input.registry.as_ref().map_or(false, |r| r.len() > 256)
+        || input
+            .features
+            .as_ref()
+            .map_or(false, |f| f.len() > 64 || f.iter().any(|f| f.len() > 256))
     {
         return;
     }
@@ -76,6 +87,20 @@ fuzz_target!(|input: DependencySpecInput| {
     let _ = depguard_repo::fuzz::parse_root_manifest(&manifest);
 });
 
+/// Render a `features` entry, optionally as a (malformed) table.
+fn format_features(features: &[String], as_table: bool) -> String {
+    let quoted: Vec<String> = features
+        .iter()
+        .map(|f| format!("\"{}\"", f.escape_default()))
+        .collect();
+    if as_table {
+        let entries: Vec<String> = quoted.iter().map(|f| format!("{f} = true")).collect();
+        format!("features = {{ {} }}", entries.join(", "))
+    } else {
+        format!("features = [{}]", quoted.join(", "))
+    }
+}
+
 /// Build a Cargo.toml manifest from a dependency specification.
 fn build_manifest_from_spec(spec: &DependencySpecInput) -> String {
     let name = if spec.name.is_empty() {
@@ -90,8 +115,10 @@ fn build_manifest_from_spec(spec: &DependencySpecInput) -> String {
             && spec.git.is_none()
             && !spec.workspace
             && spec.default_features.is_none()
+            && spec.features.is_none()
             && !spec.optional
             && spec.package.is_none()
+            && spec.registry.is_none()
         {
             // Simple string form
             format!("\"{}\"", version.escape_default())
@@ -121,12 +148,18 @@ fn build_manifest_from_spec(spec: &DependencySpecInput) -> String {
             if let Some(df) = spec.default_features {
                 parts.push(format!("default-features = {}", df));
             }
+            if let Some(features) = &spec.features {
+                parts.push(format_features(features, spec.features_as_table));
+            }
             if spec.optional {
                 parts.push("optional = true".to_string());
             }
             if let Some(pkg) = &spec.package {
                 parts.push(format!("package = \"{}\"", pkg.escape_default()));
             }
+            if let Some(registry) = &spec.registry {
+                parts.push(format!("registry = \"{}\"", registry.escape_default()));
+            }
 
             format!("{{ {} }}", parts.join(", "))
         }
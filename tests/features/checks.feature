@@ -235,6 +235,41 @@ Feature: Individual check behaviors
     When I run the check
     Then a finding is emitted with check_id "deps.no_multiple_versions" and code "duplicate_different_versions"
 
+  Scenario: Crates resolved at several versions in Cargo.lock are flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+      """
+    And a Cargo.lock with:
+      """
+      version = 4
+
+      [[package]]
+      name = "syn"
+      version = "1.0.109"
+
+      [[package]]
+      name = "syn"
+      version = "2.0.48"
+
+      [[package]]
+      name = "windows-sys"
+      version = "0.48.0"
+
+      [[package]]
+      name = "windows-sys"
+      version = "0.52.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_multiple_versions"]
+      enabled = true
+      ignore = ["windows-sys"]
+      """
+    When I run the check
+    Then the report contains 1 findings with code "multiple_versions"
+
   Scenario: Single version of a crate passes
     Given a Cargo.toml with:
       """
@@ -293,6 +328,24 @@ Feature: Individual check behaviors
     When I run the check
     Then no finding is emitted for "deps.optional_unused"
 
+  Scenario: Optional dependency referenced only weakly is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = { version = "1.0", optional = true }
+
+      [features]
+      derive = ["serde?/derive"]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.optional_unused"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.optional_unused" and code "optional_never_enabled"
+
   # ===========================================================================
   # deps.dev_only_in_normal
   # ===========================================================================
@@ -326,3 +379,1769 @@ Feature: Individual check behaviors
       """
     When I run the check
     Then no finding is emitted for "deps.dev_only_in_normal"
+
+  # ===========================================================================
+  # deps.banned_crates
+  # ===========================================================================
+
+  Scenario: Dependency on a denied crate is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      openssl = "0.10"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.banned_crates"]
+      enabled = true
+      severity = "error"
+      deny = ["openssl*"]
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.banned_crates" and code "banned_crate"
+
+  Scenario: Dependency not on the deny list passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      rustls = "0.23"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.banned_crates"]
+      enabled = true
+      severity = "error"
+      deny = ["openssl*"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.banned_crates"
+
+  # ===========================================================================
+  # deps.allowed_registries
+  # ===========================================================================
+
+  Scenario: Dependency from a registry outside the allowlist is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      internal-utils = { version = "1.2", registry = "my-mirror" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.allowed_registries"]
+      enabled = true
+      severity = "error"
+      allow = ["internal"]
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.allowed_registries" and code "disallowed_registry"
+
+  Scenario: Allowlisted registry and crates.io dependencies pass
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      internal-utils = { version = "1.2", registry = "internal" }
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.allowed_registries"]
+      enabled = true
+      severity = "error"
+      allow = ["internal"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.allowed_registries"
+
+  # ===========================================================================
+  # deps.git_pinned
+  # ===========================================================================
+
+  Scenario: Git dependency tracking a branch is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      my-lib = { git = "https://github.com/org/my-lib", branch = "main" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.git_pinned"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.git_pinned" and code "git_branch_unpinned"
+
+  Scenario: Git dependency pinned to a rev passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      my-lib = { git = "https://github.com/org/my-lib", rev = "4f2c1e9" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.git_pinned"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then no finding is emitted for "deps.git_pinned"
+
+  # ===========================================================================
+  # deps.duplicate_across_kinds
+  # ===========================================================================
+
+  Scenario: Crate declared in both dependencies and dev-dependencies is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+
+      [dev-dependencies]
+      serde = { version = "1.0", features = ["derive"] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.duplicate_across_kinds"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.duplicate_across_kinds" and code "duplicate_dependency_kinds"
+
+  # ===========================================================================
+  # deps.sorted
+  # ===========================================================================
+
+  Scenario: Unsorted dependency table is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+      anyhow = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.sorted"]
+      enabled = true
+      severity = "warning"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.sorted" and code "deps_unsorted"
+
+  Scenario: Target tables are sorted independently
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      anyhow = "1.0"
+      serde = "1.0"
+
+      [target.'cfg(unix)'.dependencies]
+      libc = "0.2"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.sorted"]
+      enabled = true
+      severity = "warning"
+      """
+    When I run the check
+    Then no finding is emitted for "deps.sorted"
+
+  # ===========================================================================
+  # deps.msrv_floor
+  # ===========================================================================
+
+  Scenario: Package below the rust-version floor is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "old-msrv"
+      version = "0.1.0"
+      edition = "2021"
+      rust-version = "1.70"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.msrv_floor"]
+      enabled = true
+      min_rust_version = "1.74"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.msrv_floor" and code "msrv_below_floor"
+
+  Scenario: Package without rust-version is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "no-msrv"
+      version = "0.1.0"
+      edition = "2021"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.msrv_floor"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.msrv_floor" and code "missing_rust_version"
+
+  # ===========================================================================
+  # deps.no_patch_override
+  # ===========================================================================
+
+  Scenario: Publishable package with a patch section is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "patched"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      serde = "1.0"
+
+      [patch.crates-io]
+      serde = { path = "../serde" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_patch_override"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_patch_override" and code "patch_section_present"
+
+  Scenario: Unpublished package may carry a replace section
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "internal"
+      version = "0.1.0"
+      edition = "2021"
+      publish = false
+
+      [replace]
+      "foo:0.1.0" = { path = "../foo" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_patch_override"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_patch_override"
+
+  # ===========================================================================
+  # deps.no_default_features
+  # ===========================================================================
+
+  Scenario: Configured crate without default-features = false is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tokio = { version = "1", features = ["rt"] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_default_features"]
+      enabled = true
+      severity = "error"
+      deny = ["tokio"]
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_default_features" and code "default_features_enabled"
+
+  Scenario: Configured crate with default-features = false passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tokio = { version = "1", default-features = false, features = ["rt"] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_default_features"]
+      enabled = true
+      severity = "error"
+      deny = ["tokio"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_default_features"
+
+  # ===========================================================================
+  # deps.no_prerelease
+  # ===========================================================================
+
+  Scenario: Prerelease requirement in a publishable package is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tokio = "1.0.0-beta.1"
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_prerelease"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_prerelease" and code "prerelease_version"
+
+  Scenario: Prerelease requirement in an unpublished package is ignored
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "internal-tool"
+      version = "0.1.0"
+      edition = "2021"
+      publish = false
+
+      [dependencies]
+      tokio = ">=1.0.0-rc.1, <2"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_prerelease"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_prerelease"
+
+  # ===========================================================================
+  # deps.version_pinning
+  # ===========================================================================
+
+  Scenario: Exact pin is flagged when mode is forbid_exact
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "=1.0.200"
+      tokio = "1"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.version_pinning"]
+      enabled = true
+      severity = "error"
+      mode = "forbid_exact"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.version_pinning" and code "exact_pin_forbidden"
+
+  Scenario: Caret requirement is flagged when mode is require_exact
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tokio = "1"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.version_pinning"]
+      enabled = true
+      severity = "error"
+      mode = "require_exact"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.version_pinning" and code "exact_pin_required"
+
+  Scenario: Version pinning without a mode reports nothing
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "=1.0.200"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.version_pinning"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.version_pinning"
+
+  # ===========================================================================
+  # deps.dev_dep_leakage
+  # ===========================================================================
+
+  Scenario: Dev-only crate used from library source is flagged
+    Given a Cargo.toml with:
+      """
+      [dev-dependencies]
+      serde_json = "1"
+      """
+    And a source file "src/lib.rs" with:
+      """
+      use serde_json::Value;
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.dev_dep_leakage"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.dev_dep_leakage" and code "dev_dep_used_in_src"
+
+  Scenario: Dev-only crate used only in test code is ignored
+    Given a Cargo.toml with:
+      """
+      [dev-dependencies]
+      proptest = "1"
+      """
+    And a source file "src/lib.rs" with:
+      """
+      pub fn add(a: u32, b: u32) -> u32 {
+          a + b
+      }
+
+      #[cfg(test)]
+      mod tests {
+          use proptest::prelude::*;
+      }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.dev_dep_leakage"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.dev_dep_leakage"
+
+  # ===========================================================================
+  # deps.crate_dir_name
+  # ===========================================================================
+
+  Scenario: Member crate named differently from its directory is flagged
+    Given a workspace fixture "crate_dir_name"
+    When I run the check
+    Then a finding is emitted with check_id "deps.crate_dir_name" and code "crate_name_dir_mismatch"
+
+  Scenario: Member crate matching its directory passes
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+      """
+    And a member Cargo.toml with:
+      """
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.crate_dir_name"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.crate_dir_name"
+
+  # ===========================================================================
+  # deps.workspace_version_override
+  # ===========================================================================
+
+  Scenario: Member pinning its own version of a workspace dependency is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [workspace.dependencies]
+      serde = "1.0.200"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0.150"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.workspace_version_override"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.workspace_version_override" and code "should_inherit_workspace_dep"
+
+  Scenario: Allowlisted version override passes
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [workspace.dependencies]
+      serde = "1.0.200"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0.150"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.workspace_version_override"]
+      enabled = true
+      allow = ["serde"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.workspace_version_override"
+
+  # ===========================================================================
+  # deps.restrict_build_deps
+  # ===========================================================================
+
+  Scenario: Build dependency outside the allowlist is flagged
+    Given a workspace fixture "restrict_build_deps"
+    When I run the check
+    Then a finding is emitted with check_id "deps.restrict_build_deps" and code "build_dependency_present"
+
+  Scenario: Allowlisted build dependency passes
+    Given a Cargo.toml with:
+      """
+      [build-dependencies]
+      cc = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.restrict_build_deps"]
+      enabled = true
+      allow = ["cc"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.restrict_build_deps"
+
+  # ===========================================================================
+  # deps.version_required
+  # ===========================================================================
+
+  Scenario: Git dependency without a version is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      foo = { git = "https://github.com/org/foo", branch = "main" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.version_required"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.version_required" and code "dependency_without_version"
+
+  Scenario: Workspace-inherited dependency is exempt from version_required
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [workspace.dependencies]
+      serde = "1.0"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      serde = { workspace = true }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.version_required"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.version_required"
+
+  # ===========================================================================
+  # deps.misleading_empty_features
+  # ===========================================================================
+
+  Scenario: Empty features list with default features enabled is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = { version = "1.0", features = [] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.misleading_empty_features"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.misleading_empty_features" and code "empty_features_with_defaults"
+
+  Scenario: Empty features list with default features disabled passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = { version = "1.0", default-features = false, features = [] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.misleading_empty_features"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.misleading_empty_features"
+
+  # ===========================================================================
+  # deps.features_after_default_off
+  # ===========================================================================
+
+  Scenario: Default features disabled without any features is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = { version = "1.0", default-features = false }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.features_after_default_off"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.features_after_default_off" and code "no_features_after_default_disabled"
+
+  Scenario: Default features disabled with replacement features passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = { version = "1.0", default-features = false, features = ["derive"] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.features_after_default_off"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.features_after_default_off"
+
+  # ===========================================================================
+  # deps.explicit_dep_feature
+  # ===========================================================================
+
+  Scenario: Optional dependency enabled by its implicit feature is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      rustls = { version = "0.23", optional = true }
+
+      [features]
+      tls = ["rustls"]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.explicit_dep_feature"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.explicit_dep_feature" and code "implicit_optional_feature"
+
+  Scenario: Optional dependency enabled with dep: passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      rustls = { version = "0.23", optional = true }
+
+      [features]
+      tls = ["dep:rustls"]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.explicit_dep_feature"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.explicit_dep_feature"
+
+  # ===========================================================================
+  # deps.max_direct_deps
+  # ===========================================================================
+
+  Scenario: Package over the direct dependency limit is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      anyhow = "1.0"
+      serde = "1.0"
+      tokio = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.max_direct_deps"]
+      enabled = true
+      max = 2
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.max_direct_deps" and code "too_many_dependencies"
+
+  Scenario: Dev-dependencies do not count toward max unless max_dev is set
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+
+      [dev-dependencies]
+      proptest = "1.0"
+      insta = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.max_direct_deps"]
+      enabled = true
+      max = 2
+      """
+    When I run the check
+    Then no finding is emitted for "deps.max_direct_deps"
+
+  # ===========================================================================
+  # deps.no_open_ranges
+  # ===========================================================================
+
+  Scenario: Open-ended version requirement is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = ">=1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_open_ranges"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_open_ranges" and code "open_ended_range"
+
+  Scenario: Bounded version range passes no_open_ranges
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = ">=1.0, <2"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_open_ranges"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_open_ranges"
+
+  # ===========================================================================
+  # deps.publish_reachability
+  # ===========================================================================
+
+  Scenario: Publishable package depending on an unpublishable member is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+
+      [dependencies]
+      member = { path = "member", version = "0.1.0" }
+      """
+    And a member Cargo.toml with:
+      """
+      publish = false
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.publish_reachability"]
+      enabled = true
+      severity = "error"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.publish_reachability" and code "depends_on_unpublishable"
+
+  Scenario: Dev-dependency on an unpublishable member passes publish_reachability
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+
+      [dev-dependencies]
+      member = { path = "member", version = "0.1.0" }
+      """
+    And a member Cargo.toml with:
+      """
+      publish = false
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.publish_reachability"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.publish_reachability"
+
+  # ===========================================================================
+  # deps.secure_git_url
+  # ===========================================================================
+
+  Scenario: Git dependency over http is flagged by the strict profile
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      my-crate = { git = "http://github.com/org/my-crate", rev = "abc123" }
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.secure_git_url" and code "insecure_git_url"
+
+  Scenario: Git dependency over https passes secure_git_url
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      my-crate = { git = "https://github.com/org/my-crate", rev = "abc123" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.secure_git_url"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.secure_git_url"
+
+  # ===========================================================================
+  # deps.valid_target_cfg
+  # ===========================================================================
+
+  Scenario: Malformed cfg predicate in a target table is flagged
+    Given a Cargo.toml with:
+      """
+      [target.'cfg(feature = )'.dependencies]
+      libc = "0.2"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.valid_target_cfg" and code "invalid_target_cfg"
+
+  Scenario: Well-formed cfg predicates and target triples pass valid_target_cfg
+    Given a Cargo.toml with:
+      """
+      [target.'cfg(all(unix, not(target_os = "macos")))'.dependencies]
+      libc = "0.2"
+
+      [target.x86_64-pc-windows-msvc.dependencies]
+      winapi = "0.3"
+      """
+    When I run the check
+    Then no finding is emitted for "deps.valid_target_cfg"
+
+  # ===========================================================================
+  # deps.no_zerover_in_stable
+  # ===========================================================================
+
+  Scenario: Pre-1.0 dependency of a 1.0 crate is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "stable-crate"
+      version = "1.2.0"
+
+      [dependencies]
+      bytes = "0.4"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_zerover_in_stable"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_zerover_in_stable" and code "zerover_dependency_in_stable_crate"
+
+  Scenario: Allowlisted pre-1.0 dependency passes no_zerover_in_stable
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "stable-crate"
+      version = "1.2.0"
+
+      [dependencies]
+      libc = "0.2"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_zerover_in_stable"]
+      enabled = true
+      allow = ["libc"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_zerover_in_stable"
+
+  # ===========================================================================
+  # deps.license_present
+  # ===========================================================================
+
+  Scenario: Publishable crate without license metadata is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "unlicensed"
+      version = "0.1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.license_present"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.license_present" and code "missing_license"
+
+  Scenario: License outside allowed_licenses is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "copyleft"
+      version = "0.1.0"
+      license = "GPL-3.0-only"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.license_present"]
+      enabled = true
+      allowed_licenses = ["MIT", "Apache-2.0"]
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.license_present" and code "disallowed_license"
+
+  # ===========================================================================
+  # deps.no_rename
+  # ===========================================================================
+
+  Scenario: Renamed dependency is reported by no_rename
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      http-client = { package = "reqwest", version = "0.12" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_rename"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_rename" and code "dependency_renamed"
+
+  Scenario: Banned crate hidden behind a rename is still flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tls = { package = "openssl", version = "0.10" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.banned_crates"]
+      enabled = true
+      deny = ["openssl"]
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.banned_crates" and code "banned_crate"
+
+  # ===========================================================================
+  # deps.consistent_versions
+  # ===========================================================================
+
+  Scenario: Root and member requiring incompatible versions is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+
+      [dependencies]
+      rand = "0.8"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      rand = "0.9"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.consistent_versions"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.consistent_versions" and code "divergent_version_requirements"
+
+  Scenario: Compatible requirements pass consistent_versions
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+
+      [dependencies]
+      serde = "1.0"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.4"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.consistent_versions"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.consistent_versions"
+
+  # ===========================================================================
+  # deps.no_optional_dev_deps
+  # ===========================================================================
+
+  Scenario: Optional dev-dependency is flagged in the strict profile
+    Given a Cargo.toml with:
+      """
+      [dev-dependencies]
+      foo = { version = "1", optional = true }
+      """
+    And a depguard.toml with:
+      """
+      profile = "strict"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_optional_dev_deps" and code "optional_dev_dependency"
+
+  Scenario: Optional normal dependency passes no_optional_dev_deps
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      foo = { version = "1", optional = true }
+
+      [features]
+      extra = ["dep:foo"]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_optional_dev_deps"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_optional_dev_deps"
+
+  # ===========================================================================
+  # deps.git_version_conflict
+  # ===========================================================================
+
+  Scenario: Git dependency with a version is flagged when enabled
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      foo = { git = "https://github.com/org/foo", tag = "v2.0.0", version = "2.0" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.git_version_conflict"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.git_version_conflict" and code "git_with_version"
+
+  Scenario: Git dependency without a version passes git_version_conflict
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      foo = { git = "https://github.com/org/foo", tag = "v2.0.0" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.git_version_conflict"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.git_version_conflict"
+
+  Scenario: git_version_conflict is disabled by default
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      foo = { git = "https://github.com/org/foo", tag = "v2.0.0", version = "2.0" }
+      """
+    When I run the check
+    Then no finding is emitted for "deps.git_version_conflict"
+
+  # ===========================================================================
+  # deps.consistent_msrv
+  # ===========================================================================
+
+  Scenario: Members with different rust-version values are flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+      edition = "2021"
+      rust-version = "1.70"
+      """
+    And a member Cargo.toml with:
+      """
+      rust-version = "1.74"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.consistent_msrv"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.consistent_msrv" and code "inconsistent_msrv"
+
+  Scenario: Member inheriting rust-version passes require_inherit
+    Given a workspace Cargo.toml with:
+      """
+      [workspace.package]
+      rust-version = "1.74"
+      """
+    And a member Cargo.toml with:
+      """
+      rust-version.workspace = true
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.consistent_msrv"]
+      enabled = true
+      mode = "require_inherit"
+      """
+    When I run the check
+    Then no finding is emitted for "deps.consistent_msrv"
+
+  Scenario: Member declaring its own rust-version fails require_inherit
+    Given a workspace Cargo.toml with:
+      """
+      [workspace.package]
+      rust-version = "1.74"
+      """
+    And a member Cargo.toml with:
+      """
+      rust-version = "1.74"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.consistent_msrv"]
+      enabled = true
+      mode = "require_inherit"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.consistent_msrv" and code "msrv_not_inherited"
+
+  Scenario: Declared dependency missing from Cargo.lock is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+      tokio = "1"
+      """
+    And a Cargo.lock with:
+      """
+      version = 4
+
+      [[package]]
+      name = "serde"
+      version = "1.0.200"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.lock_in_sync"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.lock_in_sync" and code "dependency_missing_from_lock"
+
+  Scenario: Cargo.lock covering every declared dependency passes lock_in_sync
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+      """
+    And a Cargo.lock with:
+      """
+      version = 4
+
+      [[package]]
+      name = "serde"
+      version = "1.0.200"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.lock_in_sync"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.lock_in_sync"
+
+  Scenario: lock_in_sync is skipped when there is no Cargo.lock
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.lock_in_sync"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.lock_in_sync"
+
+  Scenario: Workspace member entry without a manifest is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member", "crates/missing"]
+      """
+    And a member Cargo.toml with:
+      """
+      license = "MIT"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.members_exist"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.members_exist" and code "workspace_member_not_found"
+
+  Scenario: Workspace whose members all exist passes members_exist
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+      """
+    And a member Cargo.toml with:
+      """
+      license = "MIT"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.members_exist"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.members_exist"
+
+  Scenario: Crate declaring its own workspace below the root is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["tools"]
+      """
+    And a nested crate at "tools" with:
+      """
+      [workspace]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_nested_workspace"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_nested_workspace" and code "nested_workspace_detected"
+
+  Scenario: Nested workspace listed in the root exclude passes no_nested_workspace
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = []
+      exclude = ["tools"]
+      """
+    And a nested crate at "tools" with:
+      """
+      [workspace]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_nested_workspace"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_nested_workspace"
+
+  # ===========================================================================
+  # deps.deprecated_crates
+  # ===========================================================================
+
+  Scenario: Dependency on a deprecated crate is flagged with its replacement
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde_yaml = "0.9"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.deprecated_crates"]
+      enabled = true
+
+      [checks."deps.deprecated_crates".options.deprecated]
+      serde_yaml = "serde_yml"
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.deprecated_crates" and code "deprecated_crate"
+
+  Scenario: Dependency not in the deprecation map passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      serde_yml = "0.0.12"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.deprecated_crates"]
+      enabled = true
+
+      [checks."deps.deprecated_crates".options.deprecated]
+      serde_yaml = "serde_yml"
+      """
+    When I run the check
+    Then no finding is emitted for "deps.deprecated_crates"
+
+  # ===========================================================================
+  # deps.publish_metadata
+  # ===========================================================================
+
+  Scenario: Publishable crate without description or repository is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "undocumented"
+      version = "0.1.0"
+      license = "MIT"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.publish_metadata"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.publish_metadata" and code "missing_publish_metadata"
+
+  Scenario: Configured require list is satisfied
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "documented"
+      version = "0.1.0"
+      description = "Parses widgets"
+      keywords = ["parser"]
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.publish_metadata"]
+      enabled = true
+      options = { require = ["description", "keywords"] }
+      """
+    When I run the check
+    Then no finding is emitted for "deps.publish_metadata"
+
+  Scenario: Unpublished crate is not checked for publish metadata
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "internal"
+      version = "0.1.0"
+      publish = false
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.publish_metadata"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.publish_metadata"
+
+  # ===========================================================================
+  # deps.banned_features
+  # ===========================================================================
+
+  Scenario: Dependency enabling a banned feature is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tokio = { version = "1", features = ["rt", "process"] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.banned_features"]
+      enabled = true
+
+      [checks."deps.banned_features".options.features]
+      tokio = { features = ["process"], reason = "spawning processes needs review" }
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.banned_features" and code "banned_feature_enabled"
+
+  Scenario: Banned default feature is flagged unless default-features is disabled
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      reqwest = "0.12"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.banned_features"]
+      enabled = true
+
+      [checks."deps.banned_features".options.features]
+      reqwest = ["default"]
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.banned_features" and code "banned_feature_enabled"
+
+  Scenario: Dependency without banned features passes
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      tokio = { version = "1", features = ["rt"] }
+      reqwest = { version = "0.12", default-features = false, features = ["rustls-tls"] }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.banned_features"]
+      enabled = true
+
+      [checks."deps.banned_features".options.features]
+      tokio = ["process"]
+      reqwest = ["default"]
+      """
+    When I run the check
+    Then no finding is emitted for "deps.banned_features"
+
+  # ===========================================================================
+  # deps.path_within_workspace
+  # ===========================================================================
+
+  Scenario: Path dependency outside the repository is flagged
+    Given a Cargo.toml with:
+      """
+      [dependencies]
+      external = { path = "../external" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.path_within_workspace"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.path_within_workspace" and code "path_outside_workspace"
+
+  Scenario: Path dependency on a crate that is not a workspace member is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace.dependencies]
+      serde = "1.0"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      vendored = { path = "../vendor/vendored" }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.path_within_workspace"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.path_within_workspace" and code "path_outside_workspace"
+
+  Scenario: Path dependency on a workspace member passes
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+      edition = "2021"
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      root = { path = ".." }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.path_within_workspace"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.path_within_workspace"
+
+  # ===========================================================================
+  # deps.build_script_consistency
+  # ===========================================================================
+
+  Scenario: Build script setting without build dependencies is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "gen"
+      version = "0.1.0"
+      edition = "2021"
+      build = "build.rs"
+
+      [dependencies]
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.build_script_consistency"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.build_script_consistency" and code "build_script_without_deps"
+
+  Scenario: Build dependencies with the build script disabled are flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "gen"
+      version = "0.1.0"
+      edition = "2021"
+      build = false
+
+      [build-dependencies]
+      cc = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.build_script_consistency"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.build_script_consistency" and code "build_deps_without_script"
+
+  Scenario: Build script with build dependencies passes
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "gen"
+      version = "0.1.0"
+      edition = "2021"
+      build = "build.rs"
+
+      [build-dependencies]
+      cc = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.build_script_consistency"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.build_script_consistency"
+
+  # ===========================================================================
+  # deps.no_dependency_cycles
+  # ===========================================================================
+
+  Scenario: Package depending on itself is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "app"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      app = { path = "." }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_dependency_cycles"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_dependency_cycles" and code "self_dependency"
+
+  Scenario: Path dependency cycle between workspace members is flagged
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      member = { path = "member" }
+      """
+    And a member Cargo.toml with:
+      """
+      [dependencies]
+      root = { path = ".." }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_dependency_cycles"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_dependency_cycles" and code "dependency_cycle"
+
+  Scenario: Dev-dependency back to a workspace member passes
+    Given a workspace Cargo.toml with:
+      """
+      [workspace]
+      members = ["member"]
+
+      [package]
+      name = "root"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      member = { path = "member" }
+      """
+    And a member Cargo.toml with:
+      """
+      [dev-dependencies]
+      root = { path = ".." }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_dependency_cycles"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_dependency_cycles"
+
+  # ===========================================================================
+  # deps.lints_configured
+  # ===========================================================================
+
+  Scenario: Package without a lints table is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "app"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.lints_configured"]
+      enabled = true
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.lints_configured" and code "missing_lints_table"
+
+  Scenario: Package with its own lint tables passes by default
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "app"
+      version = "0.1.0"
+      edition = "2021"
+
+      [lints.clippy]
+      unwrap_used = "deny"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.lints_configured"]
+      enabled = true
+      """
+    When I run the check
+    Then no finding is emitted for "deps.lints_configured"
+
+  Scenario: Package not inheriting workspace lints is flagged when required
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "app"
+      version = "0.1.0"
+      edition = "2021"
+
+      [lints.clippy]
+      unwrap_used = "deny"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.lints_configured"]
+      enabled = true
+      options = { require_workspace = true }
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.lints_configured" and code "lints_not_inherited"
+
+  # ===========================================================================
+  # deps.no_std_default_features
+  # ===========================================================================
+
+  Scenario: Dependency of a no_std crate keeping default features is flagged
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "my-embedded"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_std_default_features"]
+      enabled = true
+      options = { no_std_crates = ["my-embedded"] }
+      """
+    When I run the check
+    Then a finding is emitted with check_id "deps.no_std_default_features" and code "default_features_in_no_std"
+
+  Scenario: Dependency of a no_std crate with default features disabled passes
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "my-embedded"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      serde = { version = "1.0", default-features = false }
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_std_default_features"]
+      enabled = true
+      options = { no_std_crates = ["my-embedded"] }
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_std_default_features"
+
+  Scenario: Crates not listed as no_std are not checked
+    Given a Cargo.toml with:
+      """
+      [package]
+      name = "app"
+      version = "0.1.0"
+      edition = "2021"
+
+      [dependencies]
+      serde = "1.0"
+      """
+    And a depguard.toml with:
+      """
+      [checks."deps.no_std_default_features"]
+      enabled = true
+      options = { no_std_crates = ["my-embedded"] }
+      """
+    When I run the check
+    Then no finding is emitted for "deps.no_std_default_features"
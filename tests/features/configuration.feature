@@ -107,6 +107,17 @@ Feature: Configuration and profiles
     Then the exit code is 2
     And the verdict is "fail"
 
+  Scenario: verdict_ignores keeps warnings out of the verdict
+    Given a workspace with warning-level findings
+    And a depguard.toml with:
+      """
+      fail_on = "warning"
+      verdict_ignores = ["warning"]
+      """
+    When I run "depguard check --repo-root ."
+    Then the exit code is 0
+    And the verdict is "pass"
+
   # ===========================================================================
   # Max findings limit
   # ===========================================================================
@@ -18,6 +18,20 @@ Feature: Diff-scoped analysis
     Then only "crates/changed/Cargo.toml" is analyzed
     And no findings are reported for unchanged files
 
+  Scenario: --since diffs HEAD against its merge-base
+    Given the following files changed between base and head:
+      | file                      |
+      | crates/changed/Cargo.toml |
+    And "crates/unchanged/Cargo.toml" has violations
+    When I run "depguard check --scope diff --since main"
+    Then only "crates/changed/Cargo.toml" is analyzed
+    And no findings are reported for unchanged files
+
+  Scenario: --since with an unknown ref explains how to fetch history
+    When I run "depguard check --scope diff --since origin/missing"
+    Then the exit code is 1
+    And stderr contains "git base revision 'origin/missing' is not reachable"
+
   Scenario: Full repo scope analyzes all files
     Given the following files changed between base and head:
       | file                      |